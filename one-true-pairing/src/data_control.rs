@@ -0,0 +1,295 @@
+//! Abstraction over the Wayland data-control protocols.
+//!
+//! `ext-data-control-v1` is the standardized successor of `wlr-data-control-unstable-v1`; both are
+//! identical on the wire except for the interface names, but older compositors only offer the
+//! latter.
+
+
+use std::os::fd::{FromRawFd, OwnedFd, RawFd};
+
+use whale_land::{Connection, Error, GlobalList, ObjectId, Packet};
+use whale_land::protocol::Proxy;
+use whale_land::protocol::ext_data_control_v1::{
+    ext_data_control_device_v1,
+    ext_data_control_device_v1_event,
+    ext_data_control_device_v1_request_set_primary_selection_args,
+    ext_data_control_manager_v1,
+    ext_data_control_offer_v1,
+    ext_data_control_offer_v1_event,
+    ext_data_control_offer_v1_interface,
+    ext_data_control_source_v1,
+    ext_data_control_source_v1_event,
+};
+use whale_land::protocol::wlr_data_control_unstable_v1::{
+    zwlr_data_control_device_v1,
+    zwlr_data_control_device_v1_event,
+    zwlr_data_control_device_v1_request_set_primary_selection_args,
+    zwlr_data_control_manager_v1,
+    zwlr_data_control_offer_v1,
+    zwlr_data_control_offer_v1_event,
+    zwlr_data_control_offer_v1_interface,
+    zwlr_data_control_source_v1,
+    zwlr_data_control_source_v1_event,
+};
+
+
+/// The data-control protocol offered by the compositor.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataControlProtocol {
+    /// `ext-data-control-v1`
+    Ext,
+
+    /// `wlr-data-control-unstable-v1`
+    Wlr,
+}
+
+/// An event sent to a data-control device.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DeviceEvent {
+    DataOffer(ObjectId),
+    Selection(Option<ObjectId>),
+    Finished,
+    PrimarySelection(Option<ObjectId>),
+}
+
+/// An event sent to a data-control source.
+#[derive(Debug)]
+pub enum SourceEvent {
+    Send { mime_type: String, fd: OwnedFd },
+    Cancelled,
+}
+
+/// An event sent to a data-control offer.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum OfferEvent {
+    Offer { mime_type: String },
+}
+
+/// A data-control manager.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataControlManager {
+    Ext(ext_data_control_manager_v1),
+    Wlr(zwlr_data_control_manager_v1),
+}
+
+/// A data-control device, which manages the selections of a seat.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataControlDevice {
+    Ext(ext_data_control_device_v1),
+    Wlr(zwlr_data_control_device_v1),
+}
+
+/// A data-control source, through which we provide the contents of a selection.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataControlSource {
+    Ext(ext_data_control_source_v1),
+    Wlr(zwlr_data_control_source_v1),
+}
+
+/// A data-control offer, through which we obtain the contents of a selection.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DataControlOffer {
+    Ext(ext_data_control_offer_v1),
+    Wlr(zwlr_data_control_offer_v1),
+}
+
+
+/// Evaluates the expression with the proxy wrapped by either variant of a data-control object.
+macro_rules! with_proxy {
+    ($object:expr, $proxy:ident => $body:expr) => {
+        match $object {
+            Self::Ext($proxy) => $body,
+            Self::Wlr($proxy) => $body,
+        }
+    };
+}
+
+
+impl DataControlProtocol {
+    /// Returns the protocol whose manager global has the given interface name.
+    pub fn try_from_manager_interface(interface: &str) -> Option<Self> {
+        match interface {
+            "ext_data_control_manager_v1" => Some(Self::Ext),
+            "zwlr_data_control_manager_v1" => Some(Self::Wlr),
+            _ => None,
+        }
+    }
+
+    /// Decodes an event sent to a data-control device.
+    ///
+    /// Fails with [`whale_land::Error::UnknownEvent`] if the opcode is unknown.
+    pub fn parse_device_event(&self, packet: &Packet) -> Result<DeviceEvent, whale_land::Error> {
+        let event = match self {
+            Self::Ext => match ext_data_control_device_v1_event::parse(packet)? {
+                ext_data_control_device_v1_event::data_offer(args) => DeviceEvent::DataOffer(args.id.0),
+                ext_data_control_device_v1_event::selection(args) => DeviceEvent::Selection(args.id),
+                ext_data_control_device_v1_event::finished(_) => DeviceEvent::Finished,
+                ext_data_control_device_v1_event::primary_selection(args) => DeviceEvent::PrimarySelection(args.id),
+            },
+            Self::Wlr => match zwlr_data_control_device_v1_event::parse(packet)? {
+                zwlr_data_control_device_v1_event::data_offer(args) => DeviceEvent::DataOffer(args.id.0),
+                zwlr_data_control_device_v1_event::selection(args) => DeviceEvent::Selection(args.id),
+                zwlr_data_control_device_v1_event::finished(_) => DeviceEvent::Finished,
+                zwlr_data_control_device_v1_event::primary_selection(args) => DeviceEvent::PrimarySelection(args.id),
+            },
+        };
+        Ok(event)
+    }
+
+    /// Decodes an event sent to a data-control source.
+    ///
+    /// Takes ownership of any file descriptor carried by the packet, so this must only be called
+    /// once per packet. Fails with [`whale_land::Error::UnknownEvent`] if the opcode is unknown.
+    pub fn parse_source_event(&self, packet: &Packet) -> Result<SourceEvent, whale_land::Error> {
+        let (mime_type, fd) = match self {
+            Self::Ext => match ext_data_control_source_v1_event::parse(packet)? {
+                ext_data_control_source_v1_event::send(args) => (args.mime_type, args.fd),
+                ext_data_control_source_v1_event::cancelled(_) => return Ok(SourceEvent::Cancelled),
+            },
+            Self::Wlr => match zwlr_data_control_source_v1_event::parse(packet)? {
+                zwlr_data_control_source_v1_event::send(args) => (args.mime_type, args.fd),
+                zwlr_data_control_source_v1_event::cancelled(_) => return Ok(SourceEvent::Cancelled),
+            },
+        };
+        // the file descriptor has been passed to us and is ours to close
+        let fd = unsafe {
+            OwnedFd::from_raw_fd(fd)
+        };
+        Ok(SourceEvent::Send { mime_type, fd })
+    }
+
+    /// Decodes an event sent to a data-control offer.
+    ///
+    /// Fails with [`whale_land::Error::UnknownEvent`] if the opcode is unknown.
+    pub fn parse_offer_event(&self, packet: &Packet) -> Result<OfferEvent, whale_land::Error> {
+        let event = match self {
+            Self::Ext => match ext_data_control_offer_v1_event::parse(packet)? {
+                ext_data_control_offer_v1_event::offer(args) => OfferEvent::Offer { mime_type: args.mime_type },
+            },
+            Self::Wlr => match zwlr_data_control_offer_v1_event::parse(packet)? {
+                zwlr_data_control_offer_v1_event::offer(args) => OfferEvent::Offer { mime_type: args.mime_type },
+            },
+        };
+        Ok(event)
+    }
+}
+
+impl DataControlManager {
+    /// Binds the global with the given name, which implements the manager of the given protocol.
+    pub async fn bind(globals: &GlobalList, protocol: DataControlProtocol, global_name: u32) -> Result<Self, Error> {
+        let manager = match protocol {
+            DataControlProtocol::Ext => Self::Ext(globals.bind_name(global_name, 1..).await?),
+            DataControlProtocol::Wlr => Self::Wlr(globals.bind_name(global_name, 1..).await?),
+        };
+        Ok(manager)
+    }
+
+    pub fn protocol(&self) -> DataControlProtocol {
+        match self {
+            Self::Ext(_) => DataControlProtocol::Ext,
+            Self::Wlr(_) => DataControlProtocol::Wlr,
+        }
+    }
+
+    pub fn id(&self) -> ObjectId {
+        with_proxy!(self, manager => manager.id())
+    }
+
+    pub fn version(&self) -> u32 {
+        with_proxy!(self, manager => manager.version())
+    }
+
+    pub async fn create_data_source(&self) -> Result<DataControlSource, Error> {
+        let source = match self {
+            Self::Ext(manager) => DataControlSource::Ext(manager.send_create_data_source().await?),
+            Self::Wlr(manager) => DataControlSource::Wlr(manager.send_create_data_source().await?),
+        };
+        Ok(source)
+    }
+
+    pub async fn get_data_device(&self, seat_id: ObjectId) -> Result<DataControlDevice, Error> {
+        let device = match self {
+            Self::Ext(manager) => DataControlDevice::Ext(manager.send_get_data_device(seat_id).await?),
+            Self::Wlr(manager) => DataControlDevice::Wlr(manager.send_get_data_device(seat_id).await?),
+        };
+        Ok(device)
+    }
+
+    pub async fn destroy(self) -> Result<(), Error> {
+        with_proxy!(self, manager => manager.send_destroy().await)
+    }
+}
+
+impl DataControlDevice {
+    pub fn id(&self) -> ObjectId {
+        with_proxy!(self, device => device.id())
+    }
+
+    /// Whether the device has been created at a version that supports the primary selection.
+    pub fn supports_primary_selection(&self) -> bool {
+        match self {
+            Self::Ext(device) => device.version() >= ext_data_control_device_v1_request_set_primary_selection_args::SINCE,
+            Self::Wlr(device) => device.version() >= zwlr_data_control_device_v1_request_set_primary_selection_args::SINCE,
+        }
+    }
+
+    /// Records an offer announced by a `data_offer` event and returns it.
+    pub fn insert_offer(&self, connection: &Connection, offer_id: ObjectId) -> Result<DataControlOffer, Error> {
+        let offer = match self {
+            Self::Ext(device) => {
+                connection.insert_server_object(offer_id, &ext_data_control_offer_v1_interface, device.version())?;
+                DataControlOffer::Ext(Proxy::from_parts(connection.downgrade(), offer_id, device.version()))
+            },
+            Self::Wlr(device) => {
+                connection.insert_server_object(offer_id, &zwlr_data_control_offer_v1_interface, device.version())?;
+                DataControlOffer::Wlr(Proxy::from_parts(connection.downgrade(), offer_id, device.version()))
+            },
+        };
+        Ok(offer)
+    }
+
+    pub async fn set_selection(&self, source: Option<&DataControlSource>) -> Result<(), Error> {
+        let source_id = source.map(|s| s.id());
+        with_proxy!(self, device => device.send_set_selection(source_id).await)
+    }
+
+    /// Fails with [`whale_land::Error::RequestTooNew`] unless the device supports the primary selection.
+    pub async fn set_primary_selection(&self, source: Option<&DataControlSource>) -> Result<(), Error> {
+        let source_id = source.map(|s| s.id());
+        with_proxy!(self, device => device.send_set_primary_selection(source_id).await)
+    }
+
+    pub async fn destroy(self) -> Result<(), Error> {
+        with_proxy!(self, device => device.send_destroy().await)
+    }
+}
+
+impl DataControlSource {
+    pub fn id(&self) -> ObjectId {
+        with_proxy!(self, source => source.id())
+    }
+
+    pub async fn offer(&self, mime_type: &str) -> Result<(), Error> {
+        with_proxy!(self, source => source.send_offer(mime_type).await)
+    }
+
+    pub async fn destroy(self) -> Result<(), Error> {
+        with_proxy!(self, source => source.send_destroy().await)
+    }
+}
+
+impl DataControlOffer {
+    pub fn id(&self) -> ObjectId {
+        with_proxy!(self, offer => offer.id())
+    }
+
+    /// Asks the owner of the selection to write its contents in the given MIME type into the file
+    /// descriptor.
+    pub async fn receive(&self, mime_type: &str, fd: RawFd) -> Result<(), Error> {
+        with_proxy!(self, offer => offer.send_receive(mime_type, fd).await)
+    }
+
+    pub async fn destroy(self) -> Result<(), Error> {
+        with_proxy!(self, offer => offer.send_destroy().await)
+    }
+}
@@ -1,49 +1,55 @@
+mod data_control;
 mod dbus_utils;
 mod notifier;
 mod secrets;
 mod totp;
+mod virtual_keyboard;
+mod x11;
 
 
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
 use std::fs::OpenOptions;
 use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
 use std::path::PathBuf;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-use clap::Parser;
+use async_trait::async_trait;
+use clap::{Parser, ValueEnum};
+use futures_util::future::join_all;
 use futures_util::stream::StreamExt;
-use libc::close;
-use tokio::io::AsyncWriteExt;
-use tokio::sync::RwLock;
+use libc::{c_int, O_CLOEXEC, pipe2};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::{Mutex, RwLock};
 use tokio::sync::mpsc;
+use tokio::time::{Instant, sleep_until, timeout, timeout_at};
 use tokio_fd::AsyncFd;
 use tracing::{debug, error, info, warn};
 use tracing_subscriber::EnvFilter;
-use whale_land::{NewObject, NewObjectId, ObjectId};
-use whale_land::protocol::ext_data_control_v1::{
-    ext_data_control_device_v1_v1_event_data_offer_args,
-    ext_data_control_device_v1_v1_event_finished_args,
-    ext_data_control_device_v1_v1_event_primary_selection_args,
-    ext_data_control_device_v1_v1_event_selection_args,
-    ext_data_control_device_v1_v1_request_set_selection_args,
-    ext_data_control_manager_v1_v1_request_create_data_source_args,
-    ext_data_control_manager_v1_v1_request_get_data_device_args,
-    ext_data_control_offer_v1_v1_event_offer_args,
-    ext_data_control_source_v1_v1_event_cancelled_args,
-    ext_data_control_source_v1_v1_event_send_args,
-    ext_data_control_source_v1_v1_request_destroy_args,
-    ext_data_control_source_v1_v1_request_offer_args,
-};
+use whale_land::{GlobalList, ObjectId};
+use whale_land::protocol::{EventHandler, Proxy};
 use whale_land::protocol::wayland::{
-    wl_display_v1_event_error_args, wl_display_v1_request_proxy, wl_registry_v1_event_global_args,
-    wl_registry_v1_request_bind_args, wl_seat_v10_event_capabilities_args,
-    wl_seat_v10_event_name_args,
+    wl_display_event_handler, wl_keyboard_key_state, wl_keyboard_keymap_format, wl_registry_event_handler,
+    wl_seat, wl_seat_capability, wl_seat_event_handler, wl_seat_request_release_args,
+};
+use whale_land::protocol::virtual_keyboard_unstable_v1::{
+    zwp_virtual_keyboard_manager_v1, zwp_virtual_keyboard_manager_v1_interface, zwp_virtual_keyboard_v1,
 };
 use zbus;
 use zbus::names::BusName;
+use zeroize::Zeroizing;
 
+use crate::data_control::{
+    DataControlDevice, DataControlManager, DataControlOffer, DataControlProtocol, DataControlSource,
+    DeviceEvent, OfferEvent, SourceEvent,
+};
 use crate::notifier::{ContextMenu, TrayIcon};
 use crate::notifier::proxies::StatusNotifierWatcherProxy;
 use crate::secrets::SecretSession;
+use crate::x11::X11Data;
 
 
 const TRAY_ICON_BUS_PATH: &str = "/StatusNotifierItem";
@@ -55,40 +61,491 @@ const PLAIN_TEXT_MIME_TYPES_SORTED: [&'static str; 5] = [
     "text/plain",
     "text/plain;charset=utf-8",
 ];
+const PASSWORD_MANAGER_HINT_MIME_TYPE: &str = "x-kde-passwordManagerHint";
+const PASSWORD_MANAGER_HINT_VALUE: &str = "secret";
+const PREVIOUS_CONTENTS_TIMEOUT: Duration = Duration::from_secs(1);
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(5);
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+const WAYLAND_DISPLAY_VAR: &str = "WAYLAND_DISPLAY";
+const WAYLAND_SOCKET_VAR: &str = "WAYLAND_SOCKET";
+const X11_DISPLAY_VAR: &str = "DISPLAY";
 static SECRET_SESSION: OnceLock<RwLock<SecretSession>> = OnceLock::new();
 
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum ClipboardMessage {
-    Copy(String),
+    Copy { value: String, clear_after: Option<Duration>, target: SelectionTarget },
+    Type { value: String, press_enter: bool },
     Clear,
     Exit,
 }
 
+/// The selection(s) into which a code is placed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, ValueEnum)]
+pub enum SelectionTarget {
+    /// The regular clipboard (Ctrl+V).
+    #[default] Clipboard,
+
+    /// The primary selection (middle click).
+    Primary,
+
+    /// Both the regular clipboard and the primary selection.
+    Both,
+}
+impl SelectionTarget {
+    pub fn try_from_str(s: &str) -> Option<Self> {
+        match s {
+            "clipboard" => Some(Self::Clipboard),
+            "primary" => Some(Self::Primary),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    pub fn includes_clipboard(&self) -> bool {
+        matches!(self, Self::Clipboard | Self::Both)
+    }
+
+    pub fn includes_primary(&self) -> bool {
+        matches!(self, Self::Primary | Self::Both)
+    }
+}
+
+/// How a code is delivered.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, ValueEnum)]
+pub enum Delivery {
+    /// Place the code into the clipboard.
+    #[default] Copy,
+
+    /// Type the code into the focused window.
+    Type,
+
+    /// Type the code into the focused window, then press Enter.
+    TypeEnter,
+}
+impl Delivery {
+    pub fn try_from_str(s: &str) -> Option<Self> {
+        match s {
+            "copy" => Some(Self::Copy),
+            "type" => Some(Self::Type),
+            "type-enter" => Some(Self::TypeEnter),
+            _ => None,
+        }
+    }
+}
+
+/// The state of one selection (the regular clipboard or the primary selection).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SelectionData {
+    /// Our data source currently providing the code.
+    pub source: Option<DataControlSource>,
+
+    /// The code provided by our data source.
+    pub data: Option<String>,
+
+    /// The offer describing the current contents of the selection.
+    pub offer_id: Option<ObjectId>,
+
+    /// Whether the offer stems from one of our own data sources.
+    pub offer_is_ours: bool,
+
+    /// Our data source re-offering the contents the selection had before the code was placed.
+    pub restore_source: Option<DataControlSource>,
+
+    /// The contents the selection had before the code was placed, as pairs of MIME type and data.
+    pub previous_contents: Vec<(String, Vec<u8>)>,
+}
+impl SelectionData {
+    pub const fn new() -> Self {
+        Self {
+            source: None,
+            data: None,
+            offer_id: None,
+            offer_is_ours: false,
+            restore_source: None,
+            previous_contents: Vec::new(),
+        }
+    }
+}
+
+/// An offer we have received through our data-control device.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OfferData {
+    pub offer: DataControlOffer,
+
+    /// The MIME types in which the offer's contents are available.
+    pub mime_types: Vec<String>,
+}
+impl OfferData {
+    pub const fn new(offer: DataControlOffer) -> Self {
+        Self {
+            offer,
+            mime_types: Vec::new(),
+        }
+    }
+}
+
+/// A pipe through which the owner of a selection sends us its contents.
+#[derive(Debug)]
+pub struct PendingContents {
+    pub is_primary: bool,
+    pub mime_type: String,
+    pub read_fd: OwnedFd,
+}
+
+/// A seat we have bound.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SeatData {
+    /// The name of the seat's global in the registry.
+    pub global_name: u32,
+
+    pub seat: wl_seat,
+
+    /// The name of the seat, once the compositor has told us.
+    pub name: Option<String>,
+}
+impl SeatData {
+    pub const fn new(global_name: u32, seat: wl_seat) -> Self {
+        Self {
+            global_name,
+            seat,
+            name: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct WaylandData {
-    pub registry_id: Option<ObjectId>,
+    /// The globals currently advertised by our registry, once we have obtained it.
+    pub globals: Option<GlobalList>,
+
+    /// Whether the globals advertised upon connection have all arrived.
+    pub initial_globals_received: bool,
+
+    pub seats: BTreeMap<ObjectId, SeatData>,
+    pub wanted_seat_name: Option<String>,
     pub seat_id: Option<ObjectId>,
-    pub clipboard_manager_id: Option<ObjectId>,
-    pub clipboard_device_id: Option<ObjectId>,
-    pub clipboard_data: Option<String>,
-    pub clipboard_source_id: Option<ObjectId>,
-    pub incoming_offer_id: Option<ObjectId>,
+    pub clipboard_manager_name: Option<u32>,
+    pub clipboard_manager: Option<DataControlManager>,
+    pub data_control_protocol: Option<DataControlProtocol>,
+    pub clipboard_device: Option<DataControlDevice>,
+    pub virtual_keyboard_manager_name: Option<u32>,
+    pub virtual_keyboard_manager: Option<zwp_virtual_keyboard_manager_v1>,
+    pub virtual_keyboard: Option<zwp_virtual_keyboard_v1>,
+    pub clipboard: SelectionData,
+    pub primary: SelectionData,
+    pub offer_id_to_offer: BTreeMap<ObjectId, OfferData>,
+    pub clear_deadline: Option<Instant>,
+    pub paste_once: bool,
 }
 impl WaylandData {
     pub const fn new() -> Self {
         Self {
-            registry_id: None,
+            globals: None,
+            initial_globals_received: false,
+            seats: BTreeMap::new(),
+            wanted_seat_name: None,
             seat_id: None,
-            clipboard_manager_id: None,
-            clipboard_device_id: None,
-            clipboard_data: None,
-            clipboard_source_id: None,
-            incoming_offer_id: None,
+            clipboard_manager_name: None,
+            clipboard_manager: None,
+            data_control_protocol: None,
+            clipboard_device: None,
+            virtual_keyboard_manager_name: None,
+            virtual_keyboard_manager: None,
+            virtual_keyboard: None,
+            clipboard: SelectionData::new(),
+            primary: SelectionData::new(),
+            offer_id_to_offer: BTreeMap::new(),
+            clear_deadline: None,
+            paste_once: false,
+        }
+    }
+
+    /// Returns fresh state for a new connection, retaining the configuration of the given state.
+    pub fn new_from_template(template: &Self) -> Self {
+        let mut data = Self::new();
+        data.paste_once = template.paste_once;
+        data.wanted_seat_name = template.wanted_seat_name.clone();
+        data
+    }
+
+    pub fn globals(&self) -> &GlobalList {
+        self.globals.as_ref().expect("registry has not been obtained yet")
+    }
+
+    pub fn globals_mut(&mut self) -> &mut GlobalList {
+        self.globals.as_mut().expect("registry has not been obtained yet")
+    }
+
+    /// Returns whether the given object is one of our data sources and, if so, whether it belongs
+    /// to the primary selection.
+    pub fn source_is_primary(&self, object_id: ObjectId) -> Option<bool> {
+        let is_ours = |sel: &SelectionData|
+            [&sel.source, &sel.restore_source].into_iter()
+                .flatten()
+                .any(|source| source.id() == object_id);
+        if is_ours(&self.clipboard) {
+            Some(false)
+        } else if is_ours(&self.primary) {
+            Some(true)
+        } else {
+            None
         }
     }
 }
 
+/// Our Wayland state, shared by the main loop and the handlers of our Wayland objects.
+///
+/// Functions that create objects take it alongside the locked state to register their handlers.
+pub type SharedWaylandData = Arc<Mutex<WaylandData>>;
+
+/// The display server connection through which we access the clipboard.
+pub enum ClipboardBackend {
+    Wayland { conn: whale_land::Connection, data: SharedWaylandData },
+
+    /// We have lost the connection to the compositor and will try to reconnect at `retry_at`.
+    ///
+    /// `data` only serves as the template for the new connection's state.
+    WaylandReconnecting { data: Box<WaylandData>, retry_at: Instant, retry_delay: Duration },
+
+    X11 { conn: crate::x11::connection::Connection, data: X11Data },
+
+    /// We have lost the connection to the X server and will try to reconnect at `retry_at`.
+    X11Reconnecting { paste_once: bool, retry_at: Instant, retry_delay: Duration },
+}
+impl ClipboardBackend {
+    pub async fn clear_deadline(&self) -> Option<Instant> {
+        match self {
+            Self::Wayland { data, .. } => data.lock().await.clear_deadline,
+            Self::WaylandReconnecting { .. } => None,
+            Self::X11 { data, .. } => data.clear_deadline,
+            Self::X11Reconnecting { .. } => None,
+        }
+    }
+
+    pub async fn recv(&self) -> BackendMessage {
+        match self {
+            Self::Wayland { conn, .. } => BackendMessage::Wayland(conn.recv_packet().await),
+            Self::WaylandReconnecting { retry_at, .. } => {
+                sleep_until(*retry_at).await;
+                BackendMessage::WaylandRetry
+            },
+            Self::X11 { conn, .. } => BackendMessage::X11(conn.recv_message().await),
+            Self::X11Reconnecting { retry_at, .. } => {
+                sleep_until(*retry_at).await;
+                BackendMessage::X11Retry
+            },
+        }
+    }
+
+    pub async fn dispatch(&mut self, message: BackendMessage) {
+        let next_state = match (&mut *self, message) {
+            (Self::Wayland { conn, data }, BackendMessage::Wayland(packet_res)) => {
+                match dispatch_wayland_packet(conn, packet_res).await {
+                    Ok(()) => None,
+                    Err(e) => {
+                        // the stream is either gone or out of sync; start over
+                        error!("error handling Wayland packet: {}; reconnecting", e);
+                        Some(Self::wayland_reconnecting(&*data.lock().await))
+                    },
+                }
+            },
+            (Self::WaylandReconnecting { data, retry_delay, .. }, BackendMessage::WaylandRetry) => {
+                match connect_wayland(data).await {
+                    Ok((new_conn, new_data)) => {
+                        info!("reconnected to Wayland");
+                        Some(Self::Wayland { conn: new_conn, data: new_data })
+                    },
+                    Err(e) => {
+                        let next_delay = (*retry_delay * 2).min(RECONNECT_MAX_DELAY);
+                        warn!("failed to reconnect to Wayland: {}; retrying in {:?}", e, next_delay);
+                        Some(Self::WaylandReconnecting {
+                            data: Box::new(WaylandData::new_from_template(data)),
+                            retry_at: Instant::now() + next_delay,
+                            retry_delay: next_delay,
+                        })
+                    },
+                }
+            },
+            (Self::X11 { conn, data }, BackendMessage::X11(message_res)) => {
+                let dispatch_res = match message_res {
+                    Ok(message) => crate::x11::x11_dispatch(conn, message, data).await,
+                    Err(e) => Err(e),
+                };
+                match dispatch_res {
+                    Ok(()) => None,
+                    Err(e) => {
+                        // the X server has closed the connection or we can no longer talk to it
+                        error!("error handling X11 message: {}; reconnecting", e);
+                        Some(Self::x11_reconnecting(data.paste_once))
+                    },
+                }
+            },
+            (Self::X11Reconnecting { paste_once, retry_delay, .. }, BackendMessage::X11Retry) => {
+                match crate::x11::connect_x11(*paste_once).await {
+                    Ok((new_conn, new_data)) => {
+                        info!("reconnected to X11");
+                        Some(Self::X11 { conn: new_conn, data: new_data })
+                    },
+                    Err(e) => {
+                        let next_delay = (*retry_delay * 2).min(RECONNECT_MAX_DELAY);
+                        warn!("failed to reconnect to X11: {}; retrying in {:?}", e, next_delay);
+                        Some(Self::X11Reconnecting {
+                            paste_once: *paste_once,
+                            retry_at: Instant::now() + next_delay,
+                            retry_delay: next_delay,
+                        })
+                    },
+                }
+            },
+            (_, message) => unreachable!("message {:?} from a different backend", message),
+        };
+        if let Some(ns) = next_state {
+            *self = ns;
+        }
+    }
+
+    pub async fn copy(&mut self, value: String, clear_after: Option<Duration>, target: SelectionTarget) {
+        let res = match self {
+            Self::Wayland { conn, data: shared } => copy_dispatch(conn, shared, value, clear_after, target).await
+                .map_err(BackendError::Wayland),
+            Self::WaylandReconnecting { .. } => {
+                error!("cannot copy while reconnecting to Wayland");
+                Ok(())
+            },
+            Self::X11 { conn, data } => crate::x11::copy_dispatch(conn, data, value, clear_after, target).await
+                .map_err(BackendError::X11),
+            Self::X11Reconnecting { .. } => {
+                error!("cannot copy while reconnecting to X11");
+                Ok(())
+            },
+        };
+        if let Err(e) = res {
+            self.reconnect_after(e).await;
+        }
+    }
+
+    pub async fn type_text(&mut self, value: String, press_enter: bool) {
+        let res = match self {
+            Self::Wayland { data, .. } => type_dispatch(&mut *data.lock().await, value, press_enter).await
+                .map_err(BackendError::Wayland),
+            Self::WaylandReconnecting { .. } => {
+                error!("cannot type while reconnecting to Wayland");
+                Ok(())
+            },
+            Self::X11 { .. } | Self::X11Reconnecting { .. } => {
+                error!("typing codes is not supported on X11");
+                Ok(())
+            },
+        };
+        if let Err(e) = res {
+            self.reconnect_after(e).await;
+        }
+    }
+
+    pub async fn clear(&mut self) {
+        let res = match self {
+            Self::Wayland { conn, data: shared } => {
+                let mut data = shared.lock().await;
+                clear_dispatch(conn, shared, &mut data).await
+                    .map_err(BackendError::Wayland)
+            },
+            Self::WaylandReconnecting { .. } | Self::X11Reconnecting { .. } => Ok(()),
+            Self::X11 { conn, data } => crate::x11::clear_dispatch(conn, data).await
+                .map_err(BackendError::X11),
+        };
+        if let Err(e) = res {
+            self.reconnect_after(e).await;
+        }
+    }
+
+    /// Abandons a display server connection that has failed and schedules a reconnection attempt.
+    async fn reconnect_after(&mut self, error: BackendError) {
+        let next_state = match self {
+            Self::Wayland { data, .. } => Self::wayland_reconnecting(&*data.lock().await),
+            Self::X11 { data, .. } => Self::x11_reconnecting(data.paste_once),
+            Self::WaylandReconnecting { .. } | Self::X11Reconnecting { .. } => return,
+        };
+        error!("{}; reconnecting", error);
+        *self = next_state;
+    }
+
+    fn wayland_reconnecting(template: &WaylandData) -> Self {
+        Self::WaylandReconnecting {
+            data: Box::new(WaylandData::new_from_template(template)),
+            retry_at: Instant::now() + RECONNECT_INITIAL_DELAY,
+            retry_delay: RECONNECT_INITIAL_DELAY,
+        }
+    }
+
+    fn x11_reconnecting(paste_once: bool) -> Self {
+        Self::X11Reconnecting {
+            paste_once,
+            retry_at: Instant::now() + RECONNECT_INITIAL_DELAY,
+            retry_delay: RECONNECT_INITIAL_DELAY,
+        }
+    }
+}
+
+/// An error that has rendered the connection to the display server unusable.
+#[derive(Debug)]
+enum BackendError {
+    Wayland(whale_land::Error),
+    X11(crate::x11::connection::Error),
+}
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wayland(e) => write!(f, "Wayland connection failed: {}", e),
+            Self::X11(e) => write!(f, "X11 connection failed: {}", e),
+        }
+    }
+}
+
+/// Passes a packet received from the compositor to the handler of its object.
+///
+/// Only fails if the connection has become unusable, e.g. because a request could not be sent.
+async fn dispatch_wayland_packet(
+    conn: &whale_land::Connection,
+    packet_res: Result<whale_land::Packet, whale_land::Error>,
+) -> Result<(), whale_land::Error> {
+    let dispatch_res = match packet_res {
+        Ok(packet) => conn.dispatch(packet).await,
+        Err(e) => Err(e),
+    };
+    match dispatch_res {
+        Err(e @ (whale_land::Error::DeadObject { .. } | whale_land::Error::NoEventHandler { .. })) => {
+            // the compositor sent this before it learned that we destroyed the object
+            debug!("{}", e);
+            Ok(())
+        },
+        other => other,
+    }
+}
+
+/// Keeps dispatching Wayland events until the future completes.
+async fn dispatch_wayland_until<F: Future>(
+    conn: &whale_land::Connection,
+    future: F,
+) -> Result<F::Output, whale_land::Error> {
+    tokio::pin!(future);
+    loop {
+        tokio::select! {
+            output = &mut future => return Ok(output),
+            packet_res = conn.recv_packet() => dispatch_wayland_packet(conn, packet_res).await?,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BackendMessage {
+    Wayland(Result<whale_land::Packet, whale_land::Error>),
+    WaylandRetry,
+    X11(Result<crate::x11::connection::Message, crate::x11::connection::Error>),
+    X11Retry,
+}
+
 #[derive(Parser)]
 struct Opts {
     #[arg(long, help = "Log to a file instead of stderr.")]
@@ -100,6 +557,34 @@ struct Opts {
         help = "Label of the secrets collection (keyring, wallet) to open. Defaults to \"Default keyring\".",
     )]
     collection: String,
+
+    #[arg(
+        long,
+        help = "Clear the clipboard after this many seconds. 0 never clears the clipboard. Defaults to the remaining validity of the TOTP code.",
+    )]
+    clear_after: Option<u64>,
+
+    #[arg(long, help = "Clear the clipboard as soon as the code has been pasted once.")]
+    paste_once: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = SelectionTarget::Clipboard,
+        help = "Selection into which codes are placed, unless overridden by the secret's \"selection\" attribute.",
+    )]
+    selection: SelectionTarget,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Delivery::Copy,
+        help = "How codes are delivered, unless overridden by the secret's \"delivery\" attribute.",
+    )]
+    delivery: Delivery,
+
+    #[arg(long, help = "Name of the Wayland seat to use. Defaults to the first seat.")]
+    seat: Option<String>,
 }
 
 
@@ -159,7 +644,13 @@ async fn main() {
 
     // introduce the notifier icon and menu
     let icon = TrayIcon;
-    let menu = ContextMenu::new(RwLock::new(secret_name_to_path), clipboard_sender.clone());
+    let menu = ContextMenu::new(
+        RwLock::new(secret_name_to_path),
+        clipboard_sender.clone(),
+        opts.clear_after,
+        opts.selection,
+        opts.delivery,
+    );
 
     // register them with the session bus
     let object_server = dbus_conn
@@ -174,21 +665,24 @@ async fn main() {
         .unique_name()
         .expect("failed to obtain unique name from D-Bus connection");
 
-    // connect to Wayland
-    debug!("connecting to Wayland");
-    let way_conn = whale_land::Connection::new_from_env()
-        .await.expect("failed to create connection to Wayland server");
-
-    // get access to Wayland registry
-    debug!("querying registry");
-    let registry_id = way_conn.get_and_increment_next_object_id();
-    let display = wl_display_v1_request_proxy::new(&way_conn);
-    display.send_get_registry(
-        ObjectId::DISPLAY,
-        NewObjectId(registry_id),
-    )
-        .await
-        .expect("failed to send wl_display::get_registry packet");
+    // connect to the display server
+    let use_x11 = env::var_os(WAYLAND_DISPLAY_VAR).is_none()
+        && env::var_os(WAYLAND_SOCKET_VAR).is_none()
+        && env::var_os(X11_DISPLAY_VAR).is_some();
+    let mut backend = if use_x11 {
+        debug!("connecting to X11");
+        let (x11_conn, x11_data) = crate::x11::connect_x11(opts.paste_once)
+            .await.expect("failed to create connection to X server");
+        ClipboardBackend::X11 { conn: x11_conn, data: x11_data }
+    } else {
+        debug!("connecting to Wayland");
+        let mut wayland_template = WaylandData::new();
+        wayland_template.paste_once = opts.paste_once;
+        wayland_template.wanted_seat_name = opts.seat.clone();
+        let (way_conn, wayland_data) = connect_wayland(&wayland_template)
+            .await.expect("failed to create connection to Wayland server");
+        ClipboardBackend::Wayland { conn: way_conn, data: wayland_data }
+    };
 
     // interact with the icon watcher
     debug!("waiting, with bated breath, for an icon watcher");
@@ -213,11 +707,13 @@ async fn main() {
     let mut new_host_stream = icon_host.receive_status_notifier_host_registered()
         .await.expect("failed to create new-icon-host stream");
 
-    let mut wayland_data = WaylandData::new();
-    wayland_data.registry_id = Some(registry_id);
-
     // alrighty
     loop {
+        // if no clearing is pending, the branch is disabled and this value is ignored
+        let clear_deadline_opt = backend.clear_deadline().await;
+        let clear_deadline = clear_deadline_opt
+            .unwrap_or_else(Instant::now);
+
         tokio::select! {
             // zbus has its own task
             message_opt = clipboard_receiver.recv() => {
@@ -230,42 +726,33 @@ async fn main() {
                         // it's time to end
                         break;
                     },
-                    Some(ClipboardMessage::Copy(value)) => {
+                    Some(ClipboardMessage::Copy { value, clear_after, target }) => {
                         // update the value
-                        copy_dispatch(
-                            &way_conn,
-                            &mut wayland_data,
-                            value,
-                        ).await;
+                        backend.copy(value, clear_after, target).await;
+                    },
+                    Some(ClipboardMessage::Type { value, press_enter }) => {
+                        // type the value
+                        backend.type_text(value, press_enter).await;
                     },
                     Some(ClipboardMessage::Clear) => {
                         // remove the value and destroy the source
-                        clear_dispatch(
-                            &way_conn,
-                            &mut wayland_data,
-                        ).await;
+                        backend.clear().await;
                     },
                 }
             },
+            _ = sleep_until(clear_deadline), if clear_deadline_opt.is_some() => {
+                // time's up; clear the clipboard
+                debug!("clipboard timeout expired");
+                backend.clear().await;
+            },
             _ = new_host_stream.next() => {
                 // re-register our icon
                 info!("there is a new icon host");
                 icon_host.register_status_notifier_item(dbus_name.to_owned())
                     .await.expect("failed to re-register icon");
             },
-            way_packet_res = way_conn.recv_packet() => {
-                match way_packet_res {
-                    Ok(way_packet) => {
-                        wayland_dispatch(
-                            &way_conn,
-                            way_packet,
-                            &mut wayland_data,
-                        ).await
-                    },
-                    Err(e) => {
-                        error!("error receiving Wayland packet: {}", e);
-                    },
-                }
+            backend_message = backend.recv() => {
+                backend.dispatch(backend_message).await;
             },
         }
     }
@@ -292,277 +779,969 @@ async fn main() {
     debug!("D-Bus connection shut down");
 }
 
-async fn wayland_dispatch(
-    conn: &whale_land::Connection,
-    packet: whale_land::Packet,
-    data: &mut WaylandData,
-) {
-    if packet.object_id() == ObjectId::DISPLAY {
-        if packet.opcode() == wl_display_v1_event_error_args::OPCODE {
-            let error_args = wl_display_v1_event_error_args::try_from_packet(&packet)
-                .expect("invalid wl_display::error packet");
-            error!(
-                "Wayland server sends an error: object {:?} says [{}] {}",
-                error_args.object_id,
-                error_args.code,
-                error_args.message,
-            );
-        } else {
-            warn!("unhandled event from wl_display: {:?}", packet);
-        }
-    } else if Some(packet.object_id()) == data.registry_id {
-        if packet.opcode() == wl_registry_v1_event_global_args::OPCODE {
-            let global_args = wl_registry_v1_event_global_args::try_from_packet(&packet)
-                .expect("invalid wl_registry::global packet");
-            debug!("{}: {} v{}", global_args.name, global_args.interface, global_args.version);
-            match &*global_args.interface {
-                "wl_seat" => {
-                    // we need this to mess with the clipboard
-                    if data.seat_id.is_some() {
-                        // dupe, skip
-                        // FIXME: what if the seat is replaced later?
-                        return;
-                    }
-                    let new_seat_id = conn.get_and_increment_next_object_id();
-                    let gimme_packet = global_event_args_to_bind_request_packet(
-                        &global_args,
-                        new_seat_id,
-                        data.registry_id.unwrap(),
-                    );
-                    conn.send_packet(&gimme_packet).await
-                        .expect("failed to send gimme-seat packet");
-                    data.seat_id = Some(new_seat_id);
-                    debug!("requested that wl_seat become {:?}", new_seat_id);
-
-                    obtain_data_device_if_ready(conn, data).await;
-                },
-                "ext_data_control_manager_v1" => {
+async fn connect_wayland(
+    template: &WaylandData,
+) -> Result<(whale_land::Connection, SharedWaylandData), whale_land::Error> {
+    let conn = whale_land::Connection::new_from_env().await?;
+
+    // get access to Wayland registry
+    debug!("querying registry");
+    let globals = GlobalList::create_registry(&conn).await?;
+    let registry_id = globals.registry().id();
+
+    let mut initial_data = WaylandData::new_from_template(template);
+    initial_data.globals = Some(globals);
+    let data = Arc::new(Mutex::new(initial_data));
+    conn.register_handler(ObjectId::DISPLAY, Box::new(DisplayHandler));
+    conn.register_handler(registry_id, Box::new(RegistryHandler { data: Arc::clone(&data) }));
+
+    // wait for the initial set of globals so that we can choose the best data-control protocol
+    conn.roundtrip().await?;
+    {
+        let mut data_guard = data.lock().await;
+        data_guard.initial_globals_received = true;
+        bind_clipboard_manager_if_available(&conn, &data, &mut data_guard).await?;
+        if data_guard.clipboard_manager.is_none() {
+            error!("the compositor supports neither ext-data-control-v1 nor wlr-data-control-unstable-v1; codes cannot be copied");
+        }
+    }
+
+    Ok((conn, data))
+}
+
+/// Handles the events of `wl_display`.
+struct DisplayHandler;
+#[async_trait]
+impl EventHandler for DisplayHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        wl_display_event_handler::handle_event(self, connection, packet).await
+    }
+}
+impl wl_display_event_handler for DisplayHandler {
+    async fn handle_error(
+        &self,
+        _connection: &whale_land::Connection,
+        _packet: whale_land::Packet,
+        object_id: ObjectId,
+        code: u32,
+        message: String,
+    ) -> Result<(), whale_land::Error> {
+        error!("Wayland server sends an error: object {:?} says [{}] {}", object_id, code, message);
+        Ok(())
+    }
+
+    async fn handle_delete_id(
+        &self,
+        _connection: &whale_land::Connection,
+        _packet: whale_land::Packet,
+        _id: u32,
+    ) -> Result<(), whale_land::Error> {
+        // the connection takes care of these
+        Ok(())
+    }
+}
+
+/// Handles the events of `wl_registry`.
+struct RegistryHandler {
+    data: SharedWaylandData,
+}
+#[async_trait]
+impl EventHandler for RegistryHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        wl_registry_event_handler::handle_event(self, connection, packet).await
+    }
+}
+impl wl_registry_event_handler for RegistryHandler {
+    async fn handle_global(
+        &self,
+        connection: &whale_land::Connection,
+        _packet: whale_land::Packet,
+        name: u32,
+        interface: String,
+        version: u32,
+    ) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        let data = &mut *data_guard;
+
+        debug!("{}: {} v{}", name, interface, version);
+        data.globals_mut().add(name, interface.clone(), version);
+        match &*interface {
+            "wl_seat" => {
+                // we need this to mess with the clipboard
+                // (bind all of them; we can only choose by name once they have told us theirs)
+                let seat: wl_seat = data.globals()
+                    .bind_name(name, 1..).await?;
+                debug!("bound seat {} v{} as {:?}", name, seat.version(), seat.id());
+                connection.register_handler(seat.id(), Box::new(SeatHandler { data: Arc::clone(&self.data) }));
+                data.seats.insert(seat.id(), SeatData::new(name, seat));
+                choose_seat(connection, &self.data, data).await?;
+            },
+            "zwp_virtual_keyboard_manager_v1" => {
+                // this allows us to type codes
+                bind_virtual_keyboard_manager_if_available(data).await?;
+            },
+            other => {
+                if DataControlProtocol::try_from_manager_interface(other).is_some() {
                     // this allows us to mess with the clipboard
-                    if data.clipboard_manager_id.is_some() {
-                        // dupe, skip
-                        return;
-                    }
-                    let new_clipboard_manager_id = conn.get_and_increment_next_object_id();
-                    let gimme_packet = global_event_args_to_bind_request_packet(
-                        &global_args,
-                        new_clipboard_manager_id,
-                        data.registry_id.unwrap(),
-                    );
-                    conn.send_packet(&gimme_packet).await
-                        .expect("failed to send gimme-clipboard-manager packet");
-                    data.clipboard_manager_id = Some(new_clipboard_manager_id);
-                    debug!("requested that ext_data_control_manager_v1 become {:?}", new_clipboard_manager_id);
-
-                    obtain_data_device_if_ready(conn, data).await;
-                },
-                _ => {},
-            }
-        } else {
-            warn!("unhandled event from wl_registry: {:?}", packet);
-        }
-    } else if Some(packet.object_id()) == data.clipboard_source_id {
-        if packet.opcode() == ext_data_control_source_v1_v1_event_send_args::OPCODE {
-            let send_args = ext_data_control_source_v1_v1_event_send_args::try_from_packet(&packet)
-                .expect("failed to deserialize ext_data_control_source_v1::send args");
-            debug!("someone's asking for our contents in format {:?} on FD {}", send_args.mime_type, send_args.fd);
-            if PLAIN_TEXT_MIME_TYPES_SORTED.binary_search(&&*send_args.mime_type).is_ok() {
-                if let Some(clip_data) = data.clipboard_data.as_ref() {
-                    let mut fd = AsyncFd::try_from(send_args.fd)
-                        .expect("failed to wrap file descriptor");
-                    fd.write_all(clip_data.as_bytes())
-                        .await.expect("failed to write clipboard data");
-                    fd.flush()
-                        .await.expect("failed to flush clipboard data");
+                    bind_clipboard_manager_if_available(connection, &self.data, data).await?;
                 }
-            }
+            },
+        }
+        Ok(())
+    }
 
-            // in any case, close the file descriptor
-            let res = unsafe {
-                close(send_args.fd)
-            };
-            if res == -1 {
-                panic!("failed to close clipboard fd: {}", io::Error::last_os_error());
-            }
-        } else if packet.opcode() == ext_data_control_source_v1_v1_event_cancelled_args::OPCODE {
-            // something replaced us
-            // oh well, drop the data and forget the no-longer-valid source ID
-            data.clipboard_data = None;
-            data.clipboard_source_id = None;
-        }
-    } else if Some(packet.object_id()) == data.seat_id {
-        if packet.opcode() == wl_seat_v10_event_capabilities_args::OPCODE {
-            let capabilities_args = wl_seat_v10_event_capabilities_args::try_from_packet(&packet)
-                .expect("failed to decode wl_seat::capabilities args");
-            debug!("our seat has capabilities {:032b}", capabilities_args.capabilities);
-        } else if packet.opcode() == wl_seat_v10_event_name_args::OPCODE {
-            let name_args = wl_seat_v10_event_name_args::try_from_packet(&packet)
-                .expect("failed to decode wl_seat::name args");
-            debug!("we are on seat {:?}", name_args.name);
-        } else {
-            warn!("unhandled event from wl_seat: {:?}", packet);
-        }
-    } else if Some(packet.object_id()) == data.clipboard_device_id {
-        if packet.opcode() == ext_data_control_device_v1_v1_event_data_offer_args::OPCODE {
-            let offer_args = ext_data_control_device_v1_v1_event_data_offer_args::try_from_packet(&packet)
-                .expect("failed to decode ext_data_control_device_v1::data_offer args");
-            debug!("we are being offered data in {:?}", offer_args.id);
-            data.incoming_offer_id = Some(offer_args.id.0);
-        } else if packet.opcode() == ext_data_control_device_v1_v1_event_selection_args::OPCODE {
-            let selection_args = ext_data_control_device_v1_v1_event_selection_args::try_from_packet(&packet)
-                .expect("failed to decode ext_data_control_device_v1::selection args");
-            debug!("selection {:?} is now on offer", selection_args.id);
-        } else if packet.opcode() == ext_data_control_device_v1_v1_event_finished_args::OPCODE {
-            ext_data_control_device_v1_v1_event_finished_args::try_from_packet(&packet)
-                .expect("failed to decode ext_data_control_device_v1::finished args");
-            error!("control device is now gone! not sure how to handle this!");
-        } else if packet.opcode() == ext_data_control_device_v1_v1_event_primary_selection_args::OPCODE {
-            let selection_args = ext_data_control_device_v1_v1_event_primary_selection_args::try_from_packet(&packet)
-                .expect("failed to decode ext_data_control_device_v1::primary_selection args");
-            debug!("selection {:?} is now on offer as the primary selection", selection_args.id);
-        } else {
-            warn!("unhandled event from ext_data_control_device_v1: {:?}", packet);
-        }
-    } else if Some(packet.object_id()) == data.clipboard_manager_id {
-        // this object doesn't even have events
-        warn!("unhandled event from ext_data_control_manager_v1: {:?}", packet);
-    } else if Some(packet.object_id()) == data.incoming_offer_id {
-        if packet.opcode() == ext_data_control_offer_v1_v1_event_offer_args::OPCODE {
-            let event_offer_args = ext_data_control_offer_v1_v1_event_offer_args::try_from_packet(&packet)
-                .expect("failed to decode ext_data_control_offer_v1::offer args");
-            debug!("offer supports MIME type {}", event_offer_args.mime_type);
-        } else {
-            warn!("unhandled event from ext_data_control_offer_v1: {:?}", packet);
+    async fn handle_global_remove(
+        &self,
+        connection: &whale_land::Connection,
+        _packet: whale_land::Packet,
+        name: u32,
+    ) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        global_remove_dispatch(connection, &self.data, &mut data_guard, name).await
+    }
+}
+
+/// Handles the events of a `wl_seat`.
+struct SeatHandler {
+    data: SharedWaylandData,
+}
+#[async_trait]
+impl EventHandler for SeatHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        wl_seat_event_handler::handle_event(self, connection, packet).await
+    }
+}
+impl wl_seat_event_handler for SeatHandler {
+    async fn handle_capabilities(
+        &self,
+        _connection: &whale_land::Connection,
+        packet: whale_land::Packet,
+        capabilities: wl_seat_capability,
+    ) -> Result<(), whale_land::Error> {
+        debug!("seat {:?} has capabilities {:?}", packet.object_id(), capabilities);
+        Ok(())
+    }
+
+    async fn handle_name(
+        &self,
+        connection: &whale_land::Connection,
+        packet: whale_land::Packet,
+        name: String,
+    ) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        let data = &mut *data_guard;
+
+        let Some(seat) = data.seats.get_mut(&packet.object_id()) else {
+            // its global has been removed in the meantime
+            return Ok(());
+        };
+        debug!("seat {:?} is named {:?}", packet.object_id(), name);
+        seat.name = Some(name);
+        choose_seat(connection, &self.data, data).await
+    }
+}
+
+/// Handles the events of one of our data-control sources.
+struct SourceHandler {
+    data: SharedWaylandData,
+}
+#[async_trait]
+impl EventHandler for SourceHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        let data = &mut *data_guard;
+
+        let protocol = data.data_control_protocol
+            .expect("data source without data-control protocol?!");
+
+        // decode even if we no longer care; this claims the packet's file descriptors
+        let source_event = match protocol.parse_source_event(&packet) {
+            Err(e @ whale_land::Error::UnknownEvent { .. }) => {
+                // no telling which file descriptors it carries
+                return Err(connection.desynchronize(e));
+            },
+            other => other?,
+        };
+        let Some(is_primary) = data.source_is_primary(packet.object_id()) else {
+            warn!("event from a data-control source we no longer use: {:?}", packet);
+            return Ok(());
+        };
+        let selection = if is_primary { &mut data.primary } else { &mut data.clipboard };
+        let is_restore_source = selection.restore_source.as_ref()
+            .is_some_and(|source| source.id() == packet.object_id());
+        match source_event {
+            SourceEvent::Send { mime_type, fd } => {
+                debug!("someone's asking for our contents in format {:?} on FD {}", mime_type, fd.as_raw_fd());
+                let is_text = PLAIN_TEXT_MIME_TYPES_SORTED.binary_search(&&*mime_type).is_ok();
+                let payload = if is_restore_source {
+                    // hand out what was there before
+                    selection.previous_contents
+                        .iter()
+                        .find(|(mt, _bytes)| *mt == mime_type)
+                        .map(|(_mt, bytes)| bytes.as_slice())
+                } else if is_text {
+                    selection.data.as_ref()
+                        .map(|d| d.as_bytes())
+                } else if mime_type == PASSWORD_MANAGER_HINT_MIME_TYPE {
+                    // tell clipboard managers not to record our contents
+                    Some(PASSWORD_MANAGER_HINT_VALUE.as_bytes())
+                } else {
+                    None
+                };
+                let sent_text = if let Some(payload) = payload {
+                    // the recipient might take its time (or never read at all); don't hold up the event loop
+                    let payload = Zeroizing::new(payload.to_vec());
+                    tokio::spawn(send_contents(fd, payload));
+                    is_text && !is_restore_source
+                } else {
+                    // nothing to give; dropping the file descriptor closes it
+                    false
+                };
+
+                if sent_text && data.paste_once {
+                    // the code has been pasted; it may not be pasted again
+                    debug!("code has been pasted once; clearing the clipboard");
+                    clear_dispatch(connection, &self.data, data).await?;
+                }
+            },
+            SourceEvent::Cancelled => {
+                // something replaced us
+                // oh well, drop the data and forget the no-longer-valid source ID
+                // (the contents we would restore have been replaced as well)
+                if is_restore_source {
+                    selection.restore_source = None;
+                } else {
+                    selection.data = None;
+                    selection.source = None;
+                }
+                selection.previous_contents.clear();
+
+                if data.clipboard.source.is_none() && data.primary.source.is_none() {
+                    // there is nothing left for us to clear
+                    data.clear_deadline = None;
+                }
+            },
         }
-    } else {
-        warn!("unhandled event: {:?}", packet);
+        Ok(())
     }
 }
 
-fn global_event_args_to_bind_request_packet(
-    global_args: &wl_registry_v1_event_global_args,
-    new_object_id: ObjectId,
-    registry_id: ObjectId,
-) -> whale_land::Packet {
-    let args = wl_registry_v1_request_bind_args {
-        name: global_args.name,
-        id: NewObject {
-            object_id: new_object_id,
-            interface: global_args.interface.clone(),
-            interface_version: global_args.version,
-        },
+/// Handles the events of our data-control device.
+struct DeviceHandler {
+    data: SharedWaylandData,
+    device: DataControlDevice,
+}
+#[async_trait]
+impl EventHandler for DeviceHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        let data = &mut *data_guard;
+
+        let protocol = data.data_control_protocol
+            .expect("data-control device without data-control protocol?!");
+        let device_event = match protocol.parse_device_event(&packet) {
+            Err(e @ whale_land::Error::UnknownEvent { .. }) => {
+                // no telling which file descriptors it carries
+                return Err(connection.desynchronize(e));
+            },
+            other => other?,
+        };
+        match device_event {
+            DeviceEvent::DataOffer(offer_id) => {
+                debug!("we are being offered data in {:?}", offer_id);
+                let offer = self.device.insert_offer(connection, offer_id)?;
+                connection.register_handler(offer_id, Box::new(OfferHandler { data: Arc::clone(&self.data) }));
+                data.offer_id_to_offer.insert(offer_id, OfferData::new(offer));
+            },
+            DeviceEvent::Selection(offer_id) => {
+                debug!("selection {:?} is now on offer", offer_id);
+                data.clipboard.offer_id = offer_id;
+                data.clipboard.offer_is_ours = data.clipboard.source.is_some() || data.clipboard.restore_source.is_some();
+                forget_unused_offers(data).await?;
+            },
+            DeviceEvent::Finished => {
+                // the device has become invalid (e.g. its seat is going away); replace it
+                warn!("data-control device {:?} has been finished; recreating it", packet.object_id());
+                tear_down_data_device(data).await?;
+                obtain_data_device_if_ready(connection, &self.data, data).await?;
+            },
+            DeviceEvent::PrimarySelection(offer_id) => {
+                debug!("selection {:?} is now on offer as the primary selection", offer_id);
+                data.primary.offer_id = offer_id;
+                data.primary.offer_is_ours = data.primary.source.is_some() || data.primary.restore_source.is_some();
+                forget_unused_offers(data).await?;
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Handles the events of a data-control offer.
+struct OfferHandler {
+    data: SharedWaylandData,
+}
+#[async_trait]
+impl EventHandler for OfferHandler {
+    async fn handle_event(&self, connection: &whale_land::Connection, packet: whale_land::Packet) -> Result<(), whale_land::Error> {
+        let mut data_guard = self.data.lock().await;
+        let data = &mut *data_guard;
+
+        let protocol = data.data_control_protocol
+            .expect("data-control offer without data-control protocol?!");
+        let offer_event = match protocol.parse_offer_event(&packet) {
+            Err(e @ whale_land::Error::UnknownEvent { .. }) => {
+                // no telling which file descriptors it carries
+                return Err(connection.desynchronize(e));
+            },
+            other => other?,
+        };
+        match offer_event {
+            OfferEvent::Offer { mime_type } => {
+                debug!("offer supports MIME type {}", mime_type);
+                if let Some(offer_data) = data.offer_id_to_offer.get_mut(&packet.object_id()) {
+                    offer_data.mime_types.push(mime_type);
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+async fn bind_clipboard_manager_if_available(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    if data.clipboard_manager.is_some() {
+        // we already have one
+        return Ok(());
+    }
+    if !data.initial_globals_received {
+        // wait until we can choose among all of them
+        return Ok(());
+    }
+
+    // prefer ext_data_control_manager_v1 over zwlr_data_control_manager_v1
+    let best_global = data.globals()
+        .iter()
+        .filter_map(|global|
+            DataControlProtocol::try_from_manager_interface(&global.interface)
+                .map(|protocol| (protocol, global.name))
+        )
+        .min();
+    let Some((protocol, global_name)) = best_global else {
+        debug!("no data-control manager is available");
+        return Ok(());
     };
-    let packet = args.try_into_packet(registry_id)
-        .expect("failed to serialize args");
-    packet
+
+    let manager = DataControlManager::bind(data.globals(), protocol, global_name).await?;
+    debug!("bound {:?} data-control manager v{} as {:?}", protocol, manager.version(), manager.id());
+    data.clipboard_manager_name = Some(global_name);
+    data.clipboard_manager = Some(manager);
+    data.data_control_protocol = Some(protocol);
+
+    obtain_data_device_if_ready(conn, shared, data).await
+}
+
+async fn bind_virtual_keyboard_manager_if_available(
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    if data.virtual_keyboard_manager.is_some() {
+        // we already have one
+        return Ok(());
+    }
+
+    let global_name_opt = data.globals()
+        .with_interface(zwp_virtual_keyboard_manager_v1_interface.name)
+        .next()
+        .map(|global| global.name);
+    let Some(global_name) = global_name_opt else {
+        debug!("no virtual keyboard manager is available");
+        return Ok(());
+    };
+
+    let manager: zwp_virtual_keyboard_manager_v1 = data.globals()
+        .bind_name(global_name, 1..).await?;
+    debug!("bound virtual keyboard manager v{} as {:?}", manager.version(), manager.id());
+    data.virtual_keyboard_manager_name = Some(global_name);
+    data.virtual_keyboard_manager = Some(manager);
+
+    obtain_virtual_keyboard_if_ready(data).await
+}
+
+/// Picks the seat to use and, if it has changed, moves our seat-specific objects over to it.
+async fn choose_seat(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    let chosen_seat_id = match &data.wanted_seat_name {
+        Some(wanted) => data.seats
+            .iter()
+            .find(|(_seat_id, seat)| seat.name.as_ref() == Some(wanted))
+            .map(|(seat_id, _seat)| *seat_id),
+        None => data.seat_id
+            .filter(|seat_id| data.seats.contains_key(seat_id))
+            .or_else(|| data.seats.keys().next().copied()),
+    };
+    if chosen_seat_id == data.seat_id {
+        return Ok(());
+    }
+
+    tear_down_data_device(data).await?;
+    tear_down_virtual_keyboard(data).await?;
+    data.seat_id = chosen_seat_id;
+    match chosen_seat_id {
+        Some(seat_id) => debug!("using seat {:?}", seat_id),
+        None => debug!("no suitable seat is available"),
+    }
+
+    obtain_data_device_if_ready(conn, shared, data).await?;
+    obtain_virtual_keyboard_if_ready(data).await
+}
+
+async fn global_remove_dispatch(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    data: &mut WaylandData,
+    global_name: u32,
+) -> Result<(), whale_land::Error> {
+    let Some(global) = data.globals_mut().remove(global_name) else {
+        warn!("global {} that was never advertised has been removed", global_name);
+        return Ok(());
+    };
+    debug!("global {} ({}) has been removed", global_name, global.interface);
+
+    let removed_seat_id = data.seats
+        .iter()
+        .find(|(_seat_id, seat)| seat.global_name == global_name)
+        .map(|(seat_id, _seat)| *seat_id);
+    if let Some(seat_id) = removed_seat_id {
+        let seat = data.seats.remove(&seat_id).unwrap();
+        if data.seat_id == Some(seat_id) {
+            tear_down_data_device(data).await?;
+            tear_down_virtual_keyboard(data).await?;
+            data.seat_id = None;
+        }
+        if seat.seat.version() >= wl_seat_request_release_args::SINCE {
+            seat.seat.send_release().await?;
+        }
+
+        // perhaps another seat can take over
+        choose_seat(conn, shared, data).await?;
+    } else if data.clipboard_manager_name == Some(global_name) {
+        tear_down_data_device(data).await?;
+        let manager = data.clipboard_manager.take().unwrap();
+        manager.destroy().await?;
+        data.clipboard_manager_name = None;
+        data.data_control_protocol = None;
+
+        // perhaps another manager can take over
+        bind_clipboard_manager_if_available(conn, shared, data).await?;
+    } else if data.virtual_keyboard_manager_name == Some(global_name) {
+        tear_down_virtual_keyboard(data).await?;
+
+        // version 1 of the manager has no destructor; simply forget it
+        data.virtual_keyboard_manager = None;
+        data.virtual_keyboard_manager_name = None;
+
+        // perhaps another manager can take over
+        bind_virtual_keyboard_manager_if_available(data).await?;
+    }
+    Ok(())
 }
 
 async fn obtain_data_device_if_ready(
     conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
     data: &mut WaylandData,
-) {
-    let Some(clipboard_manager_id) = data.clipboard_manager_id else {
+) -> Result<(), whale_land::Error> {
+    let Some(manager) = &data.clipboard_manager else {
         debug!("we're still missing the clipboard manager");
-        return;
+        return Ok(());
+    };
+    let Some(seat_id) = data.seat_id else {
+        debug!("we're still missing the seat");
+        return Ok(());
+    };
+    let device = manager.get_data_device(seat_id).await?;
+    conn.register_handler(device.id(), Box::new(DeviceHandler { data: Arc::clone(shared), device: device.clone() }));
+    debug!("requested that the {:?} data-control device become {:?}", manager.protocol(), device.id());
+    if !device.supports_primary_selection() {
+        warn!("{:?} data-control v{} does not support the primary selection", manager.protocol(), manager.version());
+    }
+    data.clipboard_device = Some(device);
+    Ok(())
+}
+
+async fn obtain_virtual_keyboard_if_ready(
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    let Some(manager) = &data.virtual_keyboard_manager else {
+        debug!("we're still missing the virtual keyboard manager");
+        return Ok(());
     };
     let Some(seat_id) = data.seat_id else {
         debug!("we're still missing the seat");
-        return;
+        return Ok(());
     };
-    let clipboard_device_id = conn.get_and_increment_next_object_id();
-    let gimme = ext_data_control_manager_v1_v1_request_get_data_device_args {
-        id: NewObjectId(clipboard_device_id),
-        seat: Some(seat_id),
+
+    // prepare the keymap first so we don't end up with a keyboard we cannot use
+    let (keymap_fd, keymap_size) = match virtual_keyboard::create_keymap_file() {
+        Ok(keymap) => keymap,
+        Err(e) => {
+            error!("failed to create keymap file: {}; typing codes will not be possible", e);
+            return Ok(());
+        },
+    };
+
+    let keyboard = manager.send_create_virtual_keyboard(seat_id).await?;
+    debug!("requested that zwp_virtual_keyboard_v1 become {:?}", keyboard.id());
+
+    // the keymap must be set before any keys are pressed
+    keyboard.send_keymap(u32::from(wl_keyboard_keymap_format::xkb_v1), keymap_fd.as_raw_fd(), keymap_size).await?;
+
+    // the compositor has received its own copy of the file descriptor; ours is closed on drop
+    drop(keymap_fd);
+
+    data.virtual_keyboard = Some(keyboard);
+    Ok(())
+}
+
+/// Destroys the data device along with our sources and the offers we have received through it.
+async fn tear_down_data_device(
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    let Some(device) = data.clipboard_device.take() else {
+        return Ok(());
+    };
+
+    for is_primary in [false, true] {
+        let selection = if is_primary { &mut data.primary } else { &mut data.clipboard };
+        for source in [selection.source.take(), selection.restore_source.take()].into_iter().flatten() {
+            destroy_source(source).await?;
+        }
+        *selection = SelectionData::new();
+    }
+    for offer_data in std::mem::take(&mut data.offer_id_to_offer).into_values() {
+        offer_data.offer.destroy().await?;
+    }
+    data.clear_deadline = None;
+
+    let device_id = device.id();
+    device.destroy().await?;
+    debug!("destroyed data-control device {:?}", device_id);
+    Ok(())
+}
+
+async fn tear_down_virtual_keyboard(
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    let Some(keyboard) = data.virtual_keyboard.take() else {
+        return Ok(());
     };
-    let packet = gimme.try_into_packet(clipboard_manager_id)
-        .expect("failed to serialize packet");
-    conn.send_packet(&packet)
-        .await.expect("failed to send obtain-data-device packet");
-    data.clipboard_device_id = Some(clipboard_device_id);
-    debug!("requested that ext_data_control_device_v1 become {:?}", clipboard_device_id);
+    let keyboard_id = keyboard.id();
+    keyboard.send_destroy().await?;
+    debug!("destroyed virtual keyboard {:?}", keyboard_id);
+    Ok(())
+}
+
+async fn forget_unused_offers(
+    data: &mut WaylandData,
+) -> Result<(), whale_land::Error> {
+    let unused_offer_ids: Vec<ObjectId> = data.offer_id_to_offer
+        .keys()
+        .copied()
+        .filter(|oid| Some(*oid) != data.clipboard.offer_id && Some(*oid) != data.primary.offer_id)
+        .collect();
+    for offer_id in unused_offer_ids {
+        let offer_data = data.offer_id_to_offer.remove(&offer_id).unwrap();
+        offer_data.offer.destroy().await?;
+        debug!("forgot about offer {:?}", offer_id);
+    }
+    Ok(())
 }
 
 async fn copy_dispatch(
     conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    new_content: String,
+    clear_after: Option<Duration>,
+    target: SelectionTarget,
+) -> Result<(), whale_land::Error> {
+    let pending_contents = {
+        let mut data = shared.lock().await;
+        prepare_copy(conn, shared, &mut data, new_content, clear_after, target).await?
+    };
+
+    // the owners of the selections may take their time (or wait on us in turn);
+    // don't hold the lock while waiting for them and keep handling events meanwhile
+    let previous_contents = dispatch_wayland_until(conn, receive_contents(pending_contents)).await?;
+
+    let mut data = shared.lock().await;
+    for (is_primary, mime_type, bytes) in previous_contents {
+        let selection = if is_primary { &mut data.primary } else { &mut data.clipboard };
+        selection.previous_contents.push((mime_type, bytes));
+    }
+    place_code(conn, shared, &mut data, target).await
+}
+
+/// Stores the code and asks the owners of the selections it replaces for their current contents.
+///
+/// Returns the pipes through which the contents will arrive.
+async fn prepare_copy(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
     data: &mut WaylandData,
     new_content: String,
-) {
-    debug!("publishing {:?} on the clipboard", new_content);
+    clear_after: Option<Duration>,
+    target: SelectionTarget,
+) -> Result<Vec<PendingContents>, whale_land::Error> {
+    debug!("publishing {:?} on the {:?} selection", new_content, target);
 
-    // store the new content
-    data.clipboard_data = Some(new_content);
+    // store the new content (and drop stale content from the other selection)
+    if target.includes_clipboard() {
+        data.clipboard.data = Some(new_content.clone());
+    } else {
+        clear_selection(conn, shared, data, false).await?;
+    }
+    if target.includes_primary() {
+        data.primary.data = Some(new_content);
+    } else {
+        clear_selection(conn, shared, data, true).await?;
+    }
 
-    // do we have a data source?
-    if data.clipboard_source_id.is_some() {
-        // yup; no need to change anything here
-        return;
+    // (re)schedule clearing (a delay too long to represent means never)
+    data.clear_deadline = clear_after
+        .and_then(|ca| Instant::now().checked_add(ca));
+    if let (Some(ca), Some(_)) = (clear_after, data.clear_deadline) {
+        debug!("clipboard will be cleared in {:?}", ca);
     }
 
-    let Some(manager_id) = data.clipboard_manager_id else {
+    let Some(device) = data.clipboard_device.clone() else {
+        // place_code complains about this
+        return Ok(Vec::new());
+    };
+
+    let mut pending_contents = Vec::new();
+    let mut write_fds = Vec::new();
+    for is_primary in [false, true] {
+        let (included, selection) = if is_primary {
+            (target.includes_primary(), &mut data.primary)
+        } else {
+            (target.includes_clipboard(), &mut data.clipboard)
+        };
+        if !included || (is_primary && !device.supports_primary_selection()) {
+            continue;
+        }
+
+        // do we have a data source?
+        if selection.source.is_some() {
+            // yup; no need to change anything here
+            continue;
+        }
+
+        // remember what was there before
+        if let Some(restore_source) = selection.restore_source.take() {
+            // we are still restoring the contents from before the previous code; keep them
+            destroy_source(restore_source).await?;
+            continue;
+        }
+        selection.previous_contents.clear();
+        if selection.offer_is_ours {
+            // the contents are our own; there is nothing worth restoring
+            continue;
+        }
+        let Some(offer_data) = selection.offer_id.and_then(|offer_id| data.offer_id_to_offer.get(&offer_id)) else {
+            continue;
+        };
+        for mime_type in &offer_data.mime_types {
+            let (read_fd, write_fd) = match create_pipe() {
+                Ok(fds) => fds,
+                Err(e) => {
+                    warn!("failed to create pipe for previous selection contents as {:?}: {}", mime_type, e);
+                    continue;
+                },
+            };
+
+            // ask the owner of the selection to write its contents into the pipe
+            offer_data.offer.receive(mime_type, write_fd.as_raw_fd()).await?;
+            write_fds.push(write_fd);
+            pending_contents.push(PendingContents { is_primary, mime_type: mime_type.clone(), read_fd });
+        }
+    }
+
+    if !write_fds.is_empty() {
+        // we are about to wait for the owners, so the requests must actually go out now
+        conn.flush().await?;
+    }
+
+    // the owners have received their own copies of the write ends; close ours so we can see EOF
+    drop(write_fds);
+
+    Ok(pending_contents)
+}
+
+/// Places data sources providing the code into the selections that do not have one yet.
+async fn place_code(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    data: &mut WaylandData,
+    target: SelectionTarget,
+) -> Result<(), whale_land::Error> {
+    let Some(manager) = data.clipboard_manager.clone() else {
         error!("cannot copy data onto clipboard without a clipboard manager");
-        return;
+        return Ok(());
     };
-    let Some(device_id) = data.clipboard_device_id else {
+    let Some(device) = data.clipboard_device.clone() else {
         error!("cannot copy data onto clipboard without a clipboard device");
-        return;
+        return Ok(());
+    };
+
+    for is_primary in [false, true] {
+        let (included, selection) = if is_primary {
+            (target.includes_primary(), &mut data.primary)
+        } else {
+            (target.includes_clipboard(), &mut data.clipboard)
+        };
+        if !included {
+            continue;
+        }
+        if is_primary && !device.supports_primary_selection() {
+            warn!("not placing the code in the primary selection; the compositor does not support it");
+            continue;
+        }
+
+        // do we have a data source?
+        if selection.source.is_some() {
+            // yup; no need to change anything here
+            continue;
+        }
+        if selection.data.is_none() {
+            // the selection has been reset while we were receiving its previous contents
+            continue;
+        }
+
+        let source = create_source(
+            conn,
+            shared,
+            &manager,
+            PLAIN_TEXT_MIME_TYPES_SORTED.iter().copied()
+                .chain(std::iter::once(PASSWORD_MANAGER_HINT_MIME_TYPE)),
+        ).await?;
+        set_selection(&device, Some(&source), is_primary).await?;
+        selection.source = Some(source);
+    }
+    Ok(())
+}
+
+async fn send_contents(fd: OwnedFd, payload: Zeroizing<Vec<u8>>) {
+    let write_res = {
+        let mut async_fd = match AsyncFd::try_from(fd.as_raw_fd()) {
+            Ok(af) => af,
+            Err(e) => {
+                error!("failed to wrap file descriptor: {}", e);
+                return;
+            },
+        };
+        timeout(TRANSFER_TIMEOUT, async {
+            async_fd.write_all(&payload).await?;
+            async_fd.flush().await
+        }).await
     };
+    match write_res {
+        Ok(Ok(())) => debug!("sent {} bytes of clipboard data", payload.len()),
+        Ok(Err(e)) => warn!("failed to write clipboard data: {}", e),
+        Err(_elapsed) => warn!("recipient did not read clipboard data within {:?}; giving up", TRANSFER_TIMEOUT),
+    }
 
+    // fd is closed and payload is zeroized on drop
+}
+
+/// Reads the previous contents of the selections as their owners send them through the pipes.
+///
+/// Returns whether each belongs to the primary selection, its MIME type and the data itself.
+async fn receive_contents(pending_contents: Vec<PendingContents>) -> Vec<(bool, String, Vec<u8>)> {
+    // all pipes share one deadline so that an unresponsive owner does not hold us up
+    let deadline = Instant::now() + PREVIOUS_CONTENTS_TIMEOUT;
+
+    let reads = pending_contents
+        .into_iter()
+        .map(|pending| async move {
+            match read_pipe(pending.read_fd, deadline).await {
+                Ok(bytes) => {
+                    debug!("saved {} bytes of previous selection contents as {:?}", bytes.len(), pending.mime_type);
+                    Some((pending.is_primary, pending.mime_type, bytes))
+                },
+                Err(e) => {
+                    warn!("failed to save previous selection contents as {:?}: {}", pending.mime_type, e);
+                    None
+                },
+            }
+        });
+    join_all(reads).await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Creates a pipe, returning its read and write ends.
+fn create_pipe() -> Result<(OwnedFd, OwnedFd), io::Error> {
+    let mut pipe_fds: [c_int; 2] = [-1, -1];
+    let res = unsafe {
+        pipe2(pipe_fds.as_mut_ptr(), O_CLOEXEC)
+    };
+    if res == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    let fds = unsafe {
+        (OwnedFd::from_raw_fd(pipe_fds[0]), OwnedFd::from_raw_fd(pipe_fds[1]))
+    };
+    Ok(fds)
+}
+
+/// Reads from a pipe until its writers have closed it or the deadline has passed.
+async fn read_pipe(read_fd: OwnedFd, deadline: Instant) -> Result<Vec<u8>, io::Error> {
+    let mut buf = Vec::new();
+    let read_res = {
+        let mut fd = AsyncFd::try_from(read_fd.as_raw_fd())?;
+        timeout_at(deadline, fd.read_to_end(&mut buf)).await
+    };
+    drop(read_fd);
+
+    match read_res {
+        Ok(Ok(_)) => Ok(buf),
+        Ok(Err(e)) => Err(e),
+        Err(_elapsed) => Err(io::ErrorKind::TimedOut.into()),
+    }
+}
+
+async fn create_source<'a, I: Iterator<Item = &'a str>>(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    manager: &DataControlManager,
+    mime_types: I,
+) -> Result<DataControlSource, whale_land::Error> {
     // request a source from the manager
-    let source_id = conn.get_and_increment_next_object_id();
-    let gimme = ext_data_control_manager_v1_v1_request_create_data_source_args {
-        id: NewObjectId(source_id),
+    let source = manager.create_data_source().await?;
+    conn.register_handler(source.id(), Box::new(SourceHandler { data: Arc::clone(shared) }));
+    debug!("requested that the {:?} data-control source become {:?}", manager.protocol(), source.id());
+
+    // inform everyone which formats we can provide
+    for mime_type in mime_types {
+        source.offer(mime_type).await?;
+        debug!("informed about our support for {:?}", mime_type);
+    }
+
+    Ok(source)
+}
+
+async fn set_selection(
+    device: &DataControlDevice,
+    source: Option<&DataControlSource>,
+    is_primary: bool,
+) -> Result<(), whale_land::Error> {
+    let set_res = if is_primary {
+        device.set_primary_selection(source).await
+    } else {
+        device.set_selection(source).await
+    };
+    set_res?;
+    debug!(
+        "asked data-control device {:?} that {:?} becomes the {} selection",
+        device.id(), source.map(|s| s.id()), if is_primary { "primary" } else { "regular" },
+    );
+    Ok(())
+}
+
+async fn type_dispatch(
+    data: &mut WaylandData,
+    value: String,
+    press_enter: bool,
+) -> Result<(), whale_land::Error> {
+    let Some(keyboard) = &data.virtual_keyboard else {
+        error!("cannot type without a virtual keyboard; does the compositor support zwp_virtual_keyboard_manager_v1?");
+        return Ok(());
     };
-    let gimme_packet = gimme.try_into_packet(manager_id)
-        .expect("failed to serialize create-data-source packet");
-    conn.send_packet(&gimme_packet)
-        .await.expect("failed to send create-data-source packet");
-    debug!("requested that ext_data_control_source_v1 become {:?}", source_id);
-
-    // inform everyone that we can do plain text (in all its variants)
-    for plain_text_type in PLAIN_TEXT_MIME_TYPES_SORTED {
-        let i_can_plain_text = ext_data_control_source_v1_v1_request_offer_args {
-            mime_type: plain_text_type.to_owned(),
+
+    let mut keys = Vec::with_capacity(value.len() + 1);
+    for c in value.chars() {
+        let Some(key) = virtual_keyboard::char_to_key(c) else {
+            error!("cannot type {:?}; our keymap only contains digits", c);
+            return Ok(());
         };
-        let i_can_packet = i_can_plain_text.try_into_packet(source_id)
-            .expect("failed to serialize I-can-do-plain-text packet");
-        conn.send_packet(&i_can_packet)
-            .await.expect("failed to send I-can-do-plain-text packet");
+        keys.push(key);
+    }
+    if press_enter {
+        keys.push(virtual_keyboard::KEY_ENTER);
     }
-    debug!("informed about our support for text/plain");
 
-    // set us as the data source
-    let set_data_source = ext_data_control_device_v1_v1_request_set_selection_args {
-        source: Some(source_id),
-    };
-    let set_data_packet = set_data_source.try_into_packet(device_id)
-        .expect("failed to serialize set-data-source packet");
-    conn.send_packet(&set_data_packet)
-        .await.expect("failed to send set-data-source packet");
-    data.clipboard_source_id = Some(source_id);
-    debug!("asked ext_data_control_device_v1 {:?} that {:?} becomes the selection", device_id, source_id);
+    debug!("typing {} keys", keys.len());
+    for key in keys {
+        for state in [wl_keyboard_key_state::pressed, wl_keyboard_key_state::released] {
+            keyboard.send_key(virtual_keyboard::key_timestamp(), key, u32::from(state)).await?;
+        }
+    }
+    Ok(())
 }
 
 
 async fn clear_dispatch(
     conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
     data: &mut WaylandData,
-) {
+) -> Result<(), whale_land::Error> {
+    data.clear_deadline = None;
+    clear_selection(conn, shared, data, false).await?;
+    clear_selection(conn, shared, data, true).await
+}
+
+async fn clear_selection(
+    conn: &whale_land::Connection,
+    shared: &SharedWaylandData,
+    data: &mut WaylandData,
+    is_primary: bool,
+) -> Result<(), whale_land::Error> {
+    let manager_opt = data.clipboard_manager.clone();
+    let device_opt = data.clipboard_device.clone();
+    let selection = if is_primary { &mut data.primary } else { &mut data.clipboard };
+
     // drop the content
-    data.clipboard_data = None;
+    selection.data = None;
 
     // do we have a data source?
-    let Some(source_id) = data.clipboard_source_id else {
+    let Some(source) = selection.source.take() else {
         // nope; no need to worry
-        return;
+        return Ok(());
     };
+    destroy_source(source).await?;
 
-    let destroy_source = ext_data_control_source_v1_v1_request_destroy_args {
+    // restore what was there before
+    if selection.previous_contents.is_empty() {
+        return Ok(());
+    }
+    let (Some(manager), Some(device)) = (manager_opt, device_opt) else {
+        return Ok(());
     };
-    let destroy_packet = destroy_source.try_into_packet(source_id)
-        .expect("failed to serialize destroy-data-source packet");
-    conn.send_packet(&destroy_packet)
-        .await.expect("failed to send destroy-data-source packet");
-    debug!("ask that we {:?} are no longer the data source", source_id);
+    let restore_source = create_source(
+        conn,
+        shared,
+        &manager,
+        selection.previous_contents
+            .iter()
+            .map(|(mime_type, _bytes)| mime_type.as_str()),
+    ).await?;
+    set_selection(&device, Some(&restore_source), is_primary).await?;
+    debug!("restoring previous contents via {:?}", restore_source.id());
+    selection.restore_source = Some(restore_source);
+    Ok(())
+}
 
-    // forget our data source
-    data.clipboard_source_id = None;
+async fn destroy_source(
+    source: DataControlSource,
+) -> Result<(), whale_land::Error> {
+    let source_id = source.id();
+    source.destroy().await?;
+    debug!("ask that we {:?} are no longer the data source", source_id);
+    Ok(())
 }
@@ -9,6 +9,7 @@ pub(crate) mod proxies;
 
 use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -17,13 +18,16 @@ use tracing::{debug, error, warn};
 use zbus::object_server::SignalEmitter;
 use zbus::zvariant::{OwnedObjectPath, OwnedValue, Str, Type, Value};
 
-use crate::{ClipboardMessage, SECRET_SESSION};
-use crate::totp::{self, TotpParameters};
+use crate::{ClipboardMessage, Delivery, SECRET_SESSION, SelectionTarget};
+use crate::totp::{self, OtpKind, TotpParameters};
 
 
 const MENU_SEPARATOR_ID: i32 = 0x7FFF_FFFE;
 const MENU_UPDATE_ID: i32 = 0x7FFF_FFFD;
 const MENU_EXIT_ID: i32 = 0x7FFF_FFFF;
+const SELECTION_ATTRIBUTE: &str = "selection";
+const DELIVERY_ATTRIBUTE: &str = "delivery";
+const AUTO_TYPE_DELAY: Duration = Duration::from_millis(250);
 
 
 pub(crate) struct TrayIcon;
@@ -172,16 +176,25 @@ pub(crate) struct ContextMenu {
     secret_name_to_path: RwLock<BTreeMap<String, OwnedObjectPath>>,
     clipboard_sender: UnboundedSender<ClipboardMessage>,
     menu_revision: AtomicU32,
+    clear_after_s: Option<u64>,
+    default_selection: SelectionTarget,
+    default_delivery: Delivery,
 }
 impl ContextMenu {
     pub fn new(
         secret_name_to_path: RwLock<BTreeMap<String, OwnedObjectPath>>,
         clipboard_sender: UnboundedSender<ClipboardMessage>,
+        clear_after_s: Option<u64>,
+        default_selection: SelectionTarget,
+        default_delivery: Delivery,
     ) -> Self {
         Self {
             secret_name_to_path,
             clipboard_sender,
             menu_revision: AtomicU32::new(1),
+            clear_after_s,
+            default_selection,
+            default_delivery,
         }
     }
 
@@ -470,18 +483,117 @@ impl ContextMenu {
                         return Ok(());
                     }
                 };
-                let period_s = params.period_seconds
-                    .unwrap_or(TotpParameters::DEFAULT_PERIOD_SECONDS);
                 let digits = params.digits
                     .unwrap_or(TotpParameters::DEFAULT_DIGITS);
-                let otp_code = totp::totp_now(
-                    algorithm,
-                    &params.key,
-                    period_s,
-                    digits,
-                );
+                let (otp_code, validity) = match params.kind {
+                    OtpKind::Totp => {
+                        let period_s = params.period_seconds
+                            .unwrap_or(TotpParameters::DEFAULT_PERIOD_SECONDS);
+                        let code = totp::totp_now(
+                            algorithm,
+                            &params.key,
+                            period_s,
+                            digits,
+                        );
+                        (code, totp::totp_validity_remaining_now(period_s))
+                    },
+                    OtpKind::Hotp => {
+                        // the parser ensures that HOTP URIs contain a counter
+                        let counter = params.counter.unwrap();
+                        let Some(next_counter) = counter.checked_add(1) else {
+                            error!("HOTP counter cannot be incremented any further");
+                            return Ok(());
+                        };
+
+                        // persist the incremented counter before handing out the code;
+                        // otherwise, we might hand out the same code twice
+                        let Some(new_url) = TotpParameters::replace_otpauth_url_counter(secret_str, next_counter) else {
+                            error!("failed to update HOTP counter in URI");
+                            return Ok(());
+                        };
+                        let stored = {
+                            let secret_session = crate::SECRET_SESSION
+                                .get().expect("SECRET_SESSION unset?!")
+                                .read().await;
+                            secret_session
+                                .set_secret(secret_path.clone().into(), new_url.as_bytes()).await
+                        };
+                        if !stored {
+                            error!("failed to store incremented HOTP counter for secret with index {}", actual_index);
+                            return Ok(());
+                        }
+                        debug!("HOTP counter incremented to {}", next_counter);
+
+                        let code = totp::hotp(
+                            algorithm,
+                            &params.key,
+                            counter,
+                            digits,
+                        );
+
+                        // HOTP codes do not expire; fall back to the default TOTP period
+                        (code, Duration::from_secs(TotpParameters::DEFAULT_PERIOD_SECONDS))
+                    },
+                };
                 let otp_code_string = format!("{0:01$}", otp_code, digits.into());
-                self.clipboard_sender.send(ClipboardMessage::Copy(otp_code_string));
+                let clear_after = match self.clear_after_s {
+                    Some(0) => None,
+                    Some(s) => Some(Duration::from_secs(s)),
+                    None => Some(validity),
+                };
+
+                // the secret's attributes may override the selection and the delivery
+                let attributes_opt = {
+                    let secret_session = crate::SECRET_SESSION
+                        .get().expect("SECRET_SESSION unset?!")
+                        .read().await;
+                    secret_session
+                        .get_attributes(secret_path.clone().into()).await
+                };
+                let selection_attribute = attributes_opt
+                    .as_ref()
+                    .and_then(|a| a.get(SELECTION_ATTRIBUTE));
+                let target = match selection_attribute {
+                    Some(sa) => match SelectionTarget::try_from_str(sa) {
+                        Some(t) => t,
+                        None => {
+                            warn!("unknown selection {:?}; using the default", sa);
+                            self.default_selection
+                        },
+                    },
+                    None => self.default_selection,
+                };
+                let delivery_attribute = attributes_opt
+                    .as_ref()
+                    .and_then(|a| a.get(DELIVERY_ATTRIBUTE));
+                let delivery = match delivery_attribute {
+                    Some(da) => match Delivery::try_from_str(da) {
+                        Some(d) => d,
+                        None => {
+                            warn!("unknown delivery {:?}; using the default", da);
+                            self.default_delivery
+                        },
+                    },
+                    None => self.default_delivery,
+                };
+
+                match delivery {
+                    Delivery::Copy => {
+                        self.clipboard_sender.send(ClipboardMessage::Copy {
+                            value: otp_code_string,
+                            clear_after,
+                            target,
+                        });
+                    },
+                    Delivery::Type|Delivery::TypeEnter => {
+                        // give the menu a moment to close and the previous window to regain focus
+                        tokio::time::sleep(AUTO_TYPE_DELAY).await;
+                        self.clipboard_sender.send(ClipboardMessage::Type {
+                            value: otp_code_string,
+                            press_enter: delivery == Delivery::TypeEnter,
+                        });
+                    },
+                }
             },
         }
 
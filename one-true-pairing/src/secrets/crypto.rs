@@ -1,8 +1,9 @@
 use std::fmt::{self, Debug};
 
-use aes::{Aes128, cipher::{BlockDecryptMut, block_padding::Pkcs7}};
-use cbc::{Decryptor, cipher::KeyIvInit};
+use aes::{Aes128, cipher::{BlockDecryptMut, BlockEncryptMut, block_padding::Pkcs7}};
+use cbc::{Decryptor, Encryptor, cipher::KeyIvInit};
 use crypto_bigint::Uint;
+use crypto_bigint::rand_core::{OsRng, RngCore};
 use hkdf::Hkdf;
 use sha2::Sha256;
 use tracing::error;
@@ -39,6 +40,11 @@ pub trait CryptoAlgorithm : Debug + Send + Sync {
     ///
     /// Returns `None` if decoding fails.
     fn decode_secret(&self, parameters: &[u8], value: &[u8]) -> Option<Zeroizing<Vec<u8>>>;
+
+    /// Encodes the given secret value and returns the parameters and the encoded value.
+    ///
+    /// Returns `None` if encoding fails.
+    fn encode_secret(&self, secret: &[u8]) -> Option<(Vec<u8>, Vec<u8>)>;
 }
 
 
@@ -76,6 +82,11 @@ impl CryptoAlgorithm for PlainCrypto {
             None
         }
     }
+
+    fn encode_secret(&self, secret: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+        // empty parameters, unchanged value
+        Some((Vec::with_capacity(0), secret.to_vec()))
+    }
 }
 
 
@@ -178,4 +189,29 @@ impl CryptoAlgorithm for DhIetf1024Sha256Aes128CbcPkcs7Crypto {
         secret_buf.drain(decrypted_slice_len..);
         Some(secret_buf)
     }
+
+    fn encode_secret(&self, secret: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+        // parameters is a fresh random 16-byte AES128-CBC initialization vector
+        // value is the ciphertext with PKCS#7 padding
+        let Some(aes_key) = self.aes_key.as_ref() else {
+            error!("no AES key set");
+            return None;
+        };
+        let mut iv = vec![0u8; 16];
+        OsRng.fill_bytes(&mut iv);
+
+        let aes128_cbc_pkcs7_enc: Encryptor<Aes128> = cbc::Encryptor::new_from_slices(&**aes_key, &iv)
+            .expect("failed to create AES-128 CBC PKCS#7-padding encryptor");
+
+        // PKCS#7 always pads, even if the plaintext is already a multiple of the block size
+        let padded_len = (secret.len() / 16 + 1) * 16;
+        let mut cipher_buf = vec![0u8; padded_len];
+        let Ok(encrypted_slice) = aes128_cbc_pkcs7_enc.encrypt_padded_b2b_mut::<Pkcs7>(secret, &mut cipher_buf) else {
+            error!("ciphertext buffer too small");
+            return None;
+        };
+        let encrypted_slice_len = encrypted_slice.len();
+        cipher_buf.truncate(encrypted_slice_len);
+        Some((iv, cipher_buf))
+    }
 }
@@ -13,7 +13,10 @@ use zbus::zvariant::{ObjectPath, OwnedObjectPath};
 use zeroize::Zeroizing;
 
 use crate::secrets::crypto::{CryptoAlgorithm, DhIetf1024Sha256Aes128CbcPkcs7Crypto, PlainCrypto};
-use crate::secrets::proxies::{CollectionProxy, ItemProxy, PromptProxy, ServiceProxy};
+use crate::secrets::proxies::{CollectionProxy, ItemProxy, PromptProxy, Secret, ServiceProxy};
+
+
+const DEFAULT_SECRET_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
 
 
 #[derive(Debug)]
@@ -168,6 +171,64 @@ impl SecretSession {
         }
     }
 
+    pub async fn get_attributes(&self, item_path: ObjectPath<'_>) -> Option<HashMap<String, String>> {
+        let item_proxy = match ItemProxy::new(self.connection.as_ref().unwrap(), item_path).await {
+            Ok(ip) => ip,
+            Err(e) => {
+                error!("failed to obtain item proxy: {}", e);
+                return None;
+            }
+        };
+        match item_proxy.attributes().await {
+            Ok(a) => Some(a),
+            Err(e) => {
+                error!("failed to obtain attributes of item: {}", e);
+                None
+            },
+        }
+    }
+
+    pub async fn set_secret(&self, item_path: ObjectPath<'_>, secret: &[u8]) -> bool {
+        let item_proxy = match ItemProxy::new(self.connection.as_ref().unwrap(), item_path).await {
+            Ok(ip) => ip,
+            Err(e) => {
+                error!("failed to obtain item proxy: {}", e);
+                return false;
+            }
+        };
+
+        // keep whatever content type the item has been given by whoever created it
+        let content_type = match item_proxy.get_secret(self.session_path.clone().into()).await {
+            Ok(old_secret) => {
+                // the old value may well be in plain text
+                drop(Zeroizing::new(old_secret.value));
+                old_secret.content_type
+            },
+            Err(e) => {
+                warn!("failed to obtain content type of secret; assuming {:?}: {}", DEFAULT_SECRET_CONTENT_TYPE, e);
+                DEFAULT_SECRET_CONTENT_TYPE.to_owned()
+            },
+        };
+
+        let Some((parameters, value)) = self.algo.encode_secret(secret) else {
+            error!("algo failed to encode secret");
+            return false;
+        };
+        let new_secret = Secret {
+            session: self.session_path.clone(),
+            parameters,
+            value,
+            content_type,
+        };
+        match item_proxy.set_secret(new_secret).await {
+            Ok(()) => true,
+            Err(e) => {
+                error!("failed to store secret in item: {}", e);
+                false
+            },
+        }
+    }
+
     pub async fn drop_connection(&mut self) {
         let connection_opt = std::mem::replace(
             &mut self.connection,
@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use hmac::{Hmac, Mac};
 use hmac::digest::DynDigest;
@@ -46,8 +46,20 @@ impl Algorithm {
 }
 
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum OtpKind {
+    /// Time-based one-time password (RFC6238).
+    #[default] Totp,
+
+    /// Counter-based one-time password (RFC4226).
+    Hotp,
+}
+
+
 #[derive(Clone, Debug, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
 pub struct TotpParameters {
+    #[zeroize(skip)]
+    pub kind: OtpKind,
     pub key: Zeroizing<Vec<u8>>,
     pub url_issuer: Option<Zeroizing<String>>,
     pub username: Option<Zeroizing<String>>,
@@ -55,6 +67,7 @@ pub struct TotpParameters {
     pub algorithm: Option<Zeroizing<String>>,
     pub digits: Option<u8>,
     pub period_seconds: Option<u64>,
+    pub counter: Option<u64>,
 }
 impl TotpParameters {
     pub const DEFAULT_ALGORITHM: &str = "SHA1";
@@ -62,8 +75,7 @@ impl TotpParameters {
     pub const DEFAULT_PERIOD_SECONDS: u64 = 30;
 
     pub fn try_from_otpauth_url(url: &str) -> Option<TotpParameters> {
-        const PREFIX: &str = "otpauth://totp/";
-        let prefixless_u = url.strip_prefix(PREFIX)?;
+        let (kind, prefixless_u) = strip_otpauth_prefix(url)?;
         let (issuer_username_u, params_str_u) = prefixless_u.split_once('?')
             .unwrap_or((prefixless_u, ""));
         let (url_issuer_u, username_u) = issuer_username_u.split_once(':')
@@ -74,6 +86,7 @@ impl TotpParameters {
         let mut algorithm = None;
         let mut digits = None;
         let mut period_seconds = None;
+        let mut counter = None;
         for property_u in params_str_u.split('&') {
             let Some((key_u, value_u)) = property_u.split_once('=')
                 else { continue };
@@ -106,6 +119,10 @@ impl TotpParameters {
                     return None;
                 }
                 period_seconds = Some(period_seconds_value);
+            } else if key.as_str() == "counter" {
+                let Ok(counter_value): Result<u64, _> = value.parse()
+                    else { return None };
+                counter = Some(counter_value);
             } else {
                 // FIXME: blow up on unknown attributes?
             }
@@ -119,6 +136,10 @@ impl TotpParameters {
             warn!("cannot process a TOTP URI with a secret that is invalid base-32");
             return None;
         };
+        if kind == OtpKind::Hotp && counter.is_none() {
+            warn!("cannot process an HOTP URI without a counter");
+            return None;
+        }
 
         let url_issuer = if url_issuer_u.len() > 0 {
             zv_to_string(urldecode(url_issuer_u, false))
@@ -132,6 +153,7 @@ impl TotpParameters {
         };
 
         Some(Self {
+            kind,
             key,
             url_issuer,
             username,
@@ -139,8 +161,64 @@ impl TotpParameters {
             algorithm,
             digits,
             period_seconds,
+            counter,
         })
     }
+
+    /// Returns a copy of the given otpauth URL with the value of the `counter` parameter replaced
+    /// by the given value.
+    ///
+    /// All other parts of the URL are retained verbatim. Returns `None` if the URL is not an
+    /// otpauth URL or does not contain a `counter` parameter.
+    pub fn replace_otpauth_url_counter(url: &str, new_counter: u64) -> Option<Zeroizing<String>> {
+        strip_otpauth_prefix(url)?;
+        let (base_u, params_str_u) = url.split_once('?')?;
+
+        let mut ret = Zeroizing::new(String::with_capacity(url.len() + 20));
+        ret.push_str(base_u);
+        ret.push('?');
+
+        let mut replaced = false;
+        for (i, property_u) in params_str_u.split('&').enumerate() {
+            if i > 0 {
+                ret.push('&');
+            }
+
+            let is_counter = match property_u.split_once('=') {
+                Some((key_u, _value_u)) => {
+                    let key_bytes = urldecode(key_u, true);
+                    key_bytes.as_slice() == b"counter"
+                },
+                None => false,
+            };
+            if is_counter {
+                ret.push_str("counter=");
+                ret.push_str(&new_counter.to_string());
+                replaced = true;
+            } else {
+                ret.push_str(property_u);
+            }
+        }
+
+        if replaced {
+            Some(ret)
+        } else {
+            None
+        }
+    }
+}
+
+fn strip_otpauth_prefix(url: &str) -> Option<(OtpKind, &str)> {
+    const TOTP_PREFIX: &str = "otpauth://totp/";
+    const HOTP_PREFIX: &str = "otpauth://hotp/";
+
+    if let Some(prefixless) = url.strip_prefix(TOTP_PREFIX) {
+        Some((OtpKind::Totp, prefixless))
+    } else if let Some(prefixless) = url.strip_prefix(HOTP_PREFIX) {
+        Some((OtpKind::Hotp, prefixless))
+    } else {
+        None
+    }
 }
 
 fn urldecode(value: &str, plus: bool) -> Zeroizing<Vec<u8>> {
@@ -364,3 +442,15 @@ pub fn totp_now(
         .expect("sorry, system dates before 1970 are not supported");
     totp(hmac_algorithm, shared_secret, unix_time.as_secs(), period_s, digits)
 }
+
+/// Returns how much longer a TOTP code generated now remains valid.
+pub fn totp_validity_remaining_now(period_s: u64) -> Duration {
+    let unix_time = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("sorry, system dates before 1970 are not supported");
+    let period = Duration::from_secs(period_s);
+    let elapsed_in_period = Duration::from_nanos(
+        (unix_time.as_nanos() % period.as_nanos()).try_into().unwrap()
+    );
+    period - elapsed_in_period
+}
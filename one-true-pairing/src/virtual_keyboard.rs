@@ -0,0 +1,102 @@
+//! Typing codes using a virtual keyboard (`virtual-keyboard-unstable-v1`).
+//!
+//! The virtual keyboard comes with its own keymap, which only needs to contain the keys we actually
+//! press: the digits and Enter.
+
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::os::fd::{FromRawFd, OwnedFd};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use libc::{MFD_CLOEXEC, memfd_create};
+
+
+/// Linux input event code of the Enter key.
+pub const KEY_ENTER: u32 = 28;
+
+/// A keymap containing the digits and Enter, using the Linux input event codes of a US keyboard
+/// (offset by 8, as is XKB tradition).
+const KEYMAP: &str = r#"xkb_keymap {
+    xkb_keycodes "one-true-pairing" {
+        minimum = 8;
+        maximum = 255;
+        <AE01> = 10;
+        <AE02> = 11;
+        <AE03> = 12;
+        <AE04> = 13;
+        <AE05> = 14;
+        <AE06> = 15;
+        <AE07> = 16;
+        <AE08> = 17;
+        <AE09> = 18;
+        <AE10> = 19;
+        <RTRN> = 36;
+    };
+    xkb_types "one-true-pairing" {
+        type "ONE_LEVEL" {
+            modifiers = none;
+            level_name[Level1] = "Any";
+        };
+    };
+    xkb_compat "one-true-pairing" {
+    };
+    xkb_symbols "one-true-pairing" {
+        key <AE01> { [ 1 ] };
+        key <AE02> { [ 2 ] };
+        key <AE03> { [ 3 ] };
+        key <AE04> { [ 4 ] };
+        key <AE05> { [ 5 ] };
+        key <AE06> { [ 6 ] };
+        key <AE07> { [ 7 ] };
+        key <AE08> { [ 8 ] };
+        key <AE09> { [ 9 ] };
+        key <AE10> { [ 0 ] };
+        key <RTRN> { [ Return ] };
+    };
+};
+"#;
+
+
+/// Returns the Linux input event code of the key that types the given character in our keymap.
+pub fn char_to_key(c: char) -> Option<u32> {
+    match c {
+        // KEY_1 = 2, ..., KEY_9 = 10
+        '1'..='9' => Some(u32::from(c) - u32::from('1') + 2),
+        // KEY_0 = 11
+        '0' => Some(11),
+        _ => None,
+    }
+}
+
+/// Writes our keymap into an anonymous file, returning the file and the keymap's size in bytes.
+pub fn create_keymap_file() -> Result<(OwnedFd, u32), io::Error> {
+    let fd = unsafe {
+        memfd_create(c"one-true-pairing-keymap".as_ptr(), MFD_CLOEXEC)
+    };
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut file = unsafe {
+        File::from_raw_fd(fd)
+    };
+
+    // the compositor expects the keymap to be NUL-terminated
+    file.write_all(KEYMAP.as_bytes())?;
+    file.write_all(b"\0")?;
+
+    let size = u32::try_from(KEYMAP.len() + 1).unwrap();
+    Ok((file.into(), size))
+}
+
+/// Returns a timestamp for key events.
+///
+/// The protocol only requires millisecond granularity and that all events on the same virtual
+/// keyboard share the same clock.
+pub fn key_timestamp() -> u32 {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    // wrapping is expected
+    since_epoch.as_millis() as u32
+}
@@ -0,0 +1,505 @@
+//! A minimal client for the X11 core protocol.
+//!
+//! Derived from the specification at
+//! https://www.x.org/releases/X11R7.7/doc/xproto/x11protocol.html
+
+
+use std::env;
+use std::ffi::CStr;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+
+const DISPLAY_VAR: &str = "DISPLAY";
+const XAUTHORITY_VAR: &str = "XAUTHORITY";
+const HOME_VAR: &str = "HOME";
+const X11_SOCKET_DIR: &str = "/tmp/.X11-unix";
+const MIT_MAGIC_COOKIE: &[u8] = b"MIT-MAGIC-COOKIE-1";
+const XAUTH_FAMILY_LOCAL: u16 = 256;
+const XAUTH_FAMILY_WILD: u16 = 65535;
+const LITTLE_ENDIAN_BYTE_ORDER: u8 = b'l';
+const PROTOCOL_MAJOR_VERSION: u16 = 11;
+const PROTOCOL_MINOR_VERSION: u16 = 0;
+const MESSAGE_LENGTH: usize = 32;
+
+const OPCODE_CREATE_WINDOW: u8 = 1;
+const OPCODE_INTERN_ATOM: u8 = 16;
+const OPCODE_CHANGE_PROPERTY: u8 = 18;
+const OPCODE_SET_SELECTION_OWNER: u8 = 22;
+const OPCODE_SEND_EVENT: u8 = 25;
+
+const WINDOW_CLASS_INPUT_ONLY: u16 = 2;
+const PROPERTY_MODE_REPLACE: u8 = 0;
+
+const MESSAGE_ERROR: u8 = 0;
+const MESSAGE_REPLY: u8 = 1;
+const EVENT_SELECTION_CLEAR: u8 = 29;
+const EVENT_SELECTION_REQUEST: u8 = 30;
+const EVENT_SELECTION_NOTIFY: u8 = 31;
+
+/// The `None` resource ID (window, atom, etc.).
+pub const NONE: u32 = 0;
+
+/// The `CurrentTime` timestamp.
+pub const CURRENT_TIME: u32 = 0;
+
+/// Predefined atoms.
+pub const ATOM_PRIMARY: u32 = 1;
+pub const ATOM_ATOM: u32 = 4;
+pub const ATOM_STRING: u32 = 31;
+
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    MissingEnvVar { name: String },
+    UnsupportedDisplay { display: String },
+    SetupFailed { reason: String },
+    SetupTooShort { actual: usize, minimum: usize },
+    NoScreens,
+    RequestTooLong { actual: usize, maximum: usize },
+    ServerError { code: u8, major_opcode: u8, bad_value: u32 },
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e)
+                => write!(f, "I/O error: {}", e),
+            Self::MissingEnvVar { name }
+                => write!(f, "missing environment variable {:?}", name),
+            Self::UnsupportedDisplay { display }
+                => write!(f, "display {:?} is not a local X11 display", display),
+            Self::SetupFailed { reason }
+                => write!(f, "the X server refused the connection: {}", reason),
+            Self::SetupTooShort { actual, minimum }
+                => write!(f, "connection setup reply ({} bytes) too short (minimum {} bytes)", actual, minimum),
+            Self::NoScreens
+                => write!(f, "the X server has no screens"),
+            Self::RequestTooLong { actual, maximum }
+                => write!(f, "request ({} bytes) too long (maximum {} bytes)", actual, maximum),
+            Self::ServerError { code, major_opcode, bad_value }
+                => write!(f, "the X server reports error {} for request {} (value {})", code, major_opcode, bad_value),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::MissingEnvVar { .. } => None,
+            Self::UnsupportedDisplay { .. } => None,
+            Self::SetupFailed { .. } => None,
+            Self::SetupTooShort { .. } => None,
+            Self::NoScreens => None,
+            Self::RequestTooLong { .. } => None,
+            Self::ServerError { .. } => None,
+        }
+    }
+}
+impl From<io::Error> for Error {
+    fn from(value: io::Error) -> Self { Self::Io(value) }
+}
+
+
+/// A message sent by the X server.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Message {
+    Error { code: u8, major_opcode: u8, bad_value: u32 },
+    Reply { data: Vec<u8> },
+    Event(Event),
+}
+
+/// An event sent by the X server.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Event {
+    SelectionClear { time: u32, owner: u32, selection: u32 },
+    SelectionRequest { time: u32, owner: u32, requestor: u32, selection: u32, target: u32, property: u32 },
+    Other { code: u8 },
+}
+
+
+/// The receiving half of the connection along with the bytes that have not been parsed yet.
+struct Receiver {
+    reader: OwnedReadHalf,
+    buffer: Vec<u8>,
+}
+
+
+pub struct Connection {
+    receiver: Mutex<Receiver>,
+    writer: Mutex<OwnedWriteHalf>,
+    resource_id_base: u32,
+    resource_id_mask: u32,
+    next_resource_id: AtomicU32,
+    maximum_request_length: usize,
+    root_window: u32,
+}
+impl Connection {
+    pub async fn new_from_env() -> Result<Self, Error> {
+        let display = env::var(DISPLAY_VAR)
+            .map_err(|_| Error::MissingEnvVar { name: DISPLAY_VAR.to_owned() })?;
+
+        // [host]:display[.screen]; we only speak to local displays
+        let Some((host, display_and_screen)) = display.rsplit_once(':') else {
+            return Err(Error::UnsupportedDisplay { display });
+        };
+        if !host.is_empty() && host != "unix" {
+            return Err(Error::UnsupportedDisplay { display });
+        }
+        let display_number = display_and_screen
+            .split('.')
+            .next().unwrap();
+        if display_number.is_empty() || !display_number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::UnsupportedDisplay { display });
+        }
+
+        let mut socket_path = PathBuf::from(X11_SOCKET_DIR);
+        socket_path.push(format!("X{}", display_number));
+        let socket = UnixStream::connect(&socket_path).await?;
+        let (mut reader, mut writer) = socket.into_split();
+
+        // introduce ourselves
+        let cookie = find_auth_cookie(display_number);
+        let (auth_name, auth_data) = match &cookie {
+            Some(c) => (MIT_MAGIC_COOKIE, c.as_slice()),
+            None => (&[][..], &[][..]),
+        };
+        let mut setup = Vec::with_capacity(12 + padded_len(auth_name.len()) + padded_len(auth_data.len()));
+        setup.push(LITTLE_ENDIAN_BYTE_ORDER);
+        setup.push(0);
+        setup.extend_from_slice(&PROTOCOL_MAJOR_VERSION.to_le_bytes());
+        setup.extend_from_slice(&PROTOCOL_MINOR_VERSION.to_le_bytes());
+        setup.extend_from_slice(&u16::try_from(auth_name.len()).unwrap().to_le_bytes());
+        setup.extend_from_slice(&u16::try_from(auth_data.len()).unwrap().to_le_bytes());
+        setup.extend_from_slice(&[0, 0]);
+        push_padded(&mut setup, auth_name);
+        push_padded(&mut setup, auth_data);
+        writer.write_all(&setup).await?;
+
+        // status, reason length, major, minor, additional length
+        let mut fixed_buf = [0u8; 8];
+        reader.read_exact(&mut fixed_buf).await?;
+        let additional_length = usize::from(u16::from_le_bytes(fixed_buf[6..8].try_into().unwrap())) * 4;
+        let mut reply = vec![0u8; additional_length];
+        reader.read_exact(&mut reply).await?;
+
+        if fixed_buf[0] != 1 {
+            // 0 = failed, 2 = further authentication required
+            let reason_length = if fixed_buf[0] == 0 { usize::from(fixed_buf[1]) } else { reply.len() };
+            let reason = String::from_utf8_lossy(&reply[..reason_length.min(reply.len())])
+                .trim_end_matches('\0')
+                .to_owned();
+            return Err(Error::SetupFailed { reason });
+        }
+
+        // the fixed part of the success reply (after the first 8 bytes) is 32 bytes long
+        if reply.len() < 32 {
+            return Err(Error::SetupTooShort { actual: reply.len() + 8, minimum: 40 });
+        }
+        let resource_id_base = u32::from_le_bytes(reply[4..8].try_into().unwrap());
+        let resource_id_mask = u32::from_le_bytes(reply[8..12].try_into().unwrap());
+        let vendor_length = usize::from(u16::from_le_bytes(reply[16..18].try_into().unwrap()));
+        let maximum_request_length = usize::from(u16::from_le_bytes(reply[18..20].try_into().unwrap())) * 4;
+        let screen_count = reply[20];
+        let format_count = usize::from(reply[21]);
+        if screen_count == 0 {
+            return Err(Error::NoScreens);
+        }
+
+        // skip the vendor string and the pixmap formats (8 bytes each) to reach the first screen
+        let first_screen_offset = 32 + padded_len(vendor_length) + 8 * format_count;
+        if reply.len() < first_screen_offset + 4 {
+            return Err(Error::SetupTooShort { actual: reply.len() + 8, minimum: first_screen_offset + 4 + 8 });
+        }
+        let root_window = u32::from_le_bytes(reply[first_screen_offset..first_screen_offset+4].try_into().unwrap());
+        debug!(
+            "connected to X11 display {} (root window 0x{:08X}, resource IDs 0x{:08X}/0x{:08X})",
+            display_number, root_window, resource_id_base, resource_id_mask,
+        );
+
+        Ok(Self {
+            receiver: Mutex::new(Receiver { reader, buffer: Vec::new() }),
+            writer: Mutex::new(writer),
+            resource_id_base,
+            resource_id_mask,
+            next_resource_id: AtomicU32::new(1),
+            maximum_request_length,
+            root_window,
+        })
+    }
+
+    pub fn generate_resource_id(&self) -> u32 {
+        // the mask's bits are contiguous; shift the counter into them
+        let shift = self.resource_id_mask.trailing_zeros();
+        let counter = self.next_resource_id.fetch_add(1, Ordering::SeqCst);
+        self.resource_id_base | ((counter << shift) & self.resource_id_mask)
+    }
+
+    async fn send_request(&self, request: &[u8]) -> Result<(), Error> {
+        debug_assert_eq!(request.len() % 4, 0);
+        if request.len() > self.maximum_request_length {
+            return Err(Error::RequestTooLong { actual: request.len(), maximum: self.maximum_request_length });
+        }
+        let mut writer = self.writer.lock().await;
+        writer.write_all(request).await?;
+        Ok(())
+    }
+
+    /// Receives the next message from the X server.
+    ///
+    /// Cancel-safe: the bytes of a partially received message are kept until the next call.
+    pub async fn recv_message(&self) -> Result<Message, Error> {
+        let mut receiver_guard = self.receiver.lock().await;
+        let receiver = &mut *receiver_guard;
+
+        loop {
+            if let Some(message_length) = complete_message_length(&receiver.buffer) {
+                let data: Vec<u8> = receiver.buffer.drain(..message_length).collect();
+                return Ok(parse_message(data));
+            }
+
+            // unlike read_exact, read_buf loses no data if it is cancelled
+            let read_bytes = receiver.reader.read_buf(&mut receiver.buffer).await?;
+            if read_bytes == 0 {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+        }
+    }
+
+    /// Creates an invisible window, e.g. to own selections.
+    pub async fn create_input_only_window(&self, window: u32) -> Result<(), Error> {
+        let mut request = Vec::with_capacity(32);
+        request.push(OPCODE_CREATE_WINDOW);
+        request.push(0); // depth: InputOnly windows have none
+        request.extend_from_slice(&8u16.to_le_bytes());
+        request.extend_from_slice(&window.to_le_bytes());
+        request.extend_from_slice(&self.root_window.to_le_bytes());
+        request.extend_from_slice(&0i16.to_le_bytes()); // x
+        request.extend_from_slice(&0i16.to_le_bytes()); // y
+        request.extend_from_slice(&1u16.to_le_bytes()); // width
+        request.extend_from_slice(&1u16.to_le_bytes()); // height
+        request.extend_from_slice(&0u16.to_le_bytes()); // border width
+        request.extend_from_slice(&WINDOW_CLASS_INPUT_ONLY.to_le_bytes());
+        request.extend_from_slice(&0u32.to_le_bytes()); // visual: CopyFromParent
+        request.extend_from_slice(&0u32.to_le_bytes()); // no attribute values
+        self.send_request(&request).await
+    }
+
+    /// Obtains the atom with the given name, creating it if necessary.
+    ///
+    /// Events that arrive before the reply are dropped; only call this before the connection is
+    /// used for anything else.
+    pub async fn intern_atom(&self, name: &str) -> Result<u32, Error> {
+        let name_bytes = name.as_bytes();
+        let mut request = Vec::with_capacity(8 + padded_len(name_bytes.len()));
+        request.push(OPCODE_INTERN_ATOM);
+        request.push(0); // only-if-exists: false
+        request.extend_from_slice(&u16::try_from(2 + padded_len(name_bytes.len()) / 4).unwrap().to_le_bytes());
+        request.extend_from_slice(&u16::try_from(name_bytes.len()).unwrap().to_le_bytes());
+        request.extend_from_slice(&[0, 0]);
+        push_padded(&mut request, name_bytes);
+        self.send_request(&request).await?;
+
+        loop {
+            match self.recv_message().await? {
+                Message::Reply { data } => {
+                    let atom = u32::from_le_bytes(data[8..12].try_into().unwrap());
+                    debug!("atom {:?} is {}", name, atom);
+                    return Ok(atom);
+                },
+                Message::Error { code, major_opcode, bad_value } => {
+                    return Err(Error::ServerError { code, major_opcode, bad_value });
+                },
+                Message::Event(event) => {
+                    warn!("dropping event while waiting for atom {:?}: {:?}", name, event);
+                },
+            }
+        }
+    }
+
+    pub async fn set_selection_owner(&self, owner: u32, selection: u32, time: u32) -> Result<(), Error> {
+        let mut request = Vec::with_capacity(16);
+        request.push(OPCODE_SET_SELECTION_OWNER);
+        request.push(0);
+        request.extend_from_slice(&4u16.to_le_bytes());
+        request.extend_from_slice(&owner.to_le_bytes());
+        request.extend_from_slice(&selection.to_le_bytes());
+        request.extend_from_slice(&time.to_le_bytes());
+        self.send_request(&request).await
+    }
+
+    /// Replaces the value of a window property.
+    ///
+    /// `format` is the width of each item in bits (8, 16 or 32); `data` must contain a whole number
+    /// of items in little-endian byte order.
+    pub async fn change_property(&self, window: u32, property: u32, property_type: u32, format: u8, data: &[u8]) -> Result<(), Error> {
+        let item_count = data.len() / usize::from(format / 8);
+        let mut request = Vec::with_capacity(24 + padded_len(data.len()));
+        request.push(OPCODE_CHANGE_PROPERTY);
+        request.push(PROPERTY_MODE_REPLACE);
+        let request_length = 6 + padded_len(data.len()) / 4;
+        let Ok(request_length_u16) = u16::try_from(request_length) else {
+            return Err(Error::RequestTooLong { actual: request_length * 4, maximum: self.maximum_request_length });
+        };
+        request.extend_from_slice(&request_length_u16.to_le_bytes());
+        request.extend_from_slice(&window.to_le_bytes());
+        request.extend_from_slice(&property.to_le_bytes());
+        request.extend_from_slice(&property_type.to_le_bytes());
+        request.push(format);
+        request.extend_from_slice(&[0, 0, 0]);
+        request.extend_from_slice(&u32::try_from(item_count).unwrap().to_le_bytes());
+        push_padded(&mut request, data);
+        self.send_request(&request).await
+    }
+
+    /// Informs the requestor of a selection that its request has been handled.
+    ///
+    /// `property` is `NONE` if the request has been refused.
+    pub async fn send_selection_notify(&self, time: u32, requestor: u32, selection: u32, target: u32, property: u32) -> Result<(), Error> {
+        let mut request = Vec::with_capacity(44);
+        request.push(OPCODE_SEND_EVENT);
+        request.push(0); // propagate: false
+        request.extend_from_slice(&11u16.to_le_bytes());
+        request.extend_from_slice(&requestor.to_le_bytes());
+        request.extend_from_slice(&0u32.to_le_bytes()); // event mask: send to the client that created the window
+
+        // the event itself
+        request.push(EVENT_SELECTION_NOTIFY);
+        request.push(0);
+        request.extend_from_slice(&0u16.to_le_bytes()); // sequence number (filled in by the server)
+        request.extend_from_slice(&time.to_le_bytes());
+        request.extend_from_slice(&requestor.to_le_bytes());
+        request.extend_from_slice(&selection.to_le_bytes());
+        request.extend_from_slice(&target.to_le_bytes());
+        request.extend_from_slice(&property.to_le_bytes());
+        request.extend_from_slice(&[0u8; 8]);
+        self.send_request(&request).await
+    }
+}
+
+
+/// Returns the length of the message at the start of the buffer if it has been received in full.
+fn complete_message_length(buffer: &[u8]) -> Option<usize> {
+    if buffer.len() < MESSAGE_LENGTH {
+        return None;
+    }
+    let length = if buffer[0] & 0x7F == MESSAGE_REPLY {
+        // replies may be followed by additional data
+        let additional_length = usize::try_from(u32::from_le_bytes(buffer[4..8].try_into().unwrap())).unwrap() * 4;
+        MESSAGE_LENGTH + additional_length
+    } else {
+        MESSAGE_LENGTH
+    };
+    (buffer.len() >= length).then_some(length)
+}
+
+/// Decodes a complete message.
+fn parse_message(data: Vec<u8>) -> Message {
+    let fixed_buf = &data[..MESSAGE_LENGTH];
+
+    // the topmost bit signals events generated by SendEvent
+    match fixed_buf[0] & 0x7F {
+        MESSAGE_ERROR => Message::Error {
+            code: fixed_buf[1],
+            bad_value: u32::from_le_bytes(fixed_buf[4..8].try_into().unwrap()),
+            major_opcode: fixed_buf[10],
+        },
+        MESSAGE_REPLY => Message::Reply { data },
+        EVENT_SELECTION_CLEAR => Message::Event(Event::SelectionClear {
+            time: u32::from_le_bytes(fixed_buf[4..8].try_into().unwrap()),
+            owner: u32::from_le_bytes(fixed_buf[8..12].try_into().unwrap()),
+            selection: u32::from_le_bytes(fixed_buf[12..16].try_into().unwrap()),
+        }),
+        EVENT_SELECTION_REQUEST => Message::Event(Event::SelectionRequest {
+            time: u32::from_le_bytes(fixed_buf[4..8].try_into().unwrap()),
+            owner: u32::from_le_bytes(fixed_buf[8..12].try_into().unwrap()),
+            requestor: u32::from_le_bytes(fixed_buf[12..16].try_into().unwrap()),
+            selection: u32::from_le_bytes(fixed_buf[16..20].try_into().unwrap()),
+            target: u32::from_le_bytes(fixed_buf[20..24].try_into().unwrap()),
+            property: u32::from_le_bytes(fixed_buf[24..28].try_into().unwrap()),
+        }),
+        other => Message::Event(Event::Other { code: other }),
+    }
+}
+
+
+const fn padded_len(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+fn push_padded(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(data);
+    buf.resize(buf.len() + padded_len(data.len()) - data.len(), 0);
+}
+
+/// Finds the MIT-MAGIC-COOKIE-1 for the given local display in the Xauthority file.
+fn find_auth_cookie(display_number: &str) -> Option<Vec<u8>> {
+    let xauthority_path = match env::var_os(XAUTHORITY_VAR) {
+        Some(p) => PathBuf::from(p),
+        None => {
+            let mut p = PathBuf::from(env::var_os(HOME_VAR)?);
+            p.push(".Xauthority");
+            p
+        },
+    };
+    let xauthority = match std::fs::read(&xauthority_path) {
+        Ok(xa) => xa,
+        Err(e) => {
+            debug!("not authenticating; failed to read {}: {}", xauthority_path.display(), e);
+            return None;
+        },
+    };
+    let hostname = hostname().unwrap_or_default();
+
+    // family, then address, display number, auth name and auth data, each prefixed by its length
+    // (everything in big endian)
+    let mut rest = xauthority.as_slice();
+    while rest.len() >= 2 {
+        let family = u16::from_be_bytes(rest[0..2].try_into().unwrap());
+        rest = &rest[2..];
+        let mut fields: [&[u8]; 4] = [&[]; 4];
+        for field in &mut fields {
+            if rest.len() < 2 {
+                return None;
+            }
+            let field_length = usize::from(u16::from_be_bytes(rest[0..2].try_into().unwrap()));
+            if rest.len() < 2 + field_length {
+                return None;
+            }
+            *field = &rest[2..2+field_length];
+            rest = &rest[2+field_length..];
+        }
+        let [address, number, name, data] = fields;
+
+        let address_matches = family == XAUTH_FAMILY_WILD
+            || (family == XAUTH_FAMILY_LOCAL && address == hostname.as_bytes());
+        let number_matches = number.is_empty() || number == display_number.as_bytes();
+        if address_matches && number_matches && name == MIT_MAGIC_COOKIE {
+            return Some(data.to_vec());
+        }
+    }
+    None
+}
+
+fn hostname() -> Option<String> {
+    let mut buf = [0u8; 256];
+    let res = unsafe {
+        libc::gethostname(buf.as_mut_ptr().cast(), buf.len())
+    };
+    if res == -1 {
+        warn!("failed to obtain hostname: {}", io::Error::last_os_error());
+        return None;
+    }
+    // not NUL-terminated if truncated; force it
+    buf[buf.len() - 1] = 0;
+    let hostname = CStr::from_bytes_until_nul(&buf).ok()?;
+    Some(hostname.to_string_lossy().into_owned())
+}
@@ -0,0 +1,229 @@
+//! Clipboard access for X11 sessions.
+//!
+//! We own the selection(s) with an invisible window and answer `SelectionRequest` events directly;
+//! codes are short enough that we never need incremental (INCR) transfers.
+
+
+pub(crate) mod connection;
+
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tracing::{debug, error, warn};
+
+use crate::{PASSWORD_MANAGER_HINT_MIME_TYPE, PASSWORD_MANAGER_HINT_VALUE, SelectionTarget};
+use crate::x11::connection::{
+    ATOM_ATOM, ATOM_PRIMARY, ATOM_STRING, CURRENT_TIME, Connection, Error, Event, Message, NONE,
+};
+
+
+/// The atoms we need beyond the predefined ones.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Atoms {
+    pub clipboard: u32,
+    pub targets: u32,
+    pub text: u32,
+    pub utf8_string: u32,
+    pub password_manager_hint: u32,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct X11Data {
+    /// Our invisible window which owns the selections.
+    pub window: u32,
+    pub atoms: Atoms,
+
+    /// The code we provide via the regular clipboard, if we own it.
+    pub clipboard: Option<String>,
+
+    /// The code we provide via the primary selection, if we own it.
+    pub primary: Option<String>,
+
+    pub clear_deadline: Option<Instant>,
+    pub paste_once: bool,
+}
+impl X11Data {
+    pub async fn new(conn: &Connection, paste_once: bool) -> Result<Self, Error> {
+        let atoms = Atoms {
+            clipboard: conn.intern_atom("CLIPBOARD").await?,
+            targets: conn.intern_atom("TARGETS").await?,
+            text: conn.intern_atom("TEXT").await?,
+            utf8_string: conn.intern_atom("UTF8_STRING").await?,
+            password_manager_hint: conn.intern_atom(PASSWORD_MANAGER_HINT_MIME_TYPE).await?,
+        };
+
+        let window = conn.generate_resource_id();
+        conn.create_input_only_window(window).await?;
+        debug!("created selection owner window 0x{:08X}", window);
+
+        Ok(Self {
+            window,
+            atoms,
+            clipboard: None,
+            primary: None,
+            clear_deadline: None,
+            paste_once,
+        })
+    }
+
+    fn selection_data_mut(&mut self, selection: u32) -> Option<&mut Option<String>> {
+        if selection == self.atoms.clipboard {
+            Some(&mut self.clipboard)
+        } else if selection == ATOM_PRIMARY {
+            Some(&mut self.primary)
+        } else {
+            None
+        }
+    }
+}
+
+
+/// Connects to the X server and prepares our access to its selections.
+pub async fn connect_x11(paste_once: bool) -> Result<(Connection, X11Data), Error> {
+    let conn = Connection::new_from_env().await?;
+    let data = X11Data::new(&conn, paste_once).await?;
+    Ok((conn, data))
+}
+
+
+pub async fn x11_dispatch(
+    conn: &Connection,
+    message: Message,
+    data: &mut X11Data,
+) -> Result<(), Error> {
+    match message {
+        Message::Error { code, major_opcode, bad_value } => {
+            error!("X server sends an error: request {} says [{}] (value {})", major_opcode, code, bad_value);
+        },
+        Message::Reply { data: reply_data } => {
+            warn!("unexpected reply from X server: {:?}", reply_data);
+        },
+        Message::Event(Event::SelectionRequest { time, owner, requestor, selection, target, property }) => {
+            debug!("window 0x{:08X} asks for selection {} as {} into {}", requestor, selection, target, property);
+            if owner != data.window {
+                warn!("selection request for a window (0x{:08X}) that is not ours", owner);
+                return Ok(());
+            }
+
+            // obsolete clients do not specify a property; use the target
+            let property = if property == NONE { target } else { property };
+
+            let atoms = data.atoms;
+            let paste_once = data.paste_once;
+            let value = data.selection_data_mut(selection)
+                .and_then(|d| d.clone());
+            let mut sent_text = false;
+            let res = if let Some(value) = value {
+                if target == atoms.targets {
+                    let targets = [atoms.targets, atoms.utf8_string, atoms.text, ATOM_STRING, atoms.password_manager_hint];
+                    let target_bytes: Vec<u8> = targets.iter()
+                        .flat_map(|t| t.to_le_bytes())
+                        .collect();
+                    conn.change_property(requestor, property, ATOM_ATOM, 32, &target_bytes)
+                        .await.map(|()| true)
+                } else if target == atoms.utf8_string || target == atoms.text || target == ATOM_STRING {
+                    // OTP codes are ASCII, which is also valid Latin-1 (STRING)
+                    let property_type = if target == ATOM_STRING { ATOM_STRING } else { atoms.utf8_string };
+                    sent_text = true;
+                    conn.change_property(requestor, property, property_type, 8, value.as_bytes())
+                        .await.map(|()| true)
+                } else if target == atoms.password_manager_hint {
+                    // tell clipboard managers not to record our contents
+                    conn.change_property(requestor, property, ATOM_STRING, 8, PASSWORD_MANAGER_HINT_VALUE.as_bytes())
+                        .await.map(|()| true)
+                } else {
+                    Ok(false)
+                }
+            } else {
+                Ok(false)
+            };
+            let provided = match res {
+                Ok(p) => p,
+                Err(e) => {
+                    error!("failed to provide selection contents: {}", e);
+                    false
+                },
+            };
+
+            let notify_property = if provided { property } else { NONE };
+            conn.send_selection_notify(time, requestor, selection, target, notify_property).await?;
+
+            if provided && sent_text && paste_once {
+                // the code has been pasted; it may not be pasted again
+                debug!("code has been pasted once; clearing the clipboard");
+                clear_dispatch(conn, data).await?;
+            }
+        },
+        Message::Event(Event::SelectionClear { time: _, owner: _, selection }) => {
+            // something replaced us
+            // oh well, drop the data
+            debug!("we no longer own selection {}", selection);
+            if let Some(selection_data) = data.selection_data_mut(selection) {
+                *selection_data = None;
+            }
+            if data.clipboard.is_none() && data.primary.is_none() {
+                // there is nothing left for us to clear
+                data.clear_deadline = None;
+            }
+        },
+        Message::Event(Event::Other { code }) => {
+            debug!("unhandled X11 event {}", code);
+        },
+    }
+    Ok(())
+}
+
+pub async fn copy_dispatch(
+    conn: &Connection,
+    data: &mut X11Data,
+    new_content: String,
+    clear_after: Option<Duration>,
+    target: SelectionTarget,
+) -> Result<(), Error> {
+    debug!("publishing {:?} on the {:?} selection", new_content, target);
+
+    // (re)schedule clearing (a delay too long to represent means never)
+    data.clear_deadline = clear_after
+        .and_then(|ca| Instant::now().checked_add(ca));
+    if let (Some(ca), Some(_)) = (clear_after, data.clear_deadline) {
+        debug!("clipboard will be cleared in {:?}", ca);
+    }
+
+    for (included, selection) in [(target.includes_clipboard(), data.atoms.clipboard), (target.includes_primary(), ATOM_PRIMARY)] {
+        if included {
+            *data.selection_data_mut(selection).unwrap() = Some(new_content.clone());
+            conn.set_selection_owner(data.window, selection, CURRENT_TIME).await?;
+        } else {
+            clear_selection(conn, data, selection).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn clear_dispatch(
+    conn: &Connection,
+    data: &mut X11Data,
+) -> Result<(), Error> {
+    data.clear_deadline = None;
+    clear_selection(conn, data, data.atoms.clipboard).await?;
+    clear_selection(conn, data, ATOM_PRIMARY).await
+}
+
+async fn clear_selection(
+    conn: &Connection,
+    data: &mut X11Data,
+    selection: u32,
+) -> Result<(), Error> {
+    let selection_data = data.selection_data_mut(selection).unwrap();
+
+    // do we own the selection?
+    if selection_data.take().is_none() {
+        // nope; no need to worry
+        return Ok(());
+    }
+
+    conn.set_selection_owner(NONE, selection, CURRENT_TIME).await?;
+    debug!("gave up ownership of selection {}", selection);
+    Ok(())
+}
@@ -16,9 +16,11 @@ build_proto() {
 	--in-crate \
         "$src" \
         | \
-        rustfmt --edition=2018 \
+        rustfmt --edition=2018 --config normalize_doc_attributes=true \
         > "$dst"
 }
 
-build_proto "ext/wayland/protocol/wayland.xml" "whale-land/src/protocol/wayland.rs"
-build_proto "ext/wayland-protocols/staging/ext-data-control/ext-data-control-v1.xml" "whale-land/src/protocol/ext_data_control_v1.rs"
+build_proto "protocols/wayland.xml" "whale-land/src/protocol/wayland.rs"
+build_proto "protocols/ext-data-control-v1.xml" "whale-land/src/protocol/ext_data_control_v1.rs"
+build_proto "protocols/wlr-data-control-unstable-v1.xml" "whale-land/src/protocol/wlr_data_control_unstable_v1.rs"
+build_proto "protocols/virtual-keyboard-unstable-v1.xml" "whale-land/src/protocol/virtual_keyboard_unstable_v1.rs"
@@ -1,156 +1,825 @@
+use std::cmp;
 use std::collections::BTreeMap;
 use std::env;
 use std::ffi::OsString;
+use std::hash::{Hash, Hasher};
+use std::io;
 use std::num::NonZero;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::net::UnixStream as StdUnixStream;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex as StdMutex, Weak};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use libc::{F_DUPFD_CLOEXEC, F_GETFD, F_SETFD, FD_CLOEXEC, fcntl};
 
 use socket_fd_ext::SocketFdExt;
 use tokio::net::UnixStream;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, oneshot};
 use tracing::debug;
 
-use crate::{Error, ObjectId, Packet};
-use crate::protocol::EventHandler;
+use crate::{Error, NewObjectId, ObjectId, Packet};
+use crate::object_table::{EventTarget, ObjectTable};
+use crate::packet::FdQueue;
+use crate::protocol::{self, EventHandler, InterfaceInfo, Proxy};
+use crate::protocol::wayland::{
+    wl_callback_event_done_args, wl_callback_interface, wl_display, wl_display_event_delete_id_args,
+    wl_display_request_sync_args,
+};
+use crate::ring_buffer::RingBuffer;
 
 
 const RUNTIME_DIR_VAR: &str = "XDG_RUNTIME_DIR";
 const WAYLAND_DISPLAY_VAR: &str = "WAYLAND_DISPLAY";
+const WAYLAND_SOCKET_VAR: &str = "WAYLAND_SOCKET";
 const DEFAULT_WAYLAND_DISPLAY: &str = "wayland-0";
 
+/// The size of the packet header (sender ID, size, opcode).
+const HEADER_SIZE: usize = 8;
 
-pub struct Connection {
+/// The capacity of the receive buffer; enough for the largest possible packet.
+const RECEIVE_BUFFER_SIZE: usize = 1 << 16;
+
+/// How many bytes may be queued for sending before the queue is flushed.
+const SEND_BUFFER_SIZE: usize = 1 << 16;
+
+/// How many file descriptors are passed with a single message (same as libwayland).
+const MAX_FDS_PER_MESSAGE: usize = 28;
+
+
+/// What has been received from the compositor.
+enum Received {
+    Packet(Packet),
+
+    /// The answer to a [`Connection::sync`] with the given callback ID.
+    SyncDone(ObjectId),
+}
+
+/// Packets that have been queued but not yet sent.
+#[derive(Debug, Default)]
+struct SendBuffer {
+    data: Vec<u8>,
+
+    // duplicates, so that the caller may close theirs right away
+    fds: Vec<OwnedFd>,
+}
+
+/// Whether the socket passed via `WAYLAND_SOCKET` has already been taken.
+///
+/// libwayland unsets the variable instead, but modifying the environment is unsound once other
+/// threads are running.
+static WAYLAND_SOCKET_TAKEN: AtomicBool = AtomicBool::new(false);
+
+
+struct ConnectionInner {
     socket: UnixStream,
-    send_lock: Mutex<()>,
-    recv_lock: Mutex<()>,
-    next_object_id: AtomicU32,
-    object_id_to_event_handler: BTreeMap<ObjectId, Box<dyn EventHandler + Send + Sync>>,
+    send_buffer: Mutex<SendBuffer>,
+    receive_buffer: Mutex<RingBuffer>,
+    received_fds: Arc<FdQueue>,
+    pending_syncs: StdMutex<BTreeMap<ObjectId, oneshot::Sender<u32>>>,
+    objects: StdMutex<ObjectTable>,
+    object_id_to_event_handler: StdMutex<BTreeMap<ObjectId, Arc<dyn EventHandler + Send + Sync>>>,
+
+    /// Whether we have lost track of which file descriptors belong to which packet.
+    desynchronized: AtomicBool,
+}
+
+/// A connection to the compositor.
+///
+/// Clones are cheap and refer to the same connection, which is closed once the last clone has
+/// been dropped.
+#[derive(Clone)]
+pub struct Connection {
+    inner: Arc<ConnectionInner>,
 }
 impl Connection {
+    /// Connects to the compositor following the same rules as libwayland.
+    ///
+    /// If `WAYLAND_SOCKET` is set, the inherited socket with that file descriptor number is used
+    /// (only by the first call; later calls fall back to the other rules). Otherwise, the socket
+    /// is found at `WAYLAND_DISPLAY` (default `wayland-0`), which is relative to `XDG_RUNTIME_DIR`
+    /// unless it is an absolute path.
     pub async fn new_from_env() -> Result<Self, Error> {
-        let runtime_dir = env::var_os(RUNTIME_DIR_VAR)
-            .ok_or_else(|| Error::MissingEnvVar { name: RUNTIME_DIR_VAR.to_owned() })?;
+        if let Some(wayland_socket) = env::var_os(WAYLAND_SOCKET_VAR)
+            && !WAYLAND_SOCKET_TAKEN.swap(true, Ordering::SeqCst)
+        {
+            let fd: RawFd = wayland_socket.to_str()
+                .and_then(|s| s.parse().ok())
+                .filter(|fd| *fd >= 0)
+                .ok_or_else(|| Error::InvalidEnvVar {
+                    name: WAYLAND_SOCKET_VAR.to_owned(),
+                    value: wayland_socket.clone(),
+                })?;
+            debug!("using inherited Wayland socket {}", fd);
+
+            // don't pass the socket on to our children
+            let flags = unsafe { fcntl(fd, F_GETFD) };
+            if flags == -1 {
+                return Err(io::Error::last_os_error().into());
+            }
+            let res = unsafe { fcntl(fd, F_SETFD, flags | FD_CLOEXEC) };
+            if res == -1 {
+                return Err(io::Error::last_os_error().into());
+            }
+
+            let owned_fd = unsafe {
+                OwnedFd::from_raw_fd(fd)
+            };
+            return Self::from_fd(owned_fd);
+        }
+
         let wayland_display = env::var_os(WAYLAND_DISPLAY_VAR)
             .unwrap_or_else(|| OsString::from(DEFAULT_WAYLAND_DISPLAY));
-        let mut wayland_display_path = PathBuf::from(&runtime_dir);
-        wayland_display_path.push(&wayland_display);
+        let wayland_display_path = if Path::new(&wayland_display).is_absolute() {
+            PathBuf::from(&wayland_display)
+        } else {
+            let runtime_dir = env::var_os(RUNTIME_DIR_VAR)
+                .ok_or_else(|| Error::MissingEnvVar { name: RUNTIME_DIR_VAR.to_owned() })?;
+            let mut path = PathBuf::from(&runtime_dir);
+            path.push(&wayland_display);
+            path
+        };
+
+        Self::connect(&wayland_display_path).await
+    }
+
+    /// Connects to the compositor listening on the socket at the given path.
+    pub async fn connect<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        debug!("connecting to Wayland socket {}", path.as_ref().display());
+        let socket = UnixStream::connect(path).await?;
+        Ok(Self::from_stream(socket))
+    }
+
+    /// Takes over an already connected socket.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn from_fd(fd: OwnedFd) -> Result<Self, Error> {
+        debug!("taking over Wayland socket {}", fd.as_raw_fd());
+        let std_socket = StdUnixStream::from(fd);
+        std_socket.set_nonblocking(true)?;
+        let socket = UnixStream::from_std(std_socket)?;
+        Ok(Self::from_stream(socket))
+    }
 
-        let socket = UnixStream::connect(&wayland_display_path).await?;
-        Ok(Self {
+    fn from_stream(socket: UnixStream) -> Self {
+        let inner = ConnectionInner {
             socket,
-            send_lock: Mutex::new(()),
-            recv_lock: Mutex::new(()),
-            next_object_id: AtomicU32::new(2), // 0 is NULL, 1 is always wl_display
-            object_id_to_event_handler: BTreeMap::new(),
-        })
+            send_buffer: Mutex::new(SendBuffer::default()),
+            receive_buffer: Mutex::new(RingBuffer::new(RECEIVE_BUFFER_SIZE)),
+            received_fds: Arc::new(FdQueue::new()),
+            pending_syncs: StdMutex::new(BTreeMap::new()),
+            objects: StdMutex::new(ObjectTable::new()),
+            object_id_to_event_handler: StdMutex::new(BTreeMap::new()),
+            desynchronized: AtomicBool::new(false),
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
     }
 
+    /// Returns a handle that does not keep the connection alive.
+    pub fn downgrade(&self) -> WeakConnection {
+        WeakConnection {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Queues a packet for sending.
+    ///
+    /// Queued packets are sent when [`Connection::flush`] is called, when [`Connection::recv_packet`]
+    /// is about to wait for the compositor, or when the queue is full. File descriptors attached to
+    /// the packet are duplicated, so the caller may close them as soon as this function returns.
     pub async fn send_packet(&self, packet: &Packet) -> Result<(), Error> {
         let serialized = packet.serialize()?;
+        let mut fds = Vec::with_capacity(packet.fds().len());
+        for fd in packet.fds() {
+            let dup_fd = unsafe { fcntl(*fd, F_DUPFD_CLOEXEC, 0) };
+            if dup_fd == -1 {
+                return Err(io::Error::last_os_error().into());
+            }
+            fds.push(unsafe { OwnedFd::from_raw_fd(dup_fd) });
+        }
 
-        {
-            let send_guard = self.send_lock.lock().await;
+        let mut send_buffer = self.inner.send_buffer.lock().await;
+        let too_much_data = send_buffer.data.len() + serialized.len() > SEND_BUFFER_SIZE;
+        let too_many_fds = send_buffer.fds.len() + fds.len() > MAX_FDS_PER_MESSAGE;
+        if too_much_data || too_many_fds {
+            self.flush_send_buffer(&mut send_buffer).await?;
+        }
+        send_buffer.data.extend_from_slice(&serialized);
+        send_buffer.fds.extend(fds);
 
-            // SocketFdExt functions handle WouldBlock for us
-            let mut total_sent = self.socket
-                .send_with_fds(&serialized, packet.fds()).await?;
+        let destroyed = self.inner.objects.lock().unwrap()
+            .send_request(packet.object_id(), packet.opcode());
+        if destroyed {
+            // any further events for the object are dropped before they reach the handler
+            self.drop_handler(packet.object_id());
+        }
+        Ok(())
+    }
 
-            while total_sent < serialized.len() {
-                // send more
-                let now_sent = self.socket.send(&serialized[total_sent..]).await?;
-                total_sent += now_sent;
-            }
+    /// Sends all queued packets.
+    pub async fn flush(&self) -> Result<(), Error> {
+        let mut send_buffer = self.inner.send_buffer.lock().await;
+        self.flush_send_buffer(&mut send_buffer).await
+    }
 
-            drop(send_guard);
-        }
+    async fn flush_send_buffer(&self, send_buffer: &mut SendBuffer) -> Result<(), Error> {
+        while !send_buffer.data.is_empty() {
+            // SocketFdExt functions handle WouldBlock for us
+            let sent = if send_buffer.fds.is_empty() {
+                self.inner.socket.send(&send_buffer.data).await?
+            } else {
+                let raw_fds: Vec<RawFd> = send_buffer.fds.iter()
+                    .map(|fd| fd.as_raw_fd())
+                    .collect();
+                let sent = self.inner.socket
+                    .send_with_fds(&send_buffer.data, &raw_fds).await?;
 
+                // the file descriptors travel with the first chunk; the compositor has its own copies
+                send_buffer.fds.clear();
+                sent
+            };
+            send_buffer.data.drain(..sent);
+        }
         Ok(())
     }
 
+    /// Receives the next packet.
+    ///
+    /// Packets that have already been buffered are returned before the socket is read again.
+    /// File descriptors are queued as they arrive and claimed by packets as their `fd` arguments
+    /// are decoded, so packets must be decoded in the order in which they are received.
+    ///
+    /// `wl_display.delete_id` events are handled internally. Events for objects that have been
+    /// destroyed fail with [`Error::DeadObject`]; their file descriptors are closed and the
+    /// connection remains usable afterwards. Events for objects that were never created fail with
+    /// [`Error::UnknownObject`]; as there is no telling how many file descriptors they carry, every
+    /// later attempt to receive a packet fails with [`Error::Desynchronized`].
     pub async fn recv_packet(&self) -> Result<Packet, Error> {
-        let packet = {
-            let recv_guard = self.recv_lock.lock().await;
+        loop {
+            match self.recv_packet_or_sync_done().await? {
+                Received::Packet(packet) => return Ok(packet),
+                Received::SyncDone(_) => continue,
+            }
+        }
+    }
+
+    async fn recv_packet_or_sync_done(&self) -> Result<Received, Error> {
+        let mut receive_buffer = self.inner.receive_buffer.lock().await;
+        loop {
+            if let Some(received) = self.next_buffered(&mut receive_buffer)? {
+                return Ok(received);
+            }
 
-            // sender ID, size, opcode
-            let mut fixed_buf = [0u8; 8];
+            // the compositor might be waiting for what we have queued
+            self.flush().await?;
 
             // SocketFdExt functions handle WouldBlock for us
-            let (mut total_received, fds) = self.socket
-                .recv_with_fds(&mut fixed_buf).await?;
-            while total_received < fixed_buf.len() {
-                // receive more
-                let now_received = self.socket
-                    .recv(&mut fixed_buf[total_received..]).await?;
-                total_received += now_received;
-            }
-
-            let object_id_u32 = u32::from_ne_bytes(fixed_buf[0..4].try_into().unwrap());
-            let size_and_opcode = u32::from_ne_bytes(fixed_buf[4..8].try_into().unwrap());
-            let packet_size: usize = (size_and_opcode >> 16).try_into().unwrap();
-            let opcode: u16 = (size_and_opcode & 0xFF).try_into().unwrap();
-
-            if packet_size < 8 {
-                // 8 bytes are the fixed header and thereby the minimum
-                return Err(Error::PacketTooShort { actual: packet_size, minimum: 8 });
-            }
-
-            let object_id_nz = NonZero::new(object_id_u32)
-                .ok_or(Error::ZeroObjectId)?;
-            let object_id = ObjectId(object_id_nz);
-
-            // read the payload
-            let mut payload = vec![0u8; packet_size - 8];
-            total_received = self.socket
-                .recv(&mut payload).await?;
-            while total_received < payload.len() {
-                let now_received = self.socket
-                    .recv(&mut payload[total_received..]).await?;
-                total_received += now_received;
-            }
-
-            drop(recv_guard);
-
-            Packet::new_from_existing(
-                object_id,
-                opcode,
-                payload,
-                fds,
-            )
+            let (received, fds) = self.inner.socket
+                .recv_with_fds(receive_buffer.free_slice_mut()).await?;
+            self.inner.received_fds.extend(fds);
+            if received == 0 {
+                // EOF; nobody will answer the pending syncs
+                self.inner.pending_syncs.lock().unwrap().clear();
+                return Err(Error::ConnectionClosed);
+            }
+            receive_buffer.commit(received);
+        }
+    }
+
+    /// Takes the next packet that has already been buffered, without reading from the socket.
+    fn next_buffered(&self, receive_buffer: &mut RingBuffer) -> Result<Option<Received>, Error> {
+        if self.inner.desynchronized.load(Ordering::SeqCst) {
+            return Err(Error::Desynchronized);
+        }
+        while let Some(packet) = self.take_buffered_packet(receive_buffer)? {
+            if self.handle_delete_id(&packet)? {
+                continue;
+            }
+
+            let target = self.inner.objects.lock().unwrap()
+                .receive_event(packet.object_id(), packet.opcode());
+            match target {
+                EventTarget::Live => {},
+                EventTarget::Unknown => {
+                    return Err(self.desynchronize(Error::UnknownObject {
+                        object_id: packet.object_id(),
+                        opcode: packet.opcode(),
+                    }));
+                },
+                EventTarget::Dead { interface, version } => {
+                    self.discard_event(&packet, interface, version)?;
+                    return Err(Error::DeadObject {
+                        object_id: packet.object_id(),
+                        opcode: packet.opcode(),
+                    });
+                },
+            }
+
+            if self.complete_sync(&packet)? {
+                return Ok(Some(Received::SyncDone(packet.object_id())));
+            }
+            return Ok(Some(Received::Packet(packet)));
+        }
+        Ok(None)
+    }
+
+    /// Drops a received event for an object implementing the given interface at the given version.
+    ///
+    /// Its file descriptors are closed, and the objects it creates are recorded as destroyed, so
+    /// that their own events are dropped in turn.
+    fn discard_event(&self, packet: &Packet, interface: &'static InterfaceInfo, version: u32) -> Result<(), Error> {
+        let Some(message) = interface.events.get(usize::from(packet.opcode())) else {
+            // no telling which file descriptors it carries
+            return Err(self.desynchronize(Error::UnknownEvent {
+                interface: interface.name,
+                opcode: packet.opcode(),
+            }));
         };
+        let new_objects = packet.discard_incoming(message.args)
+            .map_err(|e| self.desynchronize(e))?;
+        for (object_id, new_interface_name) in new_objects {
+            let Some(new_interface) = protocol::interface_info_by_name(new_interface_name) else {
+                debug!("cannot track new object ID {} of unknown interface {}", object_id.0, new_interface_name);
+                continue;
+            };
+            if !self.inner.objects.lock().unwrap().insert_dead_server_object(object_id, new_interface, version) {
+                return Err(Error::InvalidNewObjectId { object_id });
+            }
+        }
+        Ok(())
+    }
 
-        Ok(packet)
+    /// Marks the connection as unusable because we have lost track of which file descriptors
+    /// belong to which packet, passing on the error that has caused it.
+    ///
+    /// Event handlers call this when they cannot decode an event and therefore cannot claim its
+    /// file descriptors; all further attempts to receive packets fail.
+    pub fn desynchronize(&self, error: Error) -> Error {
+        debug!("connection is out of sync: {}", error);
+        self.inner.desynchronized.store(true, Ordering::SeqCst);
+        error
     }
 
-    pub fn get_and_increment_next_object_id(&self) -> ObjectId {
+    /// Waits until the compositor has processed all requests sent so far.
+    ///
+    /// This only sends the request; the answer must be received by someone calling
+    /// [`Connection::recv_packet`] (possibly concurrently), which consumes it. Returns the serial
+    /// of the answer (the `wl_callback.done` event).
+    ///
+    /// Use [`Connection::roundtrip`] if nobody else is receiving packets.
+    pub async fn sync(&self) -> Result<u32, Error> {
+        let (_callback_id, receiver) = self.start_sync().await?;
+
+        // the sender is only dropped without an answer if the connection goes away
+        receiver.await
+            .map_err(|_| Error::ConnectionClosed)
+    }
+
+    /// Sends all queued requests, then receives and dispatches packets until the compositor has
+    /// processed them.
+    ///
+    /// Each packet is dispatched as soon as it has been received, so handlers can register the
+    /// objects announced by one event before the events of those objects arrive. Like
+    /// [`Connection::run`], events for objects that have been destroyed or that have no handler are
+    /// dropped; any other error ends the roundtrip.
+    pub async fn roundtrip(&self) -> Result<(), Error> {
+        let (callback_id, _receiver) = self.start_sync().await?;
+
         loop {
-            let new_val = self.next_object_id.fetch_add(1, Ordering::SeqCst);
-            if let Some(oid) = ObjectId::new(new_val) {
-                return oid;
+            let dispatched = match self.recv_packet_or_sync_done().await {
+                Ok(Received::Packet(packet)) => self.dispatch(packet).await,
+                Ok(Received::SyncDone(done_id)) if done_id == callback_id => return Ok(()),
+                Ok(Received::SyncDone(_)) => continue,
+                Err(e) => Err(e),
+            };
+            match dispatched {
+                Ok(()) => {},
+                Err(e @ (Error::DeadObject { .. } | Error::NoEventHandler { .. })) => {
+                    debug!("dropping event during roundtrip: {}", e);
+                },
+                Err(e) => return Err(e),
             }
         }
     }
 
-    pub fn register_handler(&mut self, object_id: ObjectId, event_handler: Box<dyn EventHandler + Send + Sync>) {
-        self.object_id_to_event_handler
-            .insert(object_id, event_handler);
+    /// Sends a `wl_display.sync` request, returning the callback ID and where its answer will arrive.
+    async fn start_sync(&self) -> Result<(ObjectId, oneshot::Receiver<u32>), Error> {
+        let callback_id = self.new_object(&wl_callback_interface, 1);
+
+        // register before sending to make sure we don't miss the answer
+        let (sender, receiver) = oneshot::channel();
+        self.inner.pending_syncs.lock().unwrap()
+            .insert(callback_id, sender);
+
+        let sync_packet = wl_display_request_sync_args {
+            callback: NewObjectId(callback_id),
+        }.try_into_packet(ObjectId::DISPLAY)?;
+        let sent = async {
+            self.send_packet(&sync_packet).await?;
+            self.flush().await
+        }.await;
+        if let Err(e) = sent {
+            self.inner.pending_syncs.lock().unwrap()
+                .remove(&callback_id);
+            return Err(e);
+        }
+
+        Ok((callback_id, receiver))
+    }
+
+    /// Completes a pending [`Connection::sync`] if the packet is its answer.
+    ///
+    /// Returns whether the packet has been consumed.
+    fn complete_sync(&self, packet: &Packet) -> Result<bool, Error> {
+        if packet.opcode() != wl_callback_event_done_args::OPCODE {
+            return Ok(false);
+        }
+        let Some(sender) = self.inner.pending_syncs.lock().unwrap().remove(&packet.object_id()) else {
+            return Ok(false);
+        };
+        let done_args = wl_callback_event_done_args::try_from_packet(packet)?;
+
+        // the waiter might have given up; that's fine
+        let _ = sender.send(done_args.callback_data);
+        Ok(true)
+    }
+
+    /// Frees the object ID if the packet is a `wl_display.delete_id` event.
+    ///
+    /// Returns whether the packet has been consumed.
+    fn handle_delete_id(&self, packet: &Packet) -> Result<bool, Error> {
+        if packet.object_id() != ObjectId::DISPLAY || packet.opcode() != wl_display_event_delete_id_args::OPCODE {
+            return Ok(false);
+        }
+        let delete_id_args = wl_display_event_delete_id_args::try_from_packet(packet)?;
+        let object_id = ObjectId::new(delete_id_args.id)
+            .ok_or(Error::ZeroObjectId)?;
+        if !self.inner.objects.lock().unwrap().delete_id(object_id) {
+            debug!("compositor has deleted unknown object ID {}", object_id.0);
+        }
+        Ok(true)
+    }
+
+    fn take_buffered_packet(&self, receive_buffer: &mut RingBuffer) -> Result<Option<Packet>, Error> {
+        // sender ID, size, opcode
+        let mut header = [0u8; HEADER_SIZE];
+        if !receive_buffer.peek(0, &mut header) {
+            return Ok(None);
+        }
+
+        let object_id_u32 = u32::from_ne_bytes(header[0..4].try_into().unwrap());
+        let size_and_opcode = u32::from_ne_bytes(header[4..8].try_into().unwrap());
+        let packet_size: usize = (size_and_opcode >> 16).try_into().unwrap();
+        let opcode: u16 = (size_and_opcode & 0xFFFF).try_into().unwrap();
+
+        if packet_size < HEADER_SIZE {
+            // the fixed header is the minimum
+            return Err(Error::PacketTooShort { actual: packet_size, minimum: HEADER_SIZE });
+        }
+
+        let object_id_nz = NonZero::new(object_id_u32)
+            .ok_or(Error::ZeroObjectId)?;
+        let object_id = ObjectId(object_id_nz);
+
+        let mut payload = vec![0u8; packet_size - HEADER_SIZE];
+        if !receive_buffer.peek(HEADER_SIZE, &mut payload) {
+            // not complete yet
+            return Ok(None);
+        }
+        receive_buffer.consume(packet_size);
+
+        Ok(Some(Packet::new_received(
+            object_id,
+            opcode,
+            payload,
+            Arc::clone(&self.inner.received_fds),
+        )))
+    }
+
+    /// Allocates an object ID for a new object, which is to be passed as the `new_id` argument of
+    /// a request.
+    ///
+    /// Object IDs are reused once the compositor has acknowledged the destruction of their
+    /// previous object. Objects are destroyed by sending one of their destructor requests.
+    pub fn new_object(&self, interface: &'static InterfaceInfo, version: u32) -> ObjectId {
+        self.inner.objects.lock().unwrap()
+            .insert_client_object(interface, version)
+    }
+
+    /// Records a new object that the compositor has announced via the `new_id` argument of an
+    /// event.
+    ///
+    /// Must be called before the object's own events are received.
+    pub fn insert_server_object(&self, object_id: ObjectId, interface: &'static InterfaceInfo, version: u32) -> Result<(), Error> {
+        if self.inner.objects.lock().unwrap().insert_server_object(object_id, interface, version) {
+            Ok(())
+        } else {
+            Err(Error::InvalidNewObjectId { object_id })
+        }
+    }
+
+    /// Returns the interface and version of the object with the given ID, unless it does not exist
+    /// or has been destroyed.
+    pub fn object_info(&self, object_id: ObjectId) -> Option<(&'static InterfaceInfo, u32)> {
+        self.inner.objects.lock().unwrap()
+            .get(object_id)
+    }
+
+    /// Returns a proxy for `wl_display`, which always exists.
+    pub fn display(&self) -> wl_display {
+        wl_display::from_parts(self.downgrade(), ObjectId::DISPLAY, 1)
+    }
+
+    /// Returns a proxy for the object with the given ID, unless it does not exist, has been
+    /// destroyed or implements a different interface.
+    pub fn proxy<P: Proxy>(&self, object_id: ObjectId) -> Option<P> {
+        let (interface, version) = self.object_info(object_id)?;
+        if interface.name != P::INFO.name {
+            return None;
+        }
+        Some(P::from_parts(self.downgrade(), object_id, version))
+    }
+
+    /// Registers the handler to which [`Connection::dispatch`] passes the events of the given
+    /// object, replacing any previously registered handler.
+    ///
+    /// The handler is dropped once the object is destroyed, whether by one of its destructor
+    /// requests or by one of its destructor events (after that event has been dispatched).
+    pub fn register_handler(&self, object_id: ObjectId, event_handler: Box<dyn EventHandler + Send + Sync>) {
+        self.inner.object_id_to_event_handler.lock().unwrap()
+            .insert(object_id, Arc::from(event_handler));
     }
 
-    pub fn drop_handler(&mut self, object_id: ObjectId) {
-        self.object_id_to_event_handler
+    pub fn drop_handler(&self, object_id: ObjectId) {
+        self.inner.object_id_to_event_handler.lock().unwrap()
             .remove(&object_id);
     }
 
+    /// Passes the packet to the handler registered for its object.
+    ///
+    /// The handler may register and drop handlers (including its own) as well as send packets.
+    /// Packets without a handler are dropped; their file descriptors are closed and the objects
+    /// they create are recorded as destroyed.
     pub async fn dispatch(&self, packet: Packet) -> Result<(), Error> {
-        let event_handler = self.object_id_to_event_handler
-            .get(&packet.object_id());
-        match event_handler {
-            Some(eh) => eh.handle_event(self, packet).await,
-            None => {
-                debug!("dropping packet as there is no handler: {:?}", packet);
-                Err(Error::NoEventHandler {
-                    object_id: packet.object_id(),
-                })
-            },
+        let object_id = packet.object_id();
+
+        // don't hold the lock while the handler runs
+        let event_handler = self.inner.object_id_to_event_handler.lock().unwrap()
+            .get(&object_id)
+            .map(Arc::clone);
+        let Some(eh) = event_handler else {
+            debug!("dropping packet as there is no handler: {:?}", packet);
+            let object_info = self.inner.objects.lock().unwrap()
+                .get_including_dead(object_id);
+            match object_info {
+                Some((interface, version)) => self.discard_event(&packet, interface, version)?,
+                None => {
+                    let opcode = packet.opcode();
+                    return Err(self.desynchronize(Error::UnknownObject { object_id, opcode }));
+                },
+            }
+            return Err(Error::NoEventHandler { object_id });
+        };
+        let result = eh.handle_event(self, packet).await;
+
+        if self.object_info(object_id).is_none() {
+            // that was a destructor event
+            let mut handlers = self.inner.object_id_to_event_handler.lock().unwrap();
+            if handlers.get(&object_id).is_some_and(|h| Arc::ptr_eq(h, &eh)) {
+                handlers.remove(&object_id);
+            }
         }
+        result
+    }
+
+    /// Dispatches the packets that have already been received, without waiting for more.
+    ///
+    /// Returns the number of dispatched packets.
+    pub async fn dispatch_pending(&self) -> Result<usize, Error> {
+        let mut dispatched = 0;
+        loop {
+            let received = {
+                let mut receive_buffer = self.inner.receive_buffer.lock().await;
+                self.next_buffered(&mut receive_buffer)?
+            };
+            match received {
+                Some(Received::Packet(packet)) => {
+                    self.dispatch(packet).await?;
+                    dispatched += 1;
+                },
+                Some(Received::SyncDone(_)) => {},
+                None => return Ok(dispatched),
+            }
+        }
+    }
+
+    /// Receives and dispatches packets until the compositor closes the connection.
+    ///
+    /// Events for objects that have been destroyed or that have no handler are dropped; any other
+    /// error ends the loop.
+    pub async fn run(&self) -> Result<(), Error> {
+        loop {
+            let dispatched = match self.recv_packet().await {
+                Ok(packet) => self.dispatch(packet).await,
+                Err(e) => Err(e),
+            };
+            match dispatched {
+                Ok(()) => {},
+                Err(Error::ConnectionClosed) => return Ok(()),
+                Err(e @ (Error::DeadObject { .. } | Error::NoEventHandler { .. })) => {
+                    debug!("dropping event: {}", e);
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// A handle to a [`Connection`] that does not keep it alive.
+///
+/// Handles compare equal if they refer to the same connection.
+#[derive(Clone, Debug)]
+pub struct WeakConnection {
+    inner: Weak<ConnectionInner>,
+}
+impl WeakConnection {
+    /// Returns the connection, unless it has already been closed.
+    pub fn upgrade(&self) -> Result<Connection, Error> {
+        self.inner.upgrade()
+            .map(|inner| Connection { inner })
+            .ok_or(Error::ConnectionClosed)
+    }
+}
+impl PartialEq for WeakConnection {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.inner, &other.inner)
+    }
+}
+impl Eq for WeakConnection {}
+impl Hash for WeakConnection {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.as_ptr().hash(state);
+    }
+}
+impl PartialOrd for WeakConnection {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for WeakConnection {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.as_ptr().cmp(&other.inner.as_ptr())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use async_trait::async_trait;
+    use libc::{O_CLOEXEC, c_int, pipe2};
+
+    use crate::object_table::SERVER_ID_START;
+
+    use crate::protocol::wayland::{
+        wl_data_device_event_data_offer_args, wl_data_device_interface, wl_data_offer_event_offer_args,
+        wl_data_offer_interface, wl_keyboard_event_keymap_args, wl_keyboard_interface,
+        wl_keyboard_keymap_format,
+    };
+
+
+    /// Returns the read end of a pipe from which the given contents can be read.
+    fn pipe_with_contents(contents: &[u8]) -> OwnedFd {
+        let mut pipe_fds: [c_int; 2] = [-1, -1];
+        let res = unsafe {
+            pipe2(pipe_fds.as_mut_ptr(), O_CLOEXEC)
+        };
+        assert_ne!(res, -1, "failed to create pipe");
+        let (read_fd, write_fd) = unsafe {
+            (OwnedFd::from_raw_fd(pipe_fds[0]), OwnedFd::from_raw_fd(pipe_fds[1]))
+        };
+        File::from(write_fd).write_all(contents).unwrap();
+        read_fd
+    }
+
+    fn keymap_event(object_id: ObjectId) -> Vec<u8> {
+        let keymap_args = wl_keyboard_event_keymap_args {
+            format: wl_keyboard_keymap_format::xkb_v1,
+            fd: -1, // only a placeholder; the file descriptor travels separately
+            size: 0,
+        };
+        keymap_args.try_into_packet(object_id).unwrap()
+            .serialize().unwrap()
+    }
+
+    /// Stores the contents of the keymaps it receives.
+    struct KeymapHandler {
+        keymaps: Arc<StdMutex<Vec<Vec<u8>>>>,
+    }
+    #[async_trait]
+    impl EventHandler for KeymapHandler {
+        async fn handle_event(&self, _connection: &Connection, packet: Packet) -> Result<(), Error> {
+            let keymap_args = wl_keyboard_event_keymap_args::try_from_packet(&packet)?;
+            let mut keymap_file = File::from(unsafe { OwnedFd::from_raw_fd(keymap_args.fd) });
+            let mut keymap = Vec::new();
+            keymap_file.read_to_end(&mut keymap)?;
+            self.keymaps.lock().unwrap().push(keymap);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_event_without_handler_releases_fds() {
+        let (client_socket, server_socket) = UnixStream::pair().unwrap();
+        let conn = Connection::from_stream(client_socket);
+
+        let unhandled_id = conn.new_object(&wl_keyboard_interface, 1);
+        let handled_id = conn.new_object(&wl_keyboard_interface, 1);
+        let keymaps = Arc::new(StdMutex::new(Vec::new()));
+        conn.register_handler(handled_id, Box::new(KeymapHandler { keymaps: Arc::clone(&keymaps) }));
+
+        let mut events = keymap_event(unhandled_id);
+        events.extend(keymap_event(handled_id));
+        let unhandled_fd = pipe_with_contents(b"unhandled");
+        let handled_fd = pipe_with_contents(b"handled");
+        server_socket
+            .send_with_fds(&events, &[unhandled_fd.as_raw_fd(), handled_fd.as_raw_fd()])
+            .await.unwrap();
+
+        let unhandled_packet = conn.recv_packet().await.unwrap();
+        assert!(matches!(
+            conn.dispatch(unhandled_packet).await,
+            Err(Error::NoEventHandler { object_id }) if object_id == unhandled_id,
+        ));
+
+        let handled_packet = conn.recv_packet().await.unwrap();
+        conn.dispatch(handled_packet).await.unwrap();
+        assert_eq!(*keymaps.lock().unwrap(), vec![b"handled".to_vec()]);
+    }
+
+    /// Registers the offers announced to a data device and stores the MIME types they offer.
+    struct DataDeviceHandler {
+        mime_types: Arc<StdMutex<Vec<String>>>,
+    }
+    #[async_trait]
+    impl EventHandler for DataDeviceHandler {
+        async fn handle_event(&self, connection: &Connection, packet: Packet) -> Result<(), Error> {
+            let data_offer_args = wl_data_device_event_data_offer_args::try_from_packet(&packet)?;
+            let offer_id = data_offer_args.id.0;
+            connection.insert_server_object(offer_id, &wl_data_offer_interface, 1)?;
+            connection.register_handler(offer_id, Box::new(DataOfferHandler { mime_types: Arc::clone(&self.mime_types) }));
+            Ok(())
+        }
+    }
+
+    struct DataOfferHandler {
+        mime_types: Arc<StdMutex<Vec<String>>>,
+    }
+    #[async_trait]
+    impl EventHandler for DataOfferHandler {
+        async fn handle_event(&self, _connection: &Connection, packet: Packet) -> Result<(), Error> {
+            let offer_args = wl_data_offer_event_offer_args::try_from_packet(&packet)?;
+            self.mime_types.lock().unwrap().push(offer_args.mime_type);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn roundtrip_dispatches_events_for_objects_created_in_the_same_batch() {
+        let (client_socket, server_socket) = UnixStream::pair().unwrap();
+        let conn = Connection::from_stream(client_socket);
+
+        let device_id = conn.new_object(&wl_data_device_interface, 1);
+        let mime_types = Arc::new(StdMutex::new(Vec::new()));
+        conn.register_handler(device_id, Box::new(DataDeviceHandler { mime_types: Arc::clone(&mime_types) }));
+
+        let server = async {
+            // wait for the wl_display.sync request
+            let mut sync_request = [0u8; HEADER_SIZE + 4];
+            let received = server_socket.recv(&mut sync_request).await.unwrap();
+            assert_eq!(received, sync_request.len());
+            let callback_id = ObjectId::new(u32::from_ne_bytes(sync_request[HEADER_SIZE..].try_into().unwrap())).unwrap();
+
+            let offer_id = ObjectId::new(SERVER_ID_START).unwrap();
+            let packets = [
+                wl_data_device_event_data_offer_args { id: NewObjectId(offer_id) }.try_into_packet(device_id).unwrap(),
+                wl_data_offer_event_offer_args { mime_type: "text/plain".to_owned() }.try_into_packet(offer_id).unwrap(),
+                wl_callback_event_done_args { callback_data: 0 }.try_into_packet(callback_id).unwrap(),
+            ];
+            let mut events = Vec::new();
+            for packet in packets {
+                events.extend(packet.serialize().unwrap());
+            }
+            server_socket.send(&events).await.unwrap();
+        };
+        let (roundtrip_res, ()) = tokio::join!(conn.roundtrip(), server);
+        roundtrip_res.unwrap();
+        assert_eq!(*mime_types.lock().unwrap(), vec!["text/plain".to_owned()]);
     }
 }
@@ -1,3 +1,4 @@
+use std::ffi::OsString;
 use std::io;
 use std::fmt;
 
@@ -8,6 +9,7 @@ use crate::ObjectId;
 pub enum Error {
     Io(io::Error),
     MissingEnvVar { name: String },
+    InvalidEnvVar { name: String, value: OsString },
     PacketTooLong { actual: usize, maximum: usize },
     PacketTooShort { actual: usize, minimum: usize },
     FieldOutOfBounds { actual: usize, maximum: usize },
@@ -16,7 +18,21 @@ pub enum Error {
     StringInvalidUtf8 { data: Vec<u8> },
     IncompleteRead { read_bytes: usize, total_bytes: usize, read_fds: usize, total_fds: usize },
     ZeroObjectId,
+    NullString,
     NoEventHandler { object_id: ObjectId },
+    ConnectionClosed,
+    UnknownObject { object_id: ObjectId, opcode: u16 },
+    DeadObject { object_id: ObjectId, opcode: u16 },
+    Desynchronized,
+    UnknownEvent { interface: &'static str, opcode: u16 },
+    InvalidNewObjectId { object_id: ObjectId },
+    MissingGlobal { interface: String },
+    UnknownGlobal { name: u32 },
+    GlobalInterfaceMismatch { name: u32, expected: String, actual: String },
+    UnsupportedGlobalVersion { interface: String, advertised: u32, minimum: u32 },
+    InvalidEnumValue { enum_name: &'static str, value: u32 },
+    RequestTooNew { interface: &'static str, request: &'static str, since: u32, version: u32 },
+    RequestDeprecated { interface: &'static str, request: &'static str, deprecated_since: u32, version: u32 },
 }
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -25,6 +41,8 @@ impl fmt::Display for Error {
                 => write!(f, "I/O error: {}", e),
             Self::MissingEnvVar { name }
                 => write!(f, "missing environment variable {:?}", name),
+            Self::InvalidEnvVar { name, value }
+                => write!(f, "invalid value {:?} for environment variable {:?}", value, name),
             Self::PacketTooLong { actual, maximum }
                 => write!(f, "packet ({} bytes) too long (maximum {} bytes)", actual, maximum),
             Self::PacketTooShort { actual, minimum }
@@ -41,8 +59,36 @@ impl fmt::Display for Error {
                 => write!(f, "incomplete read ({}/{} bytes, {}/{} file descriptors)", read_bytes, total_bytes, read_fds, total_fds),
             Self::ZeroObjectId
                 => write!(f, "object ID was zero where a concrete object ID was expected"),
+            Self::NullString
+                => write!(f, "string was null where a non-null string was expected"),
             Self::NoEventHandler { object_id }
                 => write!(f, "no event handler for object ID {}", object_id.0),
+            Self::ConnectionClosed
+                => write!(f, "the connection has been closed by the server"),
+            Self::UnknownObject { object_id, opcode }
+                => write!(f, "received event {} for unknown object ID {}", opcode, object_id.0),
+            Self::DeadObject { object_id, opcode }
+                => write!(f, "received event {} for already destroyed object ID {}", opcode, object_id.0),
+            Self::Desynchronized
+                => write!(f, "the connection has lost track of which file descriptors belong to which packet"),
+            Self::UnknownEvent { interface, opcode }
+                => write!(f, "received unknown event {} for interface {}", opcode, interface),
+            Self::InvalidNewObjectId { object_id }
+                => write!(f, "object ID {} cannot be used for a new object created by the server", object_id.0),
+            Self::MissingGlobal { interface }
+                => write!(f, "no global implementing {} has been advertised", interface),
+            Self::UnknownGlobal { name }
+                => write!(f, "no global named {} has been advertised", name),
+            Self::GlobalInterfaceMismatch { name, expected, actual }
+                => write!(f, "global {} implements {}, not {}", name, actual, expected),
+            Self::UnsupportedGlobalVersion { interface, advertised, minimum }
+                => write!(f, "global {} is advertised at version {}, but at least version {} is required", interface, advertised, minimum),
+            Self::InvalidEnumValue { enum_name, value }
+                => write!(f, "value {} is not valid for enum {}", value, enum_name),
+            Self::RequestTooNew { interface, request, since, version }
+                => write!(f, "request {}.{} requires version {}, but the object has version {}", interface, request, since, version),
+            Self::RequestDeprecated { interface, request, deprecated_since, version }
+                => write!(f, "request {}.{} is deprecated since version {}, and the object has version {}", interface, request, deprecated_since, version),
         }
     }
 }
@@ -51,6 +97,7 @@ impl std::error::Error for Error {
         match self {
             Self::Io(e) => Some(e),
             Self::MissingEnvVar { .. } => None,
+            Self::InvalidEnvVar { .. } => None,
             Self::PacketTooLong { .. } => None,
             Self::PacketTooShort { .. } => None,
             Self::FieldOutOfBounds { .. } => None,
@@ -59,7 +106,21 @@ impl std::error::Error for Error {
             Self::StringInvalidUtf8 { .. } => None,
             Self::IncompleteRead { .. } => None,
             Self::ZeroObjectId => None,
+            Self::NullString => None,
             Self::NoEventHandler { .. } => None,
+            Self::ConnectionClosed => None,
+            Self::UnknownObject { .. } => None,
+            Self::DeadObject { .. } => None,
+            Self::Desynchronized => None,
+            Self::UnknownEvent { .. } => None,
+            Self::InvalidNewObjectId { .. } => None,
+            Self::MissingGlobal { .. } => None,
+            Self::UnknownGlobal { .. } => None,
+            Self::GlobalInterfaceMismatch { .. } => None,
+            Self::UnsupportedGlobalVersion { .. } => None,
+            Self::InvalidEnumValue { .. } => None,
+            Self::RequestTooNew { .. } => None,
+            Self::RequestDeprecated { .. } => None,
         }
     }
 }
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Connection, Error, NewObject, ObjectId};
+use crate::protocol::{InterfaceInfo, Proxy};
+use crate::protocol::wayland::{wl_registry, wl_registry_request_bind_args};
+
+
+/// A global object advertised by the compositor via `wl_registry.global`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Global {
+    /// The numeric name by which the registry refers to the global.
+    pub name: u32,
+
+    pub interface: String,
+
+    /// The newest version of the interface supported by the compositor.
+    pub version: u32,
+}
+
+/// The globals currently advertised by a registry.
+///
+/// The list does not receive events by itself; feed it the registry's `global` and
+/// `global_remove` events using [`GlobalList::add`] and [`GlobalList::remove`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GlobalList {
+    registry: wl_registry,
+    name_to_global: BTreeMap<u32, Global>,
+}
+impl GlobalList {
+    /// Returns an empty list for the given registry.
+    pub const fn new(registry: wl_registry) -> Self {
+        Self {
+            registry,
+            name_to_global: BTreeMap::new(),
+        }
+    }
+
+    /// Obtains a new registry via `wl_display.get_registry` and returns an empty list for it.
+    ///
+    /// Register a handler for the registry before dispatching further events; the globals are
+    /// advertised right away.
+    pub async fn create_registry(connection: &Connection) -> Result<Self, Error> {
+        let registry = connection.display()
+            .send_get_registry().await?;
+        Ok(Self::new(registry))
+    }
+
+    pub fn registry(&self) -> &wl_registry {
+        &self.registry
+    }
+
+    /// Records a global announced by `wl_registry.global`.
+    pub fn add(&mut self, name: u32, interface: String, version: u32) {
+        self.name_to_global.insert(name, Global {
+            name,
+            interface,
+            version,
+        });
+    }
+
+    /// Forgets a global withdrawn by `wl_registry.global_remove`, returning it if it was known.
+    pub fn remove(&mut self, name: u32) -> Option<Global> {
+        self.name_to_global.remove(&name)
+    }
+
+    pub fn get(&self, name: u32) -> Option<&Global> {
+        self.name_to_global.get(&name)
+    }
+
+    /// Iterates over the globals, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = &Global> {
+        self.name_to_global.values()
+    }
+
+    /// Iterates over the globals implementing the given interface, ordered by name.
+    pub fn with_interface<'a>(&'a self, interface: &'a str) -> impl Iterator<Item = &'a Global> + 'a {
+        self.iter()
+            .filter(move |g| g.interface == interface)
+    }
+
+    /// Binds the first advertised global implementing the interface of `P`.
+    ///
+    /// See [`GlobalList::bind_name`] for the version negotiation.
+    pub async fn bind<P: Proxy>(&self, versions: impl RangeBounds<u32>) -> Result<P, Error> {
+        let name = self.with_interface(P::INFO.name)
+            .next()
+            .ok_or_else(|| Error::MissingGlobal { interface: P::INFO.name.to_owned() })?
+            .name;
+        self.bind_name(name, versions).await
+    }
+
+    /// Binds the global with the given name, which must implement the interface of `P`.
+    ///
+    /// The global is bound at the newest version that is advertised by the compositor, known to
+    /// the generated code and within `versions`; the proxy knows that version.
+    pub async fn bind_name<P: Proxy>(&self, name: u32, versions: impl RangeBounds<u32>) -> Result<P, Error> {
+        let version = self.negotiate_version(name, P::INFO, versions)?;
+        self.registry.send_bind(name, version).await
+    }
+
+    /// Binds the global with the given name, whose interface is only known at runtime.
+    ///
+    /// See [`GlobalList::bind_name`] for the version negotiation. Returns the ID of the new object
+    /// and the version at which it has been bound.
+    pub async fn bind_interface(
+        &self,
+        name: u32,
+        interface: &'static InterfaceInfo,
+        versions: impl RangeBounds<u32>,
+    ) -> Result<(ObjectId, u32), Error> {
+        let version = self.negotiate_version(name, interface, versions)?;
+        let connection = self.registry.connection().upgrade()?;
+        let object_id = connection.new_object(interface, version);
+        let bind_packet = wl_registry_request_bind_args {
+            name,
+            id: NewObject {
+                object_id,
+                interface: interface.name.to_owned(),
+                interface_version: version,
+            },
+        }.try_into_packet(self.registry.id())?;
+        connection.send_packet(&bind_packet).await?;
+        Ok((object_id, version))
+    }
+
+    /// Returns the version at which the global with the given name is to be bound.
+    fn negotiate_version(&self, name: u32, interface: &'static InterfaceInfo, versions: impl RangeBounds<u32>) -> Result<u32, Error> {
+        let global = self.name_to_global.get(&name)
+            .ok_or(Error::UnknownGlobal { name })?;
+        if global.interface != interface.name {
+            return Err(Error::GlobalInterfaceMismatch {
+                name,
+                expected: interface.name.to_owned(),
+                actual: global.interface.clone(),
+            });
+        }
+
+        let minimum = match versions.start_bound() {
+            Bound::Included(v) => *v,
+            Bound::Excluded(v) => v.saturating_add(1),
+            Bound::Unbounded => 1,
+        };
+        let maximum = match versions.end_bound() {
+            Bound::Included(v) => *v,
+            Bound::Excluded(v) => v.saturating_sub(1),
+            Bound::Unbounded => u32::MAX,
+        };
+        let version = global.version
+            .min(maximum)
+            .min(interface.version);
+        if version < minimum {
+            return Err(Error::UnsupportedGlobalVersion {
+                interface: global.interface.clone(),
+                advertised: global.version,
+                minimum,
+            });
+        }
+        Ok(version)
+    }
+}
@@ -1,15 +1,19 @@
 pub mod connection;
 pub mod error;
 pub mod fixed;
+pub mod global_list;
+mod object_table;
 pub mod packet;
 pub mod protocol;
+mod ring_buffer;
 
 
 use std::num::NonZero;
 
-pub use crate::connection::Connection;
+pub use crate::connection::{Connection, WeakConnection};
 pub use crate::error::Error;
 pub use crate::fixed::Fixed;
+pub use crate::global_list::{Global, GlobalList};
 pub use crate::packet::Packet;
 
 
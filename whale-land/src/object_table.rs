@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use crate::ObjectId;
+use crate::protocol::InterfaceInfo;
+use crate::protocol::wayland::wl_display_interface;
+
+
+/// The first object ID allocated by the compositor; everything below is allocated by the client.
+pub const SERVER_ID_START: u32 = 0xFF00_0000;
+
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum ObjectState {
+    /// The object is in use.
+    Live,
+
+    /// The compositor has already released the object ID (`wl_display.delete_id`), but we have not
+    /// destroyed the object yet.
+    IdDeleted,
+
+    /// We have destroyed the object, but the compositor has not yet released the object ID.
+    ///
+    /// Events may still arrive for the object in the meantime. Objects created by the compositor
+    /// remain in this state until the compositor reuses their ID.
+    Zombie,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Object {
+    interface: &'static InterfaceInfo,
+    version: u32,
+    state: ObjectState,
+}
+
+/// What happened to an event's target object.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EventTarget {
+    Live,
+    Unknown,
+
+    /// The object, which implemented the given interface at the given version, has been destroyed.
+    Dead { interface: &'static InterfaceInfo, version: u32 },
+}
+
+/// Tracks which object IDs are in use, like libwayland's object map.
+///
+/// Client-side object IDs are only reused once the compositor has acknowledged their destruction
+/// with `wl_display.delete_id`; freed IDs are reused most-recently-freed first.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct ObjectTable {
+    objects: BTreeMap<ObjectId, Object>,
+    free_client_ids: Vec<ObjectId>,
+    next_client_id: u32,
+}
+impl ObjectTable {
+    pub fn new() -> Self {
+        let mut objects = BTreeMap::new();
+        objects.insert(ObjectId::DISPLAY, Object {
+            interface: &wl_display_interface,
+            version: 1,
+            state: ObjectState::Live,
+        });
+        Self {
+            objects,
+            free_client_ids: Vec::new(),
+            next_client_id: 2, // 0 is NULL, 1 is always wl_display
+        }
+    }
+
+    /// Allocates a client-side object ID for a new object.
+    pub fn insert_client_object(&mut self, interface: &'static InterfaceInfo, version: u32) -> ObjectId {
+        let object_id = match self.free_client_ids.pop() {
+            Some(oid) => oid,
+            None => {
+                assert!(self.next_client_id < SERVER_ID_START, "out of client-side object IDs");
+                let oid = ObjectId::new(self.next_client_id).unwrap();
+                self.next_client_id += 1;
+                oid
+            },
+        };
+        self.objects.insert(object_id, Object {
+            interface,
+            version,
+            state: ObjectState::Live,
+        });
+        object_id
+    }
+
+    /// Records an object created by the compositor.
+    ///
+    /// Returns `false` if the object ID is not a server-side ID or is already in use.
+    pub fn insert_server_object(&mut self, object_id: ObjectId, interface: &'static InterfaceInfo, version: u32) -> bool {
+        self.insert_server_object_with_state(object_id, interface, version, ObjectState::Live)
+    }
+
+    /// Records an object created by the compositor that we will never use, so that its events can
+    /// be dropped like those of a destroyed object.
+    ///
+    /// Returns `false` if the object ID is not a server-side ID or is already in use.
+    pub fn insert_dead_server_object(&mut self, object_id: ObjectId, interface: &'static InterfaceInfo, version: u32) -> bool {
+        self.insert_server_object_with_state(object_id, interface, version, ObjectState::Zombie)
+    }
+
+    fn insert_server_object_with_state(&mut self, object_id: ObjectId, interface: &'static InterfaceInfo, version: u32, state: ObjectState) -> bool {
+        if object_id.0.get() < SERVER_ID_START {
+            return false;
+        }
+        if self.objects.get(&object_id).is_some_and(|o| o.state != ObjectState::Zombie) {
+            // destroyed server-side objects make way once the compositor reuses their ID
+            return false;
+        }
+        self.objects.insert(object_id, Object {
+            interface,
+            version,
+            state,
+        });
+        true
+    }
+
+    /// Returns the interface and version of a live object.
+    pub fn get(&self, object_id: ObjectId) -> Option<(&'static InterfaceInfo, u32)> {
+        self.objects.get(&object_id)
+            .filter(|o| o.state != ObjectState::Zombie)
+            .map(|o| (o.interface, o.version))
+    }
+
+    /// Returns the interface and version of an object, even if it has been destroyed.
+    pub fn get_including_dead(&self, object_id: ObjectId) -> Option<(&'static InterfaceInfo, u32)> {
+        self.objects.get(&object_id)
+            .map(|o| (o.interface, o.version))
+    }
+
+    /// Marks an object as destroyed.
+    pub fn destroy(&mut self, object_id: ObjectId) {
+        let Some(object) = self.objects.get_mut(&object_id) else { return };
+        match object.state {
+            ObjectState::Live => {
+                // client-side objects wait for the compositor to acknowledge; it does not
+                // acknowledge the destruction of its own objects, which linger until it reuses
+                // their ID (events may be on their way either way)
+                object.state = ObjectState::Zombie;
+            },
+            ObjectState::IdDeleted => {
+                self.objects.remove(&object_id);
+                self.free_client_ids.push(object_id);
+            },
+            ObjectState::Zombie => {},
+        }
+    }
+
+    /// Handles `wl_display.delete_id`: the compositor will no longer use the given object ID.
+    ///
+    /// Returns `false` if the object ID is not known.
+    pub fn delete_id(&mut self, object_id: ObjectId) -> bool {
+        if object_id.0.get() >= SERVER_ID_START {
+            return false;
+        }
+        let Some(object) = self.objects.get_mut(&object_id) else { return false };
+        match object.state {
+            ObjectState::Live => {
+                // we still hold on to the object; free the ID once we destroy it
+                object.state = ObjectState::IdDeleted;
+            },
+            ObjectState::IdDeleted => {},
+            ObjectState::Zombie => {
+                self.objects.remove(&object_id);
+                self.free_client_ids.push(object_id);
+            },
+        }
+        true
+    }
+
+    /// Looks up the target of a received event, destroying it if the event is a destructor.
+    pub fn receive_event(&mut self, object_id: ObjectId, opcode: u16) -> EventTarget {
+        let Some(object) = self.objects.get(&object_id) else {
+            return EventTarget::Unknown;
+        };
+        if object.state == ObjectState::Zombie {
+            return EventTarget::Dead { interface: object.interface, version: object.version };
+        }
+        let message = object.interface.events.get(usize::from(opcode));
+        if message.map(|m| m.destructor).unwrap_or(false) {
+            self.destroy(object_id);
+        }
+        EventTarget::Live
+    }
+
+    /// Notes that a request has been sent, destroying its object if the request is a destructor.
+    ///
+    /// Returns whether the object has been destroyed.
+    pub fn send_request(&mut self, object_id: ObjectId, opcode: u16) -> bool {
+        let Some(object) = self.objects.get(&object_id) else { return false };
+        let destructor = object.interface.requests.get(usize::from(opcode))
+            .map(|m| m.destructor)
+            .unwrap_or(false);
+        if destructor {
+            self.destroy(object_id);
+        }
+        destructor
+    }
+}
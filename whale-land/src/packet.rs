@@ -1,9 +1,88 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
 use std::num::NonZero;
 use std::os::fd::RawFd;
+use std::sync::{Arc, Mutex};
+
+use libc::close;
 
 use crate::{NewObject, NewObjectId, ObjectId};
 use crate::error::Error;
 use crate::fixed::Fixed;
+use crate::protocol::ArgKind;
+
+
+/// File descriptors that have been received but not yet claimed by a packet.
+///
+/// The wire protocol does not associate file descriptors with specific messages; they are handed
+/// out in order as the `fd` arguments of received packets are decoded. File descriptors that are
+/// never claimed are closed when the queue is dropped.
+#[derive(Debug, Default)]
+pub(crate) struct FdQueue {
+    fds: Mutex<VecDeque<RawFd>>,
+}
+impl FdQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn extend<I: IntoIterator<Item = RawFd>>(&self, fds: I) {
+        self.fds.lock().unwrap().extend(fds);
+    }
+
+    fn pop(&self) -> Option<RawFd> {
+        self.fds.lock().unwrap().pop_front()
+    }
+}
+impl Drop for FdQueue {
+    fn drop(&mut self) {
+        let fds = self.fds.get_mut().unwrap();
+        for fd in fds.drain(..) {
+            unsafe {
+                close(fd);
+            }
+        }
+    }
+}
+
+/// The file descriptors claimed by a received packet.
+///
+/// Claiming is idempotent: decoding the same packet again yields the same file descriptors.
+#[derive(Debug)]
+struct IncomingFds {
+    queue: Arc<FdQueue>,
+    claimed: Mutex<Vec<RawFd>>,
+}
+impl IncomingFds {
+    fn claim(&self, index: usize) -> Result<RawFd, Error> {
+        let mut claimed = self.claimed.lock().unwrap();
+        while claimed.len() <= index {
+            match self.queue.pop() {
+                Some(fd) => claimed.push(fd),
+                None => return Err(Error::FdOutOfBounds { total: claimed.len() }),
+            }
+        }
+        Ok(claimed[index])
+    }
+}
+
+/// Reference to the [`IncomingFds`] of a packet, compared by identity.
+#[derive(Clone, Debug)]
+struct IncomingFdsRef(Arc<IncomingFds>);
+impl PartialEq for IncomingFdsRef {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+impl Eq for IncomingFdsRef {}
+impl PartialOrd for IncomingFdsRef {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for IncomingFdsRef {
+    fn cmp(&self, other: &Self) -> Ordering { Arc::as_ptr(&self.0).cmp(&Arc::as_ptr(&other.0)) }
+}
+impl Hash for IncomingFdsRef {
+    fn hash<H: Hasher>(&self, state: &mut H) { Arc::as_ptr(&self.0).hash(state) }
+}
 
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -13,6 +92,9 @@ pub struct Packet {
     opcode: u16, // merged with size in protocol
     payload: Vec<u8>,
     fds: Vec<RawFd>,
+
+    // where received packets obtain their file descriptors from
+    incoming_fds: Option<IncomingFdsRef>,
 }
 impl Packet {
     pub fn new(
@@ -24,6 +106,7 @@ impl Packet {
             opcode,
             payload: Vec::new(),
             fds: Vec::new(),
+            incoming_fds: None,
         }
     }
 
@@ -38,9 +121,74 @@ impl Packet {
             opcode,
             payload,
             fds,
+            incoming_fds: None,
+        }
+    }
+
+    /// Creates a packet that has been received, which claims its file descriptors from the given
+    /// queue as they are decoded.
+    pub(crate) fn new_received(
+        object_id: ObjectId,
+        opcode: u16,
+        payload: Vec<u8>,
+        fd_queue: Arc<FdQueue>,
+    ) -> Self {
+        Self {
+            object_id,
+            opcode,
+            payload,
+            fds: Vec::new(),
+            incoming_fds: Some(IncomingFdsRef(Arc::new(IncomingFds {
+                queue: fd_queue,
+                claimed: Mutex::new(Vec::new()),
+            }))),
         }
     }
 
+    /// Skips over a received packet with the given arguments that will never be decoded.
+    ///
+    /// Its file descriptors are claimed and closed, so that later packets claim the correct ones.
+    /// Returns the objects that the packet creates, along with the names of their interfaces.
+    pub(crate) fn discard_incoming(&self, args: &[ArgKind]) -> Result<Vec<(ObjectId, &'static str)>, Error> {
+        // the file descriptors come first; they must be claimed even if the payload is garbled
+        if let Some(incoming_fds) = &self.incoming_fds {
+            let fd_count = args.iter()
+                .filter(|a| **a == ArgKind::Fd)
+                .count();
+            for index in 0..fd_count {
+                let fd = incoming_fds.0.claim(index)?;
+                unsafe {
+                    close(fd);
+                }
+            }
+        }
+
+        let mut reader = self.read();
+        let mut new_objects = Vec::new();
+        for arg in args {
+            match arg {
+                ArgKind::Int|ArgKind::Uint|ArgKind::Fixed|ArgKind::Object => {
+                    reader.read_uint()?;
+                },
+                ArgKind::String => {
+                    reader.read_nullable_str()?;
+                },
+                ArgKind::NewId { interface: Some(interface) } => {
+                    new_objects.push((reader.read_object()?, *interface));
+                },
+                ArgKind::NewId { interface: None } => {
+                    // only requests (wl_registry.bind) create objects of arbitrary interfaces
+                    reader.read_new_id_unknown_interface()?;
+                },
+                ArgKind::Array => {
+                    reader.read_array()?;
+                },
+                ArgKind::Fd => {},
+            }
+        }
+        Ok(new_objects)
+    }
+
     pub fn object_id(&self) -> ObjectId { self.object_id }
     pub fn opcode(&self) -> u16 { self.opcode }
 
@@ -75,6 +223,13 @@ impl Packet {
         self.payload.extend(std::iter::repeat_n(0x00, realign_count));
     }
 
+    pub fn push_nullable_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(v) => self.push_str(v),
+            None => self.push_uint(0),
+        }
+    }
+
     pub fn push_array(&mut self, array: &[u8]) {
         let len = array.len();
         let len_u32: u32 = len.try_into().unwrap();
@@ -85,21 +240,25 @@ impl Packet {
         self.payload.extend(std::iter::repeat_n(0x00, realign_count));
     }
 
-    pub fn push_object(&mut self, obj_id: Option<ObjectId>) {
+    pub fn push_object(&mut self, obj_id: ObjectId) {
+        self.push_uint(obj_id.0.into())
+    }
+
+    pub fn push_nullable_object(&mut self, obj_id: Option<ObjectId>) {
         match obj_id {
-            Some(oi) => self.push_uint(oi.0.into()),
+            Some(oi) => self.push_object(oi),
             None => self.push_uint(0),
         }
     }
 
     pub fn push_new_id_known_interface(&mut self, new_id: NewObjectId) {
-        self.push_object(Some(new_id.0))
+        self.push_object(new_id.0)
     }
 
     pub fn push_new_id_unknown_interface(&mut self, new_obj: &NewObject) {
         self.push_str(&new_obj.interface);
         self.push_uint(new_obj.interface_version);
-        self.push_object(Some(new_obj.object_id));
+        self.push_object(new_obj.object_id);
     }
 
     pub fn push_fd(&mut self, fd: RawFd) {
@@ -186,10 +345,20 @@ impl<'a> PacketReader<'a> {
     }
 
     pub fn read_str(&mut self) -> Result<String, Error> {
+        self.read_nullable_str()?
+            .ok_or(Error::NullString)
+    }
+
+    pub fn read_nullable_str(&mut self) -> Result<Option<String>, Error> {
         let mut len_buf = [0u8; 4];
         self.peek_bytes(&mut len_buf)?;
         let len_u32 = u32::from_ne_bytes(len_buf);
         let len: usize = len_u32.try_into().unwrap();
+        if len == 0 {
+            // a null string has no NUL terminator
+            self.payload_pos += 4;
+            return Ok(None);
+        }
         let padding_len = (4 - (len % 4)) % 4;
         let len_padded = len + padding_len;
 
@@ -215,7 +384,7 @@ impl<'a> PacketReader<'a> {
 
         let stringy = std::str::from_utf8(no_nul_string_slice)
             .map_err(|_| Error::StringInvalidUtf8 { data: no_nul_string_slice.to_vec() })?;
-        Ok(stringy.to_owned())
+        Ok(Some(stringy.to_owned()))
     }
 
     pub fn read_array(&mut self) -> Result<Vec<u8>, Error> {
@@ -240,26 +409,25 @@ impl<'a> PacketReader<'a> {
         Ok(byte_slice.to_vec())
     }
 
-    pub fn read_object(&mut self) -> Result<Option<ObjectId>, Error> {
+    pub fn read_object(&mut self) -> Result<ObjectId, Error> {
+        self.read_nullable_object()?
+            .ok_or(Error::ZeroObjectId)
+    }
+
+    pub fn read_nullable_object(&mut self) -> Result<Option<ObjectId>, Error> {
         let oid = self.read_uint()?;
         Ok(NonZero::new(oid).map(ObjectId))
     }
 
     pub fn read_new_id_known_interface(&mut self) -> Result<NewObjectId, Error> {
-        let oid_opt = self.read_object()?;
-        let Some(oid) = oid_opt else {
-            return Err(Error::ZeroObjectId);
-        };
+        let oid = self.read_object()?;
         Ok(NewObjectId(oid))
     }
 
     pub fn read_new_id_unknown_interface(&mut self) -> Result<NewObject, Error> {
         let interface = self.read_str()?;
         let version = self.read_uint()?;
-        let oid_opt = self.read_object()?;
-        let Some(oid) = oid_opt else {
-            return Err(Error::ZeroObjectId);
-        };
+        let oid = self.read_object()?;
 
         Ok(NewObject {
             object_id: oid,
@@ -269,13 +437,15 @@ impl<'a> PacketReader<'a> {
     }
 
     pub fn read_fd(&mut self) -> Result<RawFd, Error> {
-        if self.fd_pos >= self.packet.fds.len() {
-            Err(Error::FdOutOfBounds { total: self.packet.fds.len() })
+        let fd = if self.fd_pos < self.packet.fds.len() {
+            self.packet.fds[self.fd_pos]
+        } else if let Some(incoming_fds) = &self.packet.incoming_fds {
+            incoming_fds.0.claim(self.fd_pos - self.packet.fds.len())?
         } else {
-            let fd = self.packet.fds[self.fd_pos];
-            self.fd_pos += 1;
-            Ok(fd)
-        }
+            return Err(Error::FdOutOfBounds { total: self.packet.fds.len() });
+        };
+        self.fd_pos += 1;
+        Ok(fd)
     }
 
     pub fn finish(&self) -> Result<(), Error> {
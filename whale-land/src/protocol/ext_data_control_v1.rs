@@ -1,48 +1,157 @@
 // This file has been autogenerated from a Wayland XML protocol definition.
 
-#![allow(non_camel_case_types)]
-#[allow(unused)]
-pub struct ext_data_control_manager_v1_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
+#![allow(non_camel_case_types, non_upper_case_globals)]
+/// Static information about all interfaces of `ext_data_control_v1`.
+pub const INTERFACES: &[&crate::protocol::InterfaceInfo] = &[
+    &ext_data_control_manager_v1_interface,
+    &ext_data_control_device_v1_interface,
+    &ext_data_control_source_v1_interface,
+    &ext_data_control_offer_v1_interface,
+];
+/// Static information about `ext_data_control_manager_v1`.
+pub const ext_data_control_manager_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "ext_data_control_manager_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "create_data_source",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("ext_data_control_source_v1"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "get_data_device",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::NewId {
+                        interface: Some("ext_data_control_device_v1"),
+                    },
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+    };
+/// Manager to control data devices.
+///
+/// This interface is a manager that allows creating per-seat data device
+/// controls.
+///
+/// A proxy for a `ext_data_control_manager_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_manager_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for ext_data_control_manager_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &ext_data_control_manager_v1_interface;
+}
+impl crate::protocol::Proxy for ext_data_control_manager_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
 }
 #[allow(unused)]
-impl<'a> ext_data_control_manager_v1_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl ext_data_control_manager_v1 {
+    /// Create a new data source.
+    ///
+    /// Create a new data source.
+    ///
+    /// Returns the new `ext_data_control_source_v1` object.
     pub async fn send_create_data_source(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
-    }
+    ) -> Result<ext_data_control_source_v1, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&ext_data_control_source_v1_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(ext_data_control_source_v1 {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Get a data device for a seat.
+    ///
+    /// Create a data device that can be used to manage a seat's selection.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`
+    ///
+    /// Returns the new `ext_data_control_device_v1` object.
     pub async fn send_get_data_device(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-        seat: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(id);
+        seat: crate::ObjectId,
+    ) -> Result<ext_data_control_device_v1, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&ext_data_control_device_v1_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_object(seat);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct ext_data_control_manager_v1_v1_request_create_data_source_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(ext_data_control_device_v1 {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Destroy the manager.
+    ///
+    /// All objects created by the manager will still remain valid, until their
+    /// appropriate destroy request has been called.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Create a new data source.
+///
+/// Create a new data source.
+///
+/// The arguments of the `ext_data_control_manager_v1.create_data_source` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_manager_v1_request_create_data_source_args {
+    /// Data source to create.
     pub id: crate::NewObjectId,
 }
-impl ext_data_control_manager_v1_v1_request_create_data_source_args {
+impl ext_data_control_manager_v1_request_create_data_source_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_manager_v1_v1_request_create_data_source_args, crate::Error> {
+    ) -> Result<ext_data_control_manager_v1_request_create_data_source_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -58,7 +167,7 @@ impl ext_data_control_manager_v1_v1_request_create_data_source_args {
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_manager_v1_v1_request_create_data_source_args
+    for ext_data_control_manager_v1_request_create_data_source_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -68,29 +177,39 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_manager_v1_v1_request_create_data_source_args,
+        ext_data_control_manager_v1_request_create_data_source_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_manager_v1_v1_request_create_data_source_args,
+            ext_data_control_manager_v1_request_create_data_source_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_manager_v1_v1_request_get_data_device_args {
+/// Get a data device for a seat.
+///
+/// Create a data device that can be used to manage a seat's selection.
+///
+/// The arguments of the `ext_data_control_manager_v1.get_data_device` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_manager_v1_request_get_data_device_args {
     pub id: crate::NewObjectId,
-    pub seat: ::std::option::Option<crate::ObjectId>,
+    pub seat: crate::ObjectId,
 }
-impl ext_data_control_manager_v1_v1_request_get_data_device_args {
+impl ext_data_control_manager_v1_request_get_data_device_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_manager_v1_v1_request_get_data_device_args, crate::Error> {
+    ) -> Result<ext_data_control_manager_v1_request_get_data_device_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let seat = __reader.read_object()?;
@@ -108,7 +227,7 @@ impl ext_data_control_manager_v1_v1_request_get_data_device_args {
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_manager_v1_v1_request_get_data_device_args
+    for ext_data_control_manager_v1_request_get_data_device_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -118,26 +237,37 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_manager_v1_v1_request_get_data_device_args,
+        ext_data_control_manager_v1_request_get_data_device_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_manager_v1_v1_request_get_data_device_args,
+            ext_data_control_manager_v1_request_get_data_device_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_manager_v1_v1_request_destroy_args {}
-impl ext_data_control_manager_v1_v1_request_destroy_args {
+/// Destroy the manager.
+///
+/// All objects created by the manager will still remain valid, until their
+/// appropriate destroy request has been called.
+///
+/// The arguments of the `ext_data_control_manager_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_manager_v1_request_destroy_args {}
+impl ext_data_control_manager_v1_request_destroy_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_manager_v1_v1_request_destroy_args, crate::Error> {
+    ) -> Result<ext_data_control_manager_v1_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -150,9 +280,7 @@ impl ext_data_control_manager_v1_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_manager_v1_v1_request_destroy_args
-{
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_manager_v1_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -161,54 +289,214 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_manager_v1_v1_request_destroy_args,
+        ext_data_control_manager_v1_request_destroy_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_manager_v1_v1_request_destroy_args,
+            ext_data_control_manager_v1_request_destroy_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// Static information about `ext_data_control_device_v1`.
+pub const ext_data_control_device_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "ext_data_control_device_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "set_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_primary_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "data_offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("ext_data_control_offer_v1"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "finished",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "primary_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+        ],
+    };
+/// Manage a data device for a seat.
+///
+/// This interface allows a client to manage a seat's selection.
+///
+/// When the seat is destroyed, this object becomes inert.
+///
+/// A proxy for a `ext_data_control_device_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for ext_data_control_device_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &ext_data_control_device_v1_interface;
+}
+impl crate::protocol::Proxy for ext_data_control_device_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `ext_data_control_device_v1` object.
 #[allow(unused)]
-pub trait ext_data_control_device_v1_v1_event_handler: crate::protocol::EventHandler {
+pub trait ext_data_control_device_v1_event_handler: crate::protocol::EventHandler {
+    /// Introduce a new ext_data_control_offer.
+    ///
+    /// The data_offer event introduces a new ext_data_control_offer object,
+    /// which will subsequently be used in either the
+    /// ext_data_control_device.selection event (for the regular clipboard
+    /// selections) or the ext_data_control_device.primary_selection event (for
+    /// the primary clipboard selections). Immediately following the
+    /// ext_data_control_device.data_offer event, the new data_offer object
+    /// will send out ext_data_control_offer.offer events to describe the MIME
+    /// types it offers.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
     fn handle_data_offer(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: crate::NewObjectId,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise new selection.
+    ///
+    /// The selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The selection event is sent to a client when a new
+    /// selection is set. The ext_data_control_offer is valid until a new
+    /// ext_data_control_offer or NULL is received. The client must destroy the
+    /// previous selection ext_data_control_offer, if any, upon receiving this
+    /// event. Regardless, the previous selection will be ignored once a new
+    /// selection ext_data_control_offer is received.
+    ///
+    /// The first selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
     fn handle_selection(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// This data control is no longer valid.
+    ///
+    /// This data control object is no longer valid and should be destroyed by
+    /// the client.
     fn handle_finished(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise new primary selection.
+    ///
+    /// The primary_selection event is sent out to notify the client of a new
+    /// ext_data_control_offer for the primary selection for this device. The
+    /// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The primary_selection event is sent to a client when a
+    /// new primary selection is set. The ext_data_control_offer is valid until
+    /// a new ext_data_control_offer or NULL is received. The client must
+    /// destroy the previous primary selection ext_data_control_offer, if any,
+    /// upon receiving this event. Regardless, the previous primary selection
+    /// will be ignored once a new primary selection ext_data_control_offer is
+    /// received.
+    ///
+    /// If the compositor supports primary selection, the first
+    /// primary_selection event is sent upon binding the
+    /// ext_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
     fn handle_primary_selection(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "ext_data_control_device_v1",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -225,80 +513,121 @@ pub trait ext_data_control_device_v1_v1_event_handler: crate::protocol::EventHan
                     let mut __packet_reader = __packet.read();
                     let id = __packet_reader.read_new_id_known_interface()?;
                     __packet_reader.finish()?;
-                    self.handle_data_offer(__connection, __packet, id).await;
-                    Ok(())
+                    self.handle_data_offer(__connection, __packet, id).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
-                    let id = __packet_reader.read_object()?;
+                    let id = __packet_reader.read_nullable_object()?;
                     __packet_reader.finish()?;
-                    self.handle_selection(__connection, __packet, id).await;
-                    Ok(())
+                    self.handle_selection(__connection, __packet, id).await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_finished(__connection, __packet).await;
-                    Ok(())
+                    self.handle_finished(__connection, __packet).await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
-                    let id = __packet_reader.read_object()?;
+                    let id = __packet_reader.read_nullable_object()?;
                     __packet_reader.finish()?;
                     self.handle_primary_selection(__connection, __packet, id)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct ext_data_control_device_v1_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> ext_data_control_device_v1_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl ext_data_control_device_v1 {
+    /// Copy data to the selection.
+    ///
+    /// This request asks the compositor to set the selection to the data from
+    /// the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source triggers the used_source protocol error.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`
     pub async fn send_set_selection(
         &self,
-        __object_id: crate::ObjectId,
         source: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_object(source);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
-    }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_nullable_object(source);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this data device.
+    ///
+    /// Destroys the data device object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Copy data to the primary selection.
+    ///
+    /// This request asks the compositor to set the primary selection to the
+    /// data from the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source triggers the used_source protocol error.
+    ///
+    /// To unset the primary selection, set the source to NULL.
+    ///
+    /// The compositor will ignore this request if it does not support primary
+    /// selection.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`
     pub async fn send_set_primary_selection(
         &self,
-        __object_id: crate::ObjectId,
         source: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        __packet.push_object(source);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct ext_data_control_device_v1_v1_request_set_selection_args {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __packet.push_nullable_object(source);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Copy data to the selection.
+///
+/// This request asks the compositor to set the selection to the data from
+/// the source on behalf of the client.
+///
+/// The given source may not be used in any further set_selection or
+/// set_primary_selection requests. Attempting to use a previously used
+/// source triggers the used_source protocol error.
+///
+/// To unset the selection, set the source to NULL.
+///
+/// The arguments of the `ext_data_control_device_v1.set_selection` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_request_set_selection_args {
     pub source: ::std::option::Option<crate::ObjectId>,
 }
-impl ext_data_control_device_v1_v1_request_set_selection_args {
+impl ext_data_control_device_v1_request_set_selection_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_request_set_selection_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_request_set_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let source = __reader.read_object()?;
+        let source = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { source })
     }
@@ -307,12 +636,12 @@ impl ext_data_control_device_v1_v1_request_set_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.source);
+        __packet.push_nullable_object(self.source);
         Ok(__packet)
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_device_v1_v1_request_set_selection_args
+    for ext_data_control_device_v1_request_set_selection_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -322,26 +651,36 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_request_set_selection_args,
+        ext_data_control_device_v1_request_set_selection_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_request_set_selection_args,
+            ext_data_control_device_v1_request_set_selection_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_request_destroy_args {}
-impl ext_data_control_device_v1_v1_request_destroy_args {
+/// Destroy this data device.
+///
+/// Destroys the data device object.
+///
+/// The arguments of the `ext_data_control_device_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_request_destroy_args {}
+impl ext_data_control_device_v1_request_destroy_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_request_destroy_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -354,7 +693,7 @@ impl ext_data_control_device_v1_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -363,31 +702,50 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_re
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_request_destroy_args,
+        ext_data_control_device_v1_request_destroy_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_request_destroy_args,
+            ext_data_control_device_v1_request_destroy_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_request_set_primary_selection_args {
+/// Copy data to the primary selection.
+///
+/// This request asks the compositor to set the primary selection to the
+/// data from the source on behalf of the client.
+///
+/// The given source may not be used in any further set_selection or
+/// set_primary_selection requests. Attempting to use a previously used
+/// source triggers the used_source protocol error.
+///
+/// To unset the primary selection, set the source to NULL.
+///
+/// The compositor will ignore this request if it does not support primary
+/// selection.
+///
+/// The arguments of the `ext_data_control_device_v1.set_primary_selection` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_request_set_primary_selection_args {
     pub source: ::std::option::Option<crate::ObjectId>,
 }
-impl ext_data_control_device_v1_v1_request_set_primary_selection_args {
+impl ext_data_control_device_v1_request_set_primary_selection_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_request_set_primary_selection_args, crate::Error>
-    {
+    ) -> Result<ext_data_control_device_v1_request_set_primary_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let source = __reader.read_object()?;
+        let source = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { source })
     }
@@ -396,12 +754,12 @@ impl ext_data_control_device_v1_v1_request_set_primary_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.source);
+        __packet.push_nullable_object(self.source);
         Ok(__packet)
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_device_v1_v1_request_set_primary_selection_args
+    for ext_data_control_device_v1_request_set_primary_selection_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -411,28 +769,45 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_request_set_primary_selection_args,
+        ext_data_control_device_v1_request_set_primary_selection_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_request_set_primary_selection_args,
+            ext_data_control_device_v1_request_set_primary_selection_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_event_data_offer_args {
+/// Introduce a new ext_data_control_offer.
+///
+/// The data_offer event introduces a new ext_data_control_offer object,
+/// which will subsequently be used in either the
+/// ext_data_control_device.selection event (for the regular clipboard
+/// selections) or the ext_data_control_device.primary_selection event (for
+/// the primary clipboard selections). Immediately following the
+/// ext_data_control_device.data_offer event, the new data_offer object
+/// will send out ext_data_control_offer.offer events to describe the MIME
+/// types it offers.
+///
+/// The arguments of the `ext_data_control_device_v1.data_offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_event_data_offer_args {
     pub id: crate::NewObjectId,
 }
-impl ext_data_control_device_v1_v1_event_data_offer_args {
+impl ext_data_control_device_v1_event_data_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_event_data_offer_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_event_data_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -447,9 +822,7 @@ impl ext_data_control_device_v1_v1_event_data_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_device_v1_v1_event_data_offer_args
-{
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_event_data_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -458,30 +831,52 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_event_data_offer_args,
+        ext_data_control_device_v1_event_data_offer_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_event_data_offer_args,
+            ext_data_control_device_v1_event_data_offer_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_event_selection_args {
+/// Advertise new selection.
+///
+/// The selection event is sent out to notify the client of a new
+/// ext_data_control_offer for the selection for this device. The
+/// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+/// events are sent out immediately before this event to introduce the data
+/// offer object. The selection event is sent to a client when a new
+/// selection is set. The ext_data_control_offer is valid until a new
+/// ext_data_control_offer or NULL is received. The client must destroy the
+/// previous selection ext_data_control_offer, if any, upon receiving this
+/// event. Regardless, the previous selection will be ignored once a new
+/// selection ext_data_control_offer is received.
+///
+/// The first selection event is sent upon binding the
+/// ext_data_control_device object.
+///
+/// The arguments of the `ext_data_control_device_v1.selection` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_event_selection_args {
     pub id: ::std::option::Option<crate::ObjectId>,
 }
-impl ext_data_control_device_v1_v1_event_selection_args {
+impl ext_data_control_device_v1_event_selection_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_event_selection_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_event_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let id = __reader.read_object()?;
+        let id = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { id })
     }
@@ -490,11 +885,11 @@ impl ext_data_control_device_v1_v1_event_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.id);
+        __packet.push_nullable_object(self.id);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_event_selection_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_event_selection_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -503,26 +898,37 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_ev
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_event_selection_args,
+        ext_data_control_device_v1_event_selection_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_event_selection_args,
+            ext_data_control_device_v1_event_selection_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_event_finished_args {}
-impl ext_data_control_device_v1_v1_event_finished_args {
+/// This data control is no longer valid.
+///
+/// This data control object is no longer valid and should be destroyed by
+/// the client.
+///
+/// The arguments of the `ext_data_control_device_v1.finished` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_event_finished_args {}
+impl ext_data_control_device_v1_event_finished_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_event_finished_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_event_finished_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -535,7 +941,7 @@ impl ext_data_control_device_v1_v1_event_finished_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_event_finished_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_event_finished_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -544,30 +950,54 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_device_v1_v1_ev
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_event_finished_args,
+        ext_data_control_device_v1_event_finished_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_event_finished_args,
+            ext_data_control_device_v1_event_finished_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_device_v1_v1_event_primary_selection_args {
+/// Advertise new primary selection.
+///
+/// The primary_selection event is sent out to notify the client of a new
+/// ext_data_control_offer for the primary selection for this device. The
+/// ext_data_control_device.data_offer and the ext_data_control_offer.offer
+/// events are sent out immediately before this event to introduce the data
+/// offer object. The primary_selection event is sent to a client when a
+/// new primary selection is set. The ext_data_control_offer is valid until
+/// a new ext_data_control_offer or NULL is received. The client must
+/// destroy the previous primary selection ext_data_control_offer, if any,
+/// upon receiving this event. Regardless, the previous primary selection
+/// will be ignored once a new primary selection ext_data_control_offer is
+/// received.
+///
+/// If the compositor supports primary selection, the first
+/// primary_selection event is sent upon binding the
+/// ext_data_control_device object.
+///
+/// The arguments of the `ext_data_control_device_v1.primary_selection` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_device_v1_event_primary_selection_args {
     pub id: ::std::option::Option<crate::ObjectId>,
 }
-impl ext_data_control_device_v1_v1_event_primary_selection_args {
+impl ext_data_control_device_v1_event_primary_selection_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_device_v1_v1_event_primary_selection_args, crate::Error> {
+    ) -> Result<ext_data_control_device_v1_event_primary_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let id = __reader.read_object()?;
+        let id = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { id })
     }
@@ -576,12 +1006,12 @@ impl ext_data_control_device_v1_v1_event_primary_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.id);
+        __packet.push_nullable_object(self.id);
         Ok(__packet)
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for ext_data_control_device_v1_v1_event_primary_selection_args
+    for ext_data_control_device_v1_event_primary_selection_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -591,43 +1021,214 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_device_v1_v1_event_primary_selection_args,
+        ext_data_control_device_v1_event_primary_selection_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_device_v1_v1_event_primary_selection_args,
+            ext_data_control_device_v1_event_primary_selection_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `ext_data_control_device_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ext_data_control_device_v1_event {
+    /// Introduce a new ext_data_control_offer.
+    data_offer(ext_data_control_device_v1_event_data_offer_args),
+    /// Advertise new selection.
+    selection(ext_data_control_device_v1_event_selection_args),
+    /// This data control is no longer valid.
+    finished(ext_data_control_device_v1_event_finished_args),
+    /// Advertise new primary selection.
+    primary_selection(ext_data_control_device_v1_event_primary_selection_args),
+}
+impl ext_data_control_device_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::data_offer(
+                ext_data_control_device_v1_event_data_offer_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::selection(
+                ext_data_control_device_v1_event_selection_args::try_from_packet(__packet)?,
+            )),
+            2 => Ok(Self::finished(
+                ext_data_control_device_v1_event_finished_args::try_from_packet(__packet)?,
+            )),
+            3 => Ok(Self::primary_selection(
+                ext_data_control_device_v1_event_primary_selection_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "ext_data_control_device_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::data_offer(_) => ext_data_control_device_v1_event_data_offer_args::OPCODE,
+            Self::selection(_) => ext_data_control_device_v1_event_selection_args::OPCODE,
+            Self::finished(_) => ext_data_control_device_v1_event_finished_args::OPCODE,
+            Self::primary_selection(_) => {
+                ext_data_control_device_v1_event_primary_selection_args::OPCODE
+            }
+        }
+    }
+}
+/// The enum `ext_data_control_device_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum ext_data_control_device_v1_error {
+    /// Source given to set_selection or set_primary_selection was already used before.
+    used_source = 1,
+}
+impl ::std::convert::TryFrom<u32> for ext_data_control_device_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            1 => Ok(Self::used_source),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "ext_data_control_device_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<ext_data_control_device_v1_error> for u32 {
+    fn from(__value: ext_data_control_device_v1_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `ext_data_control_source_v1`.
+pub const ext_data_control_source_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "ext_data_control_source_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "send",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "cancelled",
+                destructor: false,
+                args: &[],
+            },
+        ],
+    };
+/// Offer to transfer data.
+///
+/// The ext_data_control_source object is the source side of a
+/// ext_data_control_offer. It is created by the source client in a data
+/// transfer and provides a way to describe the offered data and a way to
+/// respond to requests to transfer the data.
+///
+/// A proxy for a `ext_data_control_source_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_source_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for ext_data_control_source_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &ext_data_control_source_v1_interface;
+}
+impl crate::protocol::Proxy for ext_data_control_source_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `ext_data_control_source_v1` object.
 #[allow(unused)]
-pub trait ext_data_control_source_v1_v1_event_handler: crate::protocol::EventHandler {
+pub trait ext_data_control_source_v1_event_handler: crate::protocol::EventHandler {
+    /// Send the data.
+    ///
+    /// Request for data from the client. Send the data as the specified MIME
+    /// type over the passed file descriptor, then close it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type for the data
+    /// * `fd`: file descriptor for the data
     fn handle_send(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         mime_type: ::std::string::String,
         fd: ::std::os::fd::RawFd,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Selection was cancelled.
+    ///
+    /// This data source is no longer valid. The data source has been replaced
+    /// by another data source.
+    ///
+    /// The client should clean up and destroy this data source.
     fn handle_cancelled(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "ext_data_control_source_v1",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -646,54 +1247,71 @@ pub trait ext_data_control_source_v1_v1_event_handler: crate::protocol::EventHan
                     let fd = __packet_reader.read_fd()?;
                     __packet_reader.finish()?;
                     self.handle_send(__connection, __packet, mime_type, fd)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_cancelled(__connection, __packet).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_cancelled(__connection, __packet).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct ext_data_control_source_v1_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> ext_data_control_source_v1_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_offer(
-        &self,
-        __object_id: crate::ObjectId,
-        mime_type: &str,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_str(&mime_type);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct ext_data_control_source_v1_v1_request_offer_args {
+impl ext_data_control_source_v1 {
+    /// Add an offered MIME type.
+    ///
+    /// This request adds a MIME type to the set of MIME types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    ///
+    /// Calling this after ext_data_control_device.set_selection is a protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type offered by the data source
+    pub async fn send_offer(&self, mime_type: &str) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_str(mime_type);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this source.
+    ///
+    /// Destroys the data source object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Add an offered MIME type.
+///
+/// This request adds a MIME type to the set of MIME types advertised to
+/// targets. Can be called several times to offer multiple types.
+///
+/// Calling this after ext_data_control_device.set_selection is a protocol
+/// error.
+///
+/// The arguments of the `ext_data_control_source_v1.offer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_source_v1_request_offer_args {
+    /// MIME type offered by the data source.
     pub mime_type: ::std::string::String,
 }
-impl ext_data_control_source_v1_v1_request_offer_args {
+impl ext_data_control_source_v1_request_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_source_v1_v1_request_offer_args, crate::Error> {
+    ) -> Result<ext_data_control_source_v1_request_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         __reader.finish()?;
@@ -708,7 +1326,7 @@ impl ext_data_control_source_v1_v1_request_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_request_offer_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_request_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -717,26 +1335,36 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_re
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_source_v1_v1_request_offer_args,
+        ext_data_control_source_v1_request_offer_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_source_v1_v1_request_offer_args,
+            ext_data_control_source_v1_request_offer_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_source_v1_v1_request_destroy_args {}
-impl ext_data_control_source_v1_v1_request_destroy_args {
+/// Destroy this source.
+///
+/// Destroys the data source object.
+///
+/// The arguments of the `ext_data_control_source_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_source_v1_request_destroy_args {}
+impl ext_data_control_source_v1_request_destroy_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_source_v1_v1_request_destroy_args, crate::Error> {
+    ) -> Result<ext_data_control_source_v1_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -749,7 +1377,7 @@ impl ext_data_control_source_v1_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -758,29 +1386,42 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_re
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_source_v1_v1_request_destroy_args,
+        ext_data_control_source_v1_request_destroy_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_source_v1_v1_request_destroy_args,
+            ext_data_control_source_v1_request_destroy_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_source_v1_v1_event_send_args {
+/// Send the data.
+///
+/// Request for data from the client. Send the data as the specified MIME
+/// type over the passed file descriptor, then close it.
+///
+/// The arguments of the `ext_data_control_source_v1.send` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_source_v1_event_send_args {
+    /// MIME type for the data.
     pub mime_type: ::std::string::String,
+    /// File descriptor for the data.
     pub fd: ::std::os::fd::RawFd,
 }
-impl ext_data_control_source_v1_v1_event_send_args {
+impl ext_data_control_source_v1_event_send_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_source_v1_v1_event_send_args, crate::Error> {
+    ) -> Result<ext_data_control_source_v1_event_send_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         let fd = __reader.read_fd()?;
@@ -797,35 +1438,42 @@ impl ext_data_control_source_v1_v1_event_send_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_event_send_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_event_send_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        ext_data_control_source_v1_v1_event_send_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, ext_data_control_source_v1_event_send_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            ext_data_control_source_v1_v1_event_send_args,
-        ),
+        __value: (crate::ObjectId, ext_data_control_source_v1_event_send_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_source_v1_v1_event_cancelled_args {}
-impl ext_data_control_source_v1_v1_event_cancelled_args {
+/// Selection was cancelled.
+///
+/// This data source is no longer valid. The data source has been replaced
+/// by another data source.
+///
+/// The client should clean up and destroy this data source.
+///
+/// The arguments of the `ext_data_control_source_v1.cancelled` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_source_v1_event_cancelled_args {}
+impl ext_data_control_source_v1_event_cancelled_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_source_v1_v1_event_cancelled_args, crate::Error> {
+    ) -> Result<ext_data_control_source_v1_event_cancelled_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -838,7 +1486,7 @@ impl ext_data_control_source_v1_v1_event_cancelled_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_event_cancelled_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_event_cancelled_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -847,37 +1495,178 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_source_v1_v1_ev
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_source_v1_v1_event_cancelled_args,
+        ext_data_control_source_v1_event_cancelled_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_source_v1_v1_event_cancelled_args,
+            ext_data_control_source_v1_event_cancelled_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `ext_data_control_source_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ext_data_control_source_v1_event {
+    /// Send the data.
+    send(ext_data_control_source_v1_event_send_args),
+    /// Selection was cancelled.
+    cancelled(ext_data_control_source_v1_event_cancelled_args),
+}
+impl ext_data_control_source_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::send(
+                ext_data_control_source_v1_event_send_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::cancelled(
+                ext_data_control_source_v1_event_cancelled_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "ext_data_control_source_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::send(_) => ext_data_control_source_v1_event_send_args::OPCODE,
+            Self::cancelled(_) => ext_data_control_source_v1_event_cancelled_args::OPCODE,
+        }
+    }
+}
+/// The enum `ext_data_control_source_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum ext_data_control_source_v1_error {
+    /// Offer sent after ext_data_control_device.set_selection.
+    invalid_offer = 1,
+}
+impl ::std::convert::TryFrom<u32> for ext_data_control_source_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            1 => Ok(Self::invalid_offer),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "ext_data_control_source_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<ext_data_control_source_v1_error> for u32 {
+    fn from(__value: ext_data_control_source_v1_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `ext_data_control_offer_v1`.
+pub const ext_data_control_offer_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "ext_data_control_offer_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "receive",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[crate::protocol::MessageInfo {
+            name: "offer",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::String],
+        }],
+    };
+/// Offer to transfer data.
+///
+/// A ext_data_control_offer represents a piece of data offered for transfer
+/// by another client (the source client). The offer describes the different
+/// MIME types that the data can be converted to and provides the mechanism
+/// for transferring the data directly from the source client.
+///
+/// A proxy for a `ext_data_control_offer_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_offer_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for ext_data_control_offer_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &ext_data_control_offer_v1_interface;
+}
+impl crate::protocol::Proxy for ext_data_control_offer_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `ext_data_control_offer_v1` object.
 #[allow(unused)]
-pub trait ext_data_control_offer_v1_v1_event_handler: crate::protocol::EventHandler {
+pub trait ext_data_control_offer_v1_event_handler: crate::protocol::EventHandler {
+    /// Advertise offered MIME type.
+    ///
+    /// Sent immediately after creating the ext_data_control_offer object.
+    /// One event per offered MIME type.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: offered MIME type
     fn handle_offer(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         mime_type: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "ext_data_control_offer_v1",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -894,51 +1683,84 @@ pub trait ext_data_control_offer_v1_v1_event_handler: crate::protocol::EventHand
                     let mut __packet_reader = __packet.read();
                     let mime_type = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
-                    self.handle_offer(__connection, __packet, mime_type).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_offer(__connection, __packet, mime_type).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct ext_data_control_offer_v1_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> ext_data_control_offer_v1_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl ext_data_control_offer_v1 {
+    /// Request that the data is transferred.
+    ///
+    /// To transfer the offered data, the client issues this request and
+    /// indicates the MIME type it wants to receive. The transfer happens
+    /// through the passed file descriptor (typically created with the pipe
+    /// system call). The source client writes the data in the MIME type
+    /// representation requested and then closes the file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until EOF and
+    /// then closes its end, at which point the transfer is complete.
+    ///
+    /// This request may happen multiple times for different MIME types.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type desired by receiver
+    /// * `fd`: file descriptor for data transfer
     pub async fn send_receive(
         &self,
-        __object_id: crate::ObjectId,
         mime_type: &str,
         fd: ::std::os::fd::RawFd,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_str(&mime_type);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_str(mime_type);
         __packet.push_fd(fd);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct ext_data_control_offer_v1_v1_request_receive_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this offer.
+    ///
+    /// Destroys the data offer object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Request that the data is transferred.
+///
+/// To transfer the offered data, the client issues this request and
+/// indicates the MIME type it wants to receive. The transfer happens
+/// through the passed file descriptor (typically created with the pipe
+/// system call). The source client writes the data in the MIME type
+/// representation requested and then closes the file descriptor.
+///
+/// The receiving client reads from the read end of the pipe until EOF and
+/// then closes its end, at which point the transfer is complete.
+///
+/// This request may happen multiple times for different MIME types.
+///
+/// The arguments of the `ext_data_control_offer_v1.receive` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_offer_v1_request_receive_args {
+    /// MIME type desired by receiver.
     pub mime_type: ::std::string::String,
+    /// File descriptor for data transfer.
     pub fd: ::std::os::fd::RawFd,
 }
-impl ext_data_control_offer_v1_v1_request_receive_args {
+impl ext_data_control_offer_v1_request_receive_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_offer_v1_v1_request_receive_args, crate::Error> {
+    ) -> Result<ext_data_control_offer_v1_request_receive_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         let fd = __reader.read_fd()?;
@@ -955,7 +1777,7 @@ impl ext_data_control_offer_v1_v1_request_receive_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_v1_request_receive_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_request_receive_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -964,26 +1786,36 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_v1_req
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_offer_v1_v1_request_receive_args,
+        ext_data_control_offer_v1_request_receive_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_offer_v1_v1_request_receive_args,
+            ext_data_control_offer_v1_request_receive_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_offer_v1_v1_request_destroy_args {}
-impl ext_data_control_offer_v1_v1_request_destroy_args {
+/// Destroy this offer.
+///
+/// Destroys the data offer object.
+///
+/// The arguments of the `ext_data_control_offer_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_offer_v1_request_destroy_args {}
+impl ext_data_control_offer_v1_request_destroy_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_offer_v1_v1_request_destroy_args, crate::Error> {
+    ) -> Result<ext_data_control_offer_v1_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -996,7 +1828,7 @@ impl ext_data_control_offer_v1_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -1005,28 +1837,40 @@ impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_v1_req
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        ext_data_control_offer_v1_v1_request_destroy_args,
+        ext_data_control_offer_v1_request_destroy_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            ext_data_control_offer_v1_v1_request_destroy_args,
+            ext_data_control_offer_v1_request_destroy_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct ext_data_control_offer_v1_v1_event_offer_args {
+/// Advertise offered MIME type.
+///
+/// Sent immediately after creating the ext_data_control_offer object.
+/// One event per offered MIME type.
+///
+/// The arguments of the `ext_data_control_offer_v1.offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ext_data_control_offer_v1_event_offer_args {
+    /// Offered MIME type.
     pub mime_type: ::std::string::String,
 }
-impl ext_data_control_offer_v1_v1_event_offer_args {
+impl ext_data_control_offer_v1_event_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<ext_data_control_offer_v1_v1_event_offer_args, crate::Error> {
+    ) -> Result<ext_data_control_offer_v1_event_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         __reader.finish()?;
@@ -1041,26 +1885,47 @@ impl ext_data_control_offer_v1_v1_event_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_v1_event_offer_args {
+impl ::std::convert::TryFrom<crate::Packet> for ext_data_control_offer_v1_event_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        ext_data_control_offer_v1_v1_event_offer_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, ext_data_control_offer_v1_event_offer_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            ext_data_control_offer_v1_v1_event_offer_args,
-        ),
+        __value: (crate::ObjectId, ext_data_control_offer_v1_event_offer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `ext_data_control_offer_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ext_data_control_offer_v1_event {
+    /// Advertise offered MIME type.
+    offer(ext_data_control_offer_v1_event_offer_args),
+}
+impl ext_data_control_offer_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::offer(
+                ext_data_control_offer_v1_event_offer_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "ext_data_control_offer_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::offer(_) => ext_data_control_offer_v1_event_offer_args::OPCODE,
+        }
+    }
+}
@@ -1,13 +1,131 @@
 pub mod ext_data_control_v1;
+pub mod virtual_keyboard_unstable_v1;
 pub mod wayland;
+pub mod wlr_data_control_unstable_v1;
 
 
+use std::fmt;
+
 use async_trait::async_trait;
 
-use crate::{Connection, Error, Packet};
+use crate::{Connection, Error, ObjectId, Packet, WeakConnection};
+
+
+/// Static information about an interface, as needed to track objects implementing it.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InterfaceInfo {
+    pub name: &'static str,
+
+    /// The newest version of the interface known to the generated code.
+    pub version: u32,
+
+    /// The requests, indexed by opcode.
+    pub requests: &'static [MessageInfo],
+
+    /// The events, indexed by opcode.
+    pub events: &'static [MessageInfo],
+}
+
+/// An interface for which code has been generated.
+///
+/// Implemented by the interface's [`Proxy`], which is named after the interface (e.g. `wl_seat`)
+/// regardless of its version.
+pub trait Interface {
+    const INFO: &'static InterfaceInfo;
+}
+
+/// A proxy through which requests are sent to an object.
+///
+/// Generated for each interface. Proxies only hold a [`WeakConnection`], so that storing them in
+/// state shared with event handlers does not keep the connection alive; their requests fail with
+/// [`Error::ConnectionClosed`] once the connection is gone.
+pub trait Proxy: Interface + Sized {
+    /// Wraps an existing object, which must implement this interface at the given version.
+    fn from_parts(connection: WeakConnection, object_id: ObjectId, version: u32) -> Self;
+
+    fn id(&self) -> ObjectId;
+
+    /// The version at which the object has been created.
+    ///
+    /// Requests that do not exist at this version are refused with [`Error::RequestTooNew`] or
+    /// [`Error::RequestDeprecated`] instead of being sent.
+    fn version(&self) -> u32;
+
+    fn connection(&self) -> &WeakConnection;
+}
+
+/// Static information about a request or event.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MessageInfo {
+    pub name: &'static str,
+
+    /// Whether the message destroys the object it is sent to.
+    pub destructor: bool,
+
+    /// The types of the message's arguments, in order.
+    pub args: &'static [ArgKind],
+}
+
+/// The type of an argument of a request or event, as needed to skip over it without decoding it.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ArgKind {
+    Int,
+    Uint,
+    Fixed,
+    String,
+    Object,
+
+    /// A new object implementing the named interface, or the interface passed along with it.
+    NewId { interface: Option<&'static str> },
+
+    Array,
+    Fd,
+}
+
+/// Returns the static information about the interface with the given name, if code has been
+/// generated for it.
+pub fn interface_info_by_name(name: &str) -> Option<&'static InterfaceInfo> {
+    let protocol_interfaces = [
+        ext_data_control_v1::INTERFACES,
+        virtual_keyboard_unstable_v1::INTERFACES,
+        wayland::INTERFACES,
+        wlr_data_control_unstable_v1::INTERFACES,
+    ];
+    protocol_interfaces.into_iter()
+        .flatten()
+        .copied()
+        .find(|interface| interface.name == name)
+}
 
 
 #[async_trait]
 pub trait EventHandler {
     async fn handle_event(&self, connection: &Connection, packet: Packet) -> Result<(), Error>;
 }
+
+
+/// Formats the value of a generated bitfield as its flags joined by `|`.
+///
+/// Bits that do not belong to any flag are appended in hexadecimal.
+pub fn fmt_bitfield(f: &mut fmt::Formatter<'_>, flags: &[(&str, u32)], bits: u32) -> fmt::Result {
+    let mut remaining = bits;
+    let mut first = true;
+    for (name, flag_bits) in flags {
+        if *flag_bits == 0 || bits & flag_bits != *flag_bits {
+            continue;
+        }
+        if !first {
+            write!(f, " | ")?;
+        }
+        write!(f, "{}", name)?;
+        remaining &= !flag_bits;
+        first = false;
+    }
+    if remaining != 0 || first {
+        if !first {
+            write!(f, " | ")?;
+        }
+        write!(f, "{:#x}", remaining)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,637 @@
+// This file has been autogenerated from a Wayland XML protocol definition.
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+/// Static information about all interfaces of `virtual_keyboard_unstable_v1`.
+pub const INTERFACES: &[&crate::protocol::InterfaceInfo] = &[
+    &zwp_virtual_keyboard_v1_interface,
+    &zwp_virtual_keyboard_manager_v1_interface,
+];
+/// Static information about `zwp_virtual_keyboard_v1`.
+pub const zwp_virtual_keyboard_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwp_virtual_keyboard_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "keymap",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Fd,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "key",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "modifiers",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+    };
+/// Virtual keyboard.
+///
+/// The virtual keyboard provides an application with requests which emulate
+/// the behaviour of a physical keyboard.
+///
+/// This interface can be used by clients on its own to provide raw input
+/// events, or it can accompany the input method protocol.
+///
+/// A proxy for a `zwp_virtual_keyboard_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwp_virtual_keyboard_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &zwp_virtual_keyboard_v1_interface;
+}
+impl crate::protocol::Proxy for zwp_virtual_keyboard_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl zwp_virtual_keyboard_v1 {
+    /// Keyboard mapping.
+    ///
+    /// Provide a file descriptor to the compositor which can be
+    /// memory-mapped to provide a keyboard mapping description.
+    ///
+    /// Format carries a value from the keymap_format enumeration.
+    ///
+    /// # Arguments
+    ///
+    /// * `format`: keymap format
+    /// * `fd`: keymap file descriptor
+    /// * `size`: keymap size, in bytes
+    pub async fn send_keymap(
+        &self,
+        format: u32,
+        fd: ::std::os::fd::RawFd,
+        size: u32,
+    ) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_uint(format);
+        __packet.push_fd(fd);
+        __packet.push_uint(size);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Key event.
+    ///
+    /// A key was pressed or released.
+    /// The time argument is a timestamp with millisecond granularity, with an
+    /// undefined base. All requests regarding a single object must share the
+    /// same clock.
+    ///
+    /// Keymap must be set before issuing this request.
+    ///
+    /// State carries a value from the key_state enumeration.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `key`: key that produced the event
+    /// * `state`: physical state of the key
+    pub async fn send_key(&self, time: u32, key: u32, state: u32) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_uint(time);
+        __packet.push_uint(key);
+        __packet.push_uint(state);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Modifier and group state.
+    ///
+    /// Notifies the compositor that the modifier and/or group state has
+    /// changed, and it should update state.
+    ///
+    /// The client should use wl_keyboard.modifiers event to synchronize its
+    /// internal state with seat state.
+    ///
+    /// Keymap must be set before issuing this request.
+    ///
+    /// # Arguments
+    ///
+    /// * `mods_depressed`: depressed modifiers
+    /// * `mods_latched`: latched modifiers
+    /// * `mods_locked`: locked modifiers
+    /// * `group`: keyboard layout
+    pub async fn send_modifiers(
+        &self,
+        mods_depressed: u32,
+        mods_latched: u32,
+        mods_locked: u32,
+        group: u32,
+    ) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __packet.push_uint(mods_depressed);
+        __packet.push_uint(mods_latched);
+        __packet.push_uint(mods_locked);
+        __packet.push_uint(group);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy the virtual keyboard keyboard object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 3);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Keyboard mapping.
+///
+/// Provide a file descriptor to the compositor which can be
+/// memory-mapped to provide a keyboard mapping description.
+///
+/// Format carries a value from the keymap_format enumeration.
+///
+/// The arguments of the `zwp_virtual_keyboard_v1.keymap` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_v1_request_keymap_args {
+    /// Keymap format.
+    pub format: u32,
+    /// Keymap file descriptor.
+    pub fd: ::std::os::fd::RawFd,
+    /// Keymap size, in bytes.
+    pub size: u32,
+}
+impl zwp_virtual_keyboard_v1_request_keymap_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwp_virtual_keyboard_v1_request_keymap_args, crate::Error> {
+        let mut __reader = __value.read();
+        let format = __reader.read_uint()?;
+        let fd = __reader.read_fd()?;
+        let size = __reader.read_uint()?;
+        __reader.finish()?;
+        Ok(Self { format, fd, size })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_uint(self.format);
+        __packet.push_fd(self.fd);
+        __packet.push_uint(self.size);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwp_virtual_keyboard_v1_request_keymap_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl ::std::convert::TryFrom<(crate::ObjectId, zwp_virtual_keyboard_v1_request_keymap_args)>
+    for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (crate::ObjectId, zwp_virtual_keyboard_v1_request_keymap_args),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Key event.
+///
+/// A key was pressed or released.
+/// The time argument is a timestamp with millisecond granularity, with an
+/// undefined base. All requests regarding a single object must share the
+/// same clock.
+///
+/// Keymap must be set before issuing this request.
+///
+/// State carries a value from the key_state enumeration.
+///
+/// The arguments of the `zwp_virtual_keyboard_v1.key` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_v1_request_key_args {
+    /// Timestamp with millisecond granularity.
+    pub time: u32,
+    /// Key that produced the event.
+    pub key: u32,
+    /// Physical state of the key.
+    pub state: u32,
+}
+impl zwp_virtual_keyboard_v1_request_key_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwp_virtual_keyboard_v1_request_key_args, crate::Error> {
+        let mut __reader = __value.read();
+        let time = __reader.read_uint()?;
+        let key = __reader.read_uint()?;
+        let state = __reader.read_uint()?;
+        __reader.finish()?;
+        Ok(Self { time, key, state })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_uint(self.time);
+        __packet.push_uint(self.key);
+        __packet.push_uint(self.state);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwp_virtual_keyboard_v1_request_key_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl ::std::convert::TryFrom<(crate::ObjectId, zwp_virtual_keyboard_v1_request_key_args)>
+    for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (crate::ObjectId, zwp_virtual_keyboard_v1_request_key_args),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Modifier and group state.
+///
+/// Notifies the compositor that the modifier and/or group state has
+/// changed, and it should update state.
+///
+/// The client should use wl_keyboard.modifiers event to synchronize its
+/// internal state with seat state.
+///
+/// Keymap must be set before issuing this request.
+///
+/// The arguments of the `zwp_virtual_keyboard_v1.modifiers` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_v1_request_modifiers_args {
+    /// Depressed modifiers.
+    pub mods_depressed: u32,
+    /// Latched modifiers.
+    pub mods_latched: u32,
+    /// Locked modifiers.
+    pub mods_locked: u32,
+    /// Keyboard layout.
+    pub group: u32,
+}
+impl zwp_virtual_keyboard_v1_request_modifiers_args {
+    pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwp_virtual_keyboard_v1_request_modifiers_args, crate::Error> {
+        let mut __reader = __value.read();
+        let mods_depressed = __reader.read_uint()?;
+        let mods_latched = __reader.read_uint()?;
+        let mods_locked = __reader.read_uint()?;
+        let group = __reader.read_uint()?;
+        __reader.finish()?;
+        Ok(Self {
+            mods_depressed,
+            mods_latched,
+            mods_locked,
+            group,
+        })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_uint(self.mods_depressed);
+        __packet.push_uint(self.mods_latched);
+        __packet.push_uint(self.mods_locked);
+        __packet.push_uint(self.group);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwp_virtual_keyboard_v1_request_modifiers_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwp_virtual_keyboard_v1_request_modifiers_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwp_virtual_keyboard_v1_request_modifiers_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Destroy the virtual keyboard keyboard object.
+///
+/// The arguments of the `zwp_virtual_keyboard_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_v1_request_destroy_args {}
+impl zwp_virtual_keyboard_v1_request_destroy_args {
+    pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwp_virtual_keyboard_v1_request_destroy_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwp_virtual_keyboard_v1_request_destroy_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwp_virtual_keyboard_v1_request_destroy_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwp_virtual_keyboard_v1_request_destroy_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// The enum `zwp_virtual_keyboard_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum zwp_virtual_keyboard_v1_error {
+    /// No keymap was set.
+    no_keymap = 0,
+}
+impl ::std::convert::TryFrom<u32> for zwp_virtual_keyboard_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::no_keymap),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "zwp_virtual_keyboard_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<zwp_virtual_keyboard_v1_error> for u32 {
+    fn from(__value: zwp_virtual_keyboard_v1_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `zwp_virtual_keyboard_manager_v1`.
+pub const zwp_virtual_keyboard_manager_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwp_virtual_keyboard_manager_v1",
+        version: 1,
+        requests: &[crate::protocol::MessageInfo {
+            name: "create_virtual_keyboard",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::NewId {
+                    interface: Some("zwp_virtual_keyboard_v1"),
+                },
+            ],
+        }],
+        events: &[],
+    };
+/// Virtual keyboard manager.
+///
+/// A virtual keyboard manager allows an application to provide keyboard
+/// input events as if they came from a physical keyboard.
+///
+/// A proxy for a `zwp_virtual_keyboard_manager_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_manager_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwp_virtual_keyboard_manager_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo =
+        &zwp_virtual_keyboard_manager_v1_interface;
+}
+impl crate::protocol::Proxy for zwp_virtual_keyboard_manager_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl zwp_virtual_keyboard_manager_v1 {
+    /// Create a new virtual keyboard.
+    ///
+    /// Creates a new virtual keyboard associated to a seat.
+    ///
+    /// If the compositor enables a keyboard to perform arbitrary actions, it
+    /// should present an error when an untrusted client requests a new
+    /// keyboard.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`
+    ///
+    /// Returns the new `zwp_virtual_keyboard_v1` object.
+    pub async fn send_create_virtual_keyboard(
+        &self,
+        seat: crate::ObjectId,
+    ) -> Result<zwp_virtual_keyboard_v1, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&zwp_virtual_keyboard_v1_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_object(seat);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(zwp_virtual_keyboard_v1 {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+}
+/// Create a new virtual keyboard.
+///
+/// Creates a new virtual keyboard associated to a seat.
+///
+/// If the compositor enables a keyboard to perform arbitrary actions, it
+/// should present an error when an untrusted client requests a new
+/// keyboard.
+///
+/// The arguments of the `zwp_virtual_keyboard_manager_v1.create_virtual_keyboard` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args {
+    pub seat: crate::ObjectId,
+    pub id: crate::NewObjectId,
+}
+impl zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args, crate::Error>
+    {
+        let mut __reader = __value.read();
+        let seat = __reader.read_object()?;
+        let id = __reader.read_new_id_known_interface()?;
+        __reader.finish()?;
+        Ok(Self { seat, id })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_object(self.seat);
+        __packet.push_new_id_known_interface(self.id);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwp_virtual_keyboard_manager_v1_request_create_virtual_keyboard_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// The enum `zwp_virtual_keyboard_manager_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum zwp_virtual_keyboard_manager_v1_error {
+    /// Client not authorized to use the interface.
+    unauthorized = 0,
+}
+impl ::std::convert::TryFrom<u32> for zwp_virtual_keyboard_manager_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::unauthorized),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "zwp_virtual_keyboard_manager_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<zwp_virtual_keyboard_manager_v1_error> for u32 {
+    fn from(__value: zwp_virtual_keyboard_manager_v1_error) -> u32 {
+        __value as u32
+    }
+}
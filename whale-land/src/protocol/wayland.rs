@@ -1,31 +1,170 @@
 // This file has been autogenerated from a Wayland XML protocol definition.
 
-#![allow(non_camel_case_types)]
+#![allow(non_camel_case_types, non_upper_case_globals)]
+/// Static information about all interfaces of `wayland`.
+pub const INTERFACES: &[&crate::protocol::InterfaceInfo] = &[
+    &wl_display_interface,
+    &wl_registry_interface,
+    &wl_callback_interface,
+    &wl_compositor_interface,
+    &wl_shm_pool_interface,
+    &wl_shm_interface,
+    &wl_buffer_interface,
+    &wl_data_offer_interface,
+    &wl_data_source_interface,
+    &wl_data_device_interface,
+    &wl_data_device_manager_interface,
+    &wl_shell_interface,
+    &wl_shell_surface_interface,
+    &wl_surface_interface,
+    &wl_seat_interface,
+    &wl_pointer_interface,
+    &wl_keyboard_interface,
+    &wl_touch_interface,
+    &wl_output_interface,
+    &wl_region_interface,
+    &wl_subcompositor_interface,
+    &wl_subsurface_interface,
+    &wl_fixes_interface,
+];
+/// Static information about `wl_display`.
+pub const wl_display_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_display",
+    version: 1,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "sync",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_callback"),
+            }],
+        },
+        crate::protocol::MessageInfo {
+            name: "get_registry",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_registry"),
+            }],
+        },
+    ],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "error",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::String,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "delete_id",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Uint],
+        },
+    ],
+};
+/// Core global object.
+///
+/// The core global object.  This is a special singleton object.  It
+/// is used for internal Wayland protocol features.
+///
+/// A proxy for a `wl_display` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_display {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_display {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_display_interface;
+}
+impl crate::protocol::Proxy for wl_display {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_display` object.
 #[allow(unused)]
-pub trait wl_display_v1_event_handler: crate::protocol::EventHandler {
+pub trait wl_display_event_handler: crate::protocol::EventHandler {
+    /// Fatal error event.
+    ///
+    /// The error event is sent out when a fatal (non-recoverable)
+    /// error has occurred.  The object_id argument is the object
+    /// where the error occurred, most often in response to a request
+    /// to that object.  The code identifies the error and is defined
+    /// by the object interface.  As such, each interface defines its
+    /// own set of error codes.  The message is a brief description
+    /// of the error, for (debugging) convenience.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id`: object where the error occurred
+    /// * `code`: error code
+    /// * `message`: error description
     fn handle_error(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        object_id: ::std::option::Option<crate::ObjectId>,
+        object_id: crate::ObjectId,
         code: u32,
         message: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Acknowledge object ID deletion.
+    ///
+    /// This event is used internally by the object ID management
+    /// logic. When a client deletes an object that it had created,
+    /// the server will send this event to acknowledge that it has
+    /// seen the delete request. When the client receives this event,
+    /// it will know that it can safely reuse the object ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: deleted object ID
     fn handle_delete_id(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_display",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -45,60 +184,103 @@ pub trait wl_display_v1_event_handler: crate::protocol::EventHandler {
                     let message = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
                     self.handle_error(__connection, __packet, object_id, code, message)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let id = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
-                    self.handle_delete_id(__connection, __packet, id).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_delete_id(__connection, __packet, id).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_display_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_display_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_sync(
-        &self,
-        __object_id: crate::ObjectId,
-        callback: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(callback);
-        self.connection.send_packet(&__packet).await
+impl wl_display {
+    /// Asynchronous roundtrip.
+    ///
+    /// The sync request asks the server to emit the 'done' event
+    /// on the returned wl_callback object.  Since requests are
+    /// handled in-order and events are delivered in-order, this can
+    /// be used as a barrier to ensure all previous requests and the
+    /// resulting events have been handled.
+    ///
+    /// The object returned by this request will be destroyed by the
+    /// compositor after the callback is fired and as such the client must not
+    /// attempt to use it after that point.
+    ///
+    /// The callback_data passed in the callback is undefined and should be ignored.
+    ///
+    /// Returns the new `wl_callback` object.
+    pub async fn send_sync(&self) -> Result<wl_callback, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_callback_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_callback {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-    pub async fn send_get_registry(
-        &self,
-        __object_id: crate::ObjectId,
-        registry: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(registry);
-        self.connection.send_packet(&__packet).await
+    /// Get global registry object.
+    ///
+    /// This request creates a registry object that allows the client
+    /// to list and bind the global objects available from the
+    /// compositor.
+    ///
+    /// It should be noted that the server side resources consumed in
+    /// response to a get_registry request can only be released when the
+    /// client disconnects, not when the client side proxy is destroyed.
+    /// Therefore, clients should invoke get_registry as infrequently as
+    /// possible to avoid wasting memory.
+    ///
+    /// Returns the new `wl_registry` object.
+    pub async fn send_get_registry(&self) -> Result<wl_registry, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_registry_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_registry {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
 }
-pub struct wl_display_v1_request_sync_args {
+/// Asynchronous roundtrip.
+///
+/// The sync request asks the server to emit the 'done' event
+/// on the returned wl_callback object.  Since requests are
+/// handled in-order and events are delivered in-order, this can
+/// be used as a barrier to ensure all previous requests and the
+/// resulting events have been handled.
+///
+/// The object returned by this request will be destroyed by the
+/// compositor after the callback is fired and as such the client must not
+/// attempt to use it after that point.
+///
+/// The callback_data passed in the callback is undefined and should be ignored.
+///
+/// The arguments of the `wl_display.sync` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_display_request_sync_args {
+    /// Callback object for the sync request.
     pub callback: crate::NewObjectId,
 }
-impl wl_display_v1_request_sync_args {
+impl wl_display_request_sync_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_display_v1_request_sync_args, crate::Error> {
+    ) -> Result<wl_display_request_sync_args, crate::Error> {
         let mut __reader = __value.read();
         let callback = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -113,29 +295,48 @@ impl wl_display_v1_request_sync_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_display_v1_request_sync_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_display_request_sync_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_v1_request_sync_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_request_sync_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_display_v1_request_sync_args),
+        __value: (crate::ObjectId, wl_display_request_sync_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_display_v1_request_get_registry_args {
+/// Get global registry object.
+///
+/// This request creates a registry object that allows the client
+/// to list and bind the global objects available from the
+/// compositor.
+///
+/// It should be noted that the server side resources consumed in
+/// response to a get_registry request can only be released when the
+/// client disconnects, not when the client side proxy is destroyed.
+/// Therefore, clients should invoke get_registry as infrequently as
+/// possible to avoid wasting memory.
+///
+/// The arguments of the `wl_display.get_registry` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_display_request_get_registry_args {
+    /// Global registry object.
     pub registry: crate::NewObjectId,
 }
-impl wl_display_v1_request_get_registry_args {
+impl wl_display_request_get_registry_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_display_v1_request_get_registry_args, crate::Error> {
+    ) -> Result<wl_display_request_get_registry_args, crate::Error> {
         let mut __reader = __value.read();
         let registry = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -150,33 +351,52 @@ impl wl_display_v1_request_get_registry_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_display_v1_request_get_registry_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_display_request_get_registry_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_v1_request_get_registry_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_request_get_registry_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_display_v1_request_get_registry_args),
+        __value: (crate::ObjectId, wl_display_request_get_registry_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_display_v1_event_error_args {
-    pub object_id: ::std::option::Option<crate::ObjectId>,
+/// Fatal error event.
+///
+/// The error event is sent out when a fatal (non-recoverable)
+/// error has occurred.  The object_id argument is the object
+/// where the error occurred, most often in response to a request
+/// to that object.  The code identifies the error and is defined
+/// by the object interface.  As such, each interface defines its
+/// own set of error codes.  The message is a brief description
+/// of the error, for (debugging) convenience.
+///
+/// The arguments of the `wl_display.error` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_display_event_error_args {
+    /// Object where the error occurred.
+    pub object_id: crate::ObjectId,
+    /// Error code.
     pub code: u32,
+    /// Error description.
     pub message: ::std::string::String,
 }
-impl wl_display_v1_event_error_args {
+impl wl_display_event_error_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_display_v1_event_error_args, crate::Error> {
+    ) -> Result<wl_display_event_error_args, crate::Error> {
         let mut __reader = __value.read();
         let object_id = __reader.read_object()?;
         let code = __reader.read_uint()?;
@@ -199,29 +419,44 @@ impl wl_display_v1_event_error_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_display_v1_event_error_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_display_event_error_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_v1_event_error_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_event_error_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_display_v1_event_error_args),
+        __value: (crate::ObjectId, wl_display_event_error_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_display_v1_event_delete_id_args {
+/// Acknowledge object ID deletion.
+///
+/// This event is used internally by the object ID management
+/// logic. When a client deletes an object that it had created,
+/// the server will send this event to acknowledge that it has
+/// seen the delete request. When the client receives this event,
+/// it will know that it can safely reuse the object ID.
+///
+/// The arguments of the `wl_display.delete_id` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_display_event_delete_id_args {
+    /// Deleted object ID.
     pub id: u32,
 }
-impl wl_display_v1_event_delete_id_args {
+impl wl_display_event_delete_id_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_display_v1_event_delete_id_args, crate::Error> {
+    ) -> Result<wl_display_event_delete_id_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_uint()?;
         __reader.finish()?;
@@ -236,25 +471,192 @@ impl wl_display_v1_event_delete_id_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_display_v1_event_delete_id_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_display_event_delete_id_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_v1_event_delete_id_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_display_event_delete_id_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_display_v1_event_delete_id_args),
+        __value: (crate::ObjectId, wl_display_event_delete_id_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_display` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_display_event {
+    /// Fatal error event.
+    error(wl_display_event_error_args),
+    /// Acknowledge object ID deletion.
+    delete_id(wl_display_event_delete_id_args),
+}
+impl wl_display_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::error(wl_display_event_error_args::try_from_packet(
+                __packet,
+            )?)),
+            1 => Ok(Self::delete_id(
+                wl_display_event_delete_id_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_display",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::error(_) => wl_display_event_error_args::OPCODE,
+            Self::delete_id(_) => wl_display_event_delete_id_args::OPCODE,
+        }
+    }
+}
+/// Global error values.
+///
+/// These errors are global and can be emitted in response to any
+/// server request.
+///
+/// The enum `wl_display.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_display_error {
+    /// Server couldn't find object.
+    invalid_object = 0,
+    /// Method doesn't exist on the specified interface or malformed request.
+    invalid_method = 1,
+    /// Server is out of memory.
+    no_memory = 2,
+    /// Implementation error in compositor.
+    implementation = 3,
+}
+impl ::std::convert::TryFrom<u32> for wl_display_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::invalid_object),
+            1 => Ok(Self::invalid_method),
+            2 => Ok(Self::no_memory),
+            3 => Ok(Self::implementation),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_display.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_display_error> for u32 {
+    fn from(__value: wl_display_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_registry`.
+pub const wl_registry_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_registry",
+    version: 1,
+    requests: &[crate::protocol::MessageInfo {
+        name: "bind",
+        destructor: false,
+        args: &[
+            crate::protocol::ArgKind::Uint,
+            crate::protocol::ArgKind::NewId { interface: None },
+        ],
+    }],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "global",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::String,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "global_remove",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Uint],
+        },
+    ],
+};
+/// Global registry object.
+///
+/// The singleton global registry object.  The server has a number of
+/// global objects that are available to all clients.  These objects
+/// typically represent an actual object in the server (for example,
+/// an input device) or they are singleton objects that provide
+/// extension functionality.
+///
+/// When a client creates a registry object, the registry object
+/// will emit a global event for each global currently in the
+/// registry.  Globals come and go as a result of device or
+/// monitor hotplugs, reconfiguration or other events, and the
+/// registry will send out global and global_remove events to
+/// keep the client up to date with the changes.  To mark the end
+/// of the initial burst of events, the client can use the
+/// wl_display.sync request immediately after calling
+/// wl_display.get_registry.
+///
+/// A client can bind to a global object by using the bind
+/// request.  This creates a client-side handle that lets the object
+/// emit events to the client and lets the client invoke requests on
+/// the object.
+///
+/// A proxy for a `wl_registry` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_registry {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_registry {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_registry_interface;
+}
+impl crate::protocol::Proxy for wl_registry {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_registry` object.
 #[allow(unused)]
-pub trait wl_registry_v1_event_handler: crate::protocol::EventHandler {
+pub trait wl_registry_event_handler: crate::protocol::EventHandler {
+    /// Announce global object.
+    ///
+    /// Notify the client of global objects.
+    ///
+    /// The event notifies the client that a global object with
+    /// the given name is now available, and it implements the
+    /// given version of the given interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: numeric name of the global object
+    /// * `interface`: interface implemented by the object
+    /// * `version`: interface version
     fn handle_global(
         &self,
         connection: &crate::Connection,
@@ -262,22 +664,50 @@ pub trait wl_registry_v1_event_handler: crate::protocol::EventHandler {
         name: u32,
         interface: ::std::string::String,
         version: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Announce removal of global object.
+    ///
+    /// Notify the client of removed global objects.
+    ///
+    /// This event notifies the client that the global identified
+    /// by name is no longer available.  If the client bound to
+    /// the global using the bind request, the client should now
+    /// destroy that object.
+    ///
+    /// The object remains valid and requests to the object will be
+    /// ignored until the client destroys it, to avoid races between
+    /// the global going away and a client sending a request to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: numeric name of the global object
     fn handle_global_remove(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         name: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_registry",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -297,55 +727,78 @@ pub trait wl_registry_v1_event_handler: crate::protocol::EventHandler {
                     let version = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
                     self.handle_global(__connection, __packet, name, interface, version)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let name = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
                     self.handle_global_remove(__connection, __packet, name)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_registry_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_registry_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_bind(
+impl wl_registry {
+    /// Bind an object to the display.
+    ///
+    /// Binds a new, client-created object to the server using the
+    /// specified name as the identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: unique numeric name of the object
+    /// * `__version`: the version at which to create the new object
+    ///
+    /// Returns the new object, which implements the interface of `__P`.
+    pub async fn send_bind<__P: crate::protocol::Proxy>(
         &self,
-        __object_id: crate::ObjectId,
         name: u32,
-        id: crate::NewObject,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
+        __version: u32,
+    ) -> Result<__P, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __info = <__P as crate::protocol::Interface>::INFO;
+        let __new_id = __connection.new_object(__info, __version);
+        let mut __packet = crate::Packet::new(self.id, 0);
         __packet.push_uint(name);
-        __packet.push_new_id_unknown_interface(&id);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_registry_v1_request_bind_args {
+        __packet.push_new_id_unknown_interface(&crate::NewObject {
+            object_id: __new_id,
+            interface: __info.name.to_owned(),
+            interface_version: __version,
+        });
+        __connection.send_packet(&__packet).await?;
+        Ok(<__P as crate::protocol::Proxy>::from_parts(
+            self.connection.clone(),
+            __new_id,
+            __version,
+        ))
+    }
+}
+/// Bind an object to the display.
+///
+/// Binds a new, client-created object to the server using the
+/// specified name as the identifier.
+///
+/// The arguments of the `wl_registry.bind` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_registry_request_bind_args {
+    /// Unique numeric name of the object.
     pub name: u32,
+    /// Bounded object.
     pub id: crate::NewObject,
 }
-impl wl_registry_v1_request_bind_args {
+impl wl_registry_request_bind_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_registry_v1_request_bind_args, crate::Error> {
+    ) -> Result<wl_registry_request_bind_args, crate::Error> {
         let mut __reader = __value.read();
         let name = __reader.read_uint()?;
         let id = __reader.read_new_id_unknown_interface()?;
@@ -362,33 +815,48 @@ impl wl_registry_v1_request_bind_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_registry_v1_request_bind_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_registry_request_bind_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_v1_request_bind_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_request_bind_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_registry_v1_request_bind_args),
+        __value: (crate::ObjectId, wl_registry_request_bind_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_registry_v1_event_global_args {
+/// Announce global object.
+///
+/// Notify the client of global objects.
+///
+/// The event notifies the client that a global object with
+/// the given name is now available, and it implements the
+/// given version of the given interface.
+///
+/// The arguments of the `wl_registry.global` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_registry_event_global_args {
+    /// Numeric name of the global object.
     pub name: u32,
+    /// Interface implemented by the object.
     pub interface: ::std::string::String,
+    /// Interface version.
     pub version: u32,
 }
-impl wl_registry_v1_event_global_args {
+impl wl_registry_event_global_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_registry_v1_event_global_args, crate::Error> {
+    ) -> Result<wl_registry_event_global_args, crate::Error> {
         let mut __reader = __value.read();
         let name = __reader.read_uint()?;
         let interface = __reader.read_str()?;
@@ -411,31 +879,49 @@ impl wl_registry_v1_event_global_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_registry_v1_event_global_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_registry_event_global_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_v1_event_global_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_event_global_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_registry_v1_event_global_args),
+        __value: (crate::ObjectId, wl_registry_event_global_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_registry_v1_event_global_remove_args {
+/// Announce removal of global object.
+///
+/// Notify the client of removed global objects.
+///
+/// This event notifies the client that the global identified
+/// by name is no longer available.  If the client bound to
+/// the global using the bind request, the client should now
+/// destroy that object.
+///
+/// The object remains valid and requests to the object will be
+/// ignored until the client destroys it, to avoid races between
+/// the global going away and a client sending a request to it.
+///
+/// The arguments of the `wl_registry.global_remove` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_registry_event_global_remove_args {
+    /// Numeric name of the global object.
     pub name: u32,
 }
-impl wl_registry_v1_event_global_remove_args {
+impl wl_registry_event_global_remove_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_registry_v1_event_global_remove_args, crate::Error> {
+    ) -> Result<wl_registry_event_global_remove_args, crate::Error> {
         let mut __reader = __value.read();
         let name = __reader.read_uint()?;
         __reader.finish()?;
@@ -450,40 +936,142 @@ impl wl_registry_v1_event_global_remove_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_registry_v1_event_global_remove_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_registry_event_global_remove_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_v1_event_global_remove_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_registry_event_global_remove_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_registry_v1_event_global_remove_args),
+        __value: (crate::ObjectId, wl_registry_event_global_remove_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_registry` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_registry_event {
+    /// Announce global object.
+    global(wl_registry_event_global_args),
+    /// Announce removal of global object.
+    global_remove(wl_registry_event_global_remove_args),
+}
+impl wl_registry_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::global(
+                wl_registry_event_global_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::global_remove(
+                wl_registry_event_global_remove_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_registry",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::global(_) => wl_registry_event_global_args::OPCODE,
+            Self::global_remove(_) => wl_registry_event_global_remove_args::OPCODE,
+        }
+    }
+}
+/// Static information about `wl_callback`.
+pub const wl_callback_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_callback",
+    version: 1,
+    requests: &[],
+    events: &[crate::protocol::MessageInfo {
+        name: "done",
+        destructor: true,
+        args: &[crate::protocol::ArgKind::Uint],
+    }],
+};
+/// Callback object.
+///
+/// Clients can handle the 'done' event to get notified when
+/// the related request is done.
+///
+/// Note, because wl_callback objects are created from multiple independent
+/// factory interfaces, the wl_callback interface is frozen at version 1.
+///
+/// A proxy for a `wl_callback` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_callback {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_callback {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_callback_interface;
+}
+impl crate::protocol::Proxy for wl_callback {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_callback` object.
 #[allow(unused)]
-pub trait wl_callback_v1_event_handler: crate::protocol::EventHandler {
+pub trait wl_callback_event_handler: crate::protocol::EventHandler {
+    /// Done event.
+    ///
+    /// Notify the client when the related request is done.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback_data`: request-specific data for the callback
     fn handle_done(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         callback_data: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_callback",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -501,25 +1089,32 @@ pub trait wl_callback_v1_event_handler: crate::protocol::EventHandler {
                     let callback_data = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
                     self.handle_done(__connection, __packet, callback_data)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
-pub struct wl_callback_v1_event_done_args {
+/// Done event.
+///
+/// Notify the client when the related request is done.
+///
+/// The arguments of the `wl_callback.done` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_callback_event_done_args {
+    /// Request-specific data for the callback.
     pub callback_data: u32,
 }
-impl wl_callback_v1_event_done_args {
+impl wl_callback_event_done_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_callback_v1_event_done_args, crate::Error> {
+    ) -> Result<wl_callback_event_done_args, crate::Error> {
         let mut __reader = __value.read();
         let callback_data = __reader.read_uint()?;
         __reader.finish()?;
@@ -534,57 +1129,165 @@ impl wl_callback_v1_event_done_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_callback_v1_event_done_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_callback_event_done_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_callback_v1_event_done_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_callback_event_done_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_callback_v1_event_done_args),
+        __value: (crate::ObjectId, wl_callback_event_done_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_compositor_v6_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// An event sent to a `wl_callback` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_callback_event {
+    /// Done event.
+    done(wl_callback_event_done_args),
+}
+impl wl_callback_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::done(wl_callback_event_done_args::try_from_packet(
+                __packet,
+            )?)),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_callback",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::done(_) => wl_callback_event_done_args::OPCODE,
+        }
+    }
 }
-#[allow(unused)]
-impl<'a> wl_compositor_v6_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+/// Static information about `wl_compositor`.
+pub const wl_compositor_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_compositor",
+        version: 6,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "create_surface",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_surface"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "create_region",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_region"),
+                }],
+            },
+        ],
+        events: &[],
+    };
+/// The compositor singleton.
+///
+/// A compositor.  This object is a singleton global.  The
+/// compositor is in charge of combining the contents of multiple
+/// surfaces into one displayable output.
+///
+/// A proxy for a `wl_compositor` object. The generated code knows this interface up to version 6.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_compositor {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_compositor {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_compositor_interface;
+}
+impl crate::protocol::Proxy for wl_compositor {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
     }
-    pub async fn send_create_surface(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
+    fn id(&self) -> crate::ObjectId {
+        self.id
     }
-    pub async fn send_create_region(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_compositor {
+    /// Create new surface.
+    ///
+    /// Ask the compositor to create a new surface.
+    ///
+    /// Returns the new `wl_surface` object.
+    pub async fn send_create_surface(&self) -> Result<wl_surface, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_surface_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_surface {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Create new region.
+    ///
+    /// Ask the compositor to create a new region.
+    ///
+    /// Returns the new `wl_region` object.
+    pub async fn send_create_region(&self) -> Result<wl_region, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_region_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_region {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
 }
-pub struct wl_compositor_v6_request_create_surface_args {
+/// Create new surface.
+///
+/// Ask the compositor to create a new surface.
+///
+/// The arguments of the `wl_compositor.create_surface` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_compositor_request_create_surface_args {
+    /// The new surface.
     pub id: crate::NewObjectId,
 }
-impl wl_compositor_v6_request_create_surface_args {
+impl wl_compositor_request_create_surface_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_compositor_v6_request_create_surface_args, crate::Error> {
+    ) -> Result<wl_compositor_request_create_surface_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -599,37 +1302,42 @@ impl wl_compositor_v6_request_create_surface_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_compositor_v6_request_create_surface_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_compositor_request_create_surface_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_compositor_v6_request_create_surface_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_compositor_request_create_surface_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_compositor_v6_request_create_surface_args,
-        ),
+        __value: (crate::ObjectId, wl_compositor_request_create_surface_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_compositor_v6_request_create_region_args {
+/// Create new region.
+///
+/// Ask the compositor to create a new region.
+///
+/// The arguments of the `wl_compositor.create_region` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_compositor_request_create_region_args {
+    /// The new region.
     pub id: crate::NewObjectId,
 }
-impl wl_compositor_v6_request_create_region_args {
+impl wl_compositor_request_create_region_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_compositor_v6_request_create_region_args, crate::Error> {
+    ) -> Result<wl_compositor_request_create_region_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -644,78 +1352,222 @@ impl wl_compositor_v6_request_create_region_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_compositor_v6_request_create_region_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_compositor_request_create_region_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_compositor_v6_request_create_region_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_compositor_request_create_region_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_compositor_v6_request_create_region_args),
+        __value: (crate::ObjectId, wl_compositor_request_create_region_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_shm_pool_v2_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// Static information about `wl_shm_pool`.
+pub const wl_shm_pool_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_shm_pool",
+    version: 2,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "create_buffer",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_buffer"),
+                },
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "destroy",
+            destructor: true,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "resize",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int],
+        },
+    ],
+    events: &[],
+};
+/// A shared memory pool.
+///
+/// The wl_shm_pool object encapsulates a piece of memory shared
+/// between the compositor and client.  Through the wl_shm_pool
+/// object, the client can allocate shared memory wl_buffer objects.
+/// All objects created through the same pool share the same
+/// underlying mapped memory. Reusing the mapped memory avoids the
+/// setup/teardown overhead and is useful when interactively resizing
+/// a surface or for many small buffers.
+///
+/// A proxy for a `wl_shm_pool` object. The generated code knows this interface up to version 2.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_pool {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_shm_pool {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_shm_pool_interface;
+}
+impl crate::protocol::Proxy for wl_shm_pool {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
 }
 #[allow(unused)]
-impl<'a> wl_shm_pool_v2_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl wl_shm_pool {
+    /// Create a buffer from the pool.
+    ///
+    /// Create a wl_buffer object from the pool.
+    ///
+    /// The buffer is created offset bytes into the pool and has
+    /// width and height as specified.  The stride argument specifies
+    /// the number of bytes from the beginning of one row to the beginning
+    /// of the next.  The format is the pixel format of the buffer and
+    /// must be one of those advertised through the wl_shm.format event.
+    ///
+    /// A buffer will keep a reference to the pool it was created from
+    /// so it is valid to destroy the pool immediately after creating
+    /// a buffer from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset`: buffer byte offset within the pool
+    /// * `width`: buffer width, in pixels
+    /// * `height`: buffer height, in pixels
+    /// * `stride`: number of bytes from the beginning of one row to the beginning of the next row
+    /// * `format`: buffer pixel format
+    ///
+    /// Returns the new `wl_buffer` object.
     pub async fn send_create_buffer(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
         offset: i32,
         width: i32,
         height: i32,
         stride: i32,
-        format: u32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
+        format: wl_shm_format,
+    ) -> Result<wl_buffer, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_buffer_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_int(offset);
         __packet.push_int(width);
         __packet.push_int(height);
         __packet.push_int(stride);
-        __packet.push_uint(format);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
+        __packet.push_uint(u32::from(format));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_buffer {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-    pub async fn send_resize(
-        &self,
-        __object_id: crate::ObjectId,
-        size: i32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
+    /// Destroy the pool.
+    ///
+    /// Destroy the shared memory pool.
+    ///
+    /// The mmapped memory will be released when all
+    /// buffers that have been created from this pool
+    /// are gone.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Change the size of the pool mapping.
+    ///
+    /// This request will cause the server to remap the backing memory
+    /// for the pool from the file descriptor passed when the pool was
+    /// created, but using the new size.  This request can only be
+    /// used to make the pool bigger.
+    ///
+    /// This request only changes the amount of bytes that are mmapped
+    /// by the server and does not touch the file corresponding to the
+    /// file descriptor passed at creation time. It is the client's
+    /// responsibility to ensure that the file is at least as big as
+    /// the new pool size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: new size of the pool, in bytes
+    pub async fn send_resize(&self, size: i32) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
         __packet.push_int(size);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_shm_pool_v2_request_create_buffer_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Create a buffer from the pool.
+///
+/// Create a wl_buffer object from the pool.
+///
+/// The buffer is created offset bytes into the pool and has
+/// width and height as specified.  The stride argument specifies
+/// the number of bytes from the beginning of one row to the beginning
+/// of the next.  The format is the pixel format of the buffer and
+/// must be one of those advertised through the wl_shm.format event.
+///
+/// A buffer will keep a reference to the pool it was created from
+/// so it is valid to destroy the pool immediately after creating
+/// a buffer from it.
+///
+/// The arguments of the `wl_shm_pool.create_buffer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_pool_request_create_buffer_args {
+    /// Buffer to create.
     pub id: crate::NewObjectId,
+    /// Buffer byte offset within the pool.
     pub offset: i32,
+    /// Buffer width, in pixels.
     pub width: i32,
+    /// Buffer height, in pixels.
     pub height: i32,
+    /// Number of bytes from the beginning of one row to the beginning of the next row.
     pub stride: i32,
-    pub format: u32,
+    /// Buffer pixel format.
+    pub format: wl_shm_format,
 }
-impl wl_shm_pool_v2_request_create_buffer_args {
+impl wl_shm_pool_request_create_buffer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_pool_v2_request_create_buffer_args, crate::Error> {
+    ) -> Result<wl_shm_pool_request_create_buffer_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let offset = __reader.read_int()?;
@@ -723,6 +1575,7 @@ impl wl_shm_pool_v2_request_create_buffer_args {
         let height = __reader.read_int()?;
         let stride = __reader.read_int()?;
         let format = __reader.read_uint()?;
+        let format = <wl_shm_format as ::std::convert::TryFrom<u32>>::try_from(format)?;
         __reader.finish()?;
         Ok(Self {
             id,
@@ -743,33 +1596,47 @@ impl wl_shm_pool_v2_request_create_buffer_args {
         __packet.push_int(self.width);
         __packet.push_int(self.height);
         __packet.push_int(self.stride);
-        __packet.push_uint(self.format);
+        __packet.push_uint(u32::from(self.format));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_v2_request_create_buffer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_request_create_buffer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_v2_request_create_buffer_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_request_create_buffer_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shm_pool_v2_request_create_buffer_args),
+        __value: (crate::ObjectId, wl_shm_pool_request_create_buffer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shm_pool_v2_request_destroy_args {}
-impl wl_shm_pool_v2_request_destroy_args {
+/// Destroy the pool.
+///
+/// Destroy the shared memory pool.
+///
+/// The mmapped memory will be released when all
+/// buffers that have been created from this pool
+/// are gone.
+///
+/// The arguments of the `wl_shm_pool.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_pool_request_destroy_args {}
+impl wl_shm_pool_request_destroy_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_pool_v2_request_destroy_args, crate::Error> {
+    ) -> Result<wl_shm_pool_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -782,31 +1649,51 @@ impl wl_shm_pool_v2_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_v2_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_v2_request_destroy_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_request_destroy_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shm_pool_v2_request_destroy_args),
+        __value: (crate::ObjectId, wl_shm_pool_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shm_pool_v2_request_resize_args {
+/// Change the size of the pool mapping.
+///
+/// This request will cause the server to remap the backing memory
+/// for the pool from the file descriptor passed when the pool was
+/// created, but using the new size.  This request can only be
+/// used to make the pool bigger.
+///
+/// This request only changes the amount of bytes that are mmapped
+/// by the server and does not touch the file corresponding to the
+/// file descriptor passed at creation time. It is the client's
+/// responsibility to ensure that the file is at least as big as
+/// the new pool size.
+///
+/// The arguments of the `wl_shm_pool.resize` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_pool_request_resize_args {
+    /// New size of the pool, in bytes.
     pub size: i32,
 }
-impl wl_shm_pool_v2_request_resize_args {
+impl wl_shm_pool_request_resize_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_pool_v2_request_resize_args, crate::Error> {
+    ) -> Result<wl_shm_pool_request_resize_args, crate::Error> {
         let mut __reader = __value.read();
         let size = __reader.read_int()?;
         __reader.finish()?;
@@ -821,40 +1708,130 @@ impl wl_shm_pool_v2_request_resize_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_v2_request_resize_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_pool_request_resize_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_v2_request_resize_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_pool_request_resize_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shm_pool_v2_request_resize_args),
+        __value: (crate::ObjectId, wl_shm_pool_request_resize_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// Static information about `wl_shm`.
+pub const wl_shm_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_shm",
+    version: 2,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "create_pool",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_shm_pool"),
+                },
+                crate::protocol::ArgKind::Fd,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "release",
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[crate::protocol::MessageInfo {
+        name: "format",
+        destructor: false,
+        args: &[crate::protocol::ArgKind::Uint],
+    }],
+};
+/// Shared memory support.
+///
+/// A singleton global object that provides support for shared
+/// memory.
+///
+/// Clients can create wl_shm_pool objects using the create_pool
+/// request.
+///
+/// On binding the wl_shm object one or more format events
+/// are emitted to inform clients about the valid pixel formats
+/// that can be used for buffers.
+///
+/// A proxy for a `wl_shm` object. The generated code knows this interface up to version 2.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_shm {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_shm_interface;
+}
+impl crate::protocol::Proxy for wl_shm {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_shm` object.
 #[allow(unused)]
-pub trait wl_shm_v2_event_handler: crate::protocol::EventHandler {
+pub trait wl_shm_event_handler: crate::protocol::EventHandler {
+    /// Pixel format description.
+    ///
+    /// Informs the client about a valid pixel format that
+    /// can be used for buffers. Known formats include
+    /// argb8888 and xrgb8888.
+    ///
+    /// # Arguments
+    ///
+    /// * `format`: buffer pixel format
     fn handle_format(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        format: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        format: wl_shm_format,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_shm",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -870,55 +1847,99 @@ pub trait wl_shm_v2_event_handler: crate::protocol::EventHandler {
                 0 => {
                     let mut __packet_reader = __packet.read();
                     let format = __packet_reader.read_uint()?;
+                    let format = <wl_shm_format as ::std::convert::TryFrom<u32>>::try_from(format)?;
                     __packet_reader.finish()?;
-                    self.handle_format(__connection, __packet, format).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_format(__connection, __packet, format).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_shm_v2_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_shm_v2_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl wl_shm {
+    /// Create a shm pool.
+    ///
+    /// Create a new wl_shm_pool object.
+    ///
+    /// The pool can be used to create shared memory based buffer
+    /// objects.  The server will mmap size bytes of the passed file
+    /// descriptor, to use as backing memory for the pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `fd`: file descriptor for the pool
+    /// * `size`: pool size, in bytes
+    ///
+    /// Returns the new `wl_shm_pool` object.
     pub async fn send_create_pool(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
         fd: ::std::os::fd::RawFd,
         size: i32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
+    ) -> Result<wl_shm_pool, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_shm_pool_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_fd(fd);
         __packet.push_int(size);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_shm_pool {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-}
-pub struct wl_shm_v2_request_create_pool_args {
+    /// Release the shm object.
+    ///
+    /// Using this request a client can tell the server that it is not going to
+    /// use the shm object anymore.
+    ///
+    /// Objects created via this interface remain unaffected.
+    ///
+    /// Available since version 2.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 2 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_shm",
+                request: "release",
+                since: 2,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Create a shm pool.
+///
+/// Create a new wl_shm_pool object.
+///
+/// The pool can be used to create shared memory based buffer
+/// objects.  The server will mmap size bytes of the passed file
+/// descriptor, to use as backing memory for the pool.
+///
+/// The arguments of the `wl_shm.create_pool` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_request_create_pool_args {
+    /// Pool to create.
     pub id: crate::NewObjectId,
+    /// File descriptor for the pool.
     pub fd: ::std::os::fd::RawFd,
+    /// Pool size, in bytes.
     pub size: i32,
 }
-impl wl_shm_v2_request_create_pool_args {
+impl wl_shm_request_create_pool_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_v2_request_create_pool_args, crate::Error> {
+    ) -> Result<wl_shm_request_create_pool_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let fd = __reader.read_fd()?;
@@ -937,29 +1958,42 @@ impl wl_shm_v2_request_create_pool_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_v2_request_create_pool_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_request_create_pool_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_v2_request_create_pool_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_request_create_pool_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shm_v2_request_create_pool_args),
+        __value: (crate::ObjectId, wl_shm_request_create_pool_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shm_v2_request_release_args {}
-impl wl_shm_v2_request_release_args {
+/// Release the shm object.
+///
+/// Using this request a client can tell the server that it is not going to
+/// use the shm object anymore.
+///
+/// Objects created via this interface remain unaffected.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_shm.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_request_release_args {}
+impl wl_shm_request_release_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_v2_request_release_args, crate::Error> {
+    ) -> Result<wl_shm_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -972,31 +2006,45 @@ impl wl_shm_v2_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_v2_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_v2_request_release_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_request_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shm_v2_request_release_args),
+        __value: (crate::ObjectId, wl_shm_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shm_v2_event_format_args {
-    pub format: u32,
+/// Pixel format description.
+///
+/// Informs the client about a valid pixel format that
+/// can be used for buffers. Known formats include
+/// argb8888 and xrgb8888.
+///
+/// The arguments of the `wl_shm.format` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shm_event_format_args {
+    /// Buffer pixel format.
+    pub format: wl_shm_format,
 }
-impl wl_shm_v2_event_format_args {
+impl wl_shm_event_format_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shm_v2_event_format_args, crate::Error> {
+    ) -> Result<wl_shm_event_format_args, crate::Error> {
         let mut __reader = __value.read();
         let format = __reader.read_uint()?;
+        let format = <wl_shm_format as ::std::convert::TryFrom<u32>>::try_from(format)?;
         __reader.finish()?;
         Ok(Self { format })
     }
@@ -1005,41 +2053,585 @@ impl wl_shm_v2_event_format_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.format);
+        __packet.push_uint(u32::from(self.format));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shm_v2_event_format_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shm_event_format_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_v2_event_format_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shm_event_format_args)> for crate::Packet {
     type Error = crate::Error;
-    fn try_from(
-        __value: (crate::ObjectId, wl_shm_v2_event_format_args),
-    ) -> Result<Self, Self::Error> {
+    fn try_from(__value: (crate::ObjectId, wl_shm_event_format_args)) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_shm` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_shm_event {
+    /// Pixel format description.
+    format(wl_shm_event_format_args),
+}
+impl wl_shm_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::format(wl_shm_event_format_args::try_from_packet(
+                __packet,
+            )?)),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_shm",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::format(_) => wl_shm_event_format_args::OPCODE,
+        }
+    }
+}
+/// Wl_shm error values.
+///
+/// These errors can be emitted in response to wl_shm requests.
+///
+/// The enum `wl_shm.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_shm_error {
+    /// Buffer format is not known.
+    invalid_format = 0,
+    /// Invalid size or stride during pool or buffer creation.
+    invalid_stride = 1,
+    /// Mmapping the file descriptor failed.
+    invalid_fd = 2,
+}
+impl ::std::convert::TryFrom<u32> for wl_shm_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::invalid_format),
+            1 => Ok(Self::invalid_stride),
+            2 => Ok(Self::invalid_fd),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shm.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_shm_error> for u32 {
+    fn from(__value: wl_shm_error) -> u32 {
+        __value as u32
+    }
+}
+/// Pixel formats.
+///
+/// This describes the memory layout of an individual pixel.
+///
+/// All renderers should support argb8888 and xrgb8888 but any other
+/// formats are optional and may not be supported by the particular
+/// renderer in use.
+///
+/// The drm format codes match the macros defined in drm_fourcc.h, except
+/// argb8888 and xrgb8888. The formats actually supported by the compositor
+/// will be reported by the format event.
+///
+/// For all wl_shm formats and unless specified in another protocol
+/// extension, pre-multiplied alpha is used for pixel values.
+///
+/// The enum `wl_shm.format`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_shm_format {
+    /// 32-bit ARGB format, \[31:0\] A:R:G:B 8:8:8:8 little endian.
+    argb8888 = 0,
+    /// 32-bit RGB format, \[31:0\] x:R:G:B 8:8:8:8 little endian.
+    xrgb8888 = 1,
+    /// 8-bit color index format, \[7:0\] C.
+    c8 = 538982467,
+    /// 8-bit RGB format, \[7:0\] R:G:B 3:3:2.
+    rgb332 = 943867730,
+    /// 8-bit BGR format, \[7:0\] B:G:R 2:3:3.
+    bgr233 = 944916290,
+    /// 16-bit xRGB format, \[15:0\] x:R:G:B 4:4:4:4 little endian.
+    xrgb4444 = 842093144,
+    /// 16-bit xBGR format, \[15:0\] x:B:G:R 4:4:4:4 little endian.
+    xbgr4444 = 842089048,
+    /// 16-bit RGBx format, \[15:0\] R:G:B:x 4:4:4:4 little endian.
+    rgbx4444 = 842094674,
+    /// 16-bit BGRx format, \[15:0\] B:G:R:x 4:4:4:4 little endian.
+    bgrx4444 = 842094658,
+    /// 16-bit ARGB format, \[15:0\] A:R:G:B 4:4:4:4 little endian.
+    argb4444 = 842093121,
+    /// 16-bit ABGR format, \[15:0\] A:B:G:R 4:4:4:4 little endian.
+    abgr4444 = 842089025,
+    /// 16-bit RBGA format, \[15:0\] R:G:B:A 4:4:4:4 little endian.
+    rgba4444 = 842088786,
+    /// 16-bit BGRA format, \[15:0\] B:G:R:A 4:4:4:4 little endian.
+    bgra4444 = 842088770,
+    /// 16-bit xRGB format, \[15:0\] x:R:G:B 1:5:5:5 little endian.
+    xrgb1555 = 892424792,
+    /// 16-bit xBGR 1555 format, \[15:0\] x:B:G:R 1:5:5:5 little endian.
+    xbgr1555 = 892420696,
+    /// 16-bit RGBx 5551 format, \[15:0\] R:G:B:x 5:5:5:1 little endian.
+    rgbx5551 = 892426322,
+    /// 16-bit BGRx 5551 format, \[15:0\] B:G:R:x 5:5:5:1 little endian.
+    bgrx5551 = 892426306,
+    /// 16-bit ARGB 1555 format, \[15:0\] A:R:G:B 1:5:5:5 little endian.
+    argb1555 = 892424769,
+    /// 16-bit ABGR 1555 format, \[15:0\] A:B:G:R 1:5:5:5 little endian.
+    abgr1555 = 892420673,
+    /// 16-bit RGBA 5551 format, \[15:0\] R:G:B:A 5:5:5:1 little endian.
+    rgba5551 = 892420434,
+    /// 16-bit BGRA 5551 format, \[15:0\] B:G:R:A 5:5:5:1 little endian.
+    bgra5551 = 892420418,
+    /// 16-bit RGB 565 format, \[15:0\] R:G:B 5:6:5 little endian.
+    rgb565 = 909199186,
+    /// 16-bit BGR 565 format, \[15:0\] B:G:R 5:6:5 little endian.
+    bgr565 = 909199170,
+    /// 24-bit RGB format, \[23:0\] R:G:B little endian.
+    rgb888 = 875710290,
+    /// 24-bit BGR format, \[23:0\] B:G:R little endian.
+    bgr888 = 875710274,
+    /// 32-bit xBGR format, \[31:0\] x:B:G:R 8:8:8:8 little endian.
+    xbgr8888 = 875709016,
+    /// 32-bit RGBx format, \[31:0\] R:G:B:x 8:8:8:8 little endian.
+    rgbx8888 = 875714642,
+    /// 32-bit BGRx format, \[31:0\] B:G:R:x 8:8:8:8 little endian.
+    bgrx8888 = 875714626,
+    /// 32-bit ABGR format, \[31:0\] A:B:G:R 8:8:8:8 little endian.
+    abgr8888 = 875708993,
+    /// 32-bit RGBA format, \[31:0\] R:G:B:A 8:8:8:8 little endian.
+    rgba8888 = 875708754,
+    /// 32-bit BGRA format, \[31:0\] B:G:R:A 8:8:8:8 little endian.
+    bgra8888 = 875708738,
+    /// 32-bit xRGB format, \[31:0\] x:R:G:B 2:10:10:10 little endian.
+    xrgb2101010 = 808669784,
+    /// 32-bit xBGR format, \[31:0\] x:B:G:R 2:10:10:10 little endian.
+    xbgr2101010 = 808665688,
+    /// 32-bit RGBx format, \[31:0\] R:G:B:x 10:10:10:2 little endian.
+    rgbx1010102 = 808671314,
+    /// 32-bit BGRx format, \[31:0\] B:G:R:x 10:10:10:2 little endian.
+    bgrx1010102 = 808671298,
+    /// 32-bit ARGB format, \[31:0\] A:R:G:B 2:10:10:10 little endian.
+    argb2101010 = 808669761,
+    /// 32-bit ABGR format, \[31:0\] A:B:G:R 2:10:10:10 little endian.
+    abgr2101010 = 808665665,
+    /// 32-bit RGBA format, \[31:0\] R:G:B:A 10:10:10:2 little endian.
+    rgba1010102 = 808665426,
+    /// 32-bit BGRA format, \[31:0\] B:G:R:A 10:10:10:2 little endian.
+    bgra1010102 = 808665410,
+    /// Packed YCbCr format, \[31:0\] Cr0:Y1:Cb0:Y0 8:8:8:8 little endian.
+    yuyv = 1448695129,
+    /// Packed YCbCr format, \[31:0\] Cb0:Y1:Cr0:Y0 8:8:8:8 little endian.
+    yvyu = 1431918169,
+    /// Packed YCbCr format, \[31:0\] Y1:Cr0:Y0:Cb0 8:8:8:8 little endian.
+    uyvy = 1498831189,
+    /// Packed YCbCr format, \[31:0\] Y1:Cb0:Y0:Cr0 8:8:8:8 little endian.
+    vyuy = 1498765654,
+    /// Packed AYCbCr format, \[31:0\] A:Y:Cb:Cr 8:8:8:8 little endian.
+    ayuv = 1448433985,
+    /// 2 plane YCbCr Cr:Cb format, 2x2 subsampled Cr:Cb plane.
+    nv12 = 842094158,
+    /// 2 plane YCbCr Cb:Cr format, 2x2 subsampled Cb:Cr plane.
+    nv21 = 825382478,
+    /// 2 plane YCbCr Cr:Cb format, 2x1 subsampled Cr:Cb plane.
+    nv16 = 909203022,
+    /// 2 plane YCbCr Cb:Cr format, 2x1 subsampled Cb:Cr plane.
+    nv61 = 825644622,
+    /// 3 plane YCbCr format, 4x4 subsampled Cb (1) and Cr (2) planes.
+    yuv410 = 961959257,
+    /// 3 plane YCbCr format, 4x4 subsampled Cr (1) and Cb (2) planes.
+    yvu410 = 961893977,
+    /// 3 plane YCbCr format, 4x1 subsampled Cb (1) and Cr (2) planes.
+    yuv411 = 825316697,
+    /// 3 plane YCbCr format, 4x1 subsampled Cr (1) and Cb (2) planes.
+    yvu411 = 825316953,
+    /// 3 plane YCbCr format, 2x2 subsampled Cb (1) and Cr (2) planes.
+    yuv420 = 842093913,
+    /// 3 plane YCbCr format, 2x2 subsampled Cr (1) and Cb (2) planes.
+    yvu420 = 842094169,
+    /// 3 plane YCbCr format, 2x1 subsampled Cb (1) and Cr (2) planes.
+    yuv422 = 909202777,
+    /// 3 plane YCbCr format, 2x1 subsampled Cr (1) and Cb (2) planes.
+    yvu422 = 909203033,
+    /// 3 plane YCbCr format, non-subsampled Cb (1) and Cr (2) planes.
+    yuv444 = 875713881,
+    /// 3 plane YCbCr format, non-subsampled Cr (1) and Cb (2) planes.
+    yvu444 = 875714137,
+    /// \[7:0\] R.
+    r8 = 538982482,
+    /// \[15:0\] R little endian.
+    r16 = 540422482,
+    /// \[15:0\] R:G 8:8 little endian.
+    rg88 = 943212370,
+    /// \[15:0\] G:R 8:8 little endian.
+    gr88 = 943215175,
+    /// \[31:0\] R:G 16:16 little endian.
+    rg1616 = 842221394,
+    /// \[31:0\] G:R 16:16 little endian.
+    gr1616 = 842224199,
+    /// \[63:0\] x:R:G:B 16:16:16:16 little endian.
+    xrgb16161616f = 1211388504,
+    /// \[63:0\] x:B:G:R 16:16:16:16 little endian.
+    xbgr16161616f = 1211384408,
+    /// \[63:0\] A:R:G:B 16:16:16:16 little endian.
+    argb16161616f = 1211388481,
+    /// \[63:0\] A:B:G:R 16:16:16:16 little endian.
+    abgr16161616f = 1211384385,
+    /// \[31:0\] X:Y:Cb:Cr 8:8:8:8 little endian.
+    xyuv8888 = 1448434008,
+    /// \[23:0\] Cr:Cb:Y 8:8:8 little endian.
+    vuy888 = 875713878,
+    /// Y followed by U then V, 10:10:10. Non-linear modifier only.
+    vuy101010 = 808670550,
+    /// \[63:0\] Cr0:0:Y1:0:Cb0:0:Y0:0 10:6:10:6:10:6:10:6 little endian per 2 Y pixels.
+    y210 = 808530521,
+    /// \[63:0\] Cr0:0:Y1:0:Cb0:0:Y0:0 12:4:12:4:12:4:12:4 little endian per 2 Y pixels.
+    y212 = 842084953,
+    /// \[63:0\] Cr0:Y1:Cb0:Y0 16:16:16:16 little endian per 2 Y pixels.
+    y216 = 909193817,
+    /// \[31:0\] A:Cr:Y:Cb 2:10:10:10 little endian.
+    y410 = 808531033,
+    /// \[63:0\] A:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian.
+    y412 = 842085465,
+    /// \[63:0\] A:Cr:Y:Cb 16:16:16:16 little endian.
+    y416 = 909194329,
+    /// \[31:0\] X:Cr:Y:Cb 2:10:10:10 little endian.
+    xvyu2101010 = 808670808,
+    /// \[63:0\] X:0:Cr:0:Y:0:Cb:0 12:4:12:4:12:4:12:4 little endian.
+    xvyu12_16161616 = 909334104,
+    /// \[63:0\] X:Cr:Y:Cb 16:16:16:16 little endian.
+    xvyu16161616 = 942954072,
+    /// \[63:0\]   A3:A2:Y3:0:Cr0:0:Y2:0:A1:A0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian.
+    y0l0 = 810299481,
+    /// \[63:0\]   X3:X2:Y3:0:Cr0:0:Y2:0:X1:X0:Y1:0:Cb0:0:Y0:0  1:1:8:2:8:2:8:2:1:1:8:2:8:2:8:2 little endian.
+    x0l0 = 810299480,
+    /// \[63:0\]   A3:A2:Y3:Cr0:Y2:A1:A0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian.
+    y0l2 = 843853913,
+    /// \[63:0\]   X3:X2:Y3:Cr0:Y2:X1:X0:Y1:Cb0:Y0  1:1:10:10:10:1:1:10:10:10 little endian.
+    x0l2 = 843853912,
+    yuv420_8bit = 942691673,
+    yuv420_10bit = 808539481,
+    xrgb8888_a8 = 943805016,
+    xbgr8888_a8 = 943800920,
+    rgbx8888_a8 = 943806546,
+    bgrx8888_a8 = 943806530,
+    rgb888_a8 = 943798354,
+    bgr888_a8 = 943798338,
+    rgb565_a8 = 943797586,
+    bgr565_a8 = 943797570,
+    /// Non-subsampled Cr:Cb plane.
+    nv24 = 875714126,
+    /// Non-subsampled Cb:Cr plane.
+    nv42 = 842290766,
+    /// 2x1 subsampled Cr:Cb plane, 10 bit per channel.
+    p210 = 808530512,
+    /// 2x2 subsampled Cr:Cb plane 10 bits per channel.
+    p010 = 808530000,
+    /// 2x2 subsampled Cr:Cb plane 12 bits per channel.
+    p012 = 842084432,
+    /// 2x2 subsampled Cr:Cb plane 16 bits per channel.
+    p016 = 909193296,
+    /// \[63:0\] A:x:B:x:G:x:R:x 10:6:10:6:10:6:10:6 little endian.
+    axbxgxrx106106106106 = 808534593,
+    /// 2x2 subsampled Cr:Cb plane.
+    nv15 = 892425806,
+    q410 = 808531025,
+    q401 = 825242705,
+    /// \[63:0\] x:R:G:B 16:16:16:16 little endian.
+    xrgb16161616 = 942953048,
+    /// \[63:0\] x:B:G:R 16:16:16:16 little endian.
+    xbgr16161616 = 942948952,
+    /// \[63:0\] A:R:G:B 16:16:16:16 little endian.
+    argb16161616 = 942953025,
+    /// \[63:0\] A:B:G:R 16:16:16:16 little endian.
+    abgr16161616 = 942948929,
+    /// \[7:0\] C0:C1:C2:C3:C4:C5:C6:C7 1:1:1:1:1:1:1:1 eight pixels/byte.
+    c1 = 538980675,
+    /// \[7:0\] C0:C1:C2:C3 2:2:2:2 four pixels/byte.
+    c2 = 538980931,
+    /// \[7:0\] C0:C1 4:4 two pixels/byte.
+    c4 = 538981443,
+    /// \[7:0\] D0:D1:D2:D3:D4:D5:D6:D7 1:1:1:1:1:1:1:1 eight pixels/byte.
+    d1 = 538980676,
+    /// \[7:0\] D0:D1:D2:D3 2:2:2:2 four pixels/byte.
+    d2 = 538980932,
+    /// \[7:0\] D0:D1 4:4 two pixels/byte.
+    d4 = 538981444,
+    /// \[7:0\] D.
+    d8 = 538982468,
+    /// \[7:0\] R0:R1:R2:R3:R4:R5:R6:R7 1:1:1:1:1:1:1:1 eight pixels/byte.
+    r1 = 538980690,
+    /// \[7:0\] R0:R1:R2:R3 2:2:2:2 four pixels/byte.
+    r2 = 538980946,
+    /// \[7:0\] R0:R1 4:4 two pixels/byte.
+    r4 = 538981458,
+    /// \[15:0\] x:R 6:10 little endian.
+    r10 = 540029266,
+    /// \[15:0\] x:R 4:12 little endian.
+    r12 = 540160338,
+    /// \[31:0\] A:Cr:Cb:Y 8:8:8:8 little endian.
+    avuy8888 = 1498764865,
+    /// \[31:0\] X:Cr:Cb:Y 8:8:8:8 little endian.
+    xvuy8888 = 1498764888,
+    /// 2x2 subsampled Cr:Cb plane 10 bits per channel packed.
+    p030 = 808661072,
+}
+impl ::std::convert::TryFrom<u32> for wl_shm_format {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::argb8888),
+            1 => Ok(Self::xrgb8888),
+            538982467 => Ok(Self::c8),
+            943867730 => Ok(Self::rgb332),
+            944916290 => Ok(Self::bgr233),
+            842093144 => Ok(Self::xrgb4444),
+            842089048 => Ok(Self::xbgr4444),
+            842094674 => Ok(Self::rgbx4444),
+            842094658 => Ok(Self::bgrx4444),
+            842093121 => Ok(Self::argb4444),
+            842089025 => Ok(Self::abgr4444),
+            842088786 => Ok(Self::rgba4444),
+            842088770 => Ok(Self::bgra4444),
+            892424792 => Ok(Self::xrgb1555),
+            892420696 => Ok(Self::xbgr1555),
+            892426322 => Ok(Self::rgbx5551),
+            892426306 => Ok(Self::bgrx5551),
+            892424769 => Ok(Self::argb1555),
+            892420673 => Ok(Self::abgr1555),
+            892420434 => Ok(Self::rgba5551),
+            892420418 => Ok(Self::bgra5551),
+            909199186 => Ok(Self::rgb565),
+            909199170 => Ok(Self::bgr565),
+            875710290 => Ok(Self::rgb888),
+            875710274 => Ok(Self::bgr888),
+            875709016 => Ok(Self::xbgr8888),
+            875714642 => Ok(Self::rgbx8888),
+            875714626 => Ok(Self::bgrx8888),
+            875708993 => Ok(Self::abgr8888),
+            875708754 => Ok(Self::rgba8888),
+            875708738 => Ok(Self::bgra8888),
+            808669784 => Ok(Self::xrgb2101010),
+            808665688 => Ok(Self::xbgr2101010),
+            808671314 => Ok(Self::rgbx1010102),
+            808671298 => Ok(Self::bgrx1010102),
+            808669761 => Ok(Self::argb2101010),
+            808665665 => Ok(Self::abgr2101010),
+            808665426 => Ok(Self::rgba1010102),
+            808665410 => Ok(Self::bgra1010102),
+            1448695129 => Ok(Self::yuyv),
+            1431918169 => Ok(Self::yvyu),
+            1498831189 => Ok(Self::uyvy),
+            1498765654 => Ok(Self::vyuy),
+            1448433985 => Ok(Self::ayuv),
+            842094158 => Ok(Self::nv12),
+            825382478 => Ok(Self::nv21),
+            909203022 => Ok(Self::nv16),
+            825644622 => Ok(Self::nv61),
+            961959257 => Ok(Self::yuv410),
+            961893977 => Ok(Self::yvu410),
+            825316697 => Ok(Self::yuv411),
+            825316953 => Ok(Self::yvu411),
+            842093913 => Ok(Self::yuv420),
+            842094169 => Ok(Self::yvu420),
+            909202777 => Ok(Self::yuv422),
+            909203033 => Ok(Self::yvu422),
+            875713881 => Ok(Self::yuv444),
+            875714137 => Ok(Self::yvu444),
+            538982482 => Ok(Self::r8),
+            540422482 => Ok(Self::r16),
+            943212370 => Ok(Self::rg88),
+            943215175 => Ok(Self::gr88),
+            842221394 => Ok(Self::rg1616),
+            842224199 => Ok(Self::gr1616),
+            1211388504 => Ok(Self::xrgb16161616f),
+            1211384408 => Ok(Self::xbgr16161616f),
+            1211388481 => Ok(Self::argb16161616f),
+            1211384385 => Ok(Self::abgr16161616f),
+            1448434008 => Ok(Self::xyuv8888),
+            875713878 => Ok(Self::vuy888),
+            808670550 => Ok(Self::vuy101010),
+            808530521 => Ok(Self::y210),
+            842084953 => Ok(Self::y212),
+            909193817 => Ok(Self::y216),
+            808531033 => Ok(Self::y410),
+            842085465 => Ok(Self::y412),
+            909194329 => Ok(Self::y416),
+            808670808 => Ok(Self::xvyu2101010),
+            909334104 => Ok(Self::xvyu12_16161616),
+            942954072 => Ok(Self::xvyu16161616),
+            810299481 => Ok(Self::y0l0),
+            810299480 => Ok(Self::x0l0),
+            843853913 => Ok(Self::y0l2),
+            843853912 => Ok(Self::x0l2),
+            942691673 => Ok(Self::yuv420_8bit),
+            808539481 => Ok(Self::yuv420_10bit),
+            943805016 => Ok(Self::xrgb8888_a8),
+            943800920 => Ok(Self::xbgr8888_a8),
+            943806546 => Ok(Self::rgbx8888_a8),
+            943806530 => Ok(Self::bgrx8888_a8),
+            943798354 => Ok(Self::rgb888_a8),
+            943798338 => Ok(Self::bgr888_a8),
+            943797586 => Ok(Self::rgb565_a8),
+            943797570 => Ok(Self::bgr565_a8),
+            875714126 => Ok(Self::nv24),
+            842290766 => Ok(Self::nv42),
+            808530512 => Ok(Self::p210),
+            808530000 => Ok(Self::p010),
+            842084432 => Ok(Self::p012),
+            909193296 => Ok(Self::p016),
+            808534593 => Ok(Self::axbxgxrx106106106106),
+            892425806 => Ok(Self::nv15),
+            808531025 => Ok(Self::q410),
+            825242705 => Ok(Self::q401),
+            942953048 => Ok(Self::xrgb16161616),
+            942948952 => Ok(Self::xbgr16161616),
+            942953025 => Ok(Self::argb16161616),
+            942948929 => Ok(Self::abgr16161616),
+            538980675 => Ok(Self::c1),
+            538980931 => Ok(Self::c2),
+            538981443 => Ok(Self::c4),
+            538980676 => Ok(Self::d1),
+            538980932 => Ok(Self::d2),
+            538981444 => Ok(Self::d4),
+            538982468 => Ok(Self::d8),
+            538980690 => Ok(Self::r1),
+            538980946 => Ok(Self::r2),
+            538981458 => Ok(Self::r4),
+            540029266 => Ok(Self::r10),
+            540160338 => Ok(Self::r12),
+            1498764865 => Ok(Self::avuy8888),
+            1498764888 => Ok(Self::xvuy8888),
+            808661072 => Ok(Self::p030),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shm.format",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_shm_format> for u32 {
+    fn from(__value: wl_shm_format) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_buffer`.
+pub const wl_buffer_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_buffer",
+    version: 1,
+    requests: &[crate::protocol::MessageInfo {
+        name: "destroy",
+        destructor: true,
+        args: &[],
+    }],
+    events: &[crate::protocol::MessageInfo {
+        name: "release",
+        destructor: false,
+        args: &[],
+    }],
+};
+/// Content for a wl_surface.
+///
+/// A buffer provides the content for a wl_surface. Buffers are
+/// created through factory interfaces such as wl_shm, wp_linux_buffer_params
+/// (from the linux-dmabuf protocol extension) or similar. It has a width and
+/// a height and can be attached to a wl_surface, but the mechanism by which a
+/// client provides and updates the contents is defined by the buffer factory
+/// interface.
+///
+/// Color channels are assumed to be electrical rather than optical (in other
+/// words, encoded with a transfer function) unless otherwise specified. If
+/// the buffer uses a format that has an alpha channel, the alpha channel is
+/// assumed to be premultiplied into the electrical color channel values
+/// (after transfer function encoding) unless otherwise specified.
+///
+/// Note, because wl_buffer objects are created from multiple independent
+/// factory interfaces, the wl_buffer interface is frozen at version 1.
+///
+/// A proxy for a `wl_buffer` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_buffer {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_buffer {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_buffer_interface;
+}
+impl crate::protocol::Proxy for wl_buffer {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_buffer` object.
 #[allow(unused)]
-pub trait wl_buffer_v1_event_handler: crate::protocol::EventHandler {
+pub trait wl_buffer_event_handler: crate::protocol::EventHandler {
+    /// Compositor releases buffer.
+    ///
+    /// Sent when this wl_buffer is no longer used by the compositor.
+    ///
+    /// For more information on when release events may or may not be sent,
+    /// and what consequences it has, please see the description of
+    /// wl_surface.attach.
+    ///
+    /// If a client receives a release event before the frame callback
+    /// requested in the same wl_surface.commit that attaches this
+    /// wl_buffer to a surface, then the client is immediately free to
+    /// reuse the buffer and its backing storage, and does not need a
+    /// second buffer for the next surface content update. Typically
+    /// this is possible, when the compositor maintains a copy of the
+    /// wl_surface contents, e.g. as a GL texture. This is an important
+    /// optimization for GL(ES) compositors with wl_shm clients.
     fn handle_release(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_buffer",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -1055,37 +2647,47 @@ pub trait wl_buffer_v1_event_handler: crate::protocol::EventHandler {
                 0 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_release(__connection, __packet).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_release(__connection, __packet).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_buffer_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_buffer_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_buffer_v1_request_destroy_args {}
-impl wl_buffer_v1_request_destroy_args {
+impl wl_buffer {
+    /// Destroy a buffer.
+    ///
+    /// Destroy a buffer. If and how you need to release the backing
+    /// storage is defined by the buffer factory interface.
+    ///
+    /// For possible side-effects to a surface, see wl_surface.attach.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Destroy a buffer.
+///
+/// Destroy a buffer. If and how you need to release the backing
+/// storage is defined by the buffer factory interface.
+///
+/// For possible side-effects to a surface, see wl_surface.attach.
+///
+/// The arguments of the `wl_buffer.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_buffer_request_destroy_args {}
+impl wl_buffer_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_buffer_v1_request_destroy_args, crate::Error> {
+    ) -> Result<wl_buffer_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1098,29 +2700,50 @@ impl wl_buffer_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_buffer_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_buffer_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_buffer_v1_request_destroy_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_buffer_request_destroy_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_buffer_v1_request_destroy_args),
+        __value: (crate::ObjectId, wl_buffer_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_buffer_v1_event_release_args {}
-impl wl_buffer_v1_event_release_args {
+/// Compositor releases buffer.
+///
+/// Sent when this wl_buffer is no longer used by the compositor.
+///
+/// For more information on when release events may or may not be sent,
+/// and what consequences it has, please see the description of
+/// wl_surface.attach.
+///
+/// If a client receives a release event before the frame callback
+/// requested in the same wl_surface.commit that attaches this
+/// wl_buffer to a surface, then the client is immediately free to
+/// reuse the buffer and its backing storage, and does not need a
+/// second buffer for the next surface content update. Typically
+/// this is possible, when the compositor maintains a copy of the
+/// wl_surface contents, e.g. as a GL texture. This is an important
+/// optimization for GL(ES) compositors with wl_shm clients.
+///
+/// The arguments of the `wl_buffer.release` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_buffer_event_release_args {}
+impl wl_buffer_event_release_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_buffer_v1_event_release_args, crate::Error> {
+    ) -> Result<wl_buffer_event_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1133,50 +2756,255 @@ impl wl_buffer_v1_event_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_buffer_v1_event_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_buffer_event_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_buffer_v1_event_release_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_buffer_event_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_buffer_v1_event_release_args),
+        __value: (crate::ObjectId, wl_buffer_event_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_buffer` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_buffer_event {
+    /// Compositor releases buffer.
+    release(wl_buffer_event_release_args),
+}
+impl wl_buffer_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::release(
+                wl_buffer_event_release_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_buffer",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::release(_) => wl_buffer_event_release_args::OPCODE,
+        }
+    }
+}
+/// Static information about `wl_data_offer`.
+pub const wl_data_offer_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_data_offer",
+        version: 3,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "accept",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::String,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "receive",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "finish",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_actions",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "source_actions",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+            crate::protocol::MessageInfo {
+                name: "action",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+        ],
+    };
+/// Offer to transfer data.
+///
+/// A wl_data_offer represents a piece of data offered for transfer
+/// by another client (the source client).  It is used by the
+/// copy-and-paste and drag-and-drop mechanisms.  The offer
+/// describes the different mime types that the data can be
+/// converted to and provides the mechanism for transferring the
+/// data directly from the source client.
+///
+/// A proxy for a `wl_data_offer` object. The generated code knows this interface up to version 3.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_data_offer {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_data_offer_interface;
+}
+impl crate::protocol::Proxy for wl_data_offer {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_data_offer` object.
 #[allow(unused)]
-pub trait wl_data_offer_v3_event_handler: crate::protocol::EventHandler {
+pub trait wl_data_offer_event_handler: crate::protocol::EventHandler {
+    /// Advertise offered mime type.
+    ///
+    /// Sent immediately after creating the wl_data_offer object.  One
+    /// event per offered mime type.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: offered mime type
     fn handle_offer(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         mime_type: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Notify the source-side available actions.
+    ///
+    /// This event indicates the actions offered by the data source. It
+    /// will be sent immediately after creating the wl_data_offer object,
+    /// or anytime the source side changes its offered actions through
+    /// wl_data_source.set_actions.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_actions`: actions offered by the data source
     fn handle_source_actions(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        source_actions: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        source_actions: wl_data_device_manager_dnd_action,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Notify the selected action.
+    ///
+    /// This event indicates the action selected by the compositor after
+    /// matching the source/destination side actions. Only one action (or
+    /// none) will be offered here.
+    ///
+    /// This event can be emitted multiple times during the drag-and-drop
+    /// operation in response to destination side action changes through
+    /// wl_data_offer.set_actions.
+    ///
+    /// This event will no longer be emitted after wl_data_device.drop
+    /// happened on the drag-and-drop destination, the client must
+    /// honor the last action received, or the last preferred one set
+    /// through wl_data_offer.set_actions when handling an "ask" action.
+    ///
+    /// Compositors may also change the selected action on the fly, mainly
+    /// in response to keyboard modifier changes during the drag-and-drop
+    /// operation.
+    ///
+    /// The most recent action received is always the valid one. Prior to
+    /// receiving wl_data_device.drop, the chosen action may change (e.g.
+    /// due to keyboard modifiers being pressed). At the time of receiving
+    /// wl_data_device.drop the drag-and-drop destination must honor the
+    /// last action received.
+    ///
+    /// Action changes may still happen after wl_data_device.drop,
+    /// especially on "ask" actions, where the drag-and-drop destination
+    /// may choose another action afterwards. Action changes happening
+    /// at this stage are always the result of inter-client negotiation, the
+    /// compositor shall no longer be able to induce a different action.
+    ///
+    /// Upon "ask" actions, it is expected that the drag-and-drop destination
+    /// may potentially choose a different action and/or mime type,
+    /// based on wl_data_offer.source_actions and finally chosen by the
+    /// user (e.g. popping up a menu with the available options). The
+    /// final wl_data_offer.set_actions and wl_data_offer.accept requests
+    /// must happen before the call to wl_data_offer.finish.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `dnd_action`: action selected by the compositor
     fn handle_action(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        dnd_action: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        dnd_action: wl_data_device_manager_dnd_action,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_data_offer",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -1193,95 +3021,236 @@ pub trait wl_data_offer_v3_event_handler: crate::protocol::EventHandler {
                     let mut __packet_reader = __packet.read();
                     let mime_type = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
-                    self.handle_offer(__connection, __packet, mime_type).await;
-                    Ok(())
+                    self.handle_offer(__connection, __packet, mime_type).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let source_actions = __packet_reader.read_uint()?;
+                    let source_actions = < wl_data_device_manager_dnd_action as :: std :: convert :: TryFrom < u32 > > :: try_from (source_actions) ? ;
                     __packet_reader.finish()?;
                     self.handle_source_actions(__connection, __packet, source_actions)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     let dnd_action = __packet_reader.read_uint()?;
+                    let dnd_action = < wl_data_device_manager_dnd_action as :: std :: convert :: TryFrom < u32 > > :: try_from (dnd_action) ? ;
                     __packet_reader.finish()?;
-                    self.handle_action(__connection, __packet, dnd_action).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_action(__connection, __packet, dnd_action).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_data_offer_v3_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_data_offer_v3_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl wl_data_offer {
+    /// Accept one of the offered mime types.
+    ///
+    /// Indicate that the client can accept the given mime type, or
+    /// NULL for not accepted.
+    ///
+    /// For objects of version 2 or older, this request is used by the
+    /// client to give feedback whether the client can receive the given
+    /// mime type, or NULL if none is accepted; the feedback does not
+    /// determine whether the drag-and-drop operation succeeds or not.
+    ///
+    /// For objects of version 3 or newer, this request determines the
+    /// final result of the drag-and-drop operation. If the end result
+    /// is that no mime types were accepted, the drag-and-drop operation
+    /// will be cancelled and the corresponding drag source will receive
+    /// wl_data_source.cancelled. Clients may still use this event in
+    /// conjunction with wl_data_source.action for feedback.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the accept request
+    /// * `mime_type`: mime type accepted by the client
     pub async fn send_accept(
         &self,
-        __object_id: crate::ObjectId,
         serial: u32,
-        mime_type: &str,
+        mime_type: ::std::option::Option<&str>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
         __packet.push_uint(serial);
-        __packet.push_str(&mime_type);
-        self.connection.send_packet(&__packet).await
-    }
+        __packet.push_nullable_str(mime_type);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Request that the data is transferred.
+    ///
+    /// To transfer the offered data, the client issues this request
+    /// and indicates the mime type it wants to receive.  The transfer
+    /// happens through the passed file descriptor (typically created
+    /// with the pipe system call).  The source client writes the data
+    /// in the mime type representation requested and then closes the
+    /// file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until
+    /// EOF and then closes its end, at which point the transfer is
+    /// complete.
+    ///
+    /// This request may happen multiple times for different mime types,
+    /// both before and after wl_data_device.drop. Drag-and-drop destination
+    /// clients may preemptively fetch data or examine it more closely to
+    /// determine acceptance.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: mime type desired by receiver
+    /// * `fd`: file descriptor for data transfer
     pub async fn send_receive(
         &self,
-        __object_id: crate::ObjectId,
         mime_type: &str,
         fd: ::std::os::fd::RawFd,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_str(&mime_type);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_str(mime_type);
         __packet.push_fd(fd);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_finish(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 3);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy data offer.
+    ///
+    /// Destroy the data offer.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// The offer will no longer be used.
+    ///
+    /// Notifies the compositor that the drag destination successfully
+    /// finished the drag-and-drop operation.
+    ///
+    /// Upon receiving this request, the compositor will emit
+    /// wl_data_source.dnd_finished on the drag source client.
+    ///
+    /// It is a client error to perform other requests than
+    /// wl_data_offer.destroy after this one. It is also an error to perform
+    /// this request after a NULL mime type has been set in
+    /// wl_data_offer.accept or no action was received through
+    /// wl_data_offer.action.
+    ///
+    /// If wl_data_offer.finish request is received for a non drag and drop
+    /// operation, the invalid_finish protocol error is raised.
+    ///
+    /// Available since version 3.
+    pub async fn send_finish(&self) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_data_offer",
+                request: "finish",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 3);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set the available/preferred drag-and-drop actions.
+    ///
+    /// Sets the actions that the destination side client supports for
+    /// this operation. This request may trigger the emission of
+    /// wl_data_source.action and wl_data_offer.action events if the compositor
+    /// needs to change the selected action.
+    ///
+    /// This request can be called multiple times throughout the
+    /// drag-and-drop operation, typically in response to wl_data_device.enter
+    /// or wl_data_device.motion events.
+    ///
+    /// This request determines the final result of the drag-and-drop
+    /// operation. If the end result is that no action is accepted,
+    /// the drag source will receive wl_data_source.cancelled.
+    ///
+    /// The dnd_actions argument must contain only values expressed in the
+    /// wl_data_device_manager.dnd_actions enum, and the preferred_action
+    /// argument must only contain one of those values set, otherwise it
+    /// will result in a protocol error.
+    ///
+    /// While managing an "ask" action, the destination drag-and-drop client
+    /// may perform further wl_data_offer.receive requests, and is expected
+    /// to perform one last wl_data_offer.set_actions request with a preferred
+    /// action other than "ask" (and optionally wl_data_offer.accept) before
+    /// requesting wl_data_offer.finish, in order to convey the action selected
+    /// by the user. If the preferred action is not in the
+    /// wl_data_offer.source_actions mask, an error will be raised.
+    ///
+    /// If the "ask" action is dismissed (e.g. user cancellation), the client
+    /// is expected to perform wl_data_offer.destroy right away.
+    ///
+    /// This request can only be made on drag-and-drop offers, a protocol error
+    /// will be raised otherwise.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `dnd_actions`: actions supported by the destination client
+    /// * `preferred_action`: action preferred by the destination client
     pub async fn send_set_actions(
         &self,
-        __object_id: crate::ObjectId,
-        dnd_actions: u32,
-        preferred_action: u32,
+        dnd_actions: wl_data_device_manager_dnd_action,
+        preferred_action: wl_data_device_manager_dnd_action,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 4);
-        __packet.push_uint(dnd_actions);
-        __packet.push_uint(preferred_action);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_data_offer_v3_request_accept_args {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_data_offer",
+                request: "set_actions",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 4);
+        __packet.push_uint(u32::from(dnd_actions));
+        __packet.push_uint(u32::from(preferred_action));
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Accept one of the offered mime types.
+///
+/// Indicate that the client can accept the given mime type, or
+/// NULL for not accepted.
+///
+/// For objects of version 2 or older, this request is used by the
+/// client to give feedback whether the client can receive the given
+/// mime type, or NULL if none is accepted; the feedback does not
+/// determine whether the drag-and-drop operation succeeds or not.
+///
+/// For objects of version 3 or newer, this request determines the
+/// final result of the drag-and-drop operation. If the end result
+/// is that no mime types were accepted, the drag-and-drop operation
+/// will be cancelled and the corresponding drag source will receive
+/// wl_data_source.cancelled. Clients may still use this event in
+/// conjunction with wl_data_source.action for feedback.
+///
+/// The arguments of the `wl_data_offer.accept` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_request_accept_args {
+    /// Serial number of the accept request.
     pub serial: u32,
-    pub mime_type: ::std::string::String,
+    /// Mime type accepted by the client.
+    pub mime_type: ::std::option::Option<::std::string::String>,
 }
-impl wl_data_offer_v3_request_accept_args {
+impl wl_data_offer_request_accept_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_request_accept_args, crate::Error> {
+    ) -> Result<wl_data_offer_request_accept_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
-        let mime_type = __reader.read_str()?;
+        let mime_type = __reader.read_nullable_str()?;
         __reader.finish()?;
         Ok(Self { serial, mime_type })
     }
@@ -1291,36 +3260,62 @@ impl wl_data_offer_v3_request_accept_args {
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
         __packet.push_uint(self.serial);
-        __packet.push_str(&self.mime_type);
+        __packet.push_nullable_str(self.mime_type.as_deref());
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_request_accept_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_request_accept_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_request_accept_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_request_accept_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_request_accept_args),
+        __value: (crate::ObjectId, wl_data_offer_request_accept_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_request_receive_args {
+/// Request that the data is transferred.
+///
+/// To transfer the offered data, the client issues this request
+/// and indicates the mime type it wants to receive.  The transfer
+/// happens through the passed file descriptor (typically created
+/// with the pipe system call).  The source client writes the data
+/// in the mime type representation requested and then closes the
+/// file descriptor.
+///
+/// The receiving client reads from the read end of the pipe until
+/// EOF and then closes its end, at which point the transfer is
+/// complete.
+///
+/// This request may happen multiple times for different mime types,
+/// both before and after wl_data_device.drop. Drag-and-drop destination
+/// clients may preemptively fetch data or examine it more closely to
+/// determine acceptance.
+///
+/// The arguments of the `wl_data_offer.receive` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_request_receive_args {
+    /// Mime type desired by receiver.
     pub mime_type: ::std::string::String,
+    /// File descriptor for data transfer.
     pub fd: ::std::os::fd::RawFd,
 }
-impl wl_data_offer_v3_request_receive_args {
+impl wl_data_offer_request_receive_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_request_receive_args, crate::Error> {
+    ) -> Result<wl_data_offer_request_receive_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         let fd = __reader.read_fd()?;
@@ -1337,29 +3332,39 @@ impl wl_data_offer_v3_request_receive_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_request_receive_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_request_receive_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_request_receive_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_request_receive_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_request_receive_args),
+        __value: (crate::ObjectId, wl_data_offer_request_receive_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_request_destroy_args {}
-impl wl_data_offer_v3_request_destroy_args {
+/// Destroy data offer.
+///
+/// Destroy the data offer.
+///
+/// The arguments of the `wl_data_offer.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_request_destroy_args {}
+impl wl_data_offer_request_destroy_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_request_destroy_args, crate::Error> {
+    ) -> Result<wl_data_offer_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1372,29 +3377,54 @@ impl wl_data_offer_v3_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_request_destroy_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_request_destroy_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_request_destroy_args),
+        __value: (crate::ObjectId, wl_data_offer_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_request_finish_args {}
-impl wl_data_offer_v3_request_finish_args {
+/// The offer will no longer be used.
+///
+/// Notifies the compositor that the drag destination successfully
+/// finished the drag-and-drop operation.
+///
+/// Upon receiving this request, the compositor will emit
+/// wl_data_source.dnd_finished on the drag source client.
+///
+/// It is a client error to perform other requests than
+/// wl_data_offer.destroy after this one. It is also an error to perform
+/// this request after a NULL mime type has been set in
+/// wl_data_offer.accept or no action was received through
+/// wl_data_offer.action.
+///
+/// If wl_data_offer.finish request is received for a non drag and drop
+/// operation, the invalid_finish protocol error is raised.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_offer.finish` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_request_finish_args {}
+impl wl_data_offer_request_finish_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_request_finish_args, crate::Error> {
+    ) -> Result<wl_data_offer_request_finish_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1407,35 +3437,86 @@ impl wl_data_offer_v3_request_finish_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_request_finish_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_request_finish_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_request_finish_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_request_finish_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_request_finish_args),
+        __value: (crate::ObjectId, wl_data_offer_request_finish_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_request_set_actions_args {
-    pub dnd_actions: u32,
-    pub preferred_action: u32,
-}
-impl wl_data_offer_v3_request_set_actions_args {
+/// Set the available/preferred drag-and-drop actions.
+///
+/// Sets the actions that the destination side client supports for
+/// this operation. This request may trigger the emission of
+/// wl_data_source.action and wl_data_offer.action events if the compositor
+/// needs to change the selected action.
+///
+/// This request can be called multiple times throughout the
+/// drag-and-drop operation, typically in response to wl_data_device.enter
+/// or wl_data_device.motion events.
+///
+/// This request determines the final result of the drag-and-drop
+/// operation. If the end result is that no action is accepted,
+/// the drag source will receive wl_data_source.cancelled.
+///
+/// The dnd_actions argument must contain only values expressed in the
+/// wl_data_device_manager.dnd_actions enum, and the preferred_action
+/// argument must only contain one of those values set, otherwise it
+/// will result in a protocol error.
+///
+/// While managing an "ask" action, the destination drag-and-drop client
+/// may perform further wl_data_offer.receive requests, and is expected
+/// to perform one last wl_data_offer.set_actions request with a preferred
+/// action other than "ask" (and optionally wl_data_offer.accept) before
+/// requesting wl_data_offer.finish, in order to convey the action selected
+/// by the user. If the preferred action is not in the
+/// wl_data_offer.source_actions mask, an error will be raised.
+///
+/// If the "ask" action is dismissed (e.g. user cancellation), the client
+/// is expected to perform wl_data_offer.destroy right away.
+///
+/// This request can only be made on drag-and-drop offers, a protocol error
+/// will be raised otherwise.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_offer.set_actions` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_request_set_actions_args {
+    /// Actions supported by the destination client.
+    pub dnd_actions: wl_data_device_manager_dnd_action,
+    /// Action preferred by the destination client.
+    pub preferred_action: wl_data_device_manager_dnd_action,
+}
+impl wl_data_offer_request_set_actions_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_request_set_actions_args, crate::Error> {
+    ) -> Result<wl_data_offer_request_set_actions_args, crate::Error> {
         let mut __reader = __value.read();
         let dnd_actions = __reader.read_uint()?;
+        let dnd_actions =
+            <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<u32>>::try_from(
+                dnd_actions,
+            )?;
         let preferred_action = __reader.read_uint()?;
+        let preferred_action = <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<
+            u32,
+        >>::try_from(preferred_action)?;
         __reader.finish()?;
         Ok(Self {
             dnd_actions,
@@ -1447,36 +3528,48 @@ impl wl_data_offer_v3_request_set_actions_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.dnd_actions);
-        __packet.push_uint(self.preferred_action);
+        __packet.push_uint(u32::from(self.dnd_actions));
+        __packet.push_uint(u32::from(self.preferred_action));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_request_set_actions_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_request_set_actions_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_request_set_actions_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_request_set_actions_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_request_set_actions_args),
+        __value: (crate::ObjectId, wl_data_offer_request_set_actions_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_event_offer_args {
+/// Advertise offered mime type.
+///
+/// Sent immediately after creating the wl_data_offer object.  One
+/// event per offered mime type.
+///
+/// The arguments of the `wl_data_offer.offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_event_offer_args {
+    /// Offered mime type.
     pub mime_type: ::std::string::String,
 }
-impl wl_data_offer_v3_event_offer_args {
+impl wl_data_offer_event_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_event_offer_args, crate::Error> {
+    ) -> Result<wl_data_offer_event_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         __reader.finish()?;
@@ -1491,33 +3584,51 @@ impl wl_data_offer_v3_event_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_event_offer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_event_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_event_offer_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_event_offer_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_event_offer_args),
+        __value: (crate::ObjectId, wl_data_offer_event_offer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_event_source_actions_args {
-    pub source_actions: u32,
-}
-impl wl_data_offer_v3_event_source_actions_args {
+/// Notify the source-side available actions.
+///
+/// This event indicates the actions offered by the data source. It
+/// will be sent immediately after creating the wl_data_offer object,
+/// or anytime the source side changes its offered actions through
+/// wl_data_source.set_actions.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_offer.source_actions` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_event_source_actions_args {
+    /// Actions offered by the data source.
+    pub source_actions: wl_data_device_manager_dnd_action,
+}
+impl wl_data_offer_event_source_actions_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_event_source_actions_args, crate::Error> {
+    ) -> Result<wl_data_offer_event_source_actions_args, crate::Error> {
         let mut __reader = __value.read();
         let source_actions = __reader.read_uint()?;
+        let source_actions =
+            <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<u32>>::try_from(
+                source_actions,
+            )?;
         __reader.finish()?;
         Ok(Self { source_actions })
     }
@@ -1526,37 +3637,88 @@ impl wl_data_offer_v3_event_source_actions_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.source_actions);
+        __packet.push_uint(u32::from(self.source_actions));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_event_source_actions_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_event_source_actions_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_event_source_actions_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_event_source_actions_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_event_source_actions_args),
+        __value: (crate::ObjectId, wl_data_offer_event_source_actions_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_offer_v3_event_action_args {
-    pub dnd_action: u32,
-}
-impl wl_data_offer_v3_event_action_args {
+/// Notify the selected action.
+///
+/// This event indicates the action selected by the compositor after
+/// matching the source/destination side actions. Only one action (or
+/// none) will be offered here.
+///
+/// This event can be emitted multiple times during the drag-and-drop
+/// operation in response to destination side action changes through
+/// wl_data_offer.set_actions.
+///
+/// This event will no longer be emitted after wl_data_device.drop
+/// happened on the drag-and-drop destination, the client must
+/// honor the last action received, or the last preferred one set
+/// through wl_data_offer.set_actions when handling an "ask" action.
+///
+/// Compositors may also change the selected action on the fly, mainly
+/// in response to keyboard modifier changes during the drag-and-drop
+/// operation.
+///
+/// The most recent action received is always the valid one. Prior to
+/// receiving wl_data_device.drop, the chosen action may change (e.g.
+/// due to keyboard modifiers being pressed). At the time of receiving
+/// wl_data_device.drop the drag-and-drop destination must honor the
+/// last action received.
+///
+/// Action changes may still happen after wl_data_device.drop,
+/// especially on "ask" actions, where the drag-and-drop destination
+/// may choose another action afterwards. Action changes happening
+/// at this stage are always the result of inter-client negotiation, the
+/// compositor shall no longer be able to induce a different action.
+///
+/// Upon "ask" actions, it is expected that the drag-and-drop destination
+/// may potentially choose a different action and/or mime type,
+/// based on wl_data_offer.source_actions and finally chosen by the
+/// user (e.g. popping up a menu with the available options). The
+/// final wl_data_offer.set_actions and wl_data_offer.accept requests
+/// must happen before the call to wl_data_offer.finish.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_offer.action` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_offer_event_action_args {
+    /// Action selected by the compositor.
+    pub dnd_action: wl_data_device_manager_dnd_action,
+}
+impl wl_data_offer_event_action_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_offer_v3_event_action_args, crate::Error> {
+    ) -> Result<wl_data_offer_event_action_args, crate::Error> {
         let mut __reader = __value.read();
         let dnd_action = __reader.read_uint()?;
+        let dnd_action =
+            <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<u32>>::try_from(
+                dnd_action,
+            )?;
         __reader.finish()?;
         Ok(Self { dnd_action })
     }
@@ -1565,72 +3727,358 @@ impl wl_data_offer_v3_event_action_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.dnd_action);
+        __packet.push_uint(u32::from(self.dnd_action));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_v3_event_action_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_offer_event_action_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_v3_event_action_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_offer_event_action_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_offer_v3_event_action_args),
+        __value: (crate::ObjectId, wl_data_offer_event_action_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_data_offer` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_data_offer_event {
+    /// Advertise offered mime type.
+    offer(wl_data_offer_event_offer_args),
+    /// Notify the source-side available actions.
+    source_actions(wl_data_offer_event_source_actions_args),
+    /// Notify the selected action.
+    action(wl_data_offer_event_action_args),
+}
+impl wl_data_offer_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::offer(
+                wl_data_offer_event_offer_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::source_actions(
+                wl_data_offer_event_source_actions_args::try_from_packet(__packet)?,
+            )),
+            2 => Ok(Self::action(
+                wl_data_offer_event_action_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_data_offer",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::offer(_) => wl_data_offer_event_offer_args::OPCODE,
+            Self::source_actions(_) => wl_data_offer_event_source_actions_args::OPCODE,
+            Self::action(_) => wl_data_offer_event_action_args::OPCODE,
+        }
+    }
+}
+/// The enum `wl_data_offer.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_data_offer_error {
+    /// Finish request was called untimely.
+    invalid_finish = 0,
+    /// Action mask contains invalid values.
+    invalid_action_mask = 1,
+    /// Action argument has an invalid value.
+    invalid_action = 2,
+    /// Offer doesn't accept this request.
+    invalid_offer = 3,
+}
+impl ::std::convert::TryFrom<u32> for wl_data_offer_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::invalid_finish),
+            1 => Ok(Self::invalid_action_mask),
+            2 => Ok(Self::invalid_action),
+            3 => Ok(Self::invalid_offer),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_data_offer.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_data_offer_error> for u32 {
+    fn from(__value: wl_data_offer_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_data_source`.
+pub const wl_data_source_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_data_source",
+        version: 3,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_actions",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "target",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "send",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "cancelled",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "dnd_drop_performed",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "dnd_finished",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "action",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+        ],
+    };
+/// Offer to transfer data.
+///
+/// The wl_data_source object is the source side of a wl_data_offer.
+/// It is created by the source client in a data transfer and
+/// provides a way to describe the offered data and a way to respond
+/// to requests to transfer the data.
+///
+/// A proxy for a `wl_data_source` object. The generated code knows this interface up to version 3.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_data_source {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_data_source_interface;
+}
+impl crate::protocol::Proxy for wl_data_source {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_data_source` object.
 #[allow(unused)]
-pub trait wl_data_source_v3_event_handler: crate::protocol::EventHandler {
+pub trait wl_data_source_event_handler: crate::protocol::EventHandler {
+    /// A target accepts an offered mime type.
+    ///
+    /// Sent when a target accepts pointer_focus or motion events.  If
+    /// a target does not accept any of the offered types, type is NULL.
+    ///
+    /// Used for feedback during drag-and-drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: mime type accepted by the target
     fn handle_target(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        mime_type: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        mime_type: ::std::option::Option<::std::string::String>,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Send the data.
+    ///
+    /// Request for data from the client.  Send the data as the
+    /// specified mime type over the passed file descriptor, then
+    /// close it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: mime type for the data
+    /// * `fd`: file descriptor for the data
     fn handle_send(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         mime_type: ::std::string::String,
         fd: ::std::os::fd::RawFd,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Selection was cancelled.
+    ///
+    /// This data source is no longer valid. There are several reasons why
+    /// this could happen:
+    ///
+    /// - The data source has been replaced by another data source.
+    /// - The drag-and-drop operation was performed, but the drop destination
+    ///   did not accept any of the mime types offered through
+    ///   wl_data_source.target.
+    /// - The drag-and-drop operation was performed, but the drop destination
+    ///   did not select any of the actions present in the mask offered through
+    ///   wl_data_source.action.
+    /// - The drag-and-drop operation was performed but didn't happen over a
+    ///   surface.
+    /// - The compositor cancelled the drag-and-drop operation (e.g. compositor
+    ///   dependent timeouts to avoid stale drag-and-drop transfers).
+    ///
+    /// The client should clean up and destroy this data source.
+    ///
+    /// For objects of version 2 or older, wl_data_source.cancelled will
+    /// only be emitted if the data source was replaced by another data
+    /// source.
     fn handle_cancelled(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// The drag-and-drop operation physically finished.
+    ///
+    /// The user performed the drop action. This event does not indicate
+    /// acceptance, wl_data_source.cancelled may still be emitted afterwards
+    /// if the drop destination does not accept any mime type.
+    ///
+    /// However, this event might however not be received if the compositor
+    /// cancelled the drag-and-drop operation before this event could happen.
+    ///
+    /// Note that the data_source may still be used in the future and should
+    /// not be destroyed here.
+    ///
+    /// Available since version 3.
     fn handle_dnd_drop_performed(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// The drag-and-drop operation concluded.
+    ///
+    /// The drop destination finished interoperating with this data
+    /// source, so the client is now free to destroy this data source and
+    /// free all associated data.
+    ///
+    /// If the action used to perform the operation was "move", the
+    /// source can now delete the transferred data.
+    ///
+    /// Available since version 3.
     fn handle_dnd_finished(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Notify the selected action.
+    ///
+    /// This event indicates the action selected by the compositor after
+    /// matching the source/destination side actions. Only one action (or
+    /// none) will be offered here.
+    ///
+    /// This event can be emitted multiple times during the drag-and-drop
+    /// operation, mainly in response to destination side changes through
+    /// wl_data_offer.set_actions, and as the data device enters/leaves
+    /// surfaces.
+    ///
+    /// It is only possible to receive this event after
+    /// wl_data_source.dnd_drop_performed if the drag-and-drop operation
+    /// ended in an "ask" action, in which case the final wl_data_source.action
+    /// event will happen immediately before wl_data_source.dnd_finished.
+    ///
+    /// Compositors may also change the selected action on the fly, mainly
+    /// in response to keyboard modifier changes during the drag-and-drop
+    /// operation.
+    ///
+    /// The most recent action received is always the valid one. The chosen
+    /// action may change alongside negotiation (e.g. an "ask" action can turn
+    /// into a "move" operation), so the effects of the final action must
+    /// always be applied in wl_data_offer.dnd_finished.
+    ///
+    /// Clients can trigger cursor surface changes from this point, so
+    /// they reflect the current action.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `dnd_action`: action selected by the compositor
     fn handle_action(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        dnd_action: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        dnd_action: wl_data_device_manager_dnd_action,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_data_source",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -1645,10 +4093,9 @@ pub trait wl_data_source_v3_event_handler: crate::protocol::EventHandler {
             match __packet.opcode() {
                 0 => {
                     let mut __packet_reader = __packet.read();
-                    let mime_type = __packet_reader.read_str()?;
+                    let mime_type = __packet_reader.read_nullable_str()?;
                     __packet_reader.finish()?;
-                    self.handle_target(__connection, __packet, mime_type).await;
-                    Ok(())
+                    self.handle_target(__connection, __packet, mime_type).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
@@ -1656,82 +4103,123 @@ pub trait wl_data_source_v3_event_handler: crate::protocol::EventHandler {
                     let fd = __packet_reader.read_fd()?;
                     __packet_reader.finish()?;
                     self.handle_send(__connection, __packet, mime_type, fd)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_cancelled(__connection, __packet).await;
-                    Ok(())
+                    self.handle_cancelled(__connection, __packet).await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_dnd_drop_performed(__connection, __packet).await;
-                    Ok(())
+                    self.handle_dnd_drop_performed(__connection, __packet).await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_dnd_finished(__connection, __packet).await;
-                    Ok(())
+                    self.handle_dnd_finished(__connection, __packet).await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
                     let dnd_action = __packet_reader.read_uint()?;
+                    let dnd_action = < wl_data_device_manager_dnd_action as :: std :: convert :: TryFrom < u32 > > :: try_from (dnd_action) ? ;
                     __packet_reader.finish()?;
-                    self.handle_action(__connection, __packet, dnd_action).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_action(__connection, __packet, dnd_action).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_data_source_v3_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_data_source_v3_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_offer(
-        &self,
-        __object_id: crate::ObjectId,
-        mime_type: &str,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_str(&mime_type);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
-    }
+impl wl_data_source {
+    /// Add an offered mime type.
+    ///
+    /// This request adds a mime type to the set of mime types
+    /// advertised to targets.  Can be called several times to offer
+    /// multiple types.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: mime type offered by the data source
+    pub async fn send_offer(&self, mime_type: &str) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_str(mime_type);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy the data source.
+    ///
+    /// Destroy the data source.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set the available drag-and-drop actions.
+    ///
+    /// Sets the actions that the source side client supports for this
+    /// operation. This request may trigger wl_data_source.action and
+    /// wl_data_offer.action events if the compositor needs to change the
+    /// selected action.
+    ///
+    /// The dnd_actions argument must contain only values expressed in the
+    /// wl_data_device_manager.dnd_actions enum, otherwise it will result
+    /// in a protocol error.
+    ///
+    /// This request must be made once only, and can only be made on sources
+    /// used in drag-and-drop, so it must be performed before
+    /// wl_data_device.start_drag. Attempting to use the source other than
+    /// for drag-and-drop will raise a protocol error.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `dnd_actions`: actions supported by the data source
     pub async fn send_set_actions(
         &self,
-        __object_id: crate::ObjectId,
-        dnd_actions: u32,
+        dnd_actions: wl_data_device_manager_dnd_action,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        __packet.push_uint(dnd_actions);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_data_source_v3_request_offer_args {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_data_source",
+                request: "set_actions",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __packet.push_uint(u32::from(dnd_actions));
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Add an offered mime type.
+///
+/// This request adds a mime type to the set of mime types
+/// advertised to targets.  Can be called several times to offer
+/// multiple types.
+///
+/// The arguments of the `wl_data_source.offer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_request_offer_args {
+    /// Mime type offered by the data source.
     pub mime_type: ::std::string::String,
 }
-impl wl_data_source_v3_request_offer_args {
+impl wl_data_source_request_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_request_offer_args, crate::Error> {
+    ) -> Result<wl_data_source_request_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         __reader.finish()?;
@@ -1746,29 +4234,39 @@ impl wl_data_source_v3_request_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_request_offer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_request_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_request_offer_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_request_offer_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_request_offer_args),
+        __value: (crate::ObjectId, wl_data_source_request_offer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_request_destroy_args {}
-impl wl_data_source_v3_request_destroy_args {
+/// Destroy the data source.
+///
+/// Destroy the data source.
+///
+/// The arguments of the `wl_data_source.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_request_destroy_args {}
+impl wl_data_source_request_destroy_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_request_destroy_args, crate::Error> {
+    ) -> Result<wl_data_source_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1781,33 +4279,62 @@ impl wl_data_source_v3_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_request_destroy_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_request_destroy_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_request_destroy_args),
+        __value: (crate::ObjectId, wl_data_source_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_request_set_actions_args {
-    pub dnd_actions: u32,
-}
-impl wl_data_source_v3_request_set_actions_args {
+/// Set the available drag-and-drop actions.
+///
+/// Sets the actions that the source side client supports for this
+/// operation. This request may trigger wl_data_source.action and
+/// wl_data_offer.action events if the compositor needs to change the
+/// selected action.
+///
+/// The dnd_actions argument must contain only values expressed in the
+/// wl_data_device_manager.dnd_actions enum, otherwise it will result
+/// in a protocol error.
+///
+/// This request must be made once only, and can only be made on sources
+/// used in drag-and-drop, so it must be performed before
+/// wl_data_device.start_drag. Attempting to use the source other than
+/// for drag-and-drop will raise a protocol error.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_source.set_actions` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_request_set_actions_args {
+    /// Actions supported by the data source.
+    pub dnd_actions: wl_data_device_manager_dnd_action,
+}
+impl wl_data_source_request_set_actions_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_request_set_actions_args, crate::Error> {
+    ) -> Result<wl_data_source_request_set_actions_args, crate::Error> {
         let mut __reader = __value.read();
         let dnd_actions = __reader.read_uint()?;
+        let dnd_actions =
+            <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<u32>>::try_from(
+                dnd_actions,
+            )?;
         __reader.finish()?;
         Ok(Self { dnd_actions })
     }
@@ -1816,37 +4343,51 @@ impl wl_data_source_v3_request_set_actions_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.dnd_actions);
+        __packet.push_uint(u32::from(self.dnd_actions));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_request_set_actions_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_request_set_actions_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_request_set_actions_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_request_set_actions_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_request_set_actions_args),
+        __value: (crate::ObjectId, wl_data_source_request_set_actions_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_target_args {
-    pub mime_type: ::std::string::String,
-}
-impl wl_data_source_v3_event_target_args {
+/// A target accepts an offered mime type.
+///
+/// Sent when a target accepts pointer_focus or motion events.  If
+/// a target does not accept any of the offered types, type is NULL.
+///
+/// Used for feedback during drag-and-drop.
+///
+/// The arguments of the `wl_data_source.target` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_target_args {
+    /// Mime type accepted by the target.
+    pub mime_type: ::std::option::Option<::std::string::String>,
+}
+impl wl_data_source_event_target_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_target_args, crate::Error> {
+    ) -> Result<wl_data_source_event_target_args, crate::Error> {
         let mut __reader = __value.read();
-        let mime_type = __reader.read_str()?;
+        let mime_type = __reader.read_nullable_str()?;
         __reader.finish()?;
         Ok(Self { mime_type })
     }
@@ -1855,36 +4396,50 @@ impl wl_data_source_v3_event_target_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_str(&self.mime_type);
+        __packet.push_nullable_str(self.mime_type.as_deref());
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_target_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_target_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_event_target_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_event_target_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_event_target_args),
+        __value: (crate::ObjectId, wl_data_source_event_target_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_send_args {
+/// Send the data.
+///
+/// Request for data from the client.  Send the data as the
+/// specified mime type over the passed file descriptor, then
+/// close it.
+///
+/// The arguments of the `wl_data_source.send` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_send_args {
+    /// Mime type for the data.
     pub mime_type: ::std::string::String,
+    /// File descriptor for the data.
     pub fd: ::std::os::fd::RawFd,
 }
-impl wl_data_source_v3_event_send_args {
+impl wl_data_source_event_send_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_send_args, crate::Error> {
+    ) -> Result<wl_data_source_event_send_args, crate::Error> {
         let mut __reader = __value.read();
         let mime_type = __reader.read_str()?;
         let fd = __reader.read_fd()?;
@@ -1901,29 +4456,56 @@ impl wl_data_source_v3_event_send_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_send_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_send_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_event_send_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_event_send_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_event_send_args),
+        __value: (crate::ObjectId, wl_data_source_event_send_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_cancelled_args {}
-impl wl_data_source_v3_event_cancelled_args {
+/// Selection was cancelled.
+///
+/// This data source is no longer valid. There are several reasons why
+/// this could happen:
+///
+/// - The data source has been replaced by another data source.
+/// - The drag-and-drop operation was performed, but the drop destination
+///   did not accept any of the mime types offered through
+///   wl_data_source.target.
+/// - The drag-and-drop operation was performed, but the drop destination
+///   did not select any of the actions present in the mask offered through
+///   wl_data_source.action.
+/// - The drag-and-drop operation was performed but didn't happen over a
+///   surface.
+/// - The compositor cancelled the drag-and-drop operation (e.g. compositor
+///   dependent timeouts to avoid stale drag-and-drop transfers).
+///
+/// The client should clean up and destroy this data source.
+///
+/// For objects of version 2 or older, wl_data_source.cancelled will
+/// only be emitted if the data source was replaced by another data
+/// source.
+///
+/// The arguments of the `wl_data_source.cancelled` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_cancelled_args {}
+impl wl_data_source_event_cancelled_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_cancelled_args, crate::Error> {
+    ) -> Result<wl_data_source_event_cancelled_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1936,29 +4518,49 @@ impl wl_data_source_v3_event_cancelled_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_cancelled_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_cancelled_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_event_cancelled_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_event_cancelled_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_event_cancelled_args),
+        __value: (crate::ObjectId, wl_data_source_event_cancelled_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_dnd_drop_performed_args {}
-impl wl_data_source_v3_event_dnd_drop_performed_args {
+/// The drag-and-drop operation physically finished.
+///
+/// The user performed the drop action. This event does not indicate
+/// acceptance, wl_data_source.cancelled may still be emitted afterwards
+/// if the drop destination does not accept any mime type.
+///
+/// However, this event might however not be received if the compositor
+/// cancelled the drag-and-drop operation before this event could happen.
+///
+/// Note that the data_source may still be used in the future and should
+/// not be destroyed here.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_source.dnd_drop_performed` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_dnd_drop_performed_args {}
+impl wl_data_source_event_dnd_drop_performed_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_dnd_drop_performed_args, crate::Error> {
+    ) -> Result<wl_data_source_event_dnd_drop_performed_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -1971,7 +4573,7 @@ impl wl_data_source_v3_event_dnd_drop_performed_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_dnd_drop_performed_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_dnd_drop_performed_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -1980,26 +4582,43 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_dnd_drop
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_data_source_v3_event_dnd_drop_performed_args,
+        wl_data_source_event_dnd_drop_performed_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_data_source_v3_event_dnd_drop_performed_args,
+            wl_data_source_event_dnd_drop_performed_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_dnd_finished_args {}
-impl wl_data_source_v3_event_dnd_finished_args {
+/// The drag-and-drop operation concluded.
+///
+/// The drop destination finished interoperating with this data
+/// source, so the client is now free to destroy this data source and
+/// free all associated data.
+///
+/// If the action used to perform the operation was "move", the
+/// source can now delete the transferred data.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_source.dnd_finished` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_dnd_finished_args {}
+impl wl_data_source_event_dnd_finished_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_dnd_finished_args, crate::Error> {
+    ) -> Result<wl_data_source_event_dnd_finished_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -2012,33 +4631,74 @@ impl wl_data_source_v3_event_dnd_finished_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_dnd_finished_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_dnd_finished_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_event_dnd_finished_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_event_dnd_finished_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_event_dnd_finished_args),
+        __value: (crate::ObjectId, wl_data_source_event_dnd_finished_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_source_v3_event_action_args {
-    pub dnd_action: u32,
-}
-impl wl_data_source_v3_event_action_args {
+/// Notify the selected action.
+///
+/// This event indicates the action selected by the compositor after
+/// matching the source/destination side actions. Only one action (or
+/// none) will be offered here.
+///
+/// This event can be emitted multiple times during the drag-and-drop
+/// operation, mainly in response to destination side changes through
+/// wl_data_offer.set_actions, and as the data device enters/leaves
+/// surfaces.
+///
+/// It is only possible to receive this event after
+/// wl_data_source.dnd_drop_performed if the drag-and-drop operation
+/// ended in an "ask" action, in which case the final wl_data_source.action
+/// event will happen immediately before wl_data_source.dnd_finished.
+///
+/// Compositors may also change the selected action on the fly, mainly
+/// in response to keyboard modifier changes during the drag-and-drop
+/// operation.
+///
+/// The most recent action received is always the valid one. The chosen
+/// action may change alongside negotiation (e.g. an "ask" action can turn
+/// into a "move" operation), so the effects of the final action must
+/// always be applied in wl_data_offer.dnd_finished.
+///
+/// Clients can trigger cursor surface changes from this point, so
+/// they reflect the current action.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_data_source.action` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_source_event_action_args {
+    /// Action selected by the compositor.
+    pub dnd_action: wl_data_device_manager_dnd_action,
+}
+impl wl_data_source_event_action_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_source_v3_event_action_args, crate::Error> {
+    ) -> Result<wl_data_source_event_action_args, crate::Error> {
         let mut __reader = __value.read();
         let dnd_action = __reader.read_uint()?;
+        let dnd_action =
+            <wl_data_device_manager_dnd_action as ::std::convert::TryFrom<u32>>::try_from(
+                dnd_action,
+            )?;
         __reader.finish()?;
         Ok(Self { dnd_action })
     }
@@ -2047,50 +4707,300 @@ impl wl_data_source_v3_event_action_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.dnd_action);
+        __packet.push_uint(u32::from(self.dnd_action));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_v3_event_action_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_source_event_action_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_v3_event_action_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_source_event_action_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_source_v3_event_action_args),
+        __value: (crate::ObjectId, wl_data_source_event_action_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_data_source` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_data_source_event {
+    /// A target accepts an offered mime type.
+    target(wl_data_source_event_target_args),
+    /// Send the data.
+    send(wl_data_source_event_send_args),
+    /// Selection was cancelled.
+    cancelled(wl_data_source_event_cancelled_args),
+    /// The drag-and-drop operation physically finished.
+    dnd_drop_performed(wl_data_source_event_dnd_drop_performed_args),
+    /// The drag-and-drop operation concluded.
+    dnd_finished(wl_data_source_event_dnd_finished_args),
+    /// Notify the selected action.
+    action(wl_data_source_event_action_args),
+}
+impl wl_data_source_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::target(
+                wl_data_source_event_target_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::send(wl_data_source_event_send_args::try_from_packet(
+                __packet,
+            )?)),
+            2 => Ok(Self::cancelled(
+                wl_data_source_event_cancelled_args::try_from_packet(__packet)?,
+            )),
+            3 => Ok(Self::dnd_drop_performed(
+                wl_data_source_event_dnd_drop_performed_args::try_from_packet(__packet)?,
+            )),
+            4 => Ok(Self::dnd_finished(
+                wl_data_source_event_dnd_finished_args::try_from_packet(__packet)?,
+            )),
+            5 => Ok(Self::action(
+                wl_data_source_event_action_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_data_source",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::target(_) => wl_data_source_event_target_args::OPCODE,
+            Self::send(_) => wl_data_source_event_send_args::OPCODE,
+            Self::cancelled(_) => wl_data_source_event_cancelled_args::OPCODE,
+            Self::dnd_drop_performed(_) => wl_data_source_event_dnd_drop_performed_args::OPCODE,
+            Self::dnd_finished(_) => wl_data_source_event_dnd_finished_args::OPCODE,
+            Self::action(_) => wl_data_source_event_action_args::OPCODE,
+        }
+    }
+}
+/// The enum `wl_data_source.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_data_source_error {
+    /// Action mask contains invalid values.
+    invalid_action_mask = 0,
+    /// Source doesn't accept this request.
+    invalid_source = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_data_source_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::invalid_action_mask),
+            1 => Ok(Self::invalid_source),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_data_source.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_data_source_error> for u32 {
+    fn from(__value: wl_data_source_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_data_device`.
+pub const wl_data_device_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_data_device",
+        version: 3,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "start_drag",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_selection",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "release",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "data_offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_data_offer"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "enter",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Fixed,
+                    crate::protocol::ArgKind::Fixed,
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "leave",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "motion",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Fixed,
+                    crate::protocol::ArgKind::Fixed,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "drop",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+        ],
+    };
+/// Data transfer device.
+///
+/// There is one wl_data_device per seat which can be obtained
+/// from the global wl_data_device_manager singleton.
+///
+/// A wl_data_device provides access to inter-client data transfer
+/// mechanisms such as copy-and-paste and drag-and-drop.
+///
+/// A proxy for a `wl_data_device` object. The generated code knows this interface up to version 3.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_data_device {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_data_device_interface;
+}
+impl crate::protocol::Proxy for wl_data_device {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_data_device` object.
 #[allow(unused)]
-pub trait wl_data_device_v3_event_handler: crate::protocol::EventHandler {
+pub trait wl_data_device_event_handler: crate::protocol::EventHandler {
+    /// Introduce a new wl_data_offer.
+    ///
+    /// The data_offer event introduces a new wl_data_offer object,
+    /// which will subsequently be used in either the
+    /// data_device.enter event (for drag-and-drop) or the
+    /// data_device.selection event (for selections).  Immediately
+    /// following the data_device.data_offer event, the new data_offer
+    /// object will send out data_offer.offer events to describe the
+    /// mime types it offers.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: the new data_offer object
     fn handle_data_offer(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: crate::NewObjectId,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Initiate drag-and-drop session.
+    ///
+    /// This event is sent when an active drag-and-drop pointer enters
+    /// a surface owned by the client.  The position of the pointer at
+    /// enter time is provided by the x and y arguments, in surface-local
+    /// coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the enter event
+    /// * `surface`: client surface entered
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
+    /// * `id`: source data_offer object
     fn handle_enter(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
+        surface: crate::ObjectId,
         x: crate::Fixed,
         y: crate::Fixed,
         id: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// End drag-and-drop session.
+    ///
+    /// This event is sent when the drag-and-drop pointer leaves the
+    /// surface and the session ends.  The client must destroy the
+    /// wl_data_offer introduced at enter time at this point.
     fn handle_leave(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Drag-and-drop session motion.
+    ///
+    /// This event is sent when the drag-and-drop pointer moves within
+    /// the currently focused surface. The new position of the pointer
+    /// is provided by the x and y arguments, in surface-local
+    /// coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
     fn handle_motion(
         &self,
         connection: &crate::Connection,
@@ -2098,27 +5008,74 @@ pub trait wl_data_device_v3_event_handler: crate::protocol::EventHandler {
         time: u32,
         x: crate::Fixed,
         y: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// End drag-and-drop session successfully.
+    ///
+    /// The event is sent when a drag-and-drop operation is ended
+    /// because the implicit grab is removed.
+    ///
+    /// The drag-and-drop destination is expected to honor the last action
+    /// received through wl_data_offer.action, if the resulting action is
+    /// "copy" or "move", the destination can still perform
+    /// wl_data_offer.receive requests, and is expected to end all
+    /// transfers with a wl_data_offer.finish request.
+    ///
+    /// If the resulting action is "ask", the action will not be considered
+    /// final. The drag-and-drop destination is expected to perform one last
+    /// wl_data_offer.set_actions request, or wl_data_offer.destroy in order
+    /// to cancel the operation.
     fn handle_drop(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise new selection.
+    ///
+    /// The selection event is sent out to notify the client of a new
+    /// wl_data_offer for the selection for this device.  The
+    /// data_device.data_offer and the data_offer.offer events are
+    /// sent out immediately before this event to introduce the data
+    /// offer object.  The selection event is sent to a client
+    /// immediately before receiving keyboard focus and when a new
+    /// selection is set while the client has keyboard focus.  The
+    /// data_offer is valid until a new data_offer or NULL is received
+    /// or until the client loses keyboard focus.  Switching surface with
+    /// keyboard focus within the same client doesn't mean a new selection
+    /// will be sent.  The client must destroy the previous selection
+    /// data_offer, if any, upon receiving this event.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: selection data_offer object
     fn handle_selection(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_data_device",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -2135,8 +5092,7 @@ pub trait wl_data_device_v3_event_handler: crate::protocol::EventHandler {
                     let mut __packet_reader = __packet.read();
                     let id = __packet_reader.read_new_id_known_interface()?;
                     __packet_reader.finish()?;
-                    self.handle_data_offer(__connection, __packet, id).await;
-                    Ok(())
+                    self.handle_data_offer(__connection, __packet, id).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
@@ -2144,17 +5100,15 @@ pub trait wl_data_device_v3_event_handler: crate::protocol::EventHandler {
                     let surface = __packet_reader.read_object()?;
                     let x = __packet_reader.read_fixed()?;
                     let y = __packet_reader.read_fixed()?;
-                    let id = __packet_reader.read_object()?;
+                    let id = __packet_reader.read_nullable_object()?;
                     __packet_reader.finish()?;
                     self.handle_enter(__connection, __packet, serial, surface, x, y, id)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_leave(__connection, __packet).await;
-                    Ok(())
+                    self.handle_leave(__connection, __packet).await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
@@ -2162,85 +5116,184 @@ pub trait wl_data_device_v3_event_handler: crate::protocol::EventHandler {
                     let x = __packet_reader.read_fixed()?;
                     let y = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
-                    self.handle_motion(__connection, __packet, time, x, y).await;
-                    Ok(())
+                    self.handle_motion(__connection, __packet, time, x, y).await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_drop(__connection, __packet).await;
-                    Ok(())
+                    self.handle_drop(__connection, __packet).await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
-                    let id = __packet_reader.read_object()?;
+                    let id = __packet_reader.read_nullable_object()?;
                     __packet_reader.finish()?;
-                    self.handle_selection(__connection, __packet, id).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_selection(__connection, __packet, id).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_data_device_v3_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_data_device_v3_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl wl_data_device {
+    /// Start drag-and-drop operation.
+    ///
+    /// This request asks the compositor to start a drag-and-drop
+    /// operation on behalf of the client.
+    ///
+    /// The source argument is the data source that provides the data
+    /// for the eventual data transfer. If source is NULL, enter, leave
+    /// and motion events are sent only to the client that initiated the
+    /// drag and the client is expected to handle the data passing
+    /// internally. If source is destroyed, the drag-and-drop session will be
+    /// cancelled.
+    ///
+    /// The origin surface is the surface where the drag originates and
+    /// the client must have an active implicit grab that matches the
+    /// serial.
+    ///
+    /// The icon surface is an optional (can be NULL) surface that
+    /// provides an icon to be moved around with the cursor.  Initially,
+    /// the top-left corner of the icon surface is placed at the cursor
+    /// hotspot, but subsequent wl_surface.offset requests can move the
+    /// relative position. Attach requests must be confirmed with
+    /// wl_surface.commit as usual. The icon surface is given the role of
+    /// a drag-and-drop icon. If the icon surface already has another role,
+    /// it raises a protocol error.
+    ///
+    /// The input region is ignored for wl_surfaces with the role of a
+    /// drag-and-drop icon.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// start_drag requests. Attempting to reuse a previously-used source
+    /// may send a used_source error.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: data source for the eventual transfer
+    /// * `origin`: surface where the drag originates
+    /// * `icon`: drag-and-drop icon surface
+    /// * `serial`: serial number of the implicit grab on the origin
     pub async fn send_start_drag(
         &self,
-        __object_id: crate::ObjectId,
         source: ::std::option::Option<crate::ObjectId>,
-        origin: ::std::option::Option<crate::ObjectId>,
+        origin: crate::ObjectId,
         icon: ::std::option::Option<crate::ObjectId>,
         serial: u32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_object(source);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_nullable_object(source);
         __packet.push_object(origin);
-        __packet.push_object(icon);
+        __packet.push_nullable_object(icon);
         __packet.push_uint(serial);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Copy data to the selection.
+    ///
+    /// This request asks the compositor to set the selection
+    /// to the data from the source on behalf of the client.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// start_drag requests. Attempting to reuse a previously-used source
+    /// may send a used_source error.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: data source for the selection
+    /// * `serial`: serial number of the event that triggered this request
     pub async fn send_set_selection(
         &self,
-        __object_id: crate::ObjectId,
         source: ::std::option::Option<crate::ObjectId>,
         serial: u32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_object(source);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_nullable_object(source);
         __packet.push_uint(serial);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_data_device_v3_request_start_drag_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy data device.
+    ///
+    /// This request destroys the data device.
+    ///
+    /// Available since version 2.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 2 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_data_device",
+                request: "release",
+                since: 2,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Start drag-and-drop operation.
+///
+/// This request asks the compositor to start a drag-and-drop
+/// operation on behalf of the client.
+///
+/// The source argument is the data source that provides the data
+/// for the eventual data transfer. If source is NULL, enter, leave
+/// and motion events are sent only to the client that initiated the
+/// drag and the client is expected to handle the data passing
+/// internally. If source is destroyed, the drag-and-drop session will be
+/// cancelled.
+///
+/// The origin surface is the surface where the drag originates and
+/// the client must have an active implicit grab that matches the
+/// serial.
+///
+/// The icon surface is an optional (can be NULL) surface that
+/// provides an icon to be moved around with the cursor.  Initially,
+/// the top-left corner of the icon surface is placed at the cursor
+/// hotspot, but subsequent wl_surface.offset requests can move the
+/// relative position. Attach requests must be confirmed with
+/// wl_surface.commit as usual. The icon surface is given the role of
+/// a drag-and-drop icon. If the icon surface already has another role,
+/// it raises a protocol error.
+///
+/// The input region is ignored for wl_surfaces with the role of a
+/// drag-and-drop icon.
+///
+/// The given source may not be used in any further set_selection or
+/// start_drag requests. Attempting to reuse a previously-used source
+/// may send a used_source error.
+///
+/// The arguments of the `wl_data_device.start_drag` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_request_start_drag_args {
+    /// Data source for the eventual transfer.
     pub source: ::std::option::Option<crate::ObjectId>,
-    pub origin: ::std::option::Option<crate::ObjectId>,
+    /// Surface where the drag originates.
+    pub origin: crate::ObjectId,
+    /// Drag-and-drop icon surface.
     pub icon: ::std::option::Option<crate::ObjectId>,
+    /// Serial number of the implicit grab on the origin.
     pub serial: u32,
 }
-impl wl_data_device_v3_request_start_drag_args {
+impl wl_data_device_request_start_drag_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_request_start_drag_args, crate::Error> {
+    ) -> Result<wl_data_device_request_start_drag_args, crate::Error> {
         let mut __reader = __value.read();
-        let source = __reader.read_object()?;
+        let source = __reader.read_nullable_object()?;
         let origin = __reader.read_object()?;
-        let icon = __reader.read_object()?;
+        let icon = __reader.read_nullable_object()?;
         let serial = __reader.read_uint()?;
         __reader.finish()?;
         Ok(Self {
@@ -2255,41 +5308,60 @@ impl wl_data_device_v3_request_start_drag_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.source);
+        __packet.push_nullable_object(self.source);
         __packet.push_object(self.origin);
-        __packet.push_object(self.icon);
+        __packet.push_nullable_object(self.icon);
         __packet.push_uint(self.serial);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_request_start_drag_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_request_start_drag_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_request_start_drag_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_request_start_drag_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_request_start_drag_args),
+        __value: (crate::ObjectId, wl_data_device_request_start_drag_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_request_set_selection_args {
+/// Copy data to the selection.
+///
+/// This request asks the compositor to set the selection
+/// to the data from the source on behalf of the client.
+///
+/// To unset the selection, set the source to NULL.
+///
+/// The given source may not be used in any further set_selection or
+/// start_drag requests. Attempting to reuse a previously-used source
+/// may send a used_source error.
+///
+/// The arguments of the `wl_data_device.set_selection` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_request_set_selection_args {
+    /// Data source for the selection.
     pub source: ::std::option::Option<crate::ObjectId>,
+    /// Serial number of the event that triggered this request.
     pub serial: u32,
 }
-impl wl_data_device_v3_request_set_selection_args {
+impl wl_data_device_request_set_selection_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_request_set_selection_args, crate::Error> {
+    ) -> Result<wl_data_device_request_set_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let source = __reader.read_object()?;
+        let source = __reader.read_nullable_object()?;
         let serial = __reader.read_uint()?;
         __reader.finish()?;
         Ok(Self { source, serial })
@@ -2299,40 +5371,46 @@ impl wl_data_device_v3_request_set_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.source);
+        __packet.push_nullable_object(self.source);
         __packet.push_uint(self.serial);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_request_set_selection_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_request_set_selection_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_data_device_v3_request_set_selection_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_request_set_selection_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_data_device_v3_request_set_selection_args,
-        ),
+        __value: (crate::ObjectId, wl_data_device_request_set_selection_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_request_release_args {}
-impl wl_data_device_v3_request_release_args {
+/// Destroy data device.
+///
+/// This request destroys the data device.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_data_device.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_request_release_args {}
+impl wl_data_device_request_release_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_request_release_args, crate::Error> {
+    ) -> Result<wl_data_device_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -2345,31 +5423,48 @@ impl wl_data_device_v3_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_request_release_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_request_release_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_request_release_args),
+        __value: (crate::ObjectId, wl_data_device_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_data_offer_args {
+/// Introduce a new wl_data_offer.
+///
+/// The data_offer event introduces a new wl_data_offer object,
+/// which will subsequently be used in either the
+/// data_device.enter event (for drag-and-drop) or the
+/// data_device.selection event (for selections).  Immediately
+/// following the data_device.data_offer event, the new data_offer
+/// object will send out data_offer.offer events to describe the
+/// mime types it offers.
+///
+/// The arguments of the `wl_data_device.data_offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_data_offer_args {
+    /// The new data_offer object.
     pub id: crate::NewObjectId,
 }
-impl wl_data_device_v3_event_data_offer_args {
+impl wl_data_device_event_data_offer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_data_offer_args, crate::Error> {
+    ) -> Result<wl_data_device_event_data_offer_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -2384,41 +5479,59 @@ impl wl_data_device_v3_event_data_offer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_data_offer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_data_offer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_data_offer_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_data_offer_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_data_offer_args),
+        __value: (crate::ObjectId, wl_data_device_event_data_offer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_enter_args {
+/// Initiate drag-and-drop session.
+///
+/// This event is sent when an active drag-and-drop pointer enters
+/// a surface owned by the client.  The position of the pointer at
+/// enter time is provided by the x and y arguments, in surface-local
+/// coordinates.
+///
+/// The arguments of the `wl_data_device.enter` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_enter_args {
+    /// Serial number of the enter event.
     pub serial: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Client surface entered.
+    pub surface: crate::ObjectId,
+    /// Surface-local x coordinate.
     pub x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub y: crate::Fixed,
+    /// Source data_offer object.
     pub id: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_data_device_v3_event_enter_args {
+impl wl_data_device_event_enter_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_enter_args, crate::Error> {
+    ) -> Result<wl_data_device_event_enter_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let surface = __reader.read_object()?;
         let x = __reader.read_fixed()?;
         let y = __reader.read_fixed()?;
-        let id = __reader.read_object()?;
+        let id = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self {
             serial,
@@ -2437,33 +5550,43 @@ impl wl_data_device_v3_event_enter_args {
         __packet.push_object(self.surface);
         __packet.push_fixed(self.x);
         __packet.push_fixed(self.y);
-        __packet.push_object(self.id);
+        __packet.push_nullable_object(self.id);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_enter_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_enter_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_enter_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_enter_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_enter_args),
+        __value: (crate::ObjectId, wl_data_device_event_enter_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_leave_args {}
-impl wl_data_device_v3_event_leave_args {
+/// End drag-and-drop session.
+///
+/// This event is sent when the drag-and-drop pointer leaves the
+/// surface and the session ends.  The client must destroy the
+/// wl_data_offer introduced at enter time at this point.
+///
+/// The arguments of the `wl_data_device.leave` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_leave_args {}
+impl wl_data_device_event_leave_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_leave_args, crate::Error> {
+    ) -> Result<wl_data_device_event_leave_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -2476,33 +5599,47 @@ impl wl_data_device_v3_event_leave_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_leave_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_leave_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_leave_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_leave_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_leave_args),
+        __value: (crate::ObjectId, wl_data_device_event_leave_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_motion_args {
+/// Drag-and-drop session motion.
+///
+/// This event is sent when the drag-and-drop pointer moves within
+/// the currently focused surface. The new position of the pointer
+/// is provided by the x and y arguments, in surface-local
+/// coordinates.
+///
+/// The arguments of the `wl_data_device.motion` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_motion_args {
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// Surface-local x coordinate.
     pub x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub y: crate::Fixed,
 }
-impl wl_data_device_v3_event_motion_args {
+impl wl_data_device_event_motion_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_motion_args, crate::Error> {
+    ) -> Result<wl_data_device_event_motion_args, crate::Error> {
         let mut __reader = __value.read();
         let time = __reader.read_uint()?;
         let x = __reader.read_fixed()?;
@@ -2521,29 +5658,51 @@ impl wl_data_device_v3_event_motion_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_motion_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_motion_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_motion_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_motion_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_motion_args),
+        __value: (crate::ObjectId, wl_data_device_event_motion_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_drop_args {}
-impl wl_data_device_v3_event_drop_args {
+/// End drag-and-drop session successfully.
+///
+/// The event is sent when a drag-and-drop operation is ended
+/// because the implicit grab is removed.
+///
+/// The drag-and-drop destination is expected to honor the last action
+/// received through wl_data_offer.action, if the resulting action is
+/// "copy" or "move", the destination can still perform
+/// wl_data_offer.receive requests, and is expected to end all
+/// transfers with a wl_data_offer.finish request.
+///
+/// If the resulting action is "ask", the action will not be considered
+/// final. The drag-and-drop destination is expected to perform one last
+/// wl_data_offer.set_actions request, or wl_data_offer.destroy in order
+/// to cancel the operation.
+///
+/// The arguments of the `wl_data_device.drop` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_drop_args {}
+impl wl_data_device_event_drop_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_drop_args, crate::Error> {
+    ) -> Result<wl_data_device_event_drop_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -2556,33 +5715,53 @@ impl wl_data_device_v3_event_drop_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_drop_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_drop_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_drop_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_drop_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_drop_args),
+        __value: (crate::ObjectId, wl_data_device_event_drop_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_v3_event_selection_args {
+/// Advertise new selection.
+///
+/// The selection event is sent out to notify the client of a new
+/// wl_data_offer for the selection for this device.  The
+/// data_device.data_offer and the data_offer.offer events are
+/// sent out immediately before this event to introduce the data
+/// offer object.  The selection event is sent to a client
+/// immediately before receiving keyboard focus and when a new
+/// selection is set while the client has keyboard focus.  The
+/// data_offer is valid until a new data_offer or NULL is received
+/// or until the client loses keyboard focus.  Switching surface with
+/// keyboard focus within the same client doesn't mean a new selection
+/// will be sent.  The client must destroy the previous selection
+/// data_offer, if any, upon receiving this event.
+///
+/// The arguments of the `wl_data_device.selection` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_event_selection_args {
+    /// Selection data_offer object.
     pub id: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_data_device_v3_event_selection_args {
+impl wl_data_device_event_selection_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_v3_event_selection_args, crate::Error> {
+    ) -> Result<wl_data_device_event_selection_args, crate::Error> {
         let mut __reader = __value.read();
-        let id = __reader.read_object()?;
+        let id = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { id })
     }
@@ -2591,65 +5770,246 @@ impl wl_data_device_v3_event_selection_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.id);
+        __packet.push_nullable_object(self.id);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_v3_event_selection_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_data_device_event_selection_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_v3_event_selection_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_data_device_event_selection_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_data_device_v3_event_selection_args),
+        __value: (crate::ObjectId, wl_data_device_event_selection_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_data_device_manager_v3_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// An event sent to a `wl_data_device` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_data_device_event {
+    /// Introduce a new wl_data_offer.
+    data_offer(wl_data_device_event_data_offer_args),
+    /// Initiate drag-and-drop session.
+    enter(wl_data_device_event_enter_args),
+    /// End drag-and-drop session.
+    leave(wl_data_device_event_leave_args),
+    /// Drag-and-drop session motion.
+    motion(wl_data_device_event_motion_args),
+    /// End drag-and-drop session successfully.
+    drop(wl_data_device_event_drop_args),
+    /// Advertise new selection.
+    selection(wl_data_device_event_selection_args),
+}
+impl wl_data_device_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::data_offer(
+                wl_data_device_event_data_offer_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::enter(
+                wl_data_device_event_enter_args::try_from_packet(__packet)?,
+            )),
+            2 => Ok(Self::leave(
+                wl_data_device_event_leave_args::try_from_packet(__packet)?,
+            )),
+            3 => Ok(Self::motion(
+                wl_data_device_event_motion_args::try_from_packet(__packet)?,
+            )),
+            4 => Ok(Self::drop(wl_data_device_event_drop_args::try_from_packet(
+                __packet,
+            )?)),
+            5 => Ok(Self::selection(
+                wl_data_device_event_selection_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_data_device",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::data_offer(_) => wl_data_device_event_data_offer_args::OPCODE,
+            Self::enter(_) => wl_data_device_event_enter_args::OPCODE,
+            Self::leave(_) => wl_data_device_event_leave_args::OPCODE,
+            Self::motion(_) => wl_data_device_event_motion_args::OPCODE,
+            Self::drop(_) => wl_data_device_event_drop_args::OPCODE,
+            Self::selection(_) => wl_data_device_event_selection_args::OPCODE,
+        }
+    }
 }
-#[allow(unused)]
-impl<'a> wl_data_device_manager_v3_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+/// The enum `wl_data_device.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_data_device_error {
+    /// Given wl_surface has another role.
+    role = 0,
+    /// Source has already been used.
+    used_source = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_data_device_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::role),
+            1 => Ok(Self::used_source),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_data_device.error",
+                value: __value,
+            }),
+        }
     }
-    pub async fn send_create_data_source(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
+}
+impl ::std::convert::From<wl_data_device_error> for u32 {
+    fn from(__value: wl_data_device_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_data_device_manager`.
+pub const wl_data_device_manager_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_data_device_manager",
+        version: 3,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "create_data_source",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("wl_data_source"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "get_data_device",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::NewId {
+                        interface: Some("wl_data_device"),
+                    },
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+        ],
+        events: &[],
+    };
+/// Data transfer interface.
+///
+/// The wl_data_device_manager is a singleton global object that
+/// provides access to inter-client data transfer mechanisms such as
+/// copy-and-paste and drag-and-drop.  These mechanisms are tied to
+/// a wl_seat and this interface lets a client get a wl_data_device
+/// corresponding to a wl_seat.
+///
+/// Depending on the version bound, the objects created from the bound
+/// wl_data_device_manager object will have different requirements for
+/// functioning properly. See wl_data_source.set_actions,
+/// wl_data_offer.accept and wl_data_offer.finish for details.
+///
+/// A proxy for a `wl_data_device_manager` object. The generated code knows this interface up to version 3.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_manager {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_data_device_manager {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_data_device_manager_interface;
+}
+impl crate::protocol::Proxy for wl_data_device_manager {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
     }
+}
+#[allow(unused)]
+impl wl_data_device_manager {
+    /// Create a new data source.
+    ///
+    /// Create a new data source.
+    ///
+    /// Returns the new `wl_data_source` object.
+    pub async fn send_create_data_source(&self) -> Result<wl_data_source, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_data_source_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_data_source {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Create a new data device.
+    ///
+    /// Create a new data device for a given seat.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`: seat associated with the data device
+    ///
+    /// Returns the new `wl_data_device` object.
     pub async fn send_get_data_device(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-        seat: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(id);
+        seat: crate::ObjectId,
+    ) -> Result<wl_data_device, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_data_device_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_object(seat);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_data_device {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
 }
-pub struct wl_data_device_manager_v3_request_create_data_source_args {
+/// Create a new data source.
+///
+/// Create a new data source.
+///
+/// The arguments of the `wl_data_device_manager.create_data_source` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_manager_request_create_data_source_args {
+    /// Data source to create.
     pub id: crate::NewObjectId,
 }
-impl wl_data_device_manager_v3_request_create_data_source_args {
+impl wl_data_device_manager_request_create_data_source_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_manager_v3_request_create_data_source_args, crate::Error> {
+    ) -> Result<wl_data_device_manager_request_create_data_source_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -2665,7 +6025,7 @@ impl wl_data_device_manager_v3_request_create_data_source_args {
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for wl_data_device_manager_v3_request_create_data_source_args
+    for wl_data_device_manager_request_create_data_source_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -2675,29 +6035,41 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_data_device_manager_v3_request_create_data_source_args,
+        wl_data_device_manager_request_create_data_source_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_data_device_manager_v3_request_create_data_source_args,
+            wl_data_device_manager_request_create_data_source_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_data_device_manager_v3_request_get_data_device_args {
+/// Create a new data device.
+///
+/// Create a new data device for a given seat.
+///
+/// The arguments of the `wl_data_device_manager.get_data_device` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_manager_request_get_data_device_args {
+    /// Data device to create.
     pub id: crate::NewObjectId,
-    pub seat: ::std::option::Option<crate::ObjectId>,
+    /// Seat associated with the data device.
+    pub seat: crate::ObjectId,
 }
-impl wl_data_device_manager_v3_request_get_data_device_args {
+impl wl_data_device_manager_request_get_data_device_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_data_device_manager_v3_request_get_data_device_args, crate::Error> {
+    ) -> Result<wl_data_device_manager_request_get_data_device_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let seat = __reader.read_object()?;
@@ -2715,7 +6087,7 @@ impl wl_data_device_manager_v3_request_get_data_device_args {
     }
 }
 impl ::std::convert::TryFrom<crate::Packet>
-    for wl_data_device_manager_v3_request_get_data_device_args
+    for wl_data_device_manager_request_get_data_device_args
 {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
@@ -2725,50 +6097,245 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_data_device_manager_v3_request_get_data_device_args,
+        wl_data_device_manager_request_get_data_device_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_data_device_manager_v3_request_get_data_device_args,
+            wl_data_device_manager_request_get_data_device_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_shell_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// Drag and drop actions.
+///
+/// This is a bitmask of the available/preferred actions in a
+/// drag-and-drop operation.
+///
+/// In the compositor, the selected action is a result of matching the
+/// actions offered by the source and destination sides.  "action" events
+/// with a "none" action will be sent to both source and destination if
+/// there is no match. All further checks will effectively happen on
+/// (source actions ∩ destination actions).
+///
+/// In addition, compositors may also pick different actions in
+/// reaction to key modifiers being pressed. One common design that
+/// is used in major toolkits (and the behavior recommended for
+/// compositors) is:
+///
+/// - If no modifiers are pressed, the first match (in bit order)
+///   will be used.
+/// - Pressing Shift selects "move", if enabled in the mask.
+/// - Pressing Control selects "copy", if enabled in the mask.
+///
+/// Behavior beyond that is considered implementation-dependent.
+/// Compositors may for example bind other modifiers (like Alt/Meta)
+/// or drags initiated with other buttons than BTN_LEFT to specific
+/// actions (e.g. "ask").
+///
+/// The bitfield `wl_data_device_manager.dnd_action`.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_data_device_manager_dnd_action(u32);
+impl wl_data_device_manager_dnd_action {
+    /// No action.
+    pub const none: Self = Self(0);
+    /// Copy action.
+    pub const copy: Self = Self(1);
+    /// Move action.
+    pub const r#move: Self = Self(2);
+    /// Ask action.
+    pub const ask: Self = Self(4);
+    const FLAGS: &'static [(&'static str, u32)] =
+        &[("none", 0), ("copy", 1), ("move", 2), ("ask", 4)];
+    /// No flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All flags known to the generated code.
+    pub const fn all() -> Self {
+        Self(7)
+    }
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// Whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+impl ::std::ops::BitOr for wl_data_device_manager_dnd_action {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl ::std::ops::BitOrAssign for wl_data_device_manager_dnd_action {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd for wl_data_device_manager_dnd_action {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl ::std::ops::BitAndAssign for wl_data_device_manager_dnd_action {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+impl ::std::fmt::Debug for wl_data_device_manager_dnd_action {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+    }
+}
+impl ::std::convert::TryFrom<u32> for wl_data_device_manager_dnd_action {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        if __value & !Self::all().0 == 0 {
+            Ok(Self(__value))
+        } else {
+            Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_data_device_manager.dnd_action",
+                value: __value,
+            })
+        }
+    }
 }
-#[allow(unused)]
-impl<'a> wl_shell_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+impl ::std::convert::From<wl_data_device_manager_dnd_action> for u32 {
+    fn from(__value: wl_data_device_manager_dnd_action) -> u32 {
+        __value.0
+    }
+}
+/// Static information about `wl_shell`.
+pub const wl_shell_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_shell",
+    version: 1,
+    requests: &[crate::protocol::MessageInfo {
+        name: "get_shell_surface",
+        destructor: false,
+        args: &[
+            crate::protocol::ArgKind::NewId {
+                interface: Some("wl_shell_surface"),
+            },
+            crate::protocol::ArgKind::Object,
+        ],
+    }],
+    events: &[],
+};
+/// Create desktop-style surfaces.
+///
+/// This interface is implemented by servers that provide
+/// desktop-style user interfaces.
+///
+/// It allows clients to associate a wl_shell_surface with
+/// a basic surface.
+///
+/// Note! This protocol is deprecated and not intended for production use.
+/// For desktop-style user interfaces, use xdg_shell. Compositors and clients
+/// should not implement this interface.
+///
+/// A proxy for a `wl_shell` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_shell {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_shell_interface;
+}
+impl crate::protocol::Proxy for wl_shell {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
     }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_shell {
+    /// Create a shell surface from a surface.
+    ///
+    /// Create a shell surface for an existing surface. This gives
+    /// the wl_surface the role of a shell surface. If the wl_surface
+    /// already has another role, it raises a protocol error.
+    ///
+    /// Only one shell surface can be associated with a given surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface`: surface to be given the shell surface role
+    ///
+    /// Returns the new `wl_shell_surface` object.
     pub async fn send_get_shell_surface(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-        surface: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
+        surface: crate::ObjectId,
+    ) -> Result<wl_shell_surface, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_shell_surface_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_object(surface);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_shell_surface {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
 }
-pub struct wl_shell_v1_request_get_shell_surface_args {
+/// Create a shell surface from a surface.
+///
+/// Create a shell surface for an existing surface. This gives
+/// the wl_surface the role of a shell surface. If the wl_surface
+/// already has another role, it raises a protocol error.
+///
+/// Only one shell surface can be associated with a given surface.
+///
+/// The arguments of the `wl_shell.get_shell_surface` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_request_get_shell_surface_args {
+    /// Shell surface to create.
     pub id: crate::NewObjectId,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface to be given the shell surface role.
+    pub surface: crate::ObjectId,
 }
-impl wl_shell_v1_request_get_shell_surface_args {
+impl wl_shell_request_get_shell_surface_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_v1_request_get_shell_surface_args, crate::Error> {
+    ) -> Result<wl_shell_request_get_shell_surface_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let surface = __reader.read_object()?;
@@ -2785,53 +6352,278 @@ impl wl_shell_v1_request_get_shell_surface_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_v1_request_get_shell_surface_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_request_get_shell_surface_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_v1_request_get_shell_surface_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_request_get_shell_surface_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_v1_request_get_shell_surface_args),
+        __value: (crate::ObjectId, wl_shell_request_get_shell_surface_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// The enum `wl_shell.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_shell_error {
+    /// Given wl_surface has another role.
+    role = 0,
+}
+impl ::std::convert::TryFrom<u32> for wl_shell_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::role),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shell.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_shell_error> for u32 {
+    fn from(__value: wl_shell_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_shell_surface`.
+pub const wl_shell_surface_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_shell_surface",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "pong",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+            crate::protocol::MessageInfo {
+                name: "move",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "resize",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_toplevel",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_transient",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Int,
+                    crate::protocol::ArgKind::Int,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_fullscreen",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_popup",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Int,
+                    crate::protocol::ArgKind::Int,
+                    crate::protocol::ArgKind::Uint,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_maximized",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_title",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_class",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "ping",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Uint],
+            },
+            crate::protocol::MessageInfo {
+                name: "configure",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::Uint,
+                    crate::protocol::ArgKind::Int,
+                    crate::protocol::ArgKind::Int,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "popup_done",
+                destructor: false,
+                args: &[],
+            },
+        ],
+    };
+/// Desktop-style metadata interface.
+///
+/// An interface that may be implemented by a wl_surface, for
+/// implementations that provide a desktop-style user interface.
+///
+/// It provides requests to treat surfaces like toplevel, fullscreen
+/// or popup windows, move, resize or maximize them, associate
+/// metadata like title and class, etc.
+///
+/// On the server side the object is automatically destroyed when
+/// the related wl_surface is destroyed. On the client side,
+/// wl_shell_surface_destroy() must be called before destroying
+/// the wl_surface object.
+///
+/// A proxy for a `wl_shell_surface` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_shell_surface {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_shell_surface_interface;
+}
+impl crate::protocol::Proxy for wl_shell_surface {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_shell_surface` object.
 #[allow(unused)]
-pub trait wl_shell_surface_v1_event_handler: crate::protocol::EventHandler {
+pub trait wl_shell_surface_event_handler: crate::protocol::EventHandler {
+    /// Ping client.
+    ///
+    /// Ping a client to check if it is receiving events and sending
+    /// requests. A client is expected to reply with a pong request.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the ping
     fn handle_ping(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Suggest resize.
+    ///
+    /// The configure event asks the client to resize its surface.
+    ///
+    /// The size is a hint, in the sense that the client is free to
+    /// ignore it if it doesn't resize, pick a smaller size (to
+    /// satisfy aspect ratio or resize in steps of NxM pixels).
+    ///
+    /// The edges parameter provides a hint about how the surface
+    /// was resized. The client may use this information to decide
+    /// how to adjust its content to the new size (e.g. a scrolling
+    /// area might adjust its content position to leave the viewable
+    /// content unmoved).
+    ///
+    /// The client is free to dismiss all but the last configure
+    /// event it received.
+    ///
+    /// The width and height arguments specify the size of the window
+    /// in surface-local coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `edges`: how the surface was resized
+    /// * `width`: new width of the surface
+    /// * `height`: new height of the surface
     fn handle_configure(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        edges: u32,
+        edges: wl_shell_surface_resize,
         width: i32,
         height: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Popup interaction is done.
+    ///
+    /// The popup_done event is sent out when a popup grab is broken,
+    /// that is, when the user clicks a surface that doesn't belong
+    /// to the client owning the popup surface.
     fn handle_popup_done(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_shell_surface",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -2848,165 +6640,335 @@ pub trait wl_shell_surface_v1_event_handler: crate::protocol::EventHandler {
                     let mut __packet_reader = __packet.read();
                     let serial = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
-                    self.handle_ping(__connection, __packet, serial).await;
-                    Ok(())
+                    self.handle_ping(__connection, __packet, serial).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let edges = __packet_reader.read_uint()?;
+                    let edges =
+                        <wl_shell_surface_resize as ::std::convert::TryFrom<u32>>::try_from(edges)?;
                     let width = __packet_reader.read_int()?;
                     let height = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_configure(__connection, __packet, edges, width, height)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_popup_done(__connection, __packet).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_popup_done(__connection, __packet).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_shell_surface_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_shell_surface_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_pong(
-        &self,
-        __object_id: crate::ObjectId,
-        serial: u32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
+impl wl_shell_surface {
+    /// Respond to a ping event.
+    ///
+    /// A client must respond to a ping event with a pong request or
+    /// the client may be deemed unresponsive.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the ping event
+    pub async fn send_pong(&self, serial: u32) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
         __packet.push_uint(serial);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_move(
-        &self,
-        __object_id: crate::ObjectId,
-        seat: ::std::option::Option<crate::ObjectId>,
-        serial: u32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Start an interactive move.
+    ///
+    /// Start a pointer-driven move of the surface.
+    ///
+    /// This request must be used in response to a button press event.
+    /// The server may ignore move requests depending on the state of
+    /// the surface (e.g. fullscreen or maximized).
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`: seat whose pointer is used
+    /// * `serial`: serial number of the implicit grab on the pointer
+    pub async fn send_move(&self, seat: crate::ObjectId, serial: u32) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
         __packet.push_object(seat);
         __packet.push_uint(serial);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Start an interactive resize.
+    ///
+    /// Start a pointer-driven resizing of the surface.
+    ///
+    /// This request must be used in response to a button press event.
+    /// The server may ignore resize requests depending on the state of
+    /// the surface (e.g. fullscreen or maximized).
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`: seat whose pointer is used
+    /// * `serial`: serial number of the implicit grab on the pointer
+    /// * `edges`: which edge or corner is being dragged
     pub async fn send_resize(
         &self,
-        __object_id: crate::ObjectId,
-        seat: ::std::option::Option<crate::ObjectId>,
+        seat: crate::ObjectId,
         serial: u32,
-        edges: u32,
+        edges: wl_shell_surface_resize,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
         __packet.push_object(seat);
         __packet.push_uint(serial);
-        __packet.push_uint(edges);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_toplevel(
-        &self,
-        __object_id: crate::ObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 3);
-        self.connection.send_packet(&__packet).await
-    }
+        __packet.push_uint(u32::from(edges));
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Make the surface a toplevel surface.
+    ///
+    /// Map the surface as a toplevel surface.
+    ///
+    /// A toplevel surface is not fullscreen, maximized or transient.
+    pub async fn send_set_toplevel(&self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 3);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Make the surface a transient surface.
+    ///
+    /// Map the surface relative to an existing surface.
+    ///
+    /// The x and y arguments specify the location of the upper left
+    /// corner of the surface relative to the upper left corner of the
+    /// parent surface, in surface-local coordinates.
+    ///
+    /// The flags argument controls details of the transient behaviour.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent`: parent surface
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
+    /// * `flags`: transient surface behavior
     pub async fn send_set_transient(
         &self,
-        __object_id: crate::ObjectId,
-        parent: ::std::option::Option<crate::ObjectId>,
+        parent: crate::ObjectId,
         x: i32,
         y: i32,
-        flags: u32,
+        flags: wl_shell_surface_transient,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 4);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 4);
         __packet.push_object(parent);
         __packet.push_int(x);
         __packet.push_int(y);
-        __packet.push_uint(flags);
-        self.connection.send_packet(&__packet).await
-    }
+        __packet.push_uint(u32::from(flags));
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Make the surface a fullscreen surface.
+    ///
+    /// Map the surface as a fullscreen surface.
+    ///
+    /// If an output parameter is given then the surface will be made
+    /// fullscreen on that output. If the client does not specify the
+    /// output then the compositor will apply its policy - usually
+    /// choosing the output on which the surface has the biggest surface
+    /// area.
+    ///
+    /// The client may specify a method to resolve a size conflict
+    /// between the output size and the surface size - this is provided
+    /// through the method parameter.
+    ///
+    /// The framerate parameter is used only when the method is set
+    /// to "driver", to indicate the preferred framerate. A value of 0
+    /// indicates that the client does not care about framerate.  The
+    /// framerate is specified in mHz, that is framerate of 60000 is 60Hz.
+    ///
+    /// A method of "scale" or "driver" implies a scaling operation of
+    /// the surface, either via a direct scaling operation or a change of
+    /// the output mode. This will override any kind of output scaling, so
+    /// that mapping a surface with a buffer size equal to the mode can
+    /// fill the screen independent of buffer_scale.
+    ///
+    /// A method of "fill" means we don't scale up the buffer, however
+    /// any output scale is applied. This means that you may run into
+    /// an edge case where the application maps a buffer with the same
+    /// size of the output mode but buffer_scale 1 (thus making a
+    /// surface larger than the output). In this case it is allowed to
+    /// downscale the results to fit the screen.
+    ///
+    /// The compositor must reply to this request with a configure event
+    /// with the dimensions for the output on which the surface will
+    /// be made fullscreen.
+    ///
+    /// # Arguments
+    ///
+    /// * `method`: method for resolving size conflict
+    /// * `framerate`: framerate in mHz
+    /// * `output`: output on which the surface is to be fullscreen
     pub async fn send_set_fullscreen(
         &self,
-        __object_id: crate::ObjectId,
-        method: u32,
+        method: wl_shell_surface_fullscreen_method,
         framerate: u32,
         output: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 5);
-        __packet.push_uint(method);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 5);
+        __packet.push_uint(u32::from(method));
         __packet.push_uint(framerate);
-        __packet.push_object(output);
-        self.connection.send_packet(&__packet).await
-    }
+        __packet.push_nullable_object(output);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Make the surface a popup surface.
+    ///
+    /// Map the surface as a popup.
+    ///
+    /// A popup surface is a transient surface with an added pointer
+    /// grab.
+    ///
+    /// An existing implicit grab will be changed to owner-events mode,
+    /// and the popup grab will continue after the implicit grab ends
+    /// (i.e. releasing the mouse button does not cause the popup to
+    /// be unmapped).
+    ///
+    /// The popup grab continues until the window is destroyed or a
+    /// mouse button is pressed in any other client's window. A click
+    /// in any of the client's surfaces is reported as normal, however,
+    /// clicks in other clients' surfaces will be discarded and trigger
+    /// the callback.
+    ///
+    /// The x and y arguments specify the location of the upper left
+    /// corner of the surface relative to the upper left corner of the
+    /// parent surface, in surface-local coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`: seat whose pointer is used
+    /// * `serial`: serial number of the implicit grab on the pointer
+    /// * `parent`: parent surface
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
+    /// * `flags`: transient surface behavior
     pub async fn send_set_popup(
         &self,
-        __object_id: crate::ObjectId,
-        seat: ::std::option::Option<crate::ObjectId>,
+        seat: crate::ObjectId,
         serial: u32,
-        parent: ::std::option::Option<crate::ObjectId>,
+        parent: crate::ObjectId,
         x: i32,
         y: i32,
-        flags: u32,
+        flags: wl_shell_surface_transient,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 6);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 6);
         __packet.push_object(seat);
         __packet.push_uint(serial);
         __packet.push_object(parent);
         __packet.push_int(x);
         __packet.push_int(y);
-        __packet.push_uint(flags);
-        self.connection.send_packet(&__packet).await
-    }
+        __packet.push_uint(u32::from(flags));
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Make the surface a maximized surface.
+    ///
+    /// Map the surface as a maximized surface.
+    ///
+    /// If an output parameter is given then the surface will be
+    /// maximized on that output. If the client does not specify the
+    /// output then the compositor will apply its policy - usually
+    /// choosing the output on which the surface has the biggest surface
+    /// area.
+    ///
+    /// The compositor will reply with a configure event telling
+    /// the expected new surface size. The operation is completed
+    /// on the next buffer attach to this surface.
+    ///
+    /// A maximized surface typically fills the entire output it is
+    /// bound to, except for desktop elements such as panels. This is
+    /// the main difference between a maximized shell surface and a
+    /// fullscreen shell surface.
+    ///
+    /// The details depend on the compositor implementation.
+    ///
+    /// # Arguments
+    ///
+    /// * `output`: output on which the surface is to be maximized
     pub async fn send_set_maximized(
         &self,
-        __object_id: crate::ObjectId,
         output: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 7);
-        __packet.push_object(output);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_title(
-        &self,
-        __object_id: crate::ObjectId,
-        title: &str,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 8);
-        __packet.push_str(&title);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_class(
-        &self,
-        __object_id: crate::ObjectId,
-        class_: &str,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 9);
-        __packet.push_str(&class_);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_shell_surface_v1_request_pong_args {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 7);
+        __packet.push_nullable_object(output);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set surface title.
+    ///
+    /// Set a short title for the surface.
+    ///
+    /// This string may be used to identify the surface in a task bar,
+    /// window list, or other user interface elements provided by the
+    /// compositor.
+    ///
+    /// The string must be encoded in UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `title`: surface title
+    pub async fn send_set_title(&self, title: &str) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 8);
+        __packet.push_str(title);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set surface class.
+    ///
+    /// Set a class for the surface.
+    ///
+    /// The surface class identifies the general class of applications
+    /// to which the surface belongs. A common convention is to use the
+    /// file name (or the full path if it is a non-standard location) of
+    /// the application's .desktop file as the class.
+    ///
+    /// # Arguments
+    ///
+    /// * `class_`: surface class
+    pub async fn send_set_class(&self, class_: &str) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 9);
+        __packet.push_str(class_);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Respond to a ping event.
+///
+/// A client must respond to a ping event with a pong request or
+/// the client may be deemed unresponsive.
+///
+/// The arguments of the `wl_shell_surface.pong` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_pong_args {
+    /// Serial number of the ping event.
     pub serial: u32,
 }
-impl wl_shell_surface_v1_request_pong_args {
+impl wl_shell_surface_request_pong_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_pong_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_pong_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         __reader.finish()?;
@@ -3021,32 +6983,48 @@ impl wl_shell_surface_v1_request_pong_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_pong_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_pong_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_pong_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_pong_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_pong_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_pong_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_move_args {
-    pub seat: ::std::option::Option<crate::ObjectId>,
+/// Start an interactive move.
+///
+/// Start a pointer-driven move of the surface.
+///
+/// This request must be used in response to a button press event.
+/// The server may ignore move requests depending on the state of
+/// the surface (e.g. fullscreen or maximized).
+///
+/// The arguments of the `wl_shell_surface.move` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_move_args {
+    /// Seat whose pointer is used.
+    pub seat: crate::ObjectId,
+    /// Serial number of the implicit grab on the pointer.
     pub serial: u32,
 }
-impl wl_shell_surface_v1_request_move_args {
+impl wl_shell_surface_request_move_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_move_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_move_args, crate::Error> {
         let mut __reader = __value.read();
         let seat = __reader.read_object()?;
         let serial = __reader.read_uint()?;
@@ -3063,37 +7041,55 @@ impl wl_shell_surface_v1_request_move_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_move_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_move_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_move_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_move_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_move_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_move_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_resize_args {
-    pub seat: ::std::option::Option<crate::ObjectId>,
+/// Start an interactive resize.
+///
+/// Start a pointer-driven resizing of the surface.
+///
+/// This request must be used in response to a button press event.
+/// The server may ignore resize requests depending on the state of
+/// the surface (e.g. fullscreen or maximized).
+///
+/// The arguments of the `wl_shell_surface.resize` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_resize_args {
+    /// Seat whose pointer is used.
+    pub seat: crate::ObjectId,
+    /// Serial number of the implicit grab on the pointer.
     pub serial: u32,
-    pub edges: u32,
+    /// Which edge or corner is being dragged.
+    pub edges: wl_shell_surface_resize,
 }
-impl wl_shell_surface_v1_request_resize_args {
+impl wl_shell_surface_request_resize_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_resize_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_resize_args, crate::Error> {
         let mut __reader = __value.read();
         let seat = __reader.read_object()?;
         let serial = __reader.read_uint()?;
         let edges = __reader.read_uint()?;
+        let edges = <wl_shell_surface_resize as ::std::convert::TryFrom<u32>>::try_from(edges)?;
         __reader.finish()?;
         Ok(Self {
             seat,
@@ -3108,33 +7104,45 @@ impl wl_shell_surface_v1_request_resize_args {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
         __packet.push_object(self.seat);
         __packet.push_uint(self.serial);
-        __packet.push_uint(self.edges);
+        __packet.push_uint(u32::from(self.edges));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_resize_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_resize_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_resize_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_resize_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_resize_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_resize_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_toplevel_args {}
-impl wl_shell_surface_v1_request_set_toplevel_args {
+/// Make the surface a toplevel surface.
+///
+/// Map the surface as a toplevel surface.
+///
+/// A toplevel surface is not fullscreen, maximized or transient.
+///
+/// The arguments of the `wl_shell_surface.set_toplevel` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_toplevel_args {}
+impl wl_shell_surface_request_set_toplevel_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_toplevel_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_toplevel_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -3147,45 +7155,60 @@ impl wl_shell_surface_v1_request_set_toplevel_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_toplevel_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_toplevel_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
-}
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_shell_surface_v1_request_set_toplevel_args,
-    )> for crate::Packet
+}
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_toplevel_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_shell_surface_v1_request_set_toplevel_args,
-        ),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_toplevel_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_transient_args {
-    pub parent: ::std::option::Option<crate::ObjectId>,
+/// Make the surface a transient surface.
+///
+/// Map the surface relative to an existing surface.
+///
+/// The x and y arguments specify the location of the upper left
+/// corner of the surface relative to the upper left corner of the
+/// parent surface, in surface-local coordinates.
+///
+/// The flags argument controls details of the transient behaviour.
+///
+/// The arguments of the `wl_shell_surface.set_transient` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_transient_args {
+    /// Parent surface.
+    pub parent: crate::ObjectId,
+    /// Surface-local x coordinate.
     pub x: i32,
+    /// Surface-local y coordinate.
     pub y: i32,
-    pub flags: u32,
+    /// Transient surface behavior.
+    pub flags: wl_shell_surface_transient,
 }
-impl wl_shell_surface_v1_request_set_transient_args {
+impl wl_shell_surface_request_set_transient_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_transient_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_transient_args, crate::Error> {
         let mut __reader = __value.read();
         let parent = __reader.read_object()?;
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
         let flags = __reader.read_uint()?;
+        let flags = <wl_shell_surface_transient as ::std::convert::TryFrom<u32>>::try_from(flags)?;
         __reader.finish()?;
         Ok(Self {
             parent,
@@ -3202,47 +7225,88 @@ impl wl_shell_surface_v1_request_set_transient_args {
         __packet.push_object(self.parent);
         __packet.push_int(self.x);
         __packet.push_int(self.y);
-        __packet.push_uint(self.flags);
+        __packet.push_uint(u32::from(self.flags));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_transient_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_transient_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_shell_surface_v1_request_set_transient_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_transient_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_shell_surface_v1_request_set_transient_args,
-        ),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_transient_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_fullscreen_args {
-    pub method: u32,
+/// Make the surface a fullscreen surface.
+///
+/// Map the surface as a fullscreen surface.
+///
+/// If an output parameter is given then the surface will be made
+/// fullscreen on that output. If the client does not specify the
+/// output then the compositor will apply its policy - usually
+/// choosing the output on which the surface has the biggest surface
+/// area.
+///
+/// The client may specify a method to resolve a size conflict
+/// between the output size and the surface size - this is provided
+/// through the method parameter.
+///
+/// The framerate parameter is used only when the method is set
+/// to "driver", to indicate the preferred framerate. A value of 0
+/// indicates that the client does not care about framerate.  The
+/// framerate is specified in mHz, that is framerate of 60000 is 60Hz.
+///
+/// A method of "scale" or "driver" implies a scaling operation of
+/// the surface, either via a direct scaling operation or a change of
+/// the output mode. This will override any kind of output scaling, so
+/// that mapping a surface with a buffer size equal to the mode can
+/// fill the screen independent of buffer_scale.
+///
+/// A method of "fill" means we don't scale up the buffer, however
+/// any output scale is applied. This means that you may run into
+/// an edge case where the application maps a buffer with the same
+/// size of the output mode but buffer_scale 1 (thus making a
+/// surface larger than the output). In this case it is allowed to
+/// downscale the results to fit the screen.
+///
+/// The compositor must reply to this request with a configure event
+/// with the dimensions for the output on which the surface will
+/// be made fullscreen.
+///
+/// The arguments of the `wl_shell_surface.set_fullscreen` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_fullscreen_args {
+    /// Method for resolving size conflict.
+    pub method: wl_shell_surface_fullscreen_method,
+    /// Framerate in mHz.
     pub framerate: u32,
+    /// Output on which the surface is to be fullscreen.
     pub output: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_shell_surface_v1_request_set_fullscreen_args {
+impl wl_shell_surface_request_set_fullscreen_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_fullscreen_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_fullscreen_args, crate::Error> {
         let mut __reader = __value.read();
         let method = __reader.read_uint()?;
+        let method =
+            <wl_shell_surface_fullscreen_method as ::std::convert::TryFrom<u32>>::try_from(method)?;
         let framerate = __reader.read_uint()?;
-        let output = __reader.read_object()?;
+        let output = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self {
             method,
@@ -3255,13 +7319,13 @@ impl wl_shell_surface_v1_request_set_fullscreen_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.method);
+        __packet.push_uint(u32::from(self.method));
         __packet.push_uint(self.framerate);
-        __packet.push_object(self.output);
+        __packet.push_nullable_object(self.output);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_fullscreen_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_fullscreen_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -3270,33 +7334,67 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_shell_surface_v1_request_set_fullscreen_args,
+        wl_shell_surface_request_set_fullscreen_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_shell_surface_v1_request_set_fullscreen_args,
+            wl_shell_surface_request_set_fullscreen_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_popup_args {
-    pub seat: ::std::option::Option<crate::ObjectId>,
+/// Make the surface a popup surface.
+///
+/// Map the surface as a popup.
+///
+/// A popup surface is a transient surface with an added pointer
+/// grab.
+///
+/// An existing implicit grab will be changed to owner-events mode,
+/// and the popup grab will continue after the implicit grab ends
+/// (i.e. releasing the mouse button does not cause the popup to
+/// be unmapped).
+///
+/// The popup grab continues until the window is destroyed or a
+/// mouse button is pressed in any other client's window. A click
+/// in any of the client's surfaces is reported as normal, however,
+/// clicks in other clients' surfaces will be discarded and trigger
+/// the callback.
+///
+/// The x and y arguments specify the location of the upper left
+/// corner of the surface relative to the upper left corner of the
+/// parent surface, in surface-local coordinates.
+///
+/// The arguments of the `wl_shell_surface.set_popup` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_popup_args {
+    /// Seat whose pointer is used.
+    pub seat: crate::ObjectId,
+    /// Serial number of the implicit grab on the pointer.
     pub serial: u32,
-    pub parent: ::std::option::Option<crate::ObjectId>,
+    /// Parent surface.
+    pub parent: crate::ObjectId,
+    /// Surface-local x coordinate.
     pub x: i32,
+    /// Surface-local y coordinate.
     pub y: i32,
-    pub flags: u32,
+    /// Transient surface behavior.
+    pub flags: wl_shell_surface_transient,
 }
-impl wl_shell_surface_v1_request_set_popup_args {
+impl wl_shell_surface_request_set_popup_args {
     pub const OPCODE: u16 = 6;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_popup_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_popup_args, crate::Error> {
         let mut __reader = __value.read();
         let seat = __reader.read_object()?;
         let serial = __reader.read_uint()?;
@@ -3304,6 +7402,7 @@ impl wl_shell_surface_v1_request_set_popup_args {
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
         let flags = __reader.read_uint()?;
+        let flags = <wl_shell_surface_transient as ::std::convert::TryFrom<u32>>::try_from(flags)?;
         __reader.finish()?;
         Ok(Self {
             seat,
@@ -3324,37 +7423,65 @@ impl wl_shell_surface_v1_request_set_popup_args {
         __packet.push_object(self.parent);
         __packet.push_int(self.x);
         __packet.push_int(self.y);
-        __packet.push_uint(self.flags);
+        __packet.push_uint(u32::from(self.flags));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_popup_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_popup_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_set_popup_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_popup_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_set_popup_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_popup_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_maximized_args {
+/// Make the surface a maximized surface.
+///
+/// Map the surface as a maximized surface.
+///
+/// If an output parameter is given then the surface will be
+/// maximized on that output. If the client does not specify the
+/// output then the compositor will apply its policy - usually
+/// choosing the output on which the surface has the biggest surface
+/// area.
+///
+/// The compositor will reply with a configure event telling
+/// the expected new surface size. The operation is completed
+/// on the next buffer attach to this surface.
+///
+/// A maximized surface typically fills the entire output it is
+/// bound to, except for desktop elements such as panels. This is
+/// the main difference between a maximized shell surface and a
+/// fullscreen shell surface.
+///
+/// The details depend on the compositor implementation.
+///
+/// The arguments of the `wl_shell_surface.set_maximized` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_maximized_args {
+    /// Output on which the surface is to be maximized.
     pub output: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_shell_surface_v1_request_set_maximized_args {
+impl wl_shell_surface_request_set_maximized_args {
     pub const OPCODE: u16 = 7;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_maximized_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_maximized_args, crate::Error> {
         let mut __reader = __value.read();
-        let output = __reader.read_object()?;
+        let output = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { output })
     }
@@ -3363,41 +7490,52 @@ impl wl_shell_surface_v1_request_set_maximized_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.output);
+        __packet.push_nullable_object(self.output);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_maximized_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_maximized_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_shell_surface_v1_request_set_maximized_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_maximized_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_shell_surface_v1_request_set_maximized_args,
-        ),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_maximized_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_title_args {
+/// Set surface title.
+///
+/// Set a short title for the surface.
+///
+/// This string may be used to identify the surface in a task bar,
+/// window list, or other user interface elements provided by the
+/// compositor.
+///
+/// The string must be encoded in UTF-8.
+///
+/// The arguments of the `wl_shell_surface.set_title` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_title_args {
+    /// Surface title.
     pub title: ::std::string::String,
 }
-impl wl_shell_surface_v1_request_set_title_args {
+impl wl_shell_surface_request_set_title_args {
     pub const OPCODE: u16 = 8;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_title_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_title_args, crate::Error> {
         let mut __reader = __value.read();
         let title = __reader.read_str()?;
         __reader.finish()?;
@@ -3412,31 +7550,47 @@ impl wl_shell_surface_v1_request_set_title_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_title_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_title_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_set_title_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_title_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_set_title_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_title_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_request_set_class_args {
+/// Set surface class.
+///
+/// Set a class for the surface.
+///
+/// The surface class identifies the general class of applications
+/// to which the surface belongs. A common convention is to use the
+/// file name (or the full path if it is a non-standard location) of
+/// the application's .desktop file as the class.
+///
+/// The arguments of the `wl_shell_surface.set_class` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_request_set_class_args {
+    /// Surface class.
     pub class_: ::std::string::String,
 }
-impl wl_shell_surface_v1_request_set_class_args {
+impl wl_shell_surface_request_set_class_args {
     pub const OPCODE: u16 = 9;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_request_set_class_args, crate::Error> {
+    ) -> Result<wl_shell_surface_request_set_class_args, crate::Error> {
         let mut __reader = __value.read();
         let class_ = __reader.read_str()?;
         __reader.finish()?;
@@ -3451,31 +7605,43 @@ impl wl_shell_surface_v1_request_set_class_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_request_set_class_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_request_set_class_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_request_set_class_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_request_set_class_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_request_set_class_args),
+        __value: (crate::ObjectId, wl_shell_surface_request_set_class_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_event_ping_args {
+/// Ping client.
+///
+/// Ping a client to check if it is receiving events and sending
+/// requests. A client is expected to reply with a pong request.
+///
+/// The arguments of the `wl_shell_surface.ping` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_event_ping_args {
+    /// Serial number of the ping.
     pub serial: u32,
 }
-impl wl_shell_surface_v1_event_ping_args {
+impl wl_shell_surface_event_ping_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_event_ping_args, crate::Error> {
+    ) -> Result<wl_shell_surface_event_ping_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         __reader.finish()?;
@@ -3490,35 +7656,65 @@ impl wl_shell_surface_v1_event_ping_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_event_ping_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_event_ping_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_event_ping_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_event_ping_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_event_ping_args),
+        __value: (crate::ObjectId, wl_shell_surface_event_ping_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_event_configure_args {
-    pub edges: u32,
+/// Suggest resize.
+///
+/// The configure event asks the client to resize its surface.
+///
+/// The size is a hint, in the sense that the client is free to
+/// ignore it if it doesn't resize, pick a smaller size (to
+/// satisfy aspect ratio or resize in steps of NxM pixels).
+///
+/// The edges parameter provides a hint about how the surface
+/// was resized. The client may use this information to decide
+/// how to adjust its content to the new size (e.g. a scrolling
+/// area might adjust its content position to leave the viewable
+/// content unmoved).
+///
+/// The client is free to dismiss all but the last configure
+/// event it received.
+///
+/// The width and height arguments specify the size of the window
+/// in surface-local coordinates.
+///
+/// The arguments of the `wl_shell_surface.configure` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_event_configure_args {
+    /// How the surface was resized.
+    pub edges: wl_shell_surface_resize,
+    /// New width of the surface.
     pub width: i32,
+    /// New height of the surface.
     pub height: i32,
 }
-impl wl_shell_surface_v1_event_configure_args {
+impl wl_shell_surface_event_configure_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_event_configure_args, crate::Error> {
+    ) -> Result<wl_shell_surface_event_configure_args, crate::Error> {
         let mut __reader = __value.read();
         let edges = __reader.read_uint()?;
+        let edges = <wl_shell_surface_resize as ::std::convert::TryFrom<u32>>::try_from(edges)?;
         let width = __reader.read_int()?;
         let height = __reader.read_int()?;
         __reader.finish()?;
@@ -3533,35 +7729,47 @@ impl wl_shell_surface_v1_event_configure_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.edges);
+        __packet.push_uint(u32::from(self.edges));
         __packet.push_int(self.width);
         __packet.push_int(self.height);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_event_configure_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_event_configure_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_event_configure_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_event_configure_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_event_configure_args),
+        __value: (crate::ObjectId, wl_shell_surface_event_configure_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_shell_surface_v1_event_popup_done_args {}
-impl wl_shell_surface_v1_event_popup_done_args {
+/// Popup interaction is done.
+///
+/// The popup_done event is sent out when a popup grab is broken,
+/// that is, when the user clicks a surface that doesn't belong
+/// to the client owning the popup surface.
+///
+/// The arguments of the `wl_shell_surface.popup_done` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_event_popup_done_args {}
+impl wl_shell_surface_event_popup_done_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_shell_surface_v1_event_popup_done_args, crate::Error> {
+    ) -> Result<wl_shell_surface_event_popup_done_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -3574,58 +7782,581 @@ impl wl_shell_surface_v1_event_popup_done_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_v1_event_popup_done_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_shell_surface_event_popup_done_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_v1_event_popup_done_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_shell_surface_event_popup_done_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_shell_surface_v1_event_popup_done_args),
+        __value: (crate::ObjectId, wl_shell_surface_event_popup_done_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_shell_surface` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_shell_surface_event {
+    /// Ping client.
+    ping(wl_shell_surface_event_ping_args),
+    /// Suggest resize.
+    configure(wl_shell_surface_event_configure_args),
+    /// Popup interaction is done.
+    popup_done(wl_shell_surface_event_popup_done_args),
+}
+impl wl_shell_surface_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::ping(
+                wl_shell_surface_event_ping_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::configure(
+                wl_shell_surface_event_configure_args::try_from_packet(__packet)?,
+            )),
+            2 => Ok(Self::popup_done(
+                wl_shell_surface_event_popup_done_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_shell_surface",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::ping(_) => wl_shell_surface_event_ping_args::OPCODE,
+            Self::configure(_) => wl_shell_surface_event_configure_args::OPCODE,
+            Self::popup_done(_) => wl_shell_surface_event_popup_done_args::OPCODE,
+        }
+    }
+}
+/// Edge values for resizing.
+///
+/// These values are used to indicate which edge of a surface
+/// is being dragged in a resize operation. The server may
+/// use this information to adapt its behavior, e.g. choose
+/// an appropriate cursor image.
+///
+/// The bitfield `wl_shell_surface.resize`.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_resize(u32);
+impl wl_shell_surface_resize {
+    /// No edge.
+    pub const none: Self = Self(0);
+    /// Top edge.
+    pub const top: Self = Self(1);
+    /// Bottom edge.
+    pub const bottom: Self = Self(2);
+    /// Left edge.
+    pub const left: Self = Self(4);
+    /// Top and left edges.
+    pub const top_left: Self = Self(5);
+    /// Bottom and left edges.
+    pub const bottom_left: Self = Self(6);
+    /// Right edge.
+    pub const right: Self = Self(8);
+    /// Top and right edges.
+    pub const top_right: Self = Self(9);
+    /// Bottom and right edges.
+    pub const bottom_right: Self = Self(10);
+    const FLAGS: &'static [(&'static str, u32)] = &[
+        ("none", 0),
+        ("top", 1),
+        ("bottom", 2),
+        ("left", 4),
+        ("top_left", 5),
+        ("bottom_left", 6),
+        ("right", 8),
+        ("top_right", 9),
+        ("bottom_right", 10),
+    ];
+    /// No flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All flags known to the generated code.
+    pub const fn all() -> Self {
+        Self(15)
+    }
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// Whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+impl ::std::ops::BitOr for wl_shell_surface_resize {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl ::std::ops::BitOrAssign for wl_shell_surface_resize {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd for wl_shell_surface_resize {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl ::std::ops::BitAndAssign for wl_shell_surface_resize {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+impl ::std::fmt::Debug for wl_shell_surface_resize {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+    }
+}
+impl ::std::convert::TryFrom<u32> for wl_shell_surface_resize {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        if __value & !Self::all().0 == 0 {
+            Ok(Self(__value))
+        } else {
+            Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shell_surface.resize",
+                value: __value,
+            })
+        }
+    }
+}
+impl ::std::convert::From<wl_shell_surface_resize> for u32 {
+    fn from(__value: wl_shell_surface_resize) -> u32 {
+        __value.0
+    }
+}
+/// Details of transient behaviour.
+///
+/// These flags specify details of the expected behaviour
+/// of transient surfaces. Used in the set_transient request.
+///
+/// The bitfield `wl_shell_surface.transient`.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_shell_surface_transient(u32);
+impl wl_shell_surface_transient {
+    /// Do not set keyboard focus.
+    pub const inactive: Self = Self(1);
+    const FLAGS: &'static [(&'static str, u32)] = &[("inactive", 1)];
+    /// No flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All flags known to the generated code.
+    pub const fn all() -> Self {
+        Self(1)
+    }
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// Whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+impl ::std::ops::BitOr for wl_shell_surface_transient {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl ::std::ops::BitOrAssign for wl_shell_surface_transient {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd for wl_shell_surface_transient {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl ::std::ops::BitAndAssign for wl_shell_surface_transient {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+impl ::std::fmt::Debug for wl_shell_surface_transient {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+    }
+}
+impl ::std::convert::TryFrom<u32> for wl_shell_surface_transient {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        if __value & !Self::all().0 == 0 {
+            Ok(Self(__value))
+        } else {
+            Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shell_surface.transient",
+                value: __value,
+            })
+        }
+    }
+}
+impl ::std::convert::From<wl_shell_surface_transient> for u32 {
+    fn from(__value: wl_shell_surface_transient) -> u32 {
+        __value.0
+    }
+}
+/// Different method to set the surface fullscreen.
+///
+/// Hints to indicate to the compositor how to deal with a conflict
+/// between the dimensions of the surface and the dimensions of the
+/// output. The compositor is free to ignore this parameter.
+///
+/// The enum `wl_shell_surface.fullscreen_method`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_shell_surface_fullscreen_method {
+    /// No preference, apply default policy.
+    default = 0,
+    /// Scale, preserve the surface's aspect ratio and center on output.
+    scale = 1,
+    /// Switch output mode to the smallest mode that can fit the surface, add black borders to compensate size mismatch.
+    driver = 2,
+    /// No upscaling, center on output and add black borders to compensate size mismatch.
+    fill = 3,
+}
+impl ::std::convert::TryFrom<u32> for wl_shell_surface_fullscreen_method {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::default),
+            1 => Ok(Self::scale),
+            2 => Ok(Self::driver),
+            3 => Ok(Self::fill),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_shell_surface.fullscreen_method",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_shell_surface_fullscreen_method> for u32 {
+    fn from(__value: wl_shell_surface_fullscreen_method) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_surface`.
+pub const wl_surface_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_surface",
+    version: 6,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "destroy",
+            destructor: true,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "attach",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "damage",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "frame",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_callback"),
+            }],
+        },
+        crate::protocol::MessageInfo {
+            name: "set_opaque_region",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Object],
+        },
+        crate::protocol::MessageInfo {
+            name: "set_input_region",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Object],
+        },
+        crate::protocol::MessageInfo {
+            name: "commit",
+            destructor: false,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "set_buffer_transform",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int],
+        },
+        crate::protocol::MessageInfo {
+            name: "set_buffer_scale",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int],
+        },
+        crate::protocol::MessageInfo {
+            name: "damage_buffer",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "offset",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int, crate::protocol::ArgKind::Int],
+        },
+    ],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "enter",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Object],
+        },
+        crate::protocol::MessageInfo {
+            name: "leave",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Object],
+        },
+        crate::protocol::MessageInfo {
+            name: "preferred_buffer_scale",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int],
+        },
+        crate::protocol::MessageInfo {
+            name: "preferred_buffer_transform",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Uint],
+        },
+    ],
+};
+/// An onscreen surface.
+///
+/// A surface is a rectangular area that may be displayed on zero
+/// or more outputs, and shown any number of times at the compositor's
+/// discretion. They can present wl_buffers, receive user input, and
+/// define a local coordinate system.
+///
+/// The size of a surface (and relative positions on it) is described
+/// in surface-local coordinates, which may differ from the buffer
+/// coordinates of the pixel content, in case a buffer_transform
+/// or a buffer_scale is used.
+///
+/// A surface without a "role" is fairly useless: a compositor does
+/// not know where, when or how to present it. The role is the
+/// purpose of a wl_surface. Examples of roles are a cursor for a
+/// pointer (as set by wl_pointer.set_cursor), a drag icon
+/// (wl_data_device.start_drag), a sub-surface
+/// (wl_subcompositor.get_subsurface), and a window as defined by a
+/// shell protocol (e.g. wl_shell.get_shell_surface).
+///
+/// A surface can have only one role at a time. Initially a
+/// wl_surface does not have a role. Once a wl_surface is given a
+/// role, it is set permanently for the whole lifetime of the
+/// wl_surface object. Giving the current role again is allowed,
+/// unless explicitly forbidden by the relevant interface
+/// specification.
+///
+/// Surface roles are given by requests in other interfaces such as
+/// wl_pointer.set_cursor. The request should explicitly mention
+/// that this request gives a role to a wl_surface. Often, this
+/// request also creates a new protocol object that represents the
+/// role and adds additional functionality to wl_surface. When a
+/// client wants to destroy a wl_surface, they must destroy this role
+/// object before the wl_surface, otherwise a defunct_role_object error is
+/// sent.
+///
+/// Destroying the role object does not remove the role from the
+/// wl_surface, but it may stop the wl_surface from "playing the role".
+/// For instance, if a wl_subsurface object is destroyed, the wl_surface
+/// it was created for will be unmapped and forget its position and
+/// z-order. It is allowed to create a wl_subsurface for the same
+/// wl_surface again, but it is not allowed to use the wl_surface as
+/// a cursor (cursor is a different role than sub-surface, and role
+/// switching is not allowed).
+///
+/// A proxy for a `wl_surface` object. The generated code knows this interface up to version 6.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_surface {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_surface_interface;
+}
+impl crate::protocol::Proxy for wl_surface {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_surface` object.
 #[allow(unused)]
-pub trait wl_surface_v6_event_handler: crate::protocol::EventHandler {
+pub trait wl_surface_event_handler: crate::protocol::EventHandler {
+    /// Surface enters an output.
+    ///
+    /// This is emitted whenever a surface's creation, movement, or resizing
+    /// results in some part of it being within the scanout region of an
+    /// output.
+    ///
+    /// Note that a surface may be overlapping with zero or more outputs.
+    ///
+    /// # Arguments
+    ///
+    /// * `output`: output entered by the surface
     fn handle_enter(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        output: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        output: crate::ObjectId,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Surface leaves an output.
+    ///
+    /// This is emitted whenever a surface's creation, movement, or resizing
+    /// results in it no longer having any part of it within the scanout region
+    /// of an output.
+    ///
+    /// Clients should not use the number of outputs the surface is on for frame
+    /// throttling purposes. The surface might be hidden even if no leave event
+    /// has been sent, and the compositor might expect new surface content
+    /// updates even if no enter event has been sent. The frame event should be
+    /// used instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `output`: output left by the surface
     fn handle_leave(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        output: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        output: crate::ObjectId,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Preferred buffer scale for the surface.
+    ///
+    /// This event indicates the preferred buffer scale for this surface. It is
+    /// sent whenever the compositor's preference changes.
+    ///
+    /// Before receiving this event the preferred buffer scale for this surface
+    /// is 1.
+    ///
+    /// It is intended that scaling aware clients use this event to scale their
+    /// content and use wl_surface.set_buffer_scale to indicate the scale they
+    /// have rendered with. This allows clients to supply a higher detail
+    /// buffer.
+    ///
+    /// The compositor shall emit a scale value greater than 0.
+    ///
+    /// Available since version 6.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor`: preferred scaling factor
     fn handle_preferred_buffer_scale(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         factor: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Preferred buffer transform for the surface.
+    ///
+    /// This event indicates the preferred buffer transform for this surface.
+    /// It is sent whenever the compositor's preference changes.
+    ///
+    /// Before receiving this event the preferred buffer transform for this
+    /// surface is normal.
+    ///
+    /// Applying this transformation to the surface buffer contents and using
+    /// wl_surface.set_buffer_transform might allow the compositor to use the
+    /// surface buffer more efficiently.
+    ///
+    /// Available since version 6.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform`: preferred transform
     fn handle_preferred_buffer_transform(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        transform: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        transform: wl_output_transform,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_surface",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -3642,163 +8373,559 @@ pub trait wl_surface_v6_event_handler: crate::protocol::EventHandler {
                     let mut __packet_reader = __packet.read();
                     let output = __packet_reader.read_object()?;
                     __packet_reader.finish()?;
-                    self.handle_enter(__connection, __packet, output).await;
-                    Ok(())
+                    self.handle_enter(__connection, __packet, output).await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let output = __packet_reader.read_object()?;
                     __packet_reader.finish()?;
-                    self.handle_leave(__connection, __packet, output).await;
-                    Ok(())
+                    self.handle_leave(__connection, __packet, output).await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     let factor = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_preferred_buffer_scale(__connection, __packet, factor)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
                     let transform = __packet_reader.read_uint()?;
+                    let transform =
+                        <wl_output_transform as ::std::convert::TryFrom<u32>>::try_from(transform)?;
                     __packet_reader.finish()?;
                     self.handle_preferred_buffer_transform(__connection, __packet, transform)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_surface_v6_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_surface_v6_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
-    }
+impl wl_surface {
+    /// Delete surface.
+    ///
+    /// Deletes the surface and invalidates its object ID.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set the surface contents.
+    ///
+    /// Set a buffer as the content of this surface.
+    ///
+    /// The new size of the surface is calculated based on the buffer
+    /// size transformed by the inverse buffer_transform and the
+    /// inverse buffer_scale. This means that at commit time the supplied
+    /// buffer size must be an integer multiple of the buffer_scale. If
+    /// that's not the case, an invalid_size error is sent.
+    ///
+    /// The x and y arguments specify the location of the new pending
+    /// buffer's upper left corner, relative to the current buffer's upper
+    /// left corner, in surface-local coordinates. In other words, the
+    /// x and y, combined with the new surface size define in which
+    /// directions the surface's size changes. Setting anything other than 0
+    /// as x and y arguments is discouraged, and should instead be replaced
+    /// with using the separate wl_surface.offset request.
+    ///
+    /// When the bound wl_surface version is 5 or higher, passing any
+    /// non-zero x or y is a protocol violation, and will result in an
+    /// 'invalid_offset' error being raised. The x and y arguments are ignored
+    /// and do not change the pending state. To achieve equivalent semantics,
+    /// use wl_surface.offset.
+    ///
+    /// Surface contents are double-buffered state, see wl_surface.commit.
+    ///
+    /// The initial surface contents are void; there is no content.
+    /// wl_surface.attach assigns the given wl_buffer as the pending
+    /// wl_buffer. wl_surface.commit makes the pending wl_buffer the new
+    /// surface contents, and the size of the surface becomes the size
+    /// calculated from the wl_buffer, as described above. After commit,
+    /// there is no pending buffer until the next attach.
+    ///
+    /// Committing a pending wl_buffer allows the compositor to read the
+    /// pixels in the wl_buffer. The compositor may access the pixels at
+    /// any time after the wl_surface.commit request. When the compositor
+    /// will not access the pixels anymore, it will send the
+    /// wl_buffer.release event. Only after receiving wl_buffer.release,
+    /// the client may reuse the wl_buffer. A wl_buffer that has been
+    /// attached and then replaced by another attach instead of committed
+    /// will not receive a release event, and is not used by the
+    /// compositor.
+    ///
+    /// If a pending wl_buffer has been committed to more than one wl_surface,
+    /// the delivery of wl_buffer.release events becomes undefined. A well
+    /// behaved client should not rely on wl_buffer.release events in this
+    /// case. Alternatively, a client could create multiple wl_buffer objects
+    /// from the same backing storage or use a protocol extension providing
+    /// per-commit release notifications.
+    ///
+    /// Destroying the wl_buffer after wl_buffer.release does not change
+    /// the surface contents. Destroying the wl_buffer before wl_buffer.release
+    /// is allowed as long as the underlying buffer storage isn't re-used (this
+    /// can happen e.g. on client process termination). However, if the client
+    /// destroys the wl_buffer before receiving the wl_buffer.release event and
+    /// mutates the underlying buffer storage, the surface contents become
+    /// undefined immediately.
+    ///
+    /// If wl_surface.attach is sent with a NULL wl_buffer, the
+    /// following wl_surface.commit will remove the surface content.
+    ///
+    /// If a pending wl_buffer has been destroyed, the result is not specified.
+    /// Many compositors are known to remove the surface content on the following
+    /// wl_surface.commit, but this behaviour is not universal. Clients seeking to
+    /// maximise compatibility should not destroy pending buffers and should
+    /// ensure that they explicitly remove content from surfaces, even after
+    /// destroying buffers.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer`: buffer of surface contents
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
     pub async fn send_attach(
         &self,
-        __object_id: crate::ObjectId,
         buffer: ::std::option::Option<crate::ObjectId>,
         x: i32,
         y: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_object(buffer);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_nullable_object(buffer);
         __packet.push_int(x);
         __packet.push_int(y);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Mark part of the surface damaged.
+    ///
+    /// This request is used to describe the regions where the pending
+    /// buffer is different from the current surface contents, and where
+    /// the surface therefore needs to be repainted. The compositor
+    /// ignores the parts of the damage that fall outside of the surface.
+    ///
+    /// Damage is double-buffered state, see wl_surface.commit.
+    ///
+    /// The damage rectangle is specified in surface-local coordinates,
+    /// where x and y specify the upper left corner of the damage rectangle.
+    ///
+    /// The initial value for pending damage is empty: no damage.
+    /// wl_surface.damage adds pending damage: the new pending damage
+    /// is the union of old pending damage and the given rectangle.
+    ///
+    /// wl_surface.commit assigns pending damage as the current damage,
+    /// and clears pending damage. The server will clear the current
+    /// damage as it repaints the surface.
+    ///
+    /// Note! New clients should not use this request. Instead damage can be
+    /// posted with wl_surface.damage_buffer which uses buffer coordinates
+    /// instead of surface coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
+    /// * `width`: width of damage rectangle
+    /// * `height`: height of damage rectangle
     pub async fn send_damage(
         &self,
-        __object_id: crate::ObjectId,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
         __packet.push_int(x);
         __packet.push_int(y);
         __packet.push_int(width);
         __packet.push_int(height);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_frame(
-        &self,
-        __object_id: crate::ObjectId,
-        callback: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 3);
-        __packet.push_new_id_known_interface(callback);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Request a frame throttling hint.
+    ///
+    /// Request a notification when it is a good time to start drawing a new
+    /// frame, by creating a frame callback. This is useful for throttling
+    /// redrawing operations, and driving animations.
+    ///
+    /// When a client is animating on a wl_surface, it can use the 'frame'
+    /// request to get notified when it is a good time to draw and commit the
+    /// next frame of animation. If the client commits an update earlier than
+    /// that, it is likely that some updates will not make it to the display,
+    /// and the client is wasting resources by drawing too often.
+    ///
+    /// The frame request will take effect on the next wl_surface.commit.
+    /// The notification will only be posted for one frame unless
+    /// requested again. For a wl_surface, the notifications are posted in
+    /// the order the frame requests were committed.
+    ///
+    /// The server must send the notifications so that a client
+    /// will not send excessive updates, while still allowing
+    /// the highest possible update rate for clients that wait for the reply
+    /// before drawing again. The server should give some time for the client
+    /// to draw and commit after sending the frame callback events to let it
+    /// hit the next output refresh.
+    ///
+    /// A server should avoid signaling the frame callbacks if the
+    /// surface is not visible in any way, e.g. the surface is off-screen,
+    /// or completely obscured by other opaque surfaces.
+    ///
+    /// The object returned by this request will be destroyed by the
+    /// compositor after the callback is fired and as such the client must not
+    /// attempt to use it after that point.
+    ///
+    /// The callback_data passed in the callback is the current time, in
+    /// milliseconds, with an undefined base.
+    ///
+    /// Returns the new `wl_callback` object.
+    pub async fn send_frame(&self) -> Result<wl_callback, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_callback_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 3);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_callback {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
+    /// Set opaque region.
+    ///
+    /// This request sets the region of the surface that contains
+    /// opaque content.
+    ///
+    /// The opaque region is an optimization hint for the compositor
+    /// that lets it optimize the redrawing of content behind opaque
+    /// regions.  Setting an opaque region is not required for correct
+    /// behaviour, but marking transparent content as opaque will result
+    /// in repaint artifacts.
+    ///
+    /// The opaque region is specified in surface-local coordinates.
+    ///
+    /// The compositor ignores the parts of the opaque region that fall
+    /// outside of the surface.
+    ///
+    /// Opaque region is double-buffered state, see wl_surface.commit.
+    ///
+    /// wl_surface.set_opaque_region changes the pending opaque region.
+    /// wl_surface.commit copies the pending region to the current region.
+    /// Otherwise, the pending and current regions are never changed.
+    ///
+    /// The initial value for an opaque region is empty. Setting the pending
+    /// opaque region has copy semantics, and the wl_region object can be
+    /// destroyed immediately. A NULL wl_region causes the pending opaque
+    /// region to be set to empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `region`: opaque region of the surface
     pub async fn send_set_opaque_region(
         &self,
-        __object_id: crate::ObjectId,
         region: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 4);
-        __packet.push_object(region);
-        self.connection.send_packet(&__packet).await
-    }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 4);
+        __packet.push_nullable_object(region);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set input region.
+    ///
+    /// This request sets the region of the surface that can receive
+    /// pointer and touch events.
+    ///
+    /// Input events happening outside of this region will try the next
+    /// surface in the server surface stack. The compositor ignores the
+    /// parts of the input region that fall outside of the surface.
+    ///
+    /// The input region is specified in surface-local coordinates.
+    ///
+    /// Input region is double-buffered state, see wl_surface.commit.
+    ///
+    /// wl_surface.set_input_region changes the pending input region.
+    /// wl_surface.commit copies the pending region to the current region.
+    /// Otherwise the pending and current regions are never changed,
+    /// except cursor and icon surfaces are special cases, see
+    /// wl_pointer.set_cursor and wl_data_device.start_drag.
+    ///
+    /// The initial value for an input region is infinite. That means the
+    /// whole surface will accept input. Setting the pending input region
+    /// has copy semantics, and the wl_region object can be destroyed
+    /// immediately. A NULL wl_region causes the input region to be set
+    /// to infinite.
+    ///
+    /// # Arguments
+    ///
+    /// * `region`: input region of the surface
     pub async fn send_set_input_region(
         &self,
-        __object_id: crate::ObjectId,
         region: ::std::option::Option<crate::ObjectId>,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 5);
-        __packet.push_object(region);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_commit(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 6);
-        self.connection.send_packet(&__packet).await
-    }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 5);
+        __packet.push_nullable_object(region);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Commit pending surface state.
+    ///
+    /// Surface state (input, opaque, and damage regions, attached buffers,
+    /// etc.) is double-buffered. Protocol requests modify the pending state,
+    /// as opposed to the active state in use by the compositor.
+    ///
+    /// A commit request atomically creates a content update from the pending
+    /// state, even if the pending state has not been touched. The content
+    /// update is placed in a queue until it becomes active. After commit, the
+    /// new pending state is as documented for each related request.
+    ///
+    /// When the content update is applied, the wl_buffer is applied before all
+    /// other state. This means that all coordinates in double-buffered state
+    /// are relative to the newly attached wl_buffers, except for
+    /// wl_surface.attach itself. If there is no newly attached wl_buffer, the
+    /// coordinates are relative to the previous content update.
+    ///
+    /// All requests that need a commit to become effective are documented
+    /// to affect double-buffered state.
+    ///
+    /// Other interfaces may add further double-buffered surface state.
+    pub async fn send_commit(&self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 6);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Sets the buffer transformation.
+    ///
+    /// This request sets the transformation that the client has already applied
+    /// to the content of the buffer. The accepted values for the transform
+    /// parameter are the values for wl_output.transform.
+    ///
+    /// The compositor applies the inverse of this transformation whenever it
+    /// uses the buffer contents.
+    ///
+    /// Buffer transform is double-buffered state, see wl_surface.commit.
+    ///
+    /// A newly created surface has its buffer transformation set to normal.
+    ///
+    /// wl_surface.set_buffer_transform changes the pending buffer
+    /// transformation. wl_surface.commit copies the pending buffer
+    /// transformation to the current one. Otherwise, the pending and current
+    /// values are never changed.
+    ///
+    /// The purpose of this request is to allow clients to render content
+    /// according to the output transform, thus permitting the compositor to
+    /// use certain optimizations even if the display is rotated. Using
+    /// hardware overlays and scanning out a client buffer for fullscreen
+    /// surfaces are examples of such optimizations. Those optimizations are
+    /// highly dependent on the compositor implementation, so the use of this
+    /// request should be considered on a case-by-case basis.
+    ///
+    /// Note that if the transform value includes 90 or 270 degree rotation,
+    /// the width of the buffer will become the surface height and the height
+    /// of the buffer will become the surface width.
+    ///
+    /// If transform is not one of the values from the
+    /// wl_output.transform enum the invalid_transform protocol error
+    /// is raised.
+    ///
+    /// Available since version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform`: transform for interpreting buffer contents
     pub async fn send_set_buffer_transform(
         &self,
-        __object_id: crate::ObjectId,
-        transform: i32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 7);
-        __packet.push_int(transform);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_buffer_scale(
-        &self,
-        __object_id: crate::ObjectId,
-        scale: i32,
+        transform: wl_output_transform,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 8);
+        if self.version < 2 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_surface",
+                request: "set_buffer_transform",
+                since: 2,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 7);
+        __packet.push_int(u32::from(transform) as i32);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Sets the buffer scaling factor.
+    ///
+    /// This request sets an optional scaling factor on how the compositor
+    /// interprets the contents of the buffer attached to the window.
+    ///
+    /// Buffer scale is double-buffered state, see wl_surface.commit.
+    ///
+    /// A newly created surface has its buffer scale set to 1.
+    ///
+    /// wl_surface.set_buffer_scale changes the pending buffer scale.
+    /// wl_surface.commit copies the pending buffer scale to the current one.
+    /// Otherwise, the pending and current values are never changed.
+    ///
+    /// The purpose of this request is to allow clients to supply higher
+    /// resolution buffer data for use on high resolution outputs. It is
+    /// intended that you pick the same buffer scale as the scale of the
+    /// output that the surface is displayed on. This means the compositor
+    /// can avoid scaling when rendering the surface on that output.
+    ///
+    /// Note that if the scale is larger than 1, then you have to attach
+    /// a buffer that is larger (by a factor of scale in each dimension)
+    /// than the desired surface size.
+    ///
+    /// If scale is not greater than 0 the invalid_scale protocol error is
+    /// raised.
+    ///
+    /// Available since version 3.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale`: scale for interpreting buffer contents
+    pub async fn send_set_buffer_scale(&self, scale: i32) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_surface",
+                request: "set_buffer_scale",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 8);
         __packet.push_int(scale);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Mark part of the surface damaged using buffer coordinates.
+    ///
+    /// This request is used to describe the regions where the pending
+    /// buffer is different from the current surface contents, and where
+    /// the surface therefore needs to be repainted. The compositor
+    /// ignores the parts of the damage that fall outside of the surface.
+    ///
+    /// Damage is double-buffered state, see wl_surface.commit.
+    ///
+    /// The damage rectangle is specified in buffer coordinates,
+    /// where x and y specify the upper left corner of the damage rectangle.
+    ///
+    /// The initial value for pending damage is empty: no damage.
+    /// wl_surface.damage_buffer adds pending damage: the new pending
+    /// damage is the union of old pending damage and the given rectangle.
+    ///
+    /// wl_surface.commit assigns pending damage as the current damage,
+    /// and clears pending damage. The server will clear the current
+    /// damage as it repaints the surface.
+    ///
+    /// This request differs from wl_surface.damage in only one way - it
+    /// takes damage in buffer coordinates instead of surface-local
+    /// coordinates. While this generally is more intuitive than surface
+    /// coordinates, it is especially desirable when using wp_viewport
+    /// or when a drawing library (like EGL) is unaware of buffer scale
+    /// and buffer transform.
+    ///
+    /// Note: Because buffer transformation changes and damage requests may
+    /// be interleaved in the protocol stream, it is impossible to determine
+    /// the actual mapping between surface and buffer damage until
+    /// wl_surface.commit time. Therefore, compositors wishing to take both
+    /// kinds of damage into account will have to accumulate damage from the
+    /// two requests separately and only transform from one to the other
+    /// after receiving the wl_surface.commit.
+    ///
+    /// Available since version 4.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: buffer-local x coordinate
+    /// * `y`: buffer-local y coordinate
+    /// * `width`: width of damage rectangle
+    /// * `height`: height of damage rectangle
     pub async fn send_damage_buffer(
         &self,
-        __object_id: crate::ObjectId,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 9);
+        if self.version < 4 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_surface",
+                request: "damage_buffer",
+                since: 4,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 9);
         __packet.push_int(x);
         __packet.push_int(y);
         __packet.push_int(width);
         __packet.push_int(height);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_offset(
-        &self,
-        __object_id: crate::ObjectId,
-        x: i32,
-        y: i32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 10);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set the surface contents offset.
+    ///
+    /// The x and y arguments specify the location of the new pending
+    /// buffer's upper left corner, relative to the current buffer's upper
+    /// left corner, in surface-local coordinates. In other words, the
+    /// x and y, combined with the new surface size define in which
+    /// directions the surface's size changes.
+    ///
+    /// The exact semantics of wl_surface.offset are role-specific. Refer to
+    /// the documentation of specific roles for more information.
+    ///
+    /// Surface location offset is double-buffered state, see
+    /// wl_surface.commit.
+    ///
+    /// This request is semantically equivalent to and the replaces the x and y
+    /// arguments in the wl_surface.attach request in wl_surface versions prior
+    /// to 5. See wl_surface.attach for details.
+    ///
+    /// Available since version 5.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
+    pub async fn send_offset(&self, x: i32, y: i32) -> Result<(), crate::Error> {
+        if self.version < 5 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_surface",
+                request: "offset",
+                since: 5,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 10);
         __packet.push_int(x);
         __packet.push_int(y);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_surface_v6_request_destroy_args {}
-impl wl_surface_v6_request_destroy_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Delete surface.
+///
+/// Deletes the surface and invalidates its object ID.
+///
+/// The arguments of the `wl_surface.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_destroy_args {}
+impl wl_surface_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_destroy_args, crate::Error> {
+    ) -> Result<wl_surface_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -3811,35 +8938,110 @@ impl wl_surface_v6_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_destroy_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_destroy_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_destroy_args),
+        __value: (crate::ObjectId, wl_surface_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_attach_args {
+/// Set the surface contents.
+///
+/// Set a buffer as the content of this surface.
+///
+/// The new size of the surface is calculated based on the buffer
+/// size transformed by the inverse buffer_transform and the
+/// inverse buffer_scale. This means that at commit time the supplied
+/// buffer size must be an integer multiple of the buffer_scale. If
+/// that's not the case, an invalid_size error is sent.
+///
+/// The x and y arguments specify the location of the new pending
+/// buffer's upper left corner, relative to the current buffer's upper
+/// left corner, in surface-local coordinates. In other words, the
+/// x and y, combined with the new surface size define in which
+/// directions the surface's size changes. Setting anything other than 0
+/// as x and y arguments is discouraged, and should instead be replaced
+/// with using the separate wl_surface.offset request.
+///
+/// When the bound wl_surface version is 5 or higher, passing any
+/// non-zero x or y is a protocol violation, and will result in an
+/// 'invalid_offset' error being raised. The x and y arguments are ignored
+/// and do not change the pending state. To achieve equivalent semantics,
+/// use wl_surface.offset.
+///
+/// Surface contents are double-buffered state, see wl_surface.commit.
+///
+/// The initial surface contents are void; there is no content.
+/// wl_surface.attach assigns the given wl_buffer as the pending
+/// wl_buffer. wl_surface.commit makes the pending wl_buffer the new
+/// surface contents, and the size of the surface becomes the size
+/// calculated from the wl_buffer, as described above. After commit,
+/// there is no pending buffer until the next attach.
+///
+/// Committing a pending wl_buffer allows the compositor to read the
+/// pixels in the wl_buffer. The compositor may access the pixels at
+/// any time after the wl_surface.commit request. When the compositor
+/// will not access the pixels anymore, it will send the
+/// wl_buffer.release event. Only after receiving wl_buffer.release,
+/// the client may reuse the wl_buffer. A wl_buffer that has been
+/// attached and then replaced by another attach instead of committed
+/// will not receive a release event, and is not used by the
+/// compositor.
+///
+/// If a pending wl_buffer has been committed to more than one wl_surface,
+/// the delivery of wl_buffer.release events becomes undefined. A well
+/// behaved client should not rely on wl_buffer.release events in this
+/// case. Alternatively, a client could create multiple wl_buffer objects
+/// from the same backing storage or use a protocol extension providing
+/// per-commit release notifications.
+///
+/// Destroying the wl_buffer after wl_buffer.release does not change
+/// the surface contents. Destroying the wl_buffer before wl_buffer.release
+/// is allowed as long as the underlying buffer storage isn't re-used (this
+/// can happen e.g. on client process termination). However, if the client
+/// destroys the wl_buffer before receiving the wl_buffer.release event and
+/// mutates the underlying buffer storage, the surface contents become
+/// undefined immediately.
+///
+/// If wl_surface.attach is sent with a NULL wl_buffer, the
+/// following wl_surface.commit will remove the surface content.
+///
+/// If a pending wl_buffer has been destroyed, the result is not specified.
+/// Many compositors are known to remove the surface content on the following
+/// wl_surface.commit, but this behaviour is not universal. Clients seeking to
+/// maximise compatibility should not destroy pending buffers and should
+/// ensure that they explicitly remove content from surfaces, even after
+/// destroying buffers.
+///
+/// The arguments of the `wl_surface.attach` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_attach_args {
+    /// Buffer of surface contents.
     pub buffer: ::std::option::Option<crate::ObjectId>,
+    /// Surface-local x coordinate.
     pub x: i32,
+    /// Surface-local y coordinate.
     pub y: i32,
 }
-impl wl_surface_v6_request_attach_args {
+impl wl_surface_request_attach_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_attach_args, crate::Error> {
+    ) -> Result<wl_surface_request_attach_args, crate::Error> {
         let mut __reader = __value.read();
-        let buffer = __reader.read_object()?;
+        let buffer = __reader.read_nullable_object()?;
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
         __reader.finish()?;
@@ -3850,40 +9052,72 @@ impl wl_surface_v6_request_attach_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.buffer);
+        __packet.push_nullable_object(self.buffer);
         __packet.push_int(self.x);
         __packet.push_int(self.y);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_attach_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_attach_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_attach_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_attach_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_attach_args),
+        __value: (crate::ObjectId, wl_surface_request_attach_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_damage_args {
+/// Mark part of the surface damaged.
+///
+/// This request is used to describe the regions where the pending
+/// buffer is different from the current surface contents, and where
+/// the surface therefore needs to be repainted. The compositor
+/// ignores the parts of the damage that fall outside of the surface.
+///
+/// Damage is double-buffered state, see wl_surface.commit.
+///
+/// The damage rectangle is specified in surface-local coordinates,
+/// where x and y specify the upper left corner of the damage rectangle.
+///
+/// The initial value for pending damage is empty: no damage.
+/// wl_surface.damage adds pending damage: the new pending damage
+/// is the union of old pending damage and the given rectangle.
+///
+/// wl_surface.commit assigns pending damage as the current damage,
+/// and clears pending damage. The server will clear the current
+/// damage as it repaints the surface.
+///
+/// Note! New clients should not use this request. Instead damage can be
+/// posted with wl_surface.damage_buffer which uses buffer coordinates
+/// instead of surface coordinates.
+///
+/// The arguments of the `wl_surface.damage` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_damage_args {
+    /// Surface-local x coordinate.
     pub x: i32,
+    /// Surface-local y coordinate.
     pub y: i32,
+    /// Width of damage rectangle.
     pub width: i32,
+    /// Height of damage rectangle.
     pub height: i32,
 }
-impl wl_surface_v6_request_damage_args {
+impl wl_surface_request_damage_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_damage_args, crate::Error> {
+    ) -> Result<wl_surface_request_damage_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -3909,31 +9143,71 @@ impl wl_surface_v6_request_damage_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_damage_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_damage_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_damage_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_damage_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_damage_args),
+        __value: (crate::ObjectId, wl_surface_request_damage_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_frame_args {
+/// Request a frame throttling hint.
+///
+/// Request a notification when it is a good time to start drawing a new
+/// frame, by creating a frame callback. This is useful for throttling
+/// redrawing operations, and driving animations.
+///
+/// When a client is animating on a wl_surface, it can use the 'frame'
+/// request to get notified when it is a good time to draw and commit the
+/// next frame of animation. If the client commits an update earlier than
+/// that, it is likely that some updates will not make it to the display,
+/// and the client is wasting resources by drawing too often.
+///
+/// The frame request will take effect on the next wl_surface.commit.
+/// The notification will only be posted for one frame unless
+/// requested again. For a wl_surface, the notifications are posted in
+/// the order the frame requests were committed.
+///
+/// The server must send the notifications so that a client
+/// will not send excessive updates, while still allowing
+/// the highest possible update rate for clients that wait for the reply
+/// before drawing again. The server should give some time for the client
+/// to draw and commit after sending the frame callback events to let it
+/// hit the next output refresh.
+///
+/// A server should avoid signaling the frame callbacks if the
+/// surface is not visible in any way, e.g. the surface is off-screen,
+/// or completely obscured by other opaque surfaces.
+///
+/// The object returned by this request will be destroyed by the
+/// compositor after the callback is fired and as such the client must not
+/// attempt to use it after that point.
+///
+/// The callback_data passed in the callback is the current time, in
+/// milliseconds, with an undefined base.
+///
+/// The arguments of the `wl_surface.frame` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_frame_args {
+    /// Callback object for the frame request.
     pub callback: crate::NewObjectId,
 }
-impl wl_surface_v6_request_frame_args {
+impl wl_surface_request_frame_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_frame_args, crate::Error> {
+    ) -> Result<wl_surface_request_frame_args, crate::Error> {
         let mut __reader = __value.read();
         let callback = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -3948,33 +9222,65 @@ impl wl_surface_v6_request_frame_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_frame_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_frame_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_frame_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_frame_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_frame_args),
+        __value: (crate::ObjectId, wl_surface_request_frame_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_set_opaque_region_args {
+/// Set opaque region.
+///
+/// This request sets the region of the surface that contains
+/// opaque content.
+///
+/// The opaque region is an optimization hint for the compositor
+/// that lets it optimize the redrawing of content behind opaque
+/// regions.  Setting an opaque region is not required for correct
+/// behaviour, but marking transparent content as opaque will result
+/// in repaint artifacts.
+///
+/// The opaque region is specified in surface-local coordinates.
+///
+/// The compositor ignores the parts of the opaque region that fall
+/// outside of the surface.
+///
+/// Opaque region is double-buffered state, see wl_surface.commit.
+///
+/// wl_surface.set_opaque_region changes the pending opaque region.
+/// wl_surface.commit copies the pending region to the current region.
+/// Otherwise, the pending and current regions are never changed.
+///
+/// The initial value for an opaque region is empty. Setting the pending
+/// opaque region has copy semantics, and the wl_region object can be
+/// destroyed immediately. A NULL wl_region causes the pending opaque
+/// region to be set to empty.
+///
+/// The arguments of the `wl_surface.set_opaque_region` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_set_opaque_region_args {
+    /// Opaque region of the surface.
     pub region: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_surface_v6_request_set_opaque_region_args {
+impl wl_surface_request_set_opaque_region_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_set_opaque_region_args, crate::Error> {
+    ) -> Result<wl_surface_request_set_opaque_region_args, crate::Error> {
         let mut __reader = __value.read();
-        let region = __reader.read_object()?;
+        let region = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { region })
     }
@@ -3983,43 +9289,69 @@ impl wl_surface_v6_request_set_opaque_region_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.region);
+        __packet.push_nullable_object(self.region);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_set_opaque_region_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_set_opaque_region_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl
-    ::std::convert::TryFrom<(
-        crate::ObjectId,
-        wl_surface_v6_request_set_opaque_region_args,
-    )> for crate::Packet
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_set_opaque_region_args)>
+    for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (
-            crate::ObjectId,
-            wl_surface_v6_request_set_opaque_region_args,
-        ),
+        __value: (crate::ObjectId, wl_surface_request_set_opaque_region_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_set_input_region_args {
+/// Set input region.
+///
+/// This request sets the region of the surface that can receive
+/// pointer and touch events.
+///
+/// Input events happening outside of this region will try the next
+/// surface in the server surface stack. The compositor ignores the
+/// parts of the input region that fall outside of the surface.
+///
+/// The input region is specified in surface-local coordinates.
+///
+/// Input region is double-buffered state, see wl_surface.commit.
+///
+/// wl_surface.set_input_region changes the pending input region.
+/// wl_surface.commit copies the pending region to the current region.
+/// Otherwise the pending and current regions are never changed,
+/// except cursor and icon surfaces are special cases, see
+/// wl_pointer.set_cursor and wl_data_device.start_drag.
+///
+/// The initial value for an input region is infinite. That means the
+/// whole surface will accept input. Setting the pending input region
+/// has copy semantics, and the wl_region object can be destroyed
+/// immediately. A NULL wl_region causes the input region to be set
+/// to infinite.
+///
+/// The arguments of the `wl_surface.set_input_region` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_set_input_region_args {
+    /// Input region of the surface.
     pub region: ::std::option::Option<crate::ObjectId>,
 }
-impl wl_surface_v6_request_set_input_region_args {
+impl wl_surface_request_set_input_region_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_set_input_region_args, crate::Error> {
+    ) -> Result<wl_surface_request_set_input_region_args, crate::Error> {
         let mut __reader = __value.read();
-        let region = __reader.read_object()?;
+        let region = __reader.read_nullable_object()?;
         __reader.finish()?;
         Ok(Self { region })
     }
@@ -4028,33 +9360,61 @@ impl wl_surface_v6_request_set_input_region_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_object(self.region);
+        __packet.push_nullable_object(self.region);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_set_input_region_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_set_input_region_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_set_input_region_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_set_input_region_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_set_input_region_args),
+        __value: (crate::ObjectId, wl_surface_request_set_input_region_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_commit_args {}
-impl wl_surface_v6_request_commit_args {
+/// Commit pending surface state.
+///
+/// Surface state (input, opaque, and damage regions, attached buffers,
+/// etc.) is double-buffered. Protocol requests modify the pending state,
+/// as opposed to the active state in use by the compositor.
+///
+/// A commit request atomically creates a content update from the pending
+/// state, even if the pending state has not been touched. The content
+/// update is placed in a queue until it becomes active. After commit, the
+/// new pending state is as documented for each related request.
+///
+/// When the content update is applied, the wl_buffer is applied before all
+/// other state. This means that all coordinates in double-buffered state
+/// are relative to the newly attached wl_buffers, except for
+/// wl_surface.attach itself. If there is no newly attached wl_buffer, the
+/// coordinates are relative to the previous content update.
+///
+/// All requests that need a commit to become effective are documented
+/// to affect double-buffered state.
+///
+/// Other interfaces may add further double-buffered surface state.
+///
+/// The arguments of the `wl_surface.commit` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_commit_args {}
+impl wl_surface_request_commit_args {
     pub const OPCODE: u16 = 6;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_commit_args, crate::Error> {
+    ) -> Result<wl_surface_request_commit_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -4067,33 +9427,76 @@ impl wl_surface_v6_request_commit_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_commit_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_commit_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_commit_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_commit_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_commit_args),
+        __value: (crate::ObjectId, wl_surface_request_commit_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_set_buffer_transform_args {
-    pub transform: i32,
-}
-impl wl_surface_v6_request_set_buffer_transform_args {
+/// Sets the buffer transformation.
+///
+/// This request sets the transformation that the client has already applied
+/// to the content of the buffer. The accepted values for the transform
+/// parameter are the values for wl_output.transform.
+///
+/// The compositor applies the inverse of this transformation whenever it
+/// uses the buffer contents.
+///
+/// Buffer transform is double-buffered state, see wl_surface.commit.
+///
+/// A newly created surface has its buffer transformation set to normal.
+///
+/// wl_surface.set_buffer_transform changes the pending buffer
+/// transformation. wl_surface.commit copies the pending buffer
+/// transformation to the current one. Otherwise, the pending and current
+/// values are never changed.
+///
+/// The purpose of this request is to allow clients to render content
+/// according to the output transform, thus permitting the compositor to
+/// use certain optimizations even if the display is rotated. Using
+/// hardware overlays and scanning out a client buffer for fullscreen
+/// surfaces are examples of such optimizations. Those optimizations are
+/// highly dependent on the compositor implementation, so the use of this
+/// request should be considered on a case-by-case basis.
+///
+/// Note that if the transform value includes 90 or 270 degree rotation,
+/// the width of the buffer will become the surface height and the height
+/// of the buffer will become the surface width.
+///
+/// If transform is not one of the values from the
+/// wl_output.transform enum the invalid_transform protocol error
+/// is raised.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_surface.set_buffer_transform` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_set_buffer_transform_args {
+    /// Transform for interpreting buffer contents.
+    pub transform: wl_output_transform,
+}
+impl wl_surface_request_set_buffer_transform_args {
     pub const OPCODE: u16 = 7;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_set_buffer_transform_args, crate::Error> {
+    ) -> Result<wl_surface_request_set_buffer_transform_args, crate::Error> {
         let mut __reader = __value.read();
         let transform = __reader.read_int()?;
+        let transform =
+            <wl_output_transform as ::std::convert::TryFrom<u32>>::try_from(transform as u32)?;
         __reader.finish()?;
         Ok(Self { transform })
     }
@@ -4102,11 +9505,11 @@ impl wl_surface_v6_request_set_buffer_transform_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_int(self.transform);
+        __packet.push_int(u32::from(self.transform) as i32);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_set_buffer_transform_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_set_buffer_transform_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -4115,28 +9518,63 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_set_buffer
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_surface_v6_request_set_buffer_transform_args,
+        wl_surface_request_set_buffer_transform_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_surface_v6_request_set_buffer_transform_args,
+            wl_surface_request_set_buffer_transform_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_set_buffer_scale_args {
+/// Sets the buffer scaling factor.
+///
+/// This request sets an optional scaling factor on how the compositor
+/// interprets the contents of the buffer attached to the window.
+///
+/// Buffer scale is double-buffered state, see wl_surface.commit.
+///
+/// A newly created surface has its buffer scale set to 1.
+///
+/// wl_surface.set_buffer_scale changes the pending buffer scale.
+/// wl_surface.commit copies the pending buffer scale to the current one.
+/// Otherwise, the pending and current values are never changed.
+///
+/// The purpose of this request is to allow clients to supply higher
+/// resolution buffer data for use on high resolution outputs. It is
+/// intended that you pick the same buffer scale as the scale of the
+/// output that the surface is displayed on. This means the compositor
+/// can avoid scaling when rendering the surface on that output.
+///
+/// Note that if the scale is larger than 1, then you have to attach
+/// a buffer that is larger (by a factor of scale in each dimension)
+/// than the desired surface size.
+///
+/// If scale is not greater than 0 the invalid_scale protocol error is
+/// raised.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_surface.set_buffer_scale` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_set_buffer_scale_args {
+    /// Scale for interpreting buffer contents.
     pub scale: i32,
 }
-impl wl_surface_v6_request_set_buffer_scale_args {
+impl wl_surface_request_set_buffer_scale_args {
     pub const OPCODE: u16 = 8;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_set_buffer_scale_args, crate::Error> {
+    ) -> Result<wl_surface_request_set_buffer_scale_args, crate::Error> {
         let mut __reader = __value.read();
         let scale = __reader.read_int()?;
         __reader.finish()?;
@@ -4151,34 +9589,81 @@ impl wl_surface_v6_request_set_buffer_scale_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_set_buffer_scale_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_set_buffer_scale_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_set_buffer_scale_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_set_buffer_scale_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_set_buffer_scale_args),
+        __value: (crate::ObjectId, wl_surface_request_set_buffer_scale_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_damage_buffer_args {
+/// Mark part of the surface damaged using buffer coordinates.
+///
+/// This request is used to describe the regions where the pending
+/// buffer is different from the current surface contents, and where
+/// the surface therefore needs to be repainted. The compositor
+/// ignores the parts of the damage that fall outside of the surface.
+///
+/// Damage is double-buffered state, see wl_surface.commit.
+///
+/// The damage rectangle is specified in buffer coordinates,
+/// where x and y specify the upper left corner of the damage rectangle.
+///
+/// The initial value for pending damage is empty: no damage.
+/// wl_surface.damage_buffer adds pending damage: the new pending
+/// damage is the union of old pending damage and the given rectangle.
+///
+/// wl_surface.commit assigns pending damage as the current damage,
+/// and clears pending damage. The server will clear the current
+/// damage as it repaints the surface.
+///
+/// This request differs from wl_surface.damage in only one way - it
+/// takes damage in buffer coordinates instead of surface-local
+/// coordinates. While this generally is more intuitive than surface
+/// coordinates, it is especially desirable when using wp_viewport
+/// or when a drawing library (like EGL) is unaware of buffer scale
+/// and buffer transform.
+///
+/// Note: Because buffer transformation changes and damage requests may
+/// be interleaved in the protocol stream, it is impossible to determine
+/// the actual mapping between surface and buffer damage until
+/// wl_surface.commit time. Therefore, compositors wishing to take both
+/// kinds of damage into account will have to accumulate damage from the
+/// two requests separately and only transform from one to the other
+/// after receiving the wl_surface.commit.
+///
+/// Available since version 4.
+///
+/// The arguments of the `wl_surface.damage_buffer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_damage_buffer_args {
+    /// Buffer-local x coordinate.
     pub x: i32,
+    /// Buffer-local y coordinate.
     pub y: i32,
+    /// Width of damage rectangle.
     pub width: i32,
+    /// Height of damage rectangle.
     pub height: i32,
 }
-impl wl_surface_v6_request_damage_buffer_args {
+impl wl_surface_request_damage_buffer_args {
     pub const OPCODE: u16 = 9;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 4;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_damage_buffer_args, crate::Error> {
+    ) -> Result<wl_surface_request_damage_buffer_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -4204,32 +9689,60 @@ impl wl_surface_v6_request_damage_buffer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_damage_buffer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_damage_buffer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_damage_buffer_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_damage_buffer_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_damage_buffer_args),
+        __value: (crate::ObjectId, wl_surface_request_damage_buffer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_request_offset_args {
+/// Set the surface contents offset.
+///
+/// The x and y arguments specify the location of the new pending
+/// buffer's upper left corner, relative to the current buffer's upper
+/// left corner, in surface-local coordinates. In other words, the
+/// x and y, combined with the new surface size define in which
+/// directions the surface's size changes.
+///
+/// The exact semantics of wl_surface.offset are role-specific. Refer to
+/// the documentation of specific roles for more information.
+///
+/// Surface location offset is double-buffered state, see
+/// wl_surface.commit.
+///
+/// This request is semantically equivalent to and the replaces the x and y
+/// arguments in the wl_surface.attach request in wl_surface versions prior
+/// to 5. See wl_surface.attach for details.
+///
+/// Available since version 5.
+///
+/// The arguments of the `wl_surface.offset` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_request_offset_args {
+    /// Surface-local x coordinate.
     pub x: i32,
+    /// Surface-local y coordinate.
     pub y: i32,
 }
-impl wl_surface_v6_request_offset_args {
+impl wl_surface_request_offset_args {
     pub const OPCODE: u16 = 10;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_request_offset_args, crate::Error> {
+    ) -> Result<wl_surface_request_offset_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -4246,31 +9759,44 @@ impl wl_surface_v6_request_offset_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_request_offset_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_request_offset_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_request_offset_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_request_offset_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_request_offset_args),
+        __value: (crate::ObjectId, wl_surface_request_offset_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_event_enter_args {
-    pub output: ::std::option::Option<crate::ObjectId>,
-}
-impl wl_surface_v6_event_enter_args {
+/// Surface enters an output.
+///
+/// This is emitted whenever a surface's creation, movement, or resizing
+/// results in some part of it being within the scanout region of an
+/// output.
+///
+/// Note that a surface may be overlapping with zero or more outputs.
+///
+/// The arguments of the `wl_surface.enter` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_event_enter_args {
+    /// Output entered by the surface.
+    pub output: crate::ObjectId,
+}
+impl wl_surface_event_enter_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_event_enter_args, crate::Error> {
+    ) -> Result<wl_surface_event_enter_args, crate::Error> {
         let mut __reader = __value.read();
         let output = __reader.read_object()?;
         __reader.finish()?;
@@ -4285,29 +9811,48 @@ impl wl_surface_v6_event_enter_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_event_enter_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_event_enter_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_event_enter_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_event_enter_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_event_enter_args),
+        __value: (crate::ObjectId, wl_surface_event_enter_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_event_leave_args {
-    pub output: ::std::option::Option<crate::ObjectId>,
-}
-impl wl_surface_v6_event_leave_args {
+/// Surface leaves an output.
+///
+/// This is emitted whenever a surface's creation, movement, or resizing
+/// results in it no longer having any part of it within the scanout region
+/// of an output.
+///
+/// Clients should not use the number of outputs the surface is on for frame
+/// throttling purposes. The surface might be hidden even if no leave event
+/// has been sent, and the compositor might expect new surface content
+/// updates even if no enter event has been sent. The frame event should be
+/// used instead.
+///
+/// The arguments of the `wl_surface.leave` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_event_leave_args {
+    /// Output left by the surface.
+    pub output: crate::ObjectId,
+}
+impl wl_surface_event_leave_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_event_leave_args, crate::Error> {
+    ) -> Result<wl_surface_event_leave_args, crate::Error> {
         let mut __reader = __value.read();
         let output = __reader.read_object()?;
         __reader.finish()?;
@@ -4322,29 +9867,53 @@ impl wl_surface_v6_event_leave_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_event_leave_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_event_leave_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_v6_event_leave_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_surface_event_leave_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_surface_v6_event_leave_args),
+        __value: (crate::ObjectId, wl_surface_event_leave_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_event_preferred_buffer_scale_args {
+/// Preferred buffer scale for the surface.
+///
+/// This event indicates the preferred buffer scale for this surface. It is
+/// sent whenever the compositor's preference changes.
+///
+/// Before receiving this event the preferred buffer scale for this surface
+/// is 1.
+///
+/// It is intended that scaling aware clients use this event to scale their
+/// content and use wl_surface.set_buffer_scale to indicate the scale they
+/// have rendered with. This allows clients to supply a higher detail
+/// buffer.
+///
+/// The compositor shall emit a scale value greater than 0.
+///
+/// Available since version 6.
+///
+/// The arguments of the `wl_surface.preferred_buffer_scale` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_event_preferred_buffer_scale_args {
+    /// Preferred scaling factor.
     pub factor: i32,
 }
-impl wl_surface_v6_event_preferred_buffer_scale_args {
+impl wl_surface_event_preferred_buffer_scale_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 6;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_event_preferred_buffer_scale_args, crate::Error> {
+    ) -> Result<wl_surface_event_preferred_buffer_scale_args, crate::Error> {
         let mut __reader = __value.read();
         let factor = __reader.read_int()?;
         __reader.finish()?;
@@ -4359,7 +9928,7 @@ impl wl_surface_v6_event_preferred_buffer_scale_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_event_preferred_buffer_scale_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_event_preferred_buffer_scale_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -4368,30 +9937,52 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_surface_v6_event_preferred_bu
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_surface_v6_event_preferred_buffer_scale_args,
+        wl_surface_event_preferred_buffer_scale_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_surface_v6_event_preferred_buffer_scale_args,
+            wl_surface_event_preferred_buffer_scale_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_surface_v6_event_preferred_buffer_transform_args {
-    pub transform: u32,
-}
-impl wl_surface_v6_event_preferred_buffer_transform_args {
+/// Preferred buffer transform for the surface.
+///
+/// This event indicates the preferred buffer transform for this surface.
+/// It is sent whenever the compositor's preference changes.
+///
+/// Before receiving this event the preferred buffer transform for this
+/// surface is normal.
+///
+/// Applying this transformation to the surface buffer contents and using
+/// wl_surface.set_buffer_transform might allow the compositor to use the
+/// surface buffer more efficiently.
+///
+/// Available since version 6.
+///
+/// The arguments of the `wl_surface.preferred_buffer_transform` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_surface_event_preferred_buffer_transform_args {
+    /// Preferred transform.
+    pub transform: wl_output_transform,
+}
+impl wl_surface_event_preferred_buffer_transform_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 6;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_surface_v6_event_preferred_buffer_transform_args, crate::Error> {
+    ) -> Result<wl_surface_event_preferred_buffer_transform_args, crate::Error> {
         let mut __reader = __value.read();
         let transform = __reader.read_uint()?;
+        let transform = <wl_output_transform as ::std::convert::TryFrom<u32>>::try_from(transform)?;
         __reader.finish()?;
         Ok(Self { transform })
     }
@@ -4400,13 +9991,11 @@ impl wl_surface_v6_event_preferred_buffer_transform_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.transform);
+        __packet.push_uint(u32::from(self.transform));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet>
-    for wl_surface_v6_event_preferred_buffer_transform_args
-{
+impl ::std::convert::TryFrom<crate::Packet> for wl_surface_event_preferred_buffer_transform_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -4415,43 +10004,282 @@ impl ::std::convert::TryFrom<crate::Packet>
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_surface_v6_event_preferred_buffer_transform_args,
+        wl_surface_event_preferred_buffer_transform_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_surface_v6_event_preferred_buffer_transform_args,
+            wl_surface_event_preferred_buffer_transform_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_surface` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_surface_event {
+    /// Surface enters an output.
+    enter(wl_surface_event_enter_args),
+    /// Surface leaves an output.
+    leave(wl_surface_event_leave_args),
+    /// Preferred buffer scale for the surface.
+    preferred_buffer_scale(wl_surface_event_preferred_buffer_scale_args),
+    /// Preferred buffer transform for the surface.
+    preferred_buffer_transform(wl_surface_event_preferred_buffer_transform_args),
+}
+impl wl_surface_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::enter(wl_surface_event_enter_args::try_from_packet(
+                __packet,
+            )?)),
+            1 => Ok(Self::leave(wl_surface_event_leave_args::try_from_packet(
+                __packet,
+            )?)),
+            2 => Ok(Self::preferred_buffer_scale(
+                wl_surface_event_preferred_buffer_scale_args::try_from_packet(__packet)?,
+            )),
+            3 => Ok(Self::preferred_buffer_transform(
+                wl_surface_event_preferred_buffer_transform_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_surface",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::enter(_) => wl_surface_event_enter_args::OPCODE,
+            Self::leave(_) => wl_surface_event_leave_args::OPCODE,
+            Self::preferred_buffer_scale(_) => wl_surface_event_preferred_buffer_scale_args::OPCODE,
+            Self::preferred_buffer_transform(_) => {
+                wl_surface_event_preferred_buffer_transform_args::OPCODE
+            }
+        }
+    }
+}
+/// Wl_surface error values.
+///
+/// These errors can be emitted in response to wl_surface requests.
+///
+/// The enum `wl_surface.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_surface_error {
+    /// Buffer scale value is invalid.
+    invalid_scale = 0,
+    /// Buffer transform value is invalid.
+    invalid_transform = 1,
+    /// Buffer size is invalid.
+    invalid_size = 2,
+    /// Buffer offset is invalid.
+    invalid_offset = 3,
+    /// Surface was destroyed before its role object.
+    defunct_role_object = 4,
+}
+impl ::std::convert::TryFrom<u32> for wl_surface_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::invalid_scale),
+            1 => Ok(Self::invalid_transform),
+            2 => Ok(Self::invalid_size),
+            3 => Ok(Self::invalid_offset),
+            4 => Ok(Self::defunct_role_object),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_surface.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_surface_error> for u32 {
+    fn from(__value: wl_surface_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_seat`.
+pub const wl_seat_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_seat",
+    version: 10,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "get_pointer",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_pointer"),
+            }],
+        },
+        crate::protocol::MessageInfo {
+            name: "get_keyboard",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_keyboard"),
+            }],
+        },
+        crate::protocol::MessageInfo {
+            name: "get_touch",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::NewId {
+                interface: Some("wl_touch"),
+            }],
+        },
+        crate::protocol::MessageInfo {
+            name: "release",
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "capabilities",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Uint],
+        },
+        crate::protocol::MessageInfo {
+            name: "name",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::String],
+        },
+    ],
+};
+/// Group of input devices.
+///
+/// A seat is a group of keyboards, pointer and touch devices. This
+/// object is published as a global during start up, or when such a
+/// device is hot plugged.  A seat typically has a pointer and
+/// maintains a keyboard focus and a pointer focus.
+///
+/// A proxy for a `wl_seat` object. The generated code knows this interface up to version 10.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_seat {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_seat_interface;
+}
+impl crate::protocol::Proxy for wl_seat {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_seat` object.
 #[allow(unused)]
-pub trait wl_seat_v10_event_handler: crate::protocol::EventHandler {
+pub trait wl_seat_event_handler: crate::protocol::EventHandler {
+    /// Seat capabilities changed.
+    ///
+    /// This is sent on binding to the seat global or whenever a seat gains
+    /// or loses the pointer, keyboard or touch capabilities.
+    /// The argument is a capability enum containing the complete set of
+    /// capabilities this seat has.
+    ///
+    /// When the pointer capability is added, a client may create a
+    /// wl_pointer object using the wl_seat.get_pointer request. This object
+    /// will receive pointer events until the capability is removed in the
+    /// future.
+    ///
+    /// When the pointer capability is removed, a client should destroy the
+    /// wl_pointer objects associated with the seat where the capability was
+    /// removed, using the wl_pointer.release request. No further pointer
+    /// events will be received on these objects.
+    ///
+    /// In some compositors, if a seat regains the pointer capability and a
+    /// client has a previously obtained wl_pointer object of version 4 or
+    /// less, that object may start sending pointer events again. This
+    /// behavior is considered a misinterpretation of the intended behavior
+    /// and must not be relied upon by the client. wl_pointer objects of
+    /// version 5 or later must not send events if created before the most
+    /// recent event notifying the client of an added pointer capability.
+    ///
+    /// The above behavior also applies to wl_keyboard and wl_touch with the
+    /// keyboard and touch capabilities, respectively.
+    ///
+    /// # Arguments
+    ///
+    /// * `capabilities`: capabilities of the seat
     fn handle_capabilities(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        capabilities: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        capabilities: wl_seat_capability,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Unique identifier for this seat.
+    ///
+    /// In a multi-seat configuration the seat name can be used by clients to
+    /// help identify which physical devices the seat represents.
+    ///
+    /// The seat name is a UTF-8 string with no convention defined for its
+    /// contents. Each name is unique among all wl_seat globals. The name is
+    /// only guaranteed to be unique for the current compositor instance.
+    ///
+    /// The same seat names are used for all clients. Thus, the name can be
+    /// shared across processes to refer to a specific wl_seat global.
+    ///
+    /// The name event is sent after binding to the seat global, and should be sent
+    /// before announcing capabilities. This event only sent once per seat object,
+    /// and the name does not change over the lifetime of the wl_seat global.
+    ///
+    /// Compositors may re-use the same seat name if the wl_seat global is
+    /// destroyed and re-created later.
+    ///
+    /// Available since version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: seat identifier
     fn handle_name(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         name: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_seat",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -4467,75 +10295,146 @@ pub trait wl_seat_v10_event_handler: crate::protocol::EventHandler {
                 0 => {
                     let mut __packet_reader = __packet.read();
                     let capabilities = __packet_reader.read_uint()?;
+                    let capabilities =
+                        <wl_seat_capability as ::std::convert::TryFrom<u32>>::try_from(
+                            capabilities,
+                        )?;
                     __packet_reader.finish()?;
                     self.handle_capabilities(__connection, __packet, capabilities)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let name = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
-                    self.handle_name(__connection, __packet, name).await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                    self.handle_name(__connection, __packet, name).await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_seat_v10_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_seat_v10_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_get_pointer(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_get_keyboard(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
+impl wl_seat {
+    /// Return pointer object.
+    ///
+    /// The ID provided will be initialized to the wl_pointer interface
+    /// for this seat.
+    ///
+    /// This request only takes effect if the seat has the pointer
+    /// capability, or has had the pointer capability in the past.
+    /// It is a protocol violation to issue this request on a seat that has
+    /// never had the pointer capability. The missing_capability error will
+    /// be sent in this case.
+    ///
+    /// Returns the new `wl_pointer` object.
+    pub async fn send_get_pointer(&self) -> Result<wl_pointer, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_pointer_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_pointer {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-    pub async fn send_get_touch(
-        &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
-        __packet.push_new_id_known_interface(id);
-        self.connection.send_packet(&__packet).await
+    /// Return keyboard object.
+    ///
+    /// The ID provided will be initialized to the wl_keyboard interface
+    /// for this seat.
+    ///
+    /// This request only takes effect if the seat has the keyboard
+    /// capability, or has had the keyboard capability in the past.
+    /// It is a protocol violation to issue this request on a seat that has
+    /// never had the keyboard capability. The missing_capability error will
+    /// be sent in this case.
+    ///
+    /// Returns the new `wl_keyboard` object.
+    pub async fn send_get_keyboard(&self) -> Result<wl_keyboard, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_keyboard_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_keyboard {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 3);
-        self.connection.send_packet(&__packet).await
+    /// Return touch object.
+    ///
+    /// The ID provided will be initialized to the wl_touch interface
+    /// for this seat.
+    ///
+    /// This request only takes effect if the seat has the touch
+    /// capability, or has had the touch capability in the past.
+    /// It is a protocol violation to issue this request on a seat that has
+    /// never had the touch capability. The missing_capability error will
+    /// be sent in this case.
+    ///
+    /// Returns the new `wl_touch` object.
+    pub async fn send_get_touch(&self) -> Result<wl_touch, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_touch_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_touch {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
-}
-pub struct wl_seat_v10_request_get_pointer_args {
+    /// Release the seat object.
+    ///
+    /// Using this request a client can tell the server that it is not going to
+    /// use the seat object anymore.
+    ///
+    /// Available since version 5.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 5 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_seat",
+                request: "release",
+                since: 5,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 3);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Return pointer object.
+///
+/// The ID provided will be initialized to the wl_pointer interface
+/// for this seat.
+///
+/// This request only takes effect if the seat has the pointer
+/// capability, or has had the pointer capability in the past.
+/// It is a protocol violation to issue this request on a seat that has
+/// never had the pointer capability. The missing_capability error will
+/// be sent in this case.
+///
+/// The arguments of the `wl_seat.get_pointer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_request_get_pointer_args {
+    /// Seat pointer.
     pub id: crate::NewObjectId,
 }
-impl wl_seat_v10_request_get_pointer_args {
+impl wl_seat_request_get_pointer_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_request_get_pointer_args, crate::Error> {
+    ) -> Result<wl_seat_request_get_pointer_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -4550,31 +10449,49 @@ impl wl_seat_v10_request_get_pointer_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_request_get_pointer_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_request_get_pointer_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_request_get_pointer_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_request_get_pointer_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_request_get_pointer_args),
+        __value: (crate::ObjectId, wl_seat_request_get_pointer_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_seat_v10_request_get_keyboard_args {
+/// Return keyboard object.
+///
+/// The ID provided will be initialized to the wl_keyboard interface
+/// for this seat.
+///
+/// This request only takes effect if the seat has the keyboard
+/// capability, or has had the keyboard capability in the past.
+/// It is a protocol violation to issue this request on a seat that has
+/// never had the keyboard capability. The missing_capability error will
+/// be sent in this case.
+///
+/// The arguments of the `wl_seat.get_keyboard` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_request_get_keyboard_args {
+    /// Seat keyboard.
     pub id: crate::NewObjectId,
 }
-impl wl_seat_v10_request_get_keyboard_args {
+impl wl_seat_request_get_keyboard_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_request_get_keyboard_args, crate::Error> {
+    ) -> Result<wl_seat_request_get_keyboard_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -4589,31 +10506,49 @@ impl wl_seat_v10_request_get_keyboard_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_request_get_keyboard_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_request_get_keyboard_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_request_get_keyboard_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_request_get_keyboard_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_request_get_keyboard_args),
+        __value: (crate::ObjectId, wl_seat_request_get_keyboard_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_seat_v10_request_get_touch_args {
+/// Return touch object.
+///
+/// The ID provided will be initialized to the wl_touch interface
+/// for this seat.
+///
+/// This request only takes effect if the seat has the touch
+/// capability, or has had the touch capability in the past.
+/// It is a protocol violation to issue this request on a seat that has
+/// never had the touch capability. The missing_capability error will
+/// be sent in this case.
+///
+/// The arguments of the `wl_seat.get_touch` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_request_get_touch_args {
+    /// Seat touch interface.
     pub id: crate::NewObjectId,
 }
-impl wl_seat_v10_request_get_touch_args {
+impl wl_seat_request_get_touch_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_request_get_touch_args, crate::Error> {
+    ) -> Result<wl_seat_request_get_touch_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         __reader.finish()?;
@@ -4628,29 +10563,40 @@ impl wl_seat_v10_request_get_touch_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_request_get_touch_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_request_get_touch_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_request_get_touch_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_request_get_touch_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_request_get_touch_args),
+        __value: (crate::ObjectId, wl_seat_request_get_touch_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_seat_v10_request_release_args {}
-impl wl_seat_v10_request_release_args {
+/// Release the seat object.
+///
+/// Using this request a client can tell the server that it is not going to
+/// use the seat object anymore.
+///
+/// Available since version 5.
+///
+/// The arguments of the `wl_seat.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_request_release_args {}
+impl wl_seat_request_release_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_request_release_args, crate::Error> {
+    ) -> Result<wl_seat_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -4663,33 +10609,68 @@ impl wl_seat_v10_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_request_release_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_request_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_request_release_args),
+        __value: (crate::ObjectId, wl_seat_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_seat_v10_event_capabilities_args {
-    pub capabilities: u32,
-}
-impl wl_seat_v10_event_capabilities_args {
+/// Seat capabilities changed.
+///
+/// This is sent on binding to the seat global or whenever a seat gains
+/// or loses the pointer, keyboard or touch capabilities.
+/// The argument is a capability enum containing the complete set of
+/// capabilities this seat has.
+///
+/// When the pointer capability is added, a client may create a
+/// wl_pointer object using the wl_seat.get_pointer request. This object
+/// will receive pointer events until the capability is removed in the
+/// future.
+///
+/// When the pointer capability is removed, a client should destroy the
+/// wl_pointer objects associated with the seat where the capability was
+/// removed, using the wl_pointer.release request. No further pointer
+/// events will be received on these objects.
+///
+/// In some compositors, if a seat regains the pointer capability and a
+/// client has a previously obtained wl_pointer object of version 4 or
+/// less, that object may start sending pointer events again. This
+/// behavior is considered a misinterpretation of the intended behavior
+/// and must not be relied upon by the client. wl_pointer objects of
+/// version 5 or later must not send events if created before the most
+/// recent event notifying the client of an added pointer capability.
+///
+/// The above behavior also applies to wl_keyboard and wl_touch with the
+/// keyboard and touch capabilities, respectively.
+///
+/// The arguments of the `wl_seat.capabilities` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_event_capabilities_args {
+    /// Capabilities of the seat.
+    pub capabilities: wl_seat_capability,
+}
+impl wl_seat_event_capabilities_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_event_capabilities_args, crate::Error> {
+    ) -> Result<wl_seat_event_capabilities_args, crate::Error> {
         let mut __reader = __value.read();
         let capabilities = __reader.read_uint()?;
+        let capabilities =
+            <wl_seat_capability as ::std::convert::TryFrom<u32>>::try_from(capabilities)?;
         __reader.finish()?;
         Ok(Self { capabilities })
     }
@@ -4698,35 +10679,61 @@ impl wl_seat_v10_event_capabilities_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.capabilities);
+        __packet.push_uint(u32::from(self.capabilities));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_event_capabilities_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_event_capabilities_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_event_capabilities_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_event_capabilities_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_event_capabilities_args),
+        __value: (crate::ObjectId, wl_seat_event_capabilities_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_seat_v10_event_name_args {
+/// Unique identifier for this seat.
+///
+/// In a multi-seat configuration the seat name can be used by clients to
+/// help identify which physical devices the seat represents.
+///
+/// The seat name is a UTF-8 string with no convention defined for its
+/// contents. Each name is unique among all wl_seat globals. The name is
+/// only guaranteed to be unique for the current compositor instance.
+///
+/// The same seat names are used for all clients. Thus, the name can be
+/// shared across processes to refer to a specific wl_seat global.
+///
+/// The name event is sent after binding to the seat global, and should be sent
+/// before announcing capabilities. This event only sent once per seat object,
+/// and the name does not change over the lifetime of the wl_seat global.
+///
+/// Compositors may re-use the same seat name if the wl_seat global is
+/// destroyed and re-created later.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_seat.name` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_event_name_args {
+    /// Seat identifier.
     pub name: ::std::string::String,
 }
-impl wl_seat_v10_event_name_args {
+impl wl_seat_event_name_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_seat_v10_event_name_args, crate::Error> {
+    ) -> Result<wl_seat_event_name_args, crate::Error> {
         let mut __reader = __value.read();
         let name = __reader.read_str()?;
         __reader.finish()?;
@@ -4741,39 +10748,380 @@ impl wl_seat_v10_event_name_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_seat_v10_event_name_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_seat_event_name_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_v10_event_name_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_seat_event_name_args)> for crate::Packet {
     type Error = crate::Error;
-    fn try_from(
-        __value: (crate::ObjectId, wl_seat_v10_event_name_args),
-    ) -> Result<Self, Self::Error> {
+    fn try_from(__value: (crate::ObjectId, wl_seat_event_name_args)) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_seat` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_seat_event {
+    /// Seat capabilities changed.
+    capabilities(wl_seat_event_capabilities_args),
+    /// Unique identifier for this seat.
+    name(wl_seat_event_name_args),
+}
+impl wl_seat_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::capabilities(
+                wl_seat_event_capabilities_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::name(wl_seat_event_name_args::try_from_packet(
+                __packet,
+            )?)),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_seat",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::capabilities(_) => wl_seat_event_capabilities_args::OPCODE,
+            Self::name(_) => wl_seat_event_name_args::OPCODE,
+        }
+    }
+}
+/// Seat capability bitmask.
+///
+/// This is a bitmask of capabilities this seat has; if a member is
+/// set, then it is present on the seat.
+///
+/// The bitfield `wl_seat.capability`.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_seat_capability(u32);
+impl wl_seat_capability {
+    /// The seat has pointer devices.
+    pub const pointer: Self = Self(1);
+    /// The seat has one or more keyboards.
+    pub const keyboard: Self = Self(2);
+    /// The seat has touch devices.
+    pub const touch: Self = Self(4);
+    const FLAGS: &'static [(&'static str, u32)] = &[("pointer", 1), ("keyboard", 2), ("touch", 4)];
+    /// No flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All flags known to the generated code.
+    pub const fn all() -> Self {
+        Self(7)
+    }
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+    /// Whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+    /// Whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+impl ::std::ops::BitOr for wl_seat_capability {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl ::std::ops::BitOrAssign for wl_seat_capability {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd for wl_seat_capability {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl ::std::ops::BitAndAssign for wl_seat_capability {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+impl ::std::fmt::Debug for wl_seat_capability {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+    }
+}
+impl ::std::convert::TryFrom<u32> for wl_seat_capability {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        if __value & !Self::all().0 == 0 {
+            Ok(Self(__value))
+        } else {
+            Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_seat.capability",
+                value: __value,
+            })
+        }
+    }
+}
+impl ::std::convert::From<wl_seat_capability> for u32 {
+    fn from(__value: wl_seat_capability) -> u32 {
+        __value.0
+    }
+}
+/// Wl_seat error values.
+///
+/// These errors can be emitted in response to wl_seat requests.
+///
+/// The enum `wl_seat.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_seat_error {
+    /// Get_pointer, get_keyboard or get_touch called on seat without the matching capability.
+    missing_capability = 0,
+}
+impl ::std::convert::TryFrom<u32> for wl_seat_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::missing_capability),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_seat.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_seat_error> for u32 {
+    fn from(__value: wl_seat_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_pointer`.
+pub const wl_pointer_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_pointer",
+    version: 10,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "set_cursor",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "release",
+            destructor: true,
+            args: &[],
+        },
+    ],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "enter",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Fixed,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "leave",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "motion",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Fixed,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "button",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "frame",
+            destructor: false,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis_source",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Uint],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis_stop",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis_discrete",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis_value120",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "axis_relative_direction",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+    ],
+};
+/// Pointer input device.
+///
+/// The wl_pointer interface represents one or more input devices,
+/// such as mice, which control the pointer location and pointer_focus
+/// of a seat.
+///
+/// The wl_pointer interface generates motion, enter and leave
+/// events for the surfaces that the pointer is located over,
+/// and button and axis events for button presses, button releases
+/// and scrolling.
+///
+/// A proxy for a `wl_pointer` object. The generated code knows this interface up to version 10.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_pointer {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_pointer_interface;
+}
+impl crate::protocol::Proxy for wl_pointer {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_pointer` object.
 #[allow(unused)]
-pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
+pub trait wl_pointer_event_handler: crate::protocol::EventHandler {
+    /// Enter event.
+    ///
+    /// Notification that this seat's pointer is focused on a certain
+    /// surface.
+    ///
+    /// When a seat's focus enters a surface, the pointer image
+    /// is undefined and a client should respond to this event by setting
+    /// an appropriate pointer image with the set_cursor request.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the enter event
+    /// * `surface`: surface entered by the pointer
+    /// * `surface_x`: surface-local x coordinate
+    /// * `surface_y`: surface-local y coordinate
     fn handle_enter(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
+        surface: crate::ObjectId,
         surface_x: crate::Fixed,
         surface_y: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Leave event.
+    ///
+    /// Notification that this seat's pointer is no longer focused on
+    /// a certain surface.
+    ///
+    /// The leave notification is sent before the enter notification
+    /// for the new focus.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the leave event
+    /// * `surface`: surface left by the pointer
     fn handle_leave(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        surface: crate::ObjectId,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Pointer motion event.
+    ///
+    /// Notification of pointer location change. The arguments
+    /// surface_x and surface_y are the location relative to the
+    /// focused surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `surface_x`: surface-local x coordinate
+    /// * `surface_y`: surface-local y coordinate
     fn handle_motion(
         &self,
         connection: &crate::Connection,
@@ -4781,7 +11129,32 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
         time: u32,
         surface_x: crate::Fixed,
         surface_y: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Pointer button event.
+    ///
+    /// Mouse button click and release notifications.
+    ///
+    /// The location of the click is given by the last motion or
+    /// enter event.
+    /// The time argument is a timestamp with millisecond
+    /// granularity, with an undefined base.
+    ///
+    /// The button is a button code as defined in the Linux kernel's
+    /// linux/input-event-codes.h header file, e.g. BTN_LEFT.
+    ///
+    /// Any 16-bit button code value is reserved for future additions to the
+    /// kernel's event code list. All other button codes above 0xFFFF are
+    /// currently undefined but may be used in future versions of this
+    /// protocol.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the button event
+    /// * `time`: timestamp with millisecond granularity
+    /// * `button`: button that produced the event
+    /// * `state`: physical state of the button
     fn handle_button(
         &self,
         connection: &crate::Connection,
@@ -4789,64 +11162,321 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
         serial: u32,
         time: u32,
         button: u32,
-        state: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        state: wl_pointer_button_state,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis event.
+    ///
+    /// Scroll and other axis notifications.
+    ///
+    /// For scroll events (vertical and horizontal scroll axes), the
+    /// value parameter is the length of a vector along the specified
+    /// axis in a coordinate space identical to those of motion events,
+    /// representing a relative movement along the specified axis.
+    ///
+    /// For devices that support movements non-parallel to axes multiple
+    /// axis events will be emitted.
+    ///
+    /// When applicable, for example for touch pads, the server can
+    /// choose to emit scroll events where the motion vector is
+    /// equivalent to a motion event vector.
+    ///
+    /// When applicable, a client can transform its content relative to the
+    /// scroll distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `axis`: axis type
+    /// * `value`: length of vector in surface-local coordinate space
     fn handle_axis(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         time: u32,
-        axis: u32,
+        axis: wl_pointer_axis,
         value: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// End of a pointer event sequence.
+    ///
+    /// Indicates the end of a set of events that logically belong together.
+    /// A client is expected to accumulate the data in all events within the
+    /// frame before proceeding.
+    ///
+    /// All wl_pointer events before a wl_pointer.frame event belong
+    /// logically together. For example, in a diagonal scroll motion the
+    /// compositor will send an optional wl_pointer.axis_source event, two
+    /// wl_pointer.axis events (horizontal and vertical) and finally a
+    /// wl_pointer.frame event. The client may use this information to
+    /// calculate a diagonal vector for scrolling.
+    ///
+    /// When multiple wl_pointer.axis events occur within the same frame,
+    /// the motion vector is the combined motion of all events.
+    /// When a wl_pointer.axis and a wl_pointer.axis_stop event occur within
+    /// the same frame, this indicates that axis movement in one axis has
+    /// stopped but continues in the other axis.
+    /// When multiple wl_pointer.axis_stop events occur within the same
+    /// frame, this indicates that these axes stopped in the same instance.
+    ///
+    /// A wl_pointer.frame event is sent for every logical event group,
+    /// even if the group only contains a single wl_pointer event.
+    /// Specifically, a client may get a sequence: motion, frame, button,
+    /// frame, axis, frame, axis_stop, frame.
+    ///
+    /// The wl_pointer.enter and wl_pointer.leave events are logical events
+    /// generated by the compositor and not the hardware. These events are
+    /// also grouped by a wl_pointer.frame. When a pointer moves from one
+    /// surface to another, a compositor should group the
+    /// wl_pointer.leave event within the same wl_pointer.frame.
+    /// However, a client must not rely on wl_pointer.leave and
+    /// wl_pointer.enter being in the same wl_pointer.frame.
+    /// Compositor-specific policies may require the wl_pointer.leave and
+    /// wl_pointer.enter event being split across multiple wl_pointer.frame
+    /// groups.
+    ///
+    /// Available since version 5.
     fn handle_frame(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis source event.
+    ///
+    /// Source information for scroll and other axes.
+    ///
+    /// This event does not occur on its own. It is sent before a
+    /// wl_pointer.frame event and carries the source information for
+    /// all events within that frame.
+    ///
+    /// The source specifies how this event was generated. If the source is
+    /// wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be
+    /// sent when the user lifts the finger off the device.
+    ///
+    /// If the source is wl_pointer.axis_source.wheel,
+    /// wl_pointer.axis_source.wheel_tilt or
+    /// wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may
+    /// or may not be sent. Whether a compositor sends an axis_stop event
+    /// for these sources is hardware-specific and implementation-dependent;
+    /// clients must not rely on receiving an axis_stop event for these
+    /// scroll sources and should treat scroll sequences from these scroll
+    /// sources as unterminated by default.
+    ///
+    /// This event is optional. If the source is unknown for a particular
+    /// axis event sequence, no event is sent.
+    /// Only one wl_pointer.axis_source event is permitted per frame.
+    ///
+    /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// Available since version 5.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis_source`: source of the axis event
     fn handle_axis_source(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        axis_source: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        axis_source: wl_pointer_axis_source,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis stop event.
+    ///
+    /// Stop notification for scroll and other axes.
+    ///
+    /// For some wl_pointer.axis_source types, a wl_pointer.axis_stop event
+    /// is sent to notify a client that the axis sequence has terminated.
+    /// This enables the client to implement kinetic scrolling.
+    /// See the wl_pointer.axis_source documentation for information on when
+    /// this event may be generated.
+    ///
+    /// Any wl_pointer.axis events with the same axis_source after this
+    /// event should be considered as the start of a new axis motion.
+    ///
+    /// The timestamp is to be interpreted identical to the timestamp in the
+    /// wl_pointer.axis event. The timestamp value may be the same as a
+    /// preceding wl_pointer.axis event.
+    ///
+    /// Available since version 5.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `axis`: the axis stopped with this event
     fn handle_axis_stop(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         time: u32,
-        axis: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        axis: wl_pointer_axis,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis click event.
+    ///
+    /// Discrete step information for scroll and other axes.
+    ///
+    /// This event carries the axis value of the wl_pointer.axis event in
+    /// discrete steps (e.g. mouse wheel clicks).
+    ///
+    /// This event is deprecated with wl_pointer version 8 - this event is not
+    /// sent to clients supporting version 8 or later.
+    ///
+    /// This event does not occur on its own, it is coupled with a
+    /// wl_pointer.axis event that represents this axis value on a
+    /// continuous scale. The protocol guarantees that each axis_discrete
+    /// event is always followed by exactly one axis event with the same
+    /// axis number within the same wl_pointer.frame. Note that the protocol
+    /// allows for other events to occur between the axis_discrete and
+    /// its coupled axis event, including other axis_discrete or axis
+    /// events. A wl_pointer.frame must not contain more than one axis_discrete
+    /// event per axis type.
+    ///
+    /// This event is optional; continuous scrolling devices
+    /// like two-finger scrolling on touchpads do not have discrete
+    /// steps and do not generate this event.
+    ///
+    /// The discrete value carries the directional information. e.g. a value
+    /// of -2 is two steps towards the negative direction of this axis.
+    ///
+    /// The axis number is identical to the axis number in the associated
+    /// axis event.
+    ///
+    /// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// Available since version 5.
+    ///
+    /// Deprecated since version 8.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: axis type
+    /// * `discrete`: number of steps
     fn handle_axis_discrete(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        axis: u32,
+        axis: wl_pointer_axis,
         discrete: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis high-resolution scroll event.
+    ///
+    /// Discrete high-resolution scroll information.
+    ///
+    /// This event carries high-resolution wheel scroll information,
+    /// with each multiple of 120 representing one logical scroll step
+    /// (a wheel detent). For example, an axis_value120 of 30 is one quarter of
+    /// a logical scroll step in the positive direction, a value120 of
+    /// -240 are two logical scroll steps in the negative direction within the
+    /// same hardware event.
+    /// Clients that rely on discrete scrolling should accumulate the
+    /// value120 to multiples of 120 before processing the event.
+    ///
+    /// The value120 must not be zero.
+    ///
+    /// This event replaces the wl_pointer.axis_discrete event in clients
+    /// supporting wl_pointer version 8 or later.
+    ///
+    /// Where a wl_pointer.axis_source event occurs in the same
+    /// wl_pointer.frame, the axis source applies to this event.
+    ///
+    /// The order of wl_pointer.axis_value120 and wl_pointer.axis_source is
+    /// not guaranteed.
+    ///
+    /// Available since version 8.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: axis type
+    /// * `value120`: scroll distance as fraction of 120
     fn handle_axis_value120(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        axis: u32,
+        axis: wl_pointer_axis,
         value120: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Axis relative physical direction event.
+    ///
+    /// Relative directional information of the entity causing the axis
+    /// motion.
+    ///
+    /// For a wl_pointer.axis event, the wl_pointer.axis_relative_direction
+    /// event specifies the movement direction of the entity causing the
+    /// wl_pointer.axis event. For example:
+    /// - if a user's fingers on a touchpad move down and this
+    ///   causes a wl_pointer.axis vertical_scroll down event, the physical
+    ///   direction is 'identical'
+    /// - if a user's fingers on a touchpad move down and this causes a
+    ///   wl_pointer.axis vertical_scroll up scroll up event ('natural
+    ///   scrolling'), the physical direction is 'inverted'.
+    ///
+    /// A client may use this information to adjust scroll motion of
+    /// components. Specifically, enabling natural scrolling causes the
+    /// content to change direction compared to traditional scrolling.
+    /// Some widgets like volume control sliders should usually match the
+    /// physical direction regardless of whether natural scrolling is
+    /// active. This event enables clients to match the scroll direction of
+    /// a widget to the physical direction.
+    ///
+    /// This event does not occur on its own, it is coupled with a
+    /// wl_pointer.axis event that represents this axis value.
+    /// The protocol guarantees that each axis_relative_direction event is
+    /// always followed by exactly one axis event with the same
+    /// axis number within the same wl_pointer.frame. Note that the protocol
+    /// allows for other events to occur between the axis_relative_direction
+    /// and its coupled axis event.
+    ///
+    /// The axis number is identical to the axis number in the associated
+    /// axis event.
+    ///
+    /// The order of wl_pointer.axis_relative_direction,
+    /// wl_pointer.axis_discrete and wl_pointer.axis_source is not
+    /// guaranteed.
+    ///
+    /// Available since version 9.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis`: axis type
+    /// * `direction`: physical direction relative to axis motion
     fn handle_axis_relative_direction(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        axis: u32,
-        direction: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        axis: wl_pointer_axis,
+        direction: wl_pointer_axis_relative_direction,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_pointer",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -4874,8 +11504,7 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
                         surface_x,
                         surface_y,
                     )
-                    .await;
-                    Ok(())
+                    .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
@@ -4883,8 +11512,7 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
                     let surface = __packet_reader.read_object()?;
                     __packet_reader.finish()?;
                     self.handle_leave(__connection, __packet, serial, surface)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
@@ -4893,8 +11521,7 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
                     let surface_y = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_motion(__connection, __packet, time, surface_x, surface_y)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
@@ -4902,122 +11529,224 @@ pub trait wl_pointer_v10_event_handler: crate::protocol::EventHandler {
                     let time = __packet_reader.read_uint()?;
                     let button = __packet_reader.read_uint()?;
                     let state = __packet_reader.read_uint()?;
+                    let state =
+                        <wl_pointer_button_state as ::std::convert::TryFrom<u32>>::try_from(state)?;
                     __packet_reader.finish()?;
                     self.handle_button(__connection, __packet, serial, time, button, state)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
                     let time = __packet_reader.read_uint()?;
                     let axis = __packet_reader.read_uint()?;
+                    let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
                     let value = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_axis(__connection, __packet, time, axis, value)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_frame(__connection, __packet).await;
-                    Ok(())
+                    self.handle_frame(__connection, __packet).await
                 }
                 6 => {
                     let mut __packet_reader = __packet.read();
                     let axis_source = __packet_reader.read_uint()?;
+                    let axis_source =
+                        <wl_pointer_axis_source as ::std::convert::TryFrom<u32>>::try_from(
+                            axis_source,
+                        )?;
                     __packet_reader.finish()?;
                     self.handle_axis_source(__connection, __packet, axis_source)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 7 => {
                     let mut __packet_reader = __packet.read();
                     let time = __packet_reader.read_uint()?;
                     let axis = __packet_reader.read_uint()?;
+                    let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
                     __packet_reader.finish()?;
                     self.handle_axis_stop(__connection, __packet, time, axis)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 8 => {
                     let mut __packet_reader = __packet.read();
                     let axis = __packet_reader.read_uint()?;
+                    let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
                     let discrete = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_axis_discrete(__connection, __packet, axis, discrete)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 9 => {
                     let mut __packet_reader = __packet.read();
                     let axis = __packet_reader.read_uint()?;
+                    let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
                     let value120 = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_axis_value120(__connection, __packet, axis, value120)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 10 => {
                     let mut __packet_reader = __packet.read();
                     let axis = __packet_reader.read_uint()?;
+                    let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
                     let direction = __packet_reader.read_uint()?;
+                    let direction = < wl_pointer_axis_relative_direction as :: std :: convert :: TryFrom < u32 > > :: try_from (direction) ? ;
                     __packet_reader.finish()?;
                     self.handle_axis_relative_direction(__connection, __packet, axis, direction)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_pointer_v10_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_pointer_v10_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
+impl wl_pointer {
+    /// Set the pointer surface.
+    ///
+    /// Set the pointer surface, i.e., the surface that contains the
+    /// pointer image (cursor). This request gives the surface the role
+    /// of a cursor. If the surface already has another role, it raises
+    /// a protocol error.
+    ///
+    /// The cursor actually changes only if the pointer
+    /// focus for this device is one of the requesting client's surfaces
+    /// or the surface parameter is the current pointer surface. If
+    /// there was a previous surface set with this request it is
+    /// replaced. If surface is NULL, the pointer image is hidden.
+    ///
+    /// The parameters hotspot_x and hotspot_y define the position of
+    /// the pointer surface relative to the pointer location. Its
+    /// top-left corner is always at (x, y) - (hotspot_x, hotspot_y),
+    /// where (x, y) are the coordinates of the pointer location, in
+    /// surface-local coordinates.
+    ///
+    /// On wl_surface.offset requests to the pointer surface, hotspot_x
+    /// and hotspot_y are decremented by the x and y parameters
+    /// passed to the request. The offset must be applied by
+    /// wl_surface.commit as usual.
+    ///
+    /// The hotspot can also be updated by passing the currently set
+    /// pointer surface to this request with new values for hotspot_x
+    /// and hotspot_y.
+    ///
+    /// The input region is ignored for wl_surfaces with the role of
+    /// a cursor. When the use as a cursor ends, the wl_surface is
+    /// unmapped.
+    ///
+    /// The serial parameter must match the latest wl_pointer.enter
+    /// serial number sent to the client. Otherwise the request will be
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the enter event
+    /// * `surface`: pointer surface
+    /// * `hotspot_x`: surface-local x coordinate
+    /// * `hotspot_y`: surface-local y coordinate
     pub async fn send_set_cursor(
         &self,
-        __object_id: crate::ObjectId,
         serial: u32,
         surface: ::std::option::Option<crate::ObjectId>,
         hotspot_x: i32,
         hotspot_y: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
         __packet.push_uint(serial);
-        __packet.push_object(surface);
+        __packet.push_nullable_object(surface);
         __packet.push_int(hotspot_x);
         __packet.push_int(hotspot_y);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_pointer_v10_request_set_cursor_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Release the pointer object.
+    ///
+    /// Using this request a client can tell the server that it is not going to
+    /// use the pointer object anymore.
+    ///
+    /// This request destroys the pointer proxy object, so clients must not call
+    /// wl_pointer_destroy() after using this request.
+    ///
+    /// Available since version 3.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_pointer",
+                request: "release",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Set the pointer surface.
+///
+/// Set the pointer surface, i.e., the surface that contains the
+/// pointer image (cursor). This request gives the surface the role
+/// of a cursor. If the surface already has another role, it raises
+/// a protocol error.
+///
+/// The cursor actually changes only if the pointer
+/// focus for this device is one of the requesting client's surfaces
+/// or the surface parameter is the current pointer surface. If
+/// there was a previous surface set with this request it is
+/// replaced. If surface is NULL, the pointer image is hidden.
+///
+/// The parameters hotspot_x and hotspot_y define the position of
+/// the pointer surface relative to the pointer location. Its
+/// top-left corner is always at (x, y) - (hotspot_x, hotspot_y),
+/// where (x, y) are the coordinates of the pointer location, in
+/// surface-local coordinates.
+///
+/// On wl_surface.offset requests to the pointer surface, hotspot_x
+/// and hotspot_y are decremented by the x and y parameters
+/// passed to the request. The offset must be applied by
+/// wl_surface.commit as usual.
+///
+/// The hotspot can also be updated by passing the currently set
+/// pointer surface to this request with new values for hotspot_x
+/// and hotspot_y.
+///
+/// The input region is ignored for wl_surfaces with the role of
+/// a cursor. When the use as a cursor ends, the wl_surface is
+/// unmapped.
+///
+/// The serial parameter must match the latest wl_pointer.enter
+/// serial number sent to the client. Otherwise the request will be
+/// ignored.
+///
+/// The arguments of the `wl_pointer.set_cursor` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_request_set_cursor_args {
+    /// Serial number of the enter event.
     pub serial: u32,
+    /// Pointer surface.
     pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface-local x coordinate.
     pub hotspot_x: i32,
+    /// Surface-local y coordinate.
     pub hotspot_y: i32,
 }
-impl wl_pointer_v10_request_set_cursor_args {
+impl wl_pointer_request_set_cursor_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_request_set_cursor_args, crate::Error> {
+    ) -> Result<wl_pointer_request_set_cursor_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
-        let surface = __reader.read_object()?;
+        let surface = __reader.read_nullable_object()?;
         let hotspot_x = __reader.read_int()?;
         let hotspot_y = __reader.read_int()?;
         __reader.finish()?;
@@ -5034,35 +11763,51 @@ impl wl_pointer_v10_request_set_cursor_args {
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
         __packet.push_uint(self.serial);
-        __packet.push_object(self.surface);
+        __packet.push_nullable_object(self.surface);
         __packet.push_int(self.hotspot_x);
         __packet.push_int(self.hotspot_y);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_request_set_cursor_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_request_set_cursor_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_request_set_cursor_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_request_set_cursor_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_request_set_cursor_args),
+        __value: (crate::ObjectId, wl_pointer_request_set_cursor_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_request_release_args {}
-impl wl_pointer_v10_request_release_args {
+/// Release the pointer object.
+///
+/// Using this request a client can tell the server that it is not going to
+/// use the pointer object anymore.
+///
+/// This request destroys the pointer proxy object, so clients must not call
+/// wl_pointer_destroy() after using this request.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_pointer.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_request_release_args {}
+impl wl_pointer_request_release_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_request_release_args, crate::Error> {
+    ) -> Result<wl_pointer_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -5075,34 +11820,51 @@ impl wl_pointer_v10_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_request_release_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_request_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_request_release_args),
+        __value: (crate::ObjectId, wl_pointer_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_enter_args {
+/// Enter event.
+///
+/// Notification that this seat's pointer is focused on a certain
+/// surface.
+///
+/// When a seat's focus enters a surface, the pointer image
+/// is undefined and a client should respond to this event by setting
+/// an appropriate pointer image with the set_cursor request.
+///
+/// The arguments of the `wl_pointer.enter` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_enter_args {
+    /// Serial number of the enter event.
     pub serial: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface entered by the pointer.
+    pub surface: crate::ObjectId,
+    /// Surface-local x coordinate.
     pub surface_x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub surface_y: crate::Fixed,
 }
-impl wl_pointer_v10_event_enter_args {
+impl wl_pointer_event_enter_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_enter_args, crate::Error> {
+    ) -> Result<wl_pointer_event_enter_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let surface = __reader.read_object()?;
@@ -5128,30 +11890,46 @@ impl wl_pointer_v10_event_enter_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_enter_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_enter_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_enter_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_enter_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_enter_args),
+        __value: (crate::ObjectId, wl_pointer_event_enter_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_leave_args {
+/// Leave event.
+///
+/// Notification that this seat's pointer is no longer focused on
+/// a certain surface.
+///
+/// The leave notification is sent before the enter notification
+/// for the new focus.
+///
+/// The arguments of the `wl_pointer.leave` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_leave_args {
+    /// Serial number of the leave event.
     pub serial: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface left by the pointer.
+    pub surface: crate::ObjectId,
 }
-impl wl_pointer_v10_event_leave_args {
+impl wl_pointer_event_leave_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_leave_args, crate::Error> {
+    ) -> Result<wl_pointer_event_leave_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let surface = __reader.read_object()?;
@@ -5168,31 +11946,46 @@ impl wl_pointer_v10_event_leave_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_leave_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_leave_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_leave_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_leave_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_leave_args),
+        __value: (crate::ObjectId, wl_pointer_event_leave_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_motion_args {
+/// Pointer motion event.
+///
+/// Notification of pointer location change. The arguments
+/// surface_x and surface_y are the location relative to the
+/// focused surface.
+///
+/// The arguments of the `wl_pointer.motion` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_motion_args {
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// Surface-local x coordinate.
     pub surface_x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub surface_y: crate::Fixed,
 }
-impl wl_pointer_v10_event_motion_args {
+impl wl_pointer_event_motion_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_motion_args, crate::Error> {
+    ) -> Result<wl_pointer_event_motion_args, crate::Error> {
         let mut __reader = __value.read();
         let time = __reader.read_uint()?;
         let surface_x = __reader.read_fixed()?;
@@ -5215,39 +12008,65 @@ impl wl_pointer_v10_event_motion_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_motion_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_motion_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_motion_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_motion_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_motion_args),
+        __value: (crate::ObjectId, wl_pointer_event_motion_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_button_args {
+/// Pointer button event.
+///
+/// Mouse button click and release notifications.
+///
+/// The location of the click is given by the last motion or
+/// enter event.
+/// The time argument is a timestamp with millisecond
+/// granularity, with an undefined base.
+///
+/// The button is a button code as defined in the Linux kernel's
+/// linux/input-event-codes.h header file, e.g. BTN_LEFT.
+///
+/// Any 16-bit button code value is reserved for future additions to the
+/// kernel's event code list. All other button codes above 0xFFFF are
+/// currently undefined but may be used in future versions of this
+/// protocol.
+///
+/// The arguments of the `wl_pointer.button` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_button_args {
+    /// Serial number of the button event.
     pub serial: u32,
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// Button that produced the event.
     pub button: u32,
-    pub state: u32,
+    /// Physical state of the button.
+    pub state: wl_pointer_button_state,
 }
-impl wl_pointer_v10_event_button_args {
+impl wl_pointer_event_button_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_button_args, crate::Error> {
+    ) -> Result<wl_pointer_event_button_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let time = __reader.read_uint()?;
         let button = __reader.read_uint()?;
         let state = __reader.read_uint()?;
+        let state = <wl_pointer_button_state as ::std::convert::TryFrom<u32>>::try_from(state)?;
         __reader.finish()?;
         Ok(Self {
             serial,
@@ -5264,40 +12083,67 @@ impl wl_pointer_v10_event_button_args {
         __packet.push_uint(self.serial);
         __packet.push_uint(self.time);
         __packet.push_uint(self.button);
-        __packet.push_uint(self.state);
+        __packet.push_uint(u32::from(self.state));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_button_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_button_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_button_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_button_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_button_args),
+        __value: (crate::ObjectId, wl_pointer_event_button_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_args {
+/// Axis event.
+///
+/// Scroll and other axis notifications.
+///
+/// For scroll events (vertical and horizontal scroll axes), the
+/// value parameter is the length of a vector along the specified
+/// axis in a coordinate space identical to those of motion events,
+/// representing a relative movement along the specified axis.
+///
+/// For devices that support movements non-parallel to axes multiple
+/// axis events will be emitted.
+///
+/// When applicable, for example for touch pads, the server can
+/// choose to emit scroll events where the motion vector is
+/// equivalent to a motion event vector.
+///
+/// When applicable, a client can transform its content relative to the
+/// scroll distance.
+///
+/// The arguments of the `wl_pointer.axis` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_args {
+    /// Timestamp with millisecond granularity.
     pub time: u32,
-    pub axis: u32,
+    /// Axis type.
+    pub axis: wl_pointer_axis,
+    /// Length of vector in surface-local coordinate space.
     pub value: crate::Fixed,
 }
-impl wl_pointer_v10_event_axis_args {
+impl wl_pointer_event_axis_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_args, crate::Error> {
         let mut __reader = __value.read();
         let time = __reader.read_uint()?;
         let axis = __reader.read_uint()?;
+        let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
         let value = __reader.read_fixed()?;
         __reader.finish()?;
         Ok(Self { time, axis, value })
@@ -5308,32 +12154,77 @@ impl wl_pointer_v10_event_axis_args {
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
         __packet.push_uint(self.time);
-        __packet.push_uint(self.axis);
+        __packet.push_uint(u32::from(self.axis));
         __packet.push_fixed(self.value);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_axis_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_axis_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_axis_args),
+        __value: (crate::ObjectId, wl_pointer_event_axis_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_frame_args {}
-impl wl_pointer_v10_event_frame_args {
+/// End of a pointer event sequence.
+///
+/// Indicates the end of a set of events that logically belong together.
+/// A client is expected to accumulate the data in all events within the
+/// frame before proceeding.
+///
+/// All wl_pointer events before a wl_pointer.frame event belong
+/// logically together. For example, in a diagonal scroll motion the
+/// compositor will send an optional wl_pointer.axis_source event, two
+/// wl_pointer.axis events (horizontal and vertical) and finally a
+/// wl_pointer.frame event. The client may use this information to
+/// calculate a diagonal vector for scrolling.
+///
+/// When multiple wl_pointer.axis events occur within the same frame,
+/// the motion vector is the combined motion of all events.
+/// When a wl_pointer.axis and a wl_pointer.axis_stop event occur within
+/// the same frame, this indicates that axis movement in one axis has
+/// stopped but continues in the other axis.
+/// When multiple wl_pointer.axis_stop events occur within the same
+/// frame, this indicates that these axes stopped in the same instance.
+///
+/// A wl_pointer.frame event is sent for every logical event group,
+/// even if the group only contains a single wl_pointer event.
+/// Specifically, a client may get a sequence: motion, frame, button,
+/// frame, axis, frame, axis_stop, frame.
+///
+/// The wl_pointer.enter and wl_pointer.leave events are logical events
+/// generated by the compositor and not the hardware. These events are
+/// also grouped by a wl_pointer.frame. When a pointer moves from one
+/// surface to another, a compositor should group the
+/// wl_pointer.leave event within the same wl_pointer.frame.
+/// However, a client must not rely on wl_pointer.leave and
+/// wl_pointer.enter being in the same wl_pointer.frame.
+/// Compositor-specific policies may require the wl_pointer.leave and
+/// wl_pointer.enter event being split across multiple wl_pointer.frame
+/// groups.
+///
+/// Available since version 5.
+///
+/// The arguments of the `wl_pointer.frame` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_frame_args {}
+impl wl_pointer_event_frame_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_frame_args, crate::Error> {
+    ) -> Result<wl_pointer_event_frame_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -5346,31 +12237,70 @@ impl wl_pointer_v10_event_frame_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_frame_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_frame_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_frame_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_frame_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_frame_args),
+        __value: (crate::ObjectId, wl_pointer_event_frame_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_source_args {
-    pub axis_source: u32,
-}
-impl wl_pointer_v10_event_axis_source_args {
+/// Axis source event.
+///
+/// Source information for scroll and other axes.
+///
+/// This event does not occur on its own. It is sent before a
+/// wl_pointer.frame event and carries the source information for
+/// all events within that frame.
+///
+/// The source specifies how this event was generated. If the source is
+/// wl_pointer.axis_source.finger, a wl_pointer.axis_stop event will be
+/// sent when the user lifts the finger off the device.
+///
+/// If the source is wl_pointer.axis_source.wheel,
+/// wl_pointer.axis_source.wheel_tilt or
+/// wl_pointer.axis_source.continuous, a wl_pointer.axis_stop event may
+/// or may not be sent. Whether a compositor sends an axis_stop event
+/// for these sources is hardware-specific and implementation-dependent;
+/// clients must not rely on receiving an axis_stop event for these
+/// scroll sources and should treat scroll sequences from these scroll
+/// sources as unterminated by default.
+///
+/// This event is optional. If the source is unknown for a particular
+/// axis event sequence, no event is sent.
+/// Only one wl_pointer.axis_source event is permitted per frame.
+///
+/// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+/// not guaranteed.
+///
+/// Available since version 5.
+///
+/// The arguments of the `wl_pointer.axis_source` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_source_args {
+    /// Source of the axis event.
+    pub axis_source: wl_pointer_axis_source,
+}
+impl wl_pointer_event_axis_source_args {
     pub const OPCODE: u16 = 6;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_source_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_source_args, crate::Error> {
         let mut __reader = __value.read();
         let axis_source = __reader.read_uint()?;
+        let axis_source =
+            <wl_pointer_axis_source as ::std::convert::TryFrom<u32>>::try_from(axis_source)?;
         __reader.finish()?;
         Ok(Self { axis_source })
     }
@@ -5379,39 +12309,67 @@ impl wl_pointer_v10_event_axis_source_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.axis_source);
+        __packet.push_uint(u32::from(self.axis_source));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_source_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_source_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_axis_source_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_axis_source_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_axis_source_args),
+        __value: (crate::ObjectId, wl_pointer_event_axis_source_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_stop_args {
+/// Axis stop event.
+///
+/// Stop notification for scroll and other axes.
+///
+/// For some wl_pointer.axis_source types, a wl_pointer.axis_stop event
+/// is sent to notify a client that the axis sequence has terminated.
+/// This enables the client to implement kinetic scrolling.
+/// See the wl_pointer.axis_source documentation for information on when
+/// this event may be generated.
+///
+/// Any wl_pointer.axis events with the same axis_source after this
+/// event should be considered as the start of a new axis motion.
+///
+/// The timestamp is to be interpreted identical to the timestamp in the
+/// wl_pointer.axis event. The timestamp value may be the same as a
+/// preceding wl_pointer.axis event.
+///
+/// Available since version 5.
+///
+/// The arguments of the `wl_pointer.axis_stop` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_stop_args {
+    /// Timestamp with millisecond granularity.
     pub time: u32,
-    pub axis: u32,
+    /// The axis stopped with this event.
+    pub axis: wl_pointer_axis,
 }
-impl wl_pointer_v10_event_axis_stop_args {
+impl wl_pointer_event_axis_stop_args {
     pub const OPCODE: u16 = 7;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_stop_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_stop_args, crate::Error> {
         let mut __reader = __value.read();
         let time = __reader.read_uint()?;
         let axis = __reader.read_uint()?;
+        let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
         __reader.finish()?;
         Ok(Self { time, axis })
     }
@@ -5421,38 +12379,82 @@ impl wl_pointer_v10_event_axis_stop_args {
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
         __packet.push_uint(self.time);
-        __packet.push_uint(self.axis);
+        __packet.push_uint(u32::from(self.axis));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_stop_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_stop_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_axis_stop_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_axis_stop_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_axis_stop_args),
+        __value: (crate::ObjectId, wl_pointer_event_axis_stop_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_discrete_args {
-    pub axis: u32,
+/// Axis click event.
+///
+/// Discrete step information for scroll and other axes.
+///
+/// This event carries the axis value of the wl_pointer.axis event in
+/// discrete steps (e.g. mouse wheel clicks).
+///
+/// This event is deprecated with wl_pointer version 8 - this event is not
+/// sent to clients supporting version 8 or later.
+///
+/// This event does not occur on its own, it is coupled with a
+/// wl_pointer.axis event that represents this axis value on a
+/// continuous scale. The protocol guarantees that each axis_discrete
+/// event is always followed by exactly one axis event with the same
+/// axis number within the same wl_pointer.frame. Note that the protocol
+/// allows for other events to occur between the axis_discrete and
+/// its coupled axis event, including other axis_discrete or axis
+/// events. A wl_pointer.frame must not contain more than one axis_discrete
+/// event per axis type.
+///
+/// This event is optional; continuous scrolling devices
+/// like two-finger scrolling on touchpads do not have discrete
+/// steps and do not generate this event.
+///
+/// The discrete value carries the directional information. e.g. a value
+/// of -2 is two steps towards the negative direction of this axis.
+///
+/// The axis number is identical to the axis number in the associated
+/// axis event.
+///
+/// The order of wl_pointer.axis_discrete and wl_pointer.axis_source is
+/// not guaranteed.
+///
+/// Available since version 5.
+///
+/// Deprecated since version 8.
+///
+/// The arguments of the `wl_pointer.axis_discrete` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_discrete_args {
+    /// Axis type.
+    pub axis: wl_pointer_axis,
+    /// Number of steps.
     pub discrete: i32,
 }
-impl wl_pointer_v10_event_axis_discrete_args {
+impl wl_pointer_event_axis_discrete_args {
     pub const OPCODE: u16 = 8;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 5;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = Some(8);
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_discrete_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_discrete_args, crate::Error> {
         let mut __reader = __value.read();
         let axis = __reader.read_uint()?;
+        let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
         let discrete = __reader.read_int()?;
         __reader.finish()?;
         Ok(Self { axis, discrete })
@@ -5462,39 +12464,74 @@ impl wl_pointer_v10_event_axis_discrete_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.axis);
+        __packet.push_uint(u32::from(self.axis));
         __packet.push_int(self.discrete);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_discrete_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_discrete_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_axis_discrete_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_axis_discrete_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_axis_discrete_args),
+        __value: (crate::ObjectId, wl_pointer_event_axis_discrete_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_value120_args {
-    pub axis: u32,
+/// Axis high-resolution scroll event.
+///
+/// Discrete high-resolution scroll information.
+///
+/// This event carries high-resolution wheel scroll information,
+/// with each multiple of 120 representing one logical scroll step
+/// (a wheel detent). For example, an axis_value120 of 30 is one quarter of
+/// a logical scroll step in the positive direction, a value120 of
+/// -240 are two logical scroll steps in the negative direction within the
+/// same hardware event.
+/// Clients that rely on discrete scrolling should accumulate the
+/// value120 to multiples of 120 before processing the event.
+///
+/// The value120 must not be zero.
+///
+/// This event replaces the wl_pointer.axis_discrete event in clients
+/// supporting wl_pointer version 8 or later.
+///
+/// Where a wl_pointer.axis_source event occurs in the same
+/// wl_pointer.frame, the axis source applies to this event.
+///
+/// The order of wl_pointer.axis_value120 and wl_pointer.axis_source is
+/// not guaranteed.
+///
+/// Available since version 8.
+///
+/// The arguments of the `wl_pointer.axis_value120` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_value120_args {
+    /// Axis type.
+    pub axis: wl_pointer_axis,
+    /// Scroll distance as fraction of 120.
     pub value120: i32,
 }
-impl wl_pointer_v10_event_axis_value120_args {
+impl wl_pointer_event_axis_value120_args {
     pub const OPCODE: u16 = 9;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 8;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_value120_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_value120_args, crate::Error> {
         let mut __reader = __value.read();
         let axis = __reader.read_uint()?;
+        let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
         let value120 = __reader.read_int()?;
         __reader.finish()?;
         Ok(Self { axis, value120 })
@@ -5504,40 +12541,93 @@ impl wl_pointer_v10_event_axis_value120_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.axis);
+        __packet.push_uint(u32::from(self.axis));
         __packet.push_int(self.value120);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_value120_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_value120_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_v10_event_axis_value120_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_pointer_event_axis_value120_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_pointer_v10_event_axis_value120_args),
+        __value: (crate::ObjectId, wl_pointer_event_axis_value120_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_pointer_v10_event_axis_relative_direction_args {
-    pub axis: u32,
-    pub direction: u32,
-}
-impl wl_pointer_v10_event_axis_relative_direction_args {
+/// Axis relative physical direction event.
+///
+/// Relative directional information of the entity causing the axis
+/// motion.
+///
+/// For a wl_pointer.axis event, the wl_pointer.axis_relative_direction
+/// event specifies the movement direction of the entity causing the
+/// wl_pointer.axis event. For example:
+/// - if a user's fingers on a touchpad move down and this
+///   causes a wl_pointer.axis vertical_scroll down event, the physical
+///   direction is 'identical'
+/// - if a user's fingers on a touchpad move down and this causes a
+///   wl_pointer.axis vertical_scroll up scroll up event ('natural
+///   scrolling'), the physical direction is 'inverted'.
+///
+/// A client may use this information to adjust scroll motion of
+/// components. Specifically, enabling natural scrolling causes the
+/// content to change direction compared to traditional scrolling.
+/// Some widgets like volume control sliders should usually match the
+/// physical direction regardless of whether natural scrolling is
+/// active. This event enables clients to match the scroll direction of
+/// a widget to the physical direction.
+///
+/// This event does not occur on its own, it is coupled with a
+/// wl_pointer.axis event that represents this axis value.
+/// The protocol guarantees that each axis_relative_direction event is
+/// always followed by exactly one axis event with the same
+/// axis number within the same wl_pointer.frame. Note that the protocol
+/// allows for other events to occur between the axis_relative_direction
+/// and its coupled axis event.
+///
+/// The axis number is identical to the axis number in the associated
+/// axis event.
+///
+/// The order of wl_pointer.axis_relative_direction,
+/// wl_pointer.axis_discrete and wl_pointer.axis_source is not
+/// guaranteed.
+///
+/// Available since version 9.
+///
+/// The arguments of the `wl_pointer.axis_relative_direction` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_pointer_event_axis_relative_direction_args {
+    /// Axis type.
+    pub axis: wl_pointer_axis,
+    /// Physical direction relative to axis motion.
+    pub direction: wl_pointer_axis_relative_direction,
+}
+impl wl_pointer_event_axis_relative_direction_args {
     pub const OPCODE: u16 = 10;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 9;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_pointer_v10_event_axis_relative_direction_args, crate::Error> {
+    ) -> Result<wl_pointer_event_axis_relative_direction_args, crate::Error> {
         let mut __reader = __value.read();
         let axis = __reader.read_uint()?;
+        let axis = <wl_pointer_axis as ::std::convert::TryFrom<u32>>::try_from(axis)?;
         let direction = __reader.read_uint()?;
+        let direction =
+            <wl_pointer_axis_relative_direction as ::std::convert::TryFrom<u32>>::try_from(
+                direction,
+            )?;
         __reader.finish()?;
         Ok(Self { axis, direction })
     }
@@ -5546,12 +12636,12 @@ impl wl_pointer_v10_event_axis_relative_direction_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.axis);
-        __packet.push_uint(self.direction);
+        __packet.push_uint(u32::from(self.axis));
+        __packet.push_uint(u32::from(self.direction));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_relative_direction_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_event_axis_relative_direction_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -5560,45 +12650,507 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_pointer_v10_event_axis_relati
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_pointer_v10_event_axis_relative_direction_args,
+        wl_pointer_event_axis_relative_direction_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_pointer_v10_event_axis_relative_direction_args,
+            wl_pointer_event_axis_relative_direction_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_pointer` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_pointer_event {
+    /// Enter event.
+    enter(wl_pointer_event_enter_args),
+    /// Leave event.
+    leave(wl_pointer_event_leave_args),
+    /// Pointer motion event.
+    motion(wl_pointer_event_motion_args),
+    /// Pointer button event.
+    button(wl_pointer_event_button_args),
+    /// Axis event.
+    axis(wl_pointer_event_axis_args),
+    /// End of a pointer event sequence.
+    frame(wl_pointer_event_frame_args),
+    /// Axis source event.
+    axis_source(wl_pointer_event_axis_source_args),
+    /// Axis stop event.
+    axis_stop(wl_pointer_event_axis_stop_args),
+    /// Axis click event.
+    axis_discrete(wl_pointer_event_axis_discrete_args),
+    /// Axis high-resolution scroll event.
+    axis_value120(wl_pointer_event_axis_value120_args),
+    /// Axis relative physical direction event.
+    axis_relative_direction(wl_pointer_event_axis_relative_direction_args),
+}
+impl wl_pointer_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::enter(wl_pointer_event_enter_args::try_from_packet(
+                __packet,
+            )?)),
+            1 => Ok(Self::leave(wl_pointer_event_leave_args::try_from_packet(
+                __packet,
+            )?)),
+            2 => Ok(Self::motion(wl_pointer_event_motion_args::try_from_packet(
+                __packet,
+            )?)),
+            3 => Ok(Self::button(wl_pointer_event_button_args::try_from_packet(
+                __packet,
+            )?)),
+            4 => Ok(Self::axis(wl_pointer_event_axis_args::try_from_packet(
+                __packet,
+            )?)),
+            5 => Ok(Self::frame(wl_pointer_event_frame_args::try_from_packet(
+                __packet,
+            )?)),
+            6 => Ok(Self::axis_source(
+                wl_pointer_event_axis_source_args::try_from_packet(__packet)?,
+            )),
+            7 => Ok(Self::axis_stop(
+                wl_pointer_event_axis_stop_args::try_from_packet(__packet)?,
+            )),
+            8 => Ok(Self::axis_discrete(
+                wl_pointer_event_axis_discrete_args::try_from_packet(__packet)?,
+            )),
+            9 => Ok(Self::axis_value120(
+                wl_pointer_event_axis_value120_args::try_from_packet(__packet)?,
+            )),
+            10 => Ok(Self::axis_relative_direction(
+                wl_pointer_event_axis_relative_direction_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_pointer",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::enter(_) => wl_pointer_event_enter_args::OPCODE,
+            Self::leave(_) => wl_pointer_event_leave_args::OPCODE,
+            Self::motion(_) => wl_pointer_event_motion_args::OPCODE,
+            Self::button(_) => wl_pointer_event_button_args::OPCODE,
+            Self::axis(_) => wl_pointer_event_axis_args::OPCODE,
+            Self::frame(_) => wl_pointer_event_frame_args::OPCODE,
+            Self::axis_source(_) => wl_pointer_event_axis_source_args::OPCODE,
+            Self::axis_stop(_) => wl_pointer_event_axis_stop_args::OPCODE,
+            Self::axis_discrete(_) => wl_pointer_event_axis_discrete_args::OPCODE,
+            Self::axis_value120(_) => wl_pointer_event_axis_value120_args::OPCODE,
+            Self::axis_relative_direction(_) => {
+                wl_pointer_event_axis_relative_direction_args::OPCODE
+            }
+        }
+    }
+}
+/// The enum `wl_pointer.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_pointer_error {
+    /// Given wl_surface has another role.
+    role = 0,
+}
+impl ::std::convert::TryFrom<u32> for wl_pointer_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::role),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_pointer.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_pointer_error> for u32 {
+    fn from(__value: wl_pointer_error) -> u32 {
+        __value as u32
+    }
+}
+/// Physical button state.
+///
+/// Describes the physical state of a button that produced the button
+/// event.
+///
+/// The enum `wl_pointer.button_state`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_pointer_button_state {
+    /// The button is not pressed.
+    released = 0,
+    /// The button is pressed.
+    pressed = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_pointer_button_state {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::released),
+            1 => Ok(Self::pressed),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_pointer.button_state",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_pointer_button_state> for u32 {
+    fn from(__value: wl_pointer_button_state) -> u32 {
+        __value as u32
+    }
+}
+/// Axis types.
+///
+/// Describes the axis types of scroll events.
+///
+/// The enum `wl_pointer.axis`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_pointer_axis {
+    /// Vertical axis.
+    vertical_scroll = 0,
+    /// Horizontal axis.
+    horizontal_scroll = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_pointer_axis {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::vertical_scroll),
+            1 => Ok(Self::horizontal_scroll),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_pointer.axis",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_pointer_axis> for u32 {
+    fn from(__value: wl_pointer_axis) -> u32 {
+        __value as u32
+    }
+}
+/// Axis source types.
+///
+/// Describes the source types for axis events. This indicates to the
+/// client how an axis event was physically generated; a client may
+/// adjust the user interface accordingly. For example, scroll events
+/// from a "finger" source may be in a smooth coordinate space with
+/// kinetic scrolling whereas a "wheel" source may be in discrete steps
+/// of a number of lines.
+///
+/// The "continuous" axis source is a device generating events in a
+/// continuous coordinate space, but using something other than a
+/// finger. One example for this source is button-based scrolling where
+/// the vertical motion of a device is converted to scroll events while
+/// a button is held down.
+///
+/// The "wheel tilt" axis source indicates that the actual device is a
+/// wheel but the scroll event is not caused by a rotation but a
+/// (usually sideways) tilt of the wheel.
+///
+/// The enum `wl_pointer.axis_source`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_pointer_axis_source {
+    /// A physical wheel rotation.
+    wheel = 0,
+    /// Finger on a touch surface.
+    finger = 1,
+    /// Continuous coordinate space.
+    continuous = 2,
+    /// A physical wheel tilt.
+    wheel_tilt = 3,
+}
+impl ::std::convert::TryFrom<u32> for wl_pointer_axis_source {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::wheel),
+            1 => Ok(Self::finger),
+            2 => Ok(Self::continuous),
+            3 => Ok(Self::wheel_tilt),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_pointer.axis_source",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_pointer_axis_source> for u32 {
+    fn from(__value: wl_pointer_axis_source) -> u32 {
+        __value as u32
+    }
+}
+/// Axis relative direction.
+///
+/// This specifies the direction of the physical motion that caused a
+/// wl_pointer.axis event, relative to the wl_pointer.axis direction.
+///
+/// The enum `wl_pointer.axis_relative_direction`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_pointer_axis_relative_direction {
+    /// Physical motion matches axis direction.
+    identical = 0,
+    /// Physical motion is the inverse of the axis direction.
+    inverted = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_pointer_axis_relative_direction {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::identical),
+            1 => Ok(Self::inverted),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_pointer.axis_relative_direction",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_pointer_axis_relative_direction> for u32 {
+    fn from(__value: wl_pointer_axis_relative_direction) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_keyboard`.
+pub const wl_keyboard_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_keyboard",
+    version: 10,
+    requests: &[crate::protocol::MessageInfo {
+        name: "release",
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "keymap",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Fd,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "enter",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Array,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "leave",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "key",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "modifiers",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "repeat_info",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int, crate::protocol::ArgKind::Int],
+        },
+    ],
+};
+/// Keyboard input device.
+///
+/// The wl_keyboard interface represents one or more keyboards
+/// associated with a seat.
+///
+/// Each wl_keyboard has the following logical state:
+///
+/// - an active surface (possibly null),
+/// - the keys currently logically down,
+/// - the active modifiers,
+/// - the active group.
+///
+/// By default, the active surface is null, the keys currently logically down
+/// are empty, the active modifiers and the active group are 0.
+///
+/// A proxy for a `wl_keyboard` object. The generated code knows this interface up to version 10.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_keyboard {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_keyboard_interface;
+}
+impl crate::protocol::Proxy for wl_keyboard {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_keyboard` object.
 #[allow(unused)]
-pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
+pub trait wl_keyboard_event_handler: crate::protocol::EventHandler {
+    /// Keyboard mapping.
+    ///
+    /// This event provides a file descriptor to the client which can be
+    /// memory-mapped in read-only mode to provide a keyboard mapping
+    /// description.
+    ///
+    /// From version 7 onwards, the fd must be mapped with MAP_PRIVATE by
+    /// the recipient, as MAP_SHARED may fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `format`: keymap format
+    /// * `fd`: keymap file descriptor
+    /// * `size`: keymap size, in bytes
     fn handle_keymap(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        format: u32,
+        format: wl_keyboard_keymap_format,
         fd: ::std::os::fd::RawFd,
         size: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Enter event.
+    ///
+    /// Notification that this seat's keyboard focus is on a certain
+    /// surface.
+    ///
+    /// The compositor must send the wl_keyboard.modifiers event after this
+    /// event.
+    ///
+    /// In the wl_keyboard logical state, this event sets the active surface to
+    /// the surface argument and the keys currently logically down to the keys
+    /// in the keys argument. The compositor must not send this event if the
+    /// wl_keyboard already had an active surface immediately before this event.
+    ///
+    /// Clients should not use the list of pressed keys to emulate key-press
+    /// events. The order of keys in the list is unspecified.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the enter event
+    /// * `surface`: surface gaining keyboard focus
+    /// * `keys`: the keys currently logically down
     fn handle_enter(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
+        surface: crate::ObjectId,
         keys: ::std::vec::Vec<u8>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Leave event.
+    ///
+    /// Notification that this seat's keyboard focus is no longer on
+    /// a certain surface.
+    ///
+    /// The leave notification is sent before the enter notification
+    /// for the new focus.
+    ///
+    /// In the wl_keyboard logical state, this event resets all values to their
+    /// defaults. The compositor must not send this event if the active surface
+    /// of the wl_keyboard was not equal to the surface argument immediately
+    /// before this event.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the leave event
+    /// * `surface`: surface that lost keyboard focus
     fn handle_leave(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        surface: crate::ObjectId,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Key event.
+    ///
+    /// A key was pressed or released.
+    /// The time argument is a timestamp with millisecond
+    /// granularity, with an undefined base.
+    ///
+    /// The key is a platform-specific key code that can be interpreted
+    /// by feeding it to the keyboard mapping (see the keymap event).
+    ///
+    /// If this event produces a change in modifiers, then the resulting
+    /// wl_keyboard.modifiers event must be sent after this event.
+    ///
+    /// In the wl_keyboard logical state, this event adds the key to the keys
+    /// currently logically down (if the state argument is pressed) or removes
+    /// the key from the keys currently logically down (if the state argument is
+    /// released). The compositor must not send this event if the wl_keyboard
+    /// did not have an active surface immediately before this event. The
+    /// compositor must not send this event if state is pressed (resp. released)
+    /// and the key was already logically down (resp. was not logically down)
+    /// immediately before this event.
+    ///
+    /// Since version 10, compositors may send key events with the "repeated"
+    /// key state when a wl_keyboard.repeat_info event with a rate argument of
+    /// 0 has been received. This allows the compositor to take over the
+    /// responsibility of key repetition.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the key event
+    /// * `time`: timestamp with millisecond granularity
+    /// * `key`: key that produced the event
+    /// * `state`: physical state of the key
     fn handle_key(
         &self,
         connection: &crate::Connection,
@@ -5606,8 +13158,33 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
         serial: u32,
         time: u32,
         key: u32,
-        state: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        state: wl_keyboard_key_state,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Modifier and group state.
+    ///
+    /// Notifies clients that the modifier and/or group state has
+    /// changed, and it should update its local state.
+    ///
+    /// The compositor may send this event without a surface of the client
+    /// having keyboard focus, for example to tie modifier information to
+    /// pointer focus instead. If a modifier event with pressed modifiers is sent
+    /// without a prior enter event, the client can assume the modifier state is
+    /// valid until it receives the next wl_keyboard.modifiers event. In order to
+    /// reset the modifier state again, the compositor can send a
+    /// wl_keyboard.modifiers event with no pressed modifiers.
+    ///
+    /// In the wl_keyboard logical state, this event updates the modifiers and
+    /// group.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the modifiers event
+    /// * `mods_depressed`: depressed modifiers
+    /// * `mods_latched`: latched modifiers
+    /// * `mods_locked`: locked modifiers
+    /// * `group`: keyboard layout
     fn handle_modifiers(
         &self,
         connection: &crate::Connection,
@@ -5617,23 +13194,56 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
         mods_latched: u32,
         mods_locked: u32,
         group: u32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Repeat rate and delay.
+    ///
+    /// Informs the client about the keyboard's repeat rate and delay.
+    ///
+    /// This event is sent as soon as the wl_keyboard object has been created,
+    /// and is guaranteed to be received by the client before any key press
+    /// event.
+    ///
+    /// Negative values for either rate or delay are illegal. A rate of zero
+    /// will disable any repeating (regardless of the value of delay).
+    ///
+    /// This event can be sent later on as well with a new value if necessary,
+    /// so clients should continue listening for the event past the creation
+    /// of wl_keyboard.
+    ///
+    /// Available since version 4.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate`: the rate of repeating keys in characters per second
+    /// * `delay`: delay in milliseconds since key down until repeating starts
     fn handle_repeat_info(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         rate: i32,
         delay: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_keyboard",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -5649,12 +13259,15 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                 0 => {
                     let mut __packet_reader = __packet.read();
                     let format = __packet_reader.read_uint()?;
+                    let format =
+                        <wl_keyboard_keymap_format as ::std::convert::TryFrom<u32>>::try_from(
+                            format,
+                        )?;
                     let fd = __packet_reader.read_fd()?;
                     let size = __packet_reader.read_uint()?;
                     __packet_reader.finish()?;
                     self.handle_keymap(__connection, __packet, format, fd, size)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
@@ -5663,8 +13276,7 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                     let keys = __packet_reader.read_array()?;
                     __packet_reader.finish()?;
                     self.handle_enter(__connection, __packet, serial, surface, keys)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
@@ -5672,8 +13284,7 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                     let surface = __packet_reader.read_object()?;
                     __packet_reader.finish()?;
                     self.handle_leave(__connection, __packet, serial, surface)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
@@ -5681,10 +13292,11 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                     let time = __packet_reader.read_uint()?;
                     let key = __packet_reader.read_uint()?;
                     let state = __packet_reader.read_uint()?;
+                    let state =
+                        <wl_keyboard_key_state as ::std::convert::TryFrom<u32>>::try_from(state)?;
                     __packet_reader.finish()?;
                     self.handle_key(__connection, __packet, serial, time, key, state)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
@@ -5703,8 +13315,7 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                         mods_locked,
                         group,
                     )
-                    .await;
-                    Ok(())
+                    .await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
@@ -5712,37 +13323,49 @@ pub trait wl_keyboard_v10_event_handler: crate::protocol::EventHandler {
                     let delay = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_repeat_info(__connection, __packet, rate, delay)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_keyboard_v10_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_keyboard_v10_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_keyboard_v10_request_release_args {}
-impl wl_keyboard_v10_request_release_args {
+impl wl_keyboard {
+    /// Release the keyboard object.
+    ///
+    /// Available since version 3.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_keyboard",
+                request: "release",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Release the keyboard object.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_keyboard.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_request_release_args {}
+impl wl_keyboard_request_release_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_request_release_args, crate::Error> {
+    ) -> Result<wl_keyboard_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -5755,35 +13378,54 @@ impl wl_keyboard_v10_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_request_release_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_request_release_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_request_release_args),
+        __value: (crate::ObjectId, wl_keyboard_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_keymap_args {
-    pub format: u32,
+/// Keyboard mapping.
+///
+/// This event provides a file descriptor to the client which can be
+/// memory-mapped in read-only mode to provide a keyboard mapping
+/// description.
+///
+/// From version 7 onwards, the fd must be mapped with MAP_PRIVATE by
+/// the recipient, as MAP_SHARED may fail.
+///
+/// The arguments of the `wl_keyboard.keymap` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_keymap_args {
+    /// Keymap format.
+    pub format: wl_keyboard_keymap_format,
+    /// Keymap file descriptor.
     pub fd: ::std::os::fd::RawFd,
+    /// Keymap size, in bytes.
     pub size: u32,
 }
-impl wl_keyboard_v10_event_keymap_args {
+impl wl_keyboard_event_keymap_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_keymap_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_keymap_args, crate::Error> {
         let mut __reader = __value.read();
         let format = __reader.read_uint()?;
+        let format = <wl_keyboard_keymap_format as ::std::convert::TryFrom<u32>>::try_from(format)?;
         let fd = __reader.read_fd()?;
         let size = __reader.read_uint()?;
         __reader.finish()?;
@@ -5794,39 +13436,62 @@ impl wl_keyboard_v10_event_keymap_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.format);
+        __packet.push_uint(u32::from(self.format));
         __packet.push_fd(self.fd);
         __packet.push_uint(self.size);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_keymap_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_keymap_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_keymap_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_keymap_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_keymap_args),
+        __value: (crate::ObjectId, wl_keyboard_event_keymap_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_enter_args {
+/// Enter event.
+///
+/// Notification that this seat's keyboard focus is on a certain
+/// surface.
+///
+/// The compositor must send the wl_keyboard.modifiers event after this
+/// event.
+///
+/// In the wl_keyboard logical state, this event sets the active surface to
+/// the surface argument and the keys currently logically down to the keys
+/// in the keys argument. The compositor must not send this event if the
+/// wl_keyboard already had an active surface immediately before this event.
+///
+/// Clients should not use the list of pressed keys to emulate key-press
+/// events. The order of keys in the list is unspecified.
+///
+/// The arguments of the `wl_keyboard.enter` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_enter_args {
+    /// Serial number of the enter event.
     pub serial: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface gaining keyboard focus.
+    pub surface: crate::ObjectId,
+    /// The keys currently logically down.
     pub keys: ::std::vec::Vec<u8>,
 }
-impl wl_keyboard_v10_event_enter_args {
+impl wl_keyboard_event_enter_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_enter_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_enter_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let surface = __reader.read_object()?;
@@ -5849,32 +13514,51 @@ impl wl_keyboard_v10_event_enter_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_enter_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_enter_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_enter_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_enter_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_enter_args),
+        __value: (crate::ObjectId, wl_keyboard_event_enter_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_leave_args {
+/// Leave event.
+///
+/// Notification that this seat's keyboard focus is no longer on
+/// a certain surface.
+///
+/// The leave notification is sent before the enter notification
+/// for the new focus.
+///
+/// In the wl_keyboard logical state, this event resets all values to their
+/// defaults. The compositor must not send this event if the active surface
+/// of the wl_keyboard was not equal to the surface argument immediately
+/// before this event.
+///
+/// The arguments of the `wl_keyboard.leave` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_leave_args {
+    /// Serial number of the leave event.
     pub serial: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface that lost keyboard focus.
+    pub surface: crate::ObjectId,
 }
-impl wl_keyboard_v10_event_leave_args {
+impl wl_keyboard_event_leave_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_leave_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_leave_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let surface = __reader.read_object()?;
@@ -5891,39 +13575,74 @@ impl wl_keyboard_v10_event_leave_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_leave_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_leave_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_leave_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_leave_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_leave_args),
+        __value: (crate::ObjectId, wl_keyboard_event_leave_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_key_args {
+/// Key event.
+///
+/// A key was pressed or released.
+/// The time argument is a timestamp with millisecond
+/// granularity, with an undefined base.
+///
+/// The key is a platform-specific key code that can be interpreted
+/// by feeding it to the keyboard mapping (see the keymap event).
+///
+/// If this event produces a change in modifiers, then the resulting
+/// wl_keyboard.modifiers event must be sent after this event.
+///
+/// In the wl_keyboard logical state, this event adds the key to the keys
+/// currently logically down (if the state argument is pressed) or removes
+/// the key from the keys currently logically down (if the state argument is
+/// released). The compositor must not send this event if the wl_keyboard
+/// did not have an active surface immediately before this event. The
+/// compositor must not send this event if state is pressed (resp. released)
+/// and the key was already logically down (resp. was not logically down)
+/// immediately before this event.
+///
+/// Since version 10, compositors may send key events with the "repeated"
+/// key state when a wl_keyboard.repeat_info event with a rate argument of
+/// 0 has been received. This allows the compositor to take over the
+/// responsibility of key repetition.
+///
+/// The arguments of the `wl_keyboard.key` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_key_args {
+    /// Serial number of the key event.
     pub serial: u32,
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// Key that produced the event.
     pub key: u32,
-    pub state: u32,
+    /// Physical state of the key.
+    pub state: wl_keyboard_key_state,
 }
-impl wl_keyboard_v10_event_key_args {
+impl wl_keyboard_event_key_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_key_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_key_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let time = __reader.read_uint()?;
         let key = __reader.read_uint()?;
         let state = __reader.read_uint()?;
+        let state = <wl_keyboard_key_state as ::std::convert::TryFrom<u32>>::try_from(state)?;
         __reader.finish()?;
         Ok(Self {
             serial,
@@ -5940,37 +13659,64 @@ impl wl_keyboard_v10_event_key_args {
         __packet.push_uint(self.serial);
         __packet.push_uint(self.time);
         __packet.push_uint(self.key);
-        __packet.push_uint(self.state);
+        __packet.push_uint(u32::from(self.state));
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_key_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_key_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_key_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_key_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_key_args),
+        __value: (crate::ObjectId, wl_keyboard_event_key_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_modifiers_args {
+/// Modifier and group state.
+///
+/// Notifies clients that the modifier and/or group state has
+/// changed, and it should update its local state.
+///
+/// The compositor may send this event without a surface of the client
+/// having keyboard focus, for example to tie modifier information to
+/// pointer focus instead. If a modifier event with pressed modifiers is sent
+/// without a prior enter event, the client can assume the modifier state is
+/// valid until it receives the next wl_keyboard.modifiers event. In order to
+/// reset the modifier state again, the compositor can send a
+/// wl_keyboard.modifiers event with no pressed modifiers.
+///
+/// In the wl_keyboard logical state, this event updates the modifiers and
+/// group.
+///
+/// The arguments of the `wl_keyboard.modifiers` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_modifiers_args {
+    /// Serial number of the modifiers event.
     pub serial: u32,
+    /// Depressed modifiers.
     pub mods_depressed: u32,
+    /// Latched modifiers.
     pub mods_latched: u32,
+    /// Locked modifiers.
     pub mods_locked: u32,
+    /// Keyboard layout.
     pub group: u32,
 }
-impl wl_keyboard_v10_event_modifiers_args {
+impl wl_keyboard_event_modifiers_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_modifiers_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_modifiers_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let mods_depressed = __reader.read_uint()?;
@@ -5999,32 +13745,57 @@ impl wl_keyboard_v10_event_modifiers_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_modifiers_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_modifiers_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_modifiers_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_modifiers_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_modifiers_args),
+        __value: (crate::ObjectId, wl_keyboard_event_modifiers_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_keyboard_v10_event_repeat_info_args {
+/// Repeat rate and delay.
+///
+/// Informs the client about the keyboard's repeat rate and delay.
+///
+/// This event is sent as soon as the wl_keyboard object has been created,
+/// and is guaranteed to be received by the client before any key press
+/// event.
+///
+/// Negative values for either rate or delay are illegal. A rate of zero
+/// will disable any repeating (regardless of the value of delay).
+///
+/// This event can be sent later on as well with a new value if necessary,
+/// so clients should continue listening for the event past the creation
+/// of wl_keyboard.
+///
+/// Available since version 4.
+///
+/// The arguments of the `wl_keyboard.repeat_info` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_keyboard_event_repeat_info_args {
+    /// The rate of repeating keys in characters per second.
     pub rate: i32,
+    /// Delay in milliseconds since key down until repeating starts.
     pub delay: i32,
 }
-impl wl_keyboard_v10_event_repeat_info_args {
+impl wl_keyboard_event_repeat_info_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 4;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_keyboard_v10_event_repeat_info_args, crate::Error> {
+    ) -> Result<wl_keyboard_event_repeat_info_args, crate::Error> {
         let mut __reader = __value.read();
         let rate = __reader.read_int()?;
         let delay = __reader.read_int()?;
@@ -6041,36 +13812,309 @@ impl wl_keyboard_v10_event_repeat_info_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_v10_event_repeat_info_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_keyboard_event_repeat_info_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_v10_event_repeat_info_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_keyboard_event_repeat_info_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_keyboard_v10_event_repeat_info_args),
+        __value: (crate::ObjectId, wl_keyboard_event_repeat_info_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_keyboard` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_keyboard_event {
+    /// Keyboard mapping.
+    keymap(wl_keyboard_event_keymap_args),
+    /// Enter event.
+    enter(wl_keyboard_event_enter_args),
+    /// Leave event.
+    leave(wl_keyboard_event_leave_args),
+    /// Key event.
+    key(wl_keyboard_event_key_args),
+    /// Modifier and group state.
+    modifiers(wl_keyboard_event_modifiers_args),
+    /// Repeat rate and delay.
+    repeat_info(wl_keyboard_event_repeat_info_args),
+}
+impl wl_keyboard_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::keymap(
+                wl_keyboard_event_keymap_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::enter(wl_keyboard_event_enter_args::try_from_packet(
+                __packet,
+            )?)),
+            2 => Ok(Self::leave(wl_keyboard_event_leave_args::try_from_packet(
+                __packet,
+            )?)),
+            3 => Ok(Self::key(wl_keyboard_event_key_args::try_from_packet(
+                __packet,
+            )?)),
+            4 => Ok(Self::modifiers(
+                wl_keyboard_event_modifiers_args::try_from_packet(__packet)?,
+            )),
+            5 => Ok(Self::repeat_info(
+                wl_keyboard_event_repeat_info_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_keyboard",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::keymap(_) => wl_keyboard_event_keymap_args::OPCODE,
+            Self::enter(_) => wl_keyboard_event_enter_args::OPCODE,
+            Self::leave(_) => wl_keyboard_event_leave_args::OPCODE,
+            Self::key(_) => wl_keyboard_event_key_args::OPCODE,
+            Self::modifiers(_) => wl_keyboard_event_modifiers_args::OPCODE,
+            Self::repeat_info(_) => wl_keyboard_event_repeat_info_args::OPCODE,
+        }
+    }
+}
+/// Keyboard mapping format.
+///
+/// This specifies the format of the keymap provided to the
+/// client with the wl_keyboard.keymap event.
+///
+/// The enum `wl_keyboard.keymap_format`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_keyboard_keymap_format {
+    /// No keymap; client must understand how to interpret the raw keycode.
+    no_keymap = 0,
+    /// Libxkbcommon compatible, null-terminated string; to determine the xkb keycode, clients must add 8 to the key event keycode.
+    xkb_v1 = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_keyboard_keymap_format {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::no_keymap),
+            1 => Ok(Self::xkb_v1),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_keyboard.keymap_format",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_keyboard_keymap_format> for u32 {
+    fn from(__value: wl_keyboard_keymap_format) -> u32 {
+        __value as u32
+    }
+}
+/// Physical key state.
+///
+/// Describes the physical state of a key that produced the key event.
+///
+/// Since version 10, the key can be in a "repeated" pseudo-state which
+/// means the same as "pressed", but is used to signal repetition in the
+/// key event.
+///
+/// The key may only enter the repeated state after entering the pressed
+/// state and before entering the released state. This event may be
+/// generated multiple times while the key is down.
+///
+/// The enum `wl_keyboard.key_state`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_keyboard_key_state {
+    /// Key is not pressed.
+    released = 0,
+    /// Key is pressed.
+    pressed = 1,
+    /// Key was repeated.
+    repeated = 2,
+}
+impl ::std::convert::TryFrom<u32> for wl_keyboard_key_state {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::released),
+            1 => Ok(Self::pressed),
+            2 => Ok(Self::repeated),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_keyboard.key_state",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_keyboard_key_state> for u32 {
+    fn from(__value: wl_keyboard_key_state) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_touch`.
+pub const wl_touch_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_touch",
+    version: 10,
+    requests: &[crate::protocol::MessageInfo {
+        name: "release",
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "down",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Object,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Fixed,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "up",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "motion",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Fixed,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "frame",
+            destructor: false,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "cancel",
+            destructor: false,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "shape",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Fixed,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "orientation",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Fixed,
+            ],
+        },
+    ],
+};
+/// Touchscreen input device.
+///
+/// The wl_touch interface represents a touchscreen
+/// associated with a seat.
+///
+/// Touch interactions can consist of one or more contacts.
+/// For each contact, a series of events is generated, starting
+/// with a down event, followed by zero or more motion events,
+/// and ending with an up event. Events relating to the same
+/// contact point can be identified by the ID of the sequence.
+///
+/// A proxy for a `wl_touch` object. The generated code knows this interface up to version 10.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_touch {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_touch_interface;
+}
+impl crate::protocol::Proxy for wl_touch {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_touch` object.
 #[allow(unused)]
-pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
+pub trait wl_touch_event_handler: crate::protocol::EventHandler {
+    /// Touch down event and beginning of a touch sequence.
+    ///
+    /// A new touch point has appeared on the surface. This touch point is
+    /// assigned a unique ID. Future events from this touch point reference
+    /// this ID. The ID ceases to be valid after a touch up event and may be
+    /// reused in the future.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the touch down event
+    /// * `time`: timestamp with millisecond granularity
+    /// * `surface`: surface touched
+    /// * `id`: the unique ID of this touch point
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
     fn handle_down(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         serial: u32,
         time: u32,
-        surface: ::std::option::Option<crate::ObjectId>,
+        surface: crate::ObjectId,
         id: i32,
         x: crate::Fixed,
         y: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// End of a touch event sequence.
+    ///
+    /// The touch point has disappeared. No further events will be sent for
+    /// this touch point and the touch point's ID is released and may be
+    /// reused in a future touch down event.
+    ///
+    /// # Arguments
+    ///
+    /// * `serial`: serial number of the touch up event
+    /// * `time`: timestamp with millisecond granularity
+    /// * `id`: the unique ID of this touch point
     fn handle_up(
         &self,
         connection: &crate::Connection,
@@ -6078,7 +14122,19 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
         serial: u32,
         time: u32,
         id: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Update of touch point coordinates.
+    ///
+    /// A touch point has changed coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `time`: timestamp with millisecond granularity
+    /// * `id`: the unique ID of this touch point
+    /// * `x`: surface-local x coordinate
+    /// * `y`: surface-local y coordinate
     fn handle_motion(
         &self,
         connection: &crate::Connection,
@@ -6087,17 +14143,78 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
         id: i32,
         x: crate::Fixed,
         y: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// End of touch frame event.
+    ///
+    /// Indicates the end of a set of events that logically belong together.
+    /// A client is expected to accumulate the data in all events within the
+    /// frame before proceeding.
+    ///
+    /// A wl_touch.frame terminates at least one event but otherwise no
+    /// guarantee is provided about the set of events within a frame. A client
+    /// must assume that any state not updated in a frame is unchanged from the
+    /// previously known state.
     fn handle_frame(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Touch session cancelled.
+    ///
+    /// Sent if the compositor decides the touch stream is a global
+    /// gesture. No further events are sent to the clients from that
+    /// particular gesture. Touch cancellation applies to all touch points
+    /// currently active on this client's surface. The client is
+    /// responsible for finalizing the touch points, future touch points on
+    /// this surface may reuse the touch point ID.
+    ///
+    /// No frame event is required after the cancel event.
     fn handle_cancel(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Update shape of touch point.
+    ///
+    /// Sent when a touchpoint has changed its shape.
+    ///
+    /// This event does not occur on its own. It is sent before a
+    /// wl_touch.frame event and carries the new shape information for
+    /// any previously reported, or new touch points of that frame.
+    ///
+    /// Other events describing the touch point such as wl_touch.down,
+    /// wl_touch.motion or wl_touch.orientation may be sent within the
+    /// same wl_touch.frame. A client should treat these events as a single
+    /// logical touch point update. The order of wl_touch.shape,
+    /// wl_touch.orientation and wl_touch.motion is not guaranteed.
+    /// A wl_touch.down event is guaranteed to occur before the first
+    /// wl_touch.shape event for this touch ID but both events may occur within
+    /// the same wl_touch.frame.
+    ///
+    /// A touchpoint shape is approximated by an ellipse through the major and
+    /// minor axis length. The major axis length describes the longer diameter
+    /// of the ellipse, while the minor axis length describes the shorter
+    /// diameter. Major and minor are orthogonal and both are specified in
+    /// surface-local coordinates. The center of the ellipse is always at the
+    /// touchpoint location as reported by wl_touch.down or wl_touch.move.
+    ///
+    /// This event is only sent by the compositor if the touch device supports
+    /// shape reports. The client has to make reasonable assumptions about the
+    /// shape if it did not receive this event.
+    ///
+    /// Available since version 6.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: the unique ID of this touch point
+    /// * `major`: length of the major axis in surface-local coordinates
+    /// * `minor`: length of the minor axis in surface-local coordinates
     fn handle_shape(
         &self,
         connection: &crate::Connection,
@@ -6105,23 +14222,67 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
         id: i32,
         major: crate::Fixed,
         minor: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Update orientation of touch point.
+    ///
+    /// Sent when a touchpoint has changed its orientation.
+    ///
+    /// This event does not occur on its own. It is sent before a
+    /// wl_touch.frame event and carries the new shape information for
+    /// any previously reported, or new touch points of that frame.
+    ///
+    /// Other events describing the touch point such as wl_touch.down,
+    /// wl_touch.motion or wl_touch.shape may be sent within the
+    /// same wl_touch.frame. A client should treat these events as a single
+    /// logical touch point update. The order of wl_touch.shape,
+    /// wl_touch.orientation and wl_touch.motion is not guaranteed.
+    /// A wl_touch.down event is guaranteed to occur before the first
+    /// wl_touch.orientation event for this touch ID but both events may occur
+    /// within the same wl_touch.frame.
+    ///
+    /// The orientation describes the clockwise angle of a touchpoint's major
+    /// axis to the positive surface y-axis and is normalized to the -180 to
+    /// +180 degree range. The granularity of orientation depends on the touch
+    /// device, some devices only support binary rotation values between 0 and
+    /// 90 degrees.
+    ///
+    /// This event is only sent by the compositor if the touch device supports
+    /// orientation reports.
+    ///
+    /// Available since version 6.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: the unique ID of this touch point
+    /// * `orientation`: angle between major axis and positive surface y-axis in degrees
     fn handle_orientation(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         id: i32,
         orientation: crate::Fixed,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_touch",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -6144,8 +14305,7 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
                     let y = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_down(__connection, __packet, serial, time, surface, id, x, y)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
@@ -6154,8 +14314,7 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
                     let id = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_up(__connection, __packet, serial, time, id)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
@@ -6165,20 +14324,17 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
                     let y = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_motion(__connection, __packet, time, id, x, y)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_frame(__connection, __packet).await;
-                    Ok(())
+                    self.handle_frame(__connection, __packet).await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_cancel(__connection, __packet).await;
-                    Ok(())
+                    self.handle_cancel(__connection, __packet).await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
@@ -6187,8 +14343,7 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
                     let minor = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_shape(__connection, __packet, id, major, minor)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 6 => {
                     let mut __packet_reader = __packet.read();
@@ -6196,37 +14351,49 @@ pub trait wl_touch_v10_event_handler: crate::protocol::EventHandler {
                     let orientation = __packet_reader.read_fixed()?;
                     __packet_reader.finish()?;
                     self.handle_orientation(__connection, __packet, id, orientation)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_touch_v10_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_touch_v10_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_touch_v10_request_release_args {}
-impl wl_touch_v10_request_release_args {
+impl wl_touch {
+    /// Release the touch object.
+    ///
+    /// Available since version 3.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_touch",
+                request: "release",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Release the touch object.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_touch.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_request_release_args {}
+impl wl_touch_request_release_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_request_release_args, crate::Error> {
+    ) -> Result<wl_touch_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -6239,36 +14406,53 @@ impl wl_touch_v10_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_request_release_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_request_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_request_release_args),
+        __value: (crate::ObjectId, wl_touch_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_down_args {
+/// Touch down event and beginning of a touch sequence.
+///
+/// A new touch point has appeared on the surface. This touch point is
+/// assigned a unique ID. Future events from this touch point reference
+/// this ID. The ID ceases to be valid after a touch up event and may be
+/// reused in the future.
+///
+/// The arguments of the `wl_touch.down` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_down_args {
+    /// Serial number of the touch down event.
     pub serial: u32,
+    /// Timestamp with millisecond granularity.
     pub time: u32,
-    pub surface: ::std::option::Option<crate::ObjectId>,
+    /// Surface touched.
+    pub surface: crate::ObjectId,
+    /// The unique ID of this touch point.
     pub id: i32,
+    /// Surface-local x coordinate.
     pub x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub y: crate::Fixed,
 }
-impl wl_touch_v10_event_down_args {
+impl wl_touch_event_down_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_down_args, crate::Error> {
+    ) -> Result<wl_touch_event_down_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let time = __reader.read_uint()?;
@@ -6300,31 +14484,44 @@ impl wl_touch_v10_event_down_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_down_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_down_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_down_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_down_args)> for crate::Packet {
     type Error = crate::Error;
-    fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_down_args),
-    ) -> Result<Self, Self::Error> {
+    fn try_from(__value: (crate::ObjectId, wl_touch_event_down_args)) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_up_args {
+/// End of a touch event sequence.
+///
+/// The touch point has disappeared. No further events will be sent for
+/// this touch point and the touch point's ID is released and may be
+/// reused in a future touch down event.
+///
+/// The arguments of the `wl_touch.up` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_up_args {
+    /// Serial number of the touch up event.
     pub serial: u32,
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// The unique ID of this touch point.
     pub id: i32,
 }
-impl wl_touch_v10_event_up_args {
+impl wl_touch_event_up_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_up_args, crate::Error> {
+    ) -> Result<wl_touch_event_up_args, crate::Error> {
         let mut __reader = __value.read();
         let serial = __reader.read_uint()?;
         let time = __reader.read_uint()?;
@@ -6343,32 +14540,44 @@ impl wl_touch_v10_event_up_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_up_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_up_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_up_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_up_args)> for crate::Packet {
     type Error = crate::Error;
-    fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_up_args),
-    ) -> Result<Self, Self::Error> {
+    fn try_from(__value: (crate::ObjectId, wl_touch_event_up_args)) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_motion_args {
+/// Update of touch point coordinates.
+///
+/// A touch point has changed coordinates.
+///
+/// The arguments of the `wl_touch.motion` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_motion_args {
+    /// Timestamp with millisecond granularity.
     pub time: u32,
+    /// The unique ID of this touch point.
     pub id: i32,
+    /// Surface-local x coordinate.
     pub x: crate::Fixed,
+    /// Surface-local y coordinate.
     pub y: crate::Fixed,
 }
-impl wl_touch_v10_event_motion_args {
+impl wl_touch_event_motion_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_motion_args, crate::Error> {
+    ) -> Result<wl_touch_event_motion_args, crate::Error> {
         let mut __reader = __value.read();
         let time = __reader.read_uint()?;
         let id = __reader.read_int()?;
@@ -6389,27 +14598,44 @@ impl wl_touch_v10_event_motion_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_motion_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_motion_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_motion_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_motion_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_motion_args),
+        __value: (crate::ObjectId, wl_touch_event_motion_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_frame_args {}
-impl wl_touch_v10_event_frame_args {
+/// End of touch frame event.
+///
+/// Indicates the end of a set of events that logically belong together.
+/// A client is expected to accumulate the data in all events within the
+/// frame before proceeding.
+///
+/// A wl_touch.frame terminates at least one event but otherwise no
+/// guarantee is provided about the set of events within a frame. A client
+/// must assume that any state not updated in a frame is unchanged from the
+/// previously known state.
+///
+/// The arguments of the `wl_touch.frame` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_frame_args {}
+impl wl_touch_event_frame_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_frame_args, crate::Error> {
+    ) -> Result<wl_touch_event_frame_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -6422,27 +14648,44 @@ impl wl_touch_v10_event_frame_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_frame_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_frame_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_frame_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_frame_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_frame_args),
+        __value: (crate::ObjectId, wl_touch_event_frame_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_cancel_args {}
-impl wl_touch_v10_event_cancel_args {
+/// Touch session cancelled.
+///
+/// Sent if the compositor decides the touch stream is a global
+/// gesture. No further events are sent to the clients from that
+/// particular gesture. Touch cancellation applies to all touch points
+/// currently active on this client's surface. The client is
+/// responsible for finalizing the touch points, future touch points on
+/// this surface may reuse the touch point ID.
+///
+/// No frame event is required after the cancel event.
+///
+/// The arguments of the `wl_touch.cancel` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_cancel_args {}
+impl wl_touch_event_cancel_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_cancel_args, crate::Error> {
+    ) -> Result<wl_touch_event_cancel_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -6455,31 +14698,70 @@ impl wl_touch_v10_event_cancel_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_cancel_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_cancel_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_cancel_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_cancel_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_cancel_args),
+        __value: (crate::ObjectId, wl_touch_event_cancel_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_shape_args {
+/// Update shape of touch point.
+///
+/// Sent when a touchpoint has changed its shape.
+///
+/// This event does not occur on its own. It is sent before a
+/// wl_touch.frame event and carries the new shape information for
+/// any previously reported, or new touch points of that frame.
+///
+/// Other events describing the touch point such as wl_touch.down,
+/// wl_touch.motion or wl_touch.orientation may be sent within the
+/// same wl_touch.frame. A client should treat these events as a single
+/// logical touch point update. The order of wl_touch.shape,
+/// wl_touch.orientation and wl_touch.motion is not guaranteed.
+/// A wl_touch.down event is guaranteed to occur before the first
+/// wl_touch.shape event for this touch ID but both events may occur within
+/// the same wl_touch.frame.
+///
+/// A touchpoint shape is approximated by an ellipse through the major and
+/// minor axis length. The major axis length describes the longer diameter
+/// of the ellipse, while the minor axis length describes the shorter
+/// diameter. Major and minor are orthogonal and both are specified in
+/// surface-local coordinates. The center of the ellipse is always at the
+/// touchpoint location as reported by wl_touch.down or wl_touch.move.
+///
+/// This event is only sent by the compositor if the touch device supports
+/// shape reports. The client has to make reasonable assumptions about the
+/// shape if it did not receive this event.
+///
+/// Available since version 6.
+///
+/// The arguments of the `wl_touch.shape` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_shape_args {
+    /// The unique ID of this touch point.
     pub id: i32,
+    /// Length of the major axis in surface-local coordinates.
     pub major: crate::Fixed,
+    /// Length of the minor axis in surface-local coordinates.
     pub minor: crate::Fixed,
 }
-impl wl_touch_v10_event_shape_args {
+impl wl_touch_event_shape_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 6;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_shape_args, crate::Error> {
+    ) -> Result<wl_touch_event_shape_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_int()?;
         let major = __reader.read_fixed()?;
@@ -6498,30 +14780,66 @@ impl wl_touch_v10_event_shape_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_shape_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_shape_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_shape_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_shape_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_shape_args),
+        __value: (crate::ObjectId, wl_touch_event_shape_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_touch_v10_event_orientation_args {
+/// Update orientation of touch point.
+///
+/// Sent when a touchpoint has changed its orientation.
+///
+/// This event does not occur on its own. It is sent before a
+/// wl_touch.frame event and carries the new shape information for
+/// any previously reported, or new touch points of that frame.
+///
+/// Other events describing the touch point such as wl_touch.down,
+/// wl_touch.motion or wl_touch.shape may be sent within the
+/// same wl_touch.frame. A client should treat these events as a single
+/// logical touch point update. The order of wl_touch.shape,
+/// wl_touch.orientation and wl_touch.motion is not guaranteed.
+/// A wl_touch.down event is guaranteed to occur before the first
+/// wl_touch.orientation event for this touch ID but both events may occur
+/// within the same wl_touch.frame.
+///
+/// The orientation describes the clockwise angle of a touchpoint's major
+/// axis to the positive surface y-axis and is normalized to the -180 to
+/// +180 degree range. The granularity of orientation depends on the touch
+/// device, some devices only support binary rotation values between 0 and
+/// 90 degrees.
+///
+/// This event is only sent by the compositor if the touch device supports
+/// orientation reports.
+///
+/// Available since version 6.
+///
+/// The arguments of the `wl_touch.orientation` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_touch_event_orientation_args {
+    /// The unique ID of this touch point.
     pub id: i32,
+    /// Angle between major axis and positive surface y-axis in degrees.
     pub orientation: crate::Fixed,
 }
-impl wl_touch_v10_event_orientation_args {
+impl wl_touch_event_orientation_args {
     pub const OPCODE: u16 = 6;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 6;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_touch_v10_event_orientation_args, crate::Error> {
+    ) -> Result<wl_touch_event_orientation_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_int()?;
         let orientation = __reader.read_fixed()?;
@@ -6538,25 +14856,215 @@ impl wl_touch_v10_event_orientation_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_touch_v10_event_orientation_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_touch_event_orientation_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_v10_event_orientation_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_touch_event_orientation_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_touch_v10_event_orientation_args),
+        __value: (crate::ObjectId, wl_touch_event_orientation_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
+/// An event sent to a `wl_touch` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_touch_event {
+    /// Touch down event and beginning of a touch sequence.
+    down(wl_touch_event_down_args),
+    /// End of a touch event sequence.
+    up(wl_touch_event_up_args),
+    /// Update of touch point coordinates.
+    motion(wl_touch_event_motion_args),
+    /// End of touch frame event.
+    frame(wl_touch_event_frame_args),
+    /// Touch session cancelled.
+    cancel(wl_touch_event_cancel_args),
+    /// Update shape of touch point.
+    shape(wl_touch_event_shape_args),
+    /// Update orientation of touch point.
+    orientation(wl_touch_event_orientation_args),
+}
+impl wl_touch_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::down(wl_touch_event_down_args::try_from_packet(
+                __packet,
+            )?)),
+            1 => Ok(Self::up(wl_touch_event_up_args::try_from_packet(__packet)?)),
+            2 => Ok(Self::motion(wl_touch_event_motion_args::try_from_packet(
+                __packet,
+            )?)),
+            3 => Ok(Self::frame(wl_touch_event_frame_args::try_from_packet(
+                __packet,
+            )?)),
+            4 => Ok(Self::cancel(wl_touch_event_cancel_args::try_from_packet(
+                __packet,
+            )?)),
+            5 => Ok(Self::shape(wl_touch_event_shape_args::try_from_packet(
+                __packet,
+            )?)),
+            6 => Ok(Self::orientation(
+                wl_touch_event_orientation_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_touch",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::down(_) => wl_touch_event_down_args::OPCODE,
+            Self::up(_) => wl_touch_event_up_args::OPCODE,
+            Self::motion(_) => wl_touch_event_motion_args::OPCODE,
+            Self::frame(_) => wl_touch_event_frame_args::OPCODE,
+            Self::cancel(_) => wl_touch_event_cancel_args::OPCODE,
+            Self::shape(_) => wl_touch_event_shape_args::OPCODE,
+            Self::orientation(_) => wl_touch_event_orientation_args::OPCODE,
+        }
+    }
+}
+/// Static information about `wl_output`.
+pub const wl_output_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_output",
+    version: 4,
+    requests: &[crate::protocol::MessageInfo {
+        name: "release",
+        destructor: true,
+        args: &[],
+    }],
+    events: &[
+        crate::protocol::MessageInfo {
+            name: "geometry",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::String,
+                crate::protocol::ArgKind::String,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "mode",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Uint,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "done",
+            destructor: false,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "scale",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Int],
+        },
+        crate::protocol::MessageInfo {
+            name: "name",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::String],
+        },
+        crate::protocol::MessageInfo {
+            name: "description",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::String],
+        },
+    ],
+};
+/// Compositor output region.
+///
+/// An output describes part of the compositor geometry.  The
+/// compositor works in the 'compositor coordinate system' and an
+/// output corresponds to a rectangular area in that space that is
+/// actually visible.  This typically corresponds to a monitor that
+/// displays part of the compositor space.  This object is published
+/// as global during start up, or when a monitor is hotplugged.
+///
+/// A proxy for a `wl_output` object. The generated code knows this interface up to version 4.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_output {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_output_interface;
+}
+impl crate::protocol::Proxy for wl_output {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `wl_output` object.
 #[allow(unused)]
-pub trait wl_output_v4_event_handler: crate::protocol::EventHandler {
+pub trait wl_output_event_handler: crate::protocol::EventHandler {
+    /// Properties of the output.
+    ///
+    /// The geometry event describes geometric properties of the output.
+    /// The event is sent when binding to the output object and whenever
+    /// any of the properties change.
+    ///
+    /// The physical size can be set to zero if it doesn't make sense for this
+    /// output (e.g. for projectors or virtual outputs).
+    ///
+    /// The geometry event will be followed by a done event (starting from
+    /// version 2).
+    ///
+    /// Clients should use wl_surface.preferred_buffer_transform instead of the
+    /// transform advertised by this event to find the preferred buffer
+    /// transform to use for a surface.
+    ///
+    /// Note: wl_output only advertises partial information about the output
+    /// position and identification. Some compositors, for instance those not
+    /// implementing a desktop-style output layout or those exposing virtual
+    /// outputs, might fake this information. Instead of using x and y, clients
+    /// should use xdg_output.logical_position. Instead of using make and model,
+    /// clients should use name and description.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: x position within the global compositor space
+    /// * `y`: y position within the global compositor space
+    /// * `physical_width`: width in millimeters of the output
+    /// * `physical_height`: height in millimeters of the output
+    /// * `subpixel`: subpixel orientation of the output
+    /// * `make`: textual description of the manufacturer
+    /// * `model`: textual description of the model
+    /// * `transform`: additional transformation applied to buffer contents during presentation
     fn handle_geometry(
         &self,
         connection: &crate::Connection,
@@ -6565,52 +15073,207 @@ pub trait wl_output_v4_event_handler: crate::protocol::EventHandler {
         y: i32,
         physical_width: i32,
         physical_height: i32,
-        subpixel: i32,
+        subpixel: wl_output_subpixel,
         make: ::std::string::String,
         model: ::std::string::String,
-        transform: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+        transform: wl_output_transform,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise available modes for the output.
+    ///
+    /// The mode event describes an available mode for the output.
+    ///
+    /// The event is sent when binding to the output object and there
+    /// will always be one mode, the current mode.  The event is sent
+    /// again if an output changes mode, for the mode that is now
+    /// current.  In other words, the current mode is always the last
+    /// mode that was received with the current flag set.
+    ///
+    /// Non-current modes are deprecated. A compositor can decide to only
+    /// advertise the current mode and never send other modes. Clients
+    /// should not rely on non-current modes.
+    ///
+    /// The size of a mode is given in physical hardware units of
+    /// the output device. This is not necessarily the same as
+    /// the output size in the global compositor space. For instance,
+    /// the output may be scaled, as described in wl_output.scale,
+    /// or transformed, as described in wl_output.transform. Clients
+    /// willing to retrieve the output size in the global compositor
+    /// space should use xdg_output.logical_size instead.
+    ///
+    /// The vertical refresh rate can be set to zero if it doesn't make
+    /// sense for this output (e.g. for virtual outputs).
+    ///
+    /// The mode event will be followed by a done event (starting from
+    /// version 2).
+    ///
+    /// Clients should not use the refresh rate to schedule frames. Instead,
+    /// they should use the wl_surface.frame event or the presentation-time
+    /// protocol.
+    ///
+    /// Note: this information is not always meaningful for all outputs. Some
+    /// compositors, such as those exposing virtual outputs, might fake the
+    /// refresh rate or the size.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags`: bitfield of mode flags
+    /// * `width`: width of the mode in hardware units
+    /// * `height`: height of the mode in hardware units
+    /// * `refresh`: vertical refresh rate in mHz
     fn handle_mode(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-        flags: u32,
+        flags: wl_output_mode,
         width: i32,
         height: i32,
         refresh: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Sent all information about output.
+    ///
+    /// This event is sent after all other properties have been
+    /// sent after binding to the output object and after any
+    /// other property changes done after that. This allows
+    /// changes to the output properties to be seen as
+    /// atomic, even if they happen via multiple events.
+    ///
+    /// Available since version 2.
     fn handle_done(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Output scaling properties.
+    ///
+    /// This event contains scaling geometry information
+    /// that is not in the geometry event. It may be sent after
+    /// binding the output object or if the output scale changes
+    /// later. The compositor will emit a non-zero, positive
+    /// value for scale. If it is not sent, the client should
+    /// assume a scale of 1.
+    ///
+    /// A scale larger than 1 means that the compositor will
+    /// automatically scale surface buffers by this amount
+    /// when rendering. This is used for very high resolution
+    /// displays where applications rendering at the native
+    /// resolution would be too small to be legible.
+    ///
+    /// Clients should use wl_surface.preferred_buffer_scale
+    /// instead of this event to find the preferred buffer
+    /// scale to use for a surface.
+    ///
+    /// The scale event will be followed by a done event.
+    ///
+    /// Available since version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor`: scaling factor of output
     fn handle_scale(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         factor: i32,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Name of this output.
+    ///
+    /// Many compositors will assign user-friendly names to their outputs, show
+    /// them to the user, allow the user to refer to an output, etc. The client
+    /// may wish to know this name as well to offer the user similar behaviors.
+    ///
+    /// The name is a UTF-8 string with no convention defined for its contents.
+    /// Each name is unique among all wl_output globals. The name is only
+    /// guaranteed to be unique for the compositor instance.
+    ///
+    /// The same output name is used for all clients for a given wl_output
+    /// global. Thus, the name can be shared across processes to refer to a
+    /// specific wl_output global.
+    ///
+    /// The name is not guaranteed to be persistent across sessions, thus cannot
+    /// be used to reliably identify an output in e.g. configuration files.
+    ///
+    /// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+    /// not assume that the name is a reflection of an underlying DRM connector,
+    /// X11 connection, etc.
+    ///
+    /// The name event is sent after binding the output object. This event is
+    /// only sent once per output object, and the name does not change over the
+    /// lifetime of the wl_output global.
+    ///
+    /// Compositors may re-use the same output name if the wl_output global is
+    /// destroyed and re-created later. Compositors should avoid re-using the
+    /// same name if possible.
+    ///
+    /// The name event will be followed by a done event.
+    ///
+    /// Available since version 4.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: output name
     fn handle_name(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         name: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Human-readable description of this output.
+    ///
+    /// Many compositors can produce human-readable descriptions of their
+    /// outputs. The client may wish to know this description as well, e.g. for
+    /// output selection purposes.
+    ///
+    /// The description is a UTF-8 string with no convention defined for its
+    /// contents. The description is not guaranteed to be unique among all
+    /// wl_output globals. Examples might include 'Foocorp 11" Display' or
+    /// 'Virtual X11 output via :1'.
+    ///
+    /// The description event is sent after binding the output object and
+    /// whenever the description changes. The description is optional, and may
+    /// not be sent at all.
+    ///
+    /// The description event will be followed by a done event.
+    ///
+    /// Available since version 4.
+    ///
+    /// # Arguments
+    ///
+    /// * `description`: output description
     fn handle_description(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
         description: ::std::string::String,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync;
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
     fn unknown_event(
         &self,
         connection: &crate::Connection,
         packet: crate::Packet,
-    ) -> impl ::std::future::Future<Output = ()> + ::std::marker::Send + ::std::marker::Sync {
-        let _ = connection;
-        let _ = packet;
-        ::std::future::ready(())
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "wl_output",
+            opcode: packet.opcode(),
+        })))
     }
+    /// Decodes an event and passes it to the matching handler function.
     fn handle_event(
         &self,
         __connection: &crate::Connection,
@@ -6630,9 +15293,16 @@ pub trait wl_output_v4_event_handler: crate::protocol::EventHandler {
                     let physical_width = __packet_reader.read_int()?;
                     let physical_height = __packet_reader.read_int()?;
                     let subpixel = __packet_reader.read_int()?;
+                    let subpixel = <wl_output_subpixel as ::std::convert::TryFrom<u32>>::try_from(
+                        subpixel as u32,
+                    )?;
                     let make = __packet_reader.read_str()?;
                     let model = __packet_reader.read_str()?;
                     let transform = __packet_reader.read_int()?;
+                    let transform =
+                        <wl_output_transform as ::std::convert::TryFrom<u32>>::try_from(
+                            transform as u32,
+                        )?;
                     __packet_reader.finish()?;
                     self.handle_geometry(
                         __connection,
@@ -6646,76 +15316,90 @@ pub trait wl_output_v4_event_handler: crate::protocol::EventHandler {
                         model,
                         transform,
                     )
-                    .await;
-                    Ok(())
+                    .await
                 }
                 1 => {
                     let mut __packet_reader = __packet.read();
                     let flags = __packet_reader.read_uint()?;
+                    let flags = <wl_output_mode as ::std::convert::TryFrom<u32>>::try_from(flags)?;
                     let width = __packet_reader.read_int()?;
                     let height = __packet_reader.read_int()?;
                     let refresh = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
                     self.handle_mode(__connection, __packet, flags, width, height, refresh)
-                        .await;
-                    Ok(())
+                        .await
                 }
                 2 => {
                     let mut __packet_reader = __packet.read();
                     __packet_reader.finish()?;
-                    self.handle_done(__connection, __packet).await;
-                    Ok(())
+                    self.handle_done(__connection, __packet).await
                 }
                 3 => {
                     let mut __packet_reader = __packet.read();
                     let factor = __packet_reader.read_int()?;
                     __packet_reader.finish()?;
-                    self.handle_scale(__connection, __packet, factor).await;
-                    Ok(())
+                    self.handle_scale(__connection, __packet, factor).await
                 }
                 4 => {
                     let mut __packet_reader = __packet.read();
                     let name = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
-                    self.handle_name(__connection, __packet, name).await;
-                    Ok(())
+                    self.handle_name(__connection, __packet, name).await
                 }
                 5 => {
                     let mut __packet_reader = __packet.read();
                     let description = __packet_reader.read_str()?;
                     __packet_reader.finish()?;
                     self.handle_description(__connection, __packet, description)
-                        .await;
-                    Ok(())
-                }
-                __other => {
-                    self.unknown_event(__connection, __packet).await;
-                    Ok(())
+                        .await
                 }
+                __other => self.unknown_event(__connection, __packet).await,
             }
         }
     }
 }
 #[allow(unused)]
-pub struct wl_output_v4_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_output_v4_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
-    }
-    pub async fn send_release(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_output_v4_request_release_args {}
-impl wl_output_v4_request_release_args {
+impl wl_output {
+    /// Release the output object.
+    ///
+    /// Using this request a client can tell the server that it is not going to
+    /// use the output object anymore.
+    ///
+    /// Available since version 3.
+    pub async fn send_release(self) -> Result<(), crate::Error> {
+        if self.version < 3 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "wl_output",
+                request: "release",
+                since: 3,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Release the output object.
+///
+/// Using this request a client can tell the server that it is not going to
+/// use the output object anymore.
+///
+/// Available since version 3.
+///
+/// The arguments of the `wl_output.release` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_request_release_args {}
+impl wl_output_request_release_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 3;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_request_release_args, crate::Error> {
+    ) -> Result<wl_output_request_release_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -6728,47 +15412,86 @@ impl wl_output_v4_request_release_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_request_release_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_request_release_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_request_release_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_request_release_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_request_release_args),
+        __value: (crate::ObjectId, wl_output_request_release_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_geometry_args {
+/// Properties of the output.
+///
+/// The geometry event describes geometric properties of the output.
+/// The event is sent when binding to the output object and whenever
+/// any of the properties change.
+///
+/// The physical size can be set to zero if it doesn't make sense for this
+/// output (e.g. for projectors or virtual outputs).
+///
+/// The geometry event will be followed by a done event (starting from
+/// version 2).
+///
+/// Clients should use wl_surface.preferred_buffer_transform instead of the
+/// transform advertised by this event to find the preferred buffer
+/// transform to use for a surface.
+///
+/// Note: wl_output only advertises partial information about the output
+/// position and identification. Some compositors, for instance those not
+/// implementing a desktop-style output layout or those exposing virtual
+/// outputs, might fake this information. Instead of using x and y, clients
+/// should use xdg_output.logical_position. Instead of using make and model,
+/// clients should use name and description.
+///
+/// The arguments of the `wl_output.geometry` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_geometry_args {
+    /// X position within the global compositor space.
     pub x: i32,
+    /// Y position within the global compositor space.
     pub y: i32,
+    /// Width in millimeters of the output.
     pub physical_width: i32,
+    /// Height in millimeters of the output.
     pub physical_height: i32,
-    pub subpixel: i32,
+    /// Subpixel orientation of the output.
+    pub subpixel: wl_output_subpixel,
+    /// Textual description of the manufacturer.
     pub make: ::std::string::String,
+    /// Textual description of the model.
     pub model: ::std::string::String,
-    pub transform: i32,
+    /// Additional transformation applied to buffer contents during presentation.
+    pub transform: wl_output_transform,
 }
-impl wl_output_v4_event_geometry_args {
+impl wl_output_event_geometry_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_geometry_args, crate::Error> {
+    ) -> Result<wl_output_event_geometry_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
         let physical_width = __reader.read_int()?;
         let physical_height = __reader.read_int()?;
         let subpixel = __reader.read_int()?;
+        let subpixel =
+            <wl_output_subpixel as ::std::convert::TryFrom<u32>>::try_from(subpixel as u32)?;
         let make = __reader.read_str()?;
         let model = __reader.read_str()?;
         let transform = __reader.read_int()?;
+        let transform =
+            <wl_output_transform as ::std::convert::TryFrom<u32>>::try_from(transform as u32)?;
         __reader.finish()?;
         Ok(Self {
             x,
@@ -6790,43 +15513,88 @@ impl wl_output_v4_event_geometry_args {
         __packet.push_int(self.y);
         __packet.push_int(self.physical_width);
         __packet.push_int(self.physical_height);
-        __packet.push_int(self.subpixel);
+        __packet.push_int(u32::from(self.subpixel) as i32);
         __packet.push_str(&self.make);
         __packet.push_str(&self.model);
-        __packet.push_int(self.transform);
+        __packet.push_int(u32::from(self.transform) as i32);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_geometry_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_geometry_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_geometry_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_geometry_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_geometry_args),
+        __value: (crate::ObjectId, wl_output_event_geometry_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_mode_args {
-    pub flags: u32,
+/// Advertise available modes for the output.
+///
+/// The mode event describes an available mode for the output.
+///
+/// The event is sent when binding to the output object and there
+/// will always be one mode, the current mode.  The event is sent
+/// again if an output changes mode, for the mode that is now
+/// current.  In other words, the current mode is always the last
+/// mode that was received with the current flag set.
+///
+/// Non-current modes are deprecated. A compositor can decide to only
+/// advertise the current mode and never send other modes. Clients
+/// should not rely on non-current modes.
+///
+/// The size of a mode is given in physical hardware units of
+/// the output device. This is not necessarily the same as
+/// the output size in the global compositor space. For instance,
+/// the output may be scaled, as described in wl_output.scale,
+/// or transformed, as described in wl_output.transform. Clients
+/// willing to retrieve the output size in the global compositor
+/// space should use xdg_output.logical_size instead.
+///
+/// The vertical refresh rate can be set to zero if it doesn't make
+/// sense for this output (e.g. for virtual outputs).
+///
+/// The mode event will be followed by a done event (starting from
+/// version 2).
+///
+/// Clients should not use the refresh rate to schedule frames. Instead,
+/// they should use the wl_surface.frame event or the presentation-time
+/// protocol.
+///
+/// Note: this information is not always meaningful for all outputs. Some
+/// compositors, such as those exposing virtual outputs, might fake the
+/// refresh rate or the size.
+///
+/// The arguments of the `wl_output.mode` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_mode_args {
+    /// Bitfield of mode flags.
+    pub flags: wl_output_mode,
+    /// Width of the mode in hardware units.
     pub width: i32,
+    /// Height of the mode in hardware units.
     pub height: i32,
+    /// Vertical refresh rate in mHz.
     pub refresh: i32,
 }
-impl wl_output_v4_event_mode_args {
+impl wl_output_event_mode_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_mode_args, crate::Error> {
+    ) -> Result<wl_output_event_mode_args, crate::Error> {
         let mut __reader = __value.read();
         let flags = __reader.read_uint()?;
+        let flags = <wl_output_mode as ::std::convert::TryFrom<u32>>::try_from(flags)?;
         let width = __reader.read_int()?;
         let height = __reader.read_int()?;
         let refresh = __reader.read_int()?;
@@ -6843,34 +15611,50 @@ impl wl_output_v4_event_mode_args {
         __object_id: crate::ObjectId,
     ) -> Result<crate::Packet, crate::Error> {
         let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
-        __packet.push_uint(self.flags);
+        __packet.push_uint(u32::from(self.flags));
         __packet.push_int(self.width);
         __packet.push_int(self.height);
         __packet.push_int(self.refresh);
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_mode_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_mode_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_mode_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_mode_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_mode_args),
+        __value: (crate::ObjectId, wl_output_event_mode_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_done_args {}
-impl wl_output_v4_event_done_args {
+/// Sent all information about output.
+///
+/// This event is sent after all other properties have been
+/// sent after binding to the output object and after any
+/// other property changes done after that. This allows
+/// changes to the output properties to be seen as
+/// atomic, even if they happen via multiple events.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_output.done` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_done_args {}
+impl wl_output_event_done_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_done_args, crate::Error> {
+    ) -> Result<wl_output_event_done_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -6883,29 +15667,59 @@ impl wl_output_v4_event_done_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_done_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_done_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_done_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_done_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_done_args),
+        __value: (crate::ObjectId, wl_output_event_done_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_scale_args {
+/// Output scaling properties.
+///
+/// This event contains scaling geometry information
+/// that is not in the geometry event. It may be sent after
+/// binding the output object or if the output scale changes
+/// later. The compositor will emit a non-zero, positive
+/// value for scale. If it is not sent, the client should
+/// assume a scale of 1.
+///
+/// A scale larger than 1 means that the compositor will
+/// automatically scale surface buffers by this amount
+/// when rendering. This is used for very high resolution
+/// displays where applications rendering at the native
+/// resolution would be too small to be legible.
+///
+/// Clients should use wl_surface.preferred_buffer_scale
+/// instead of this event to find the preferred buffer
+/// scale to use for a surface.
+///
+/// The scale event will be followed by a done event.
+///
+/// Available since version 2.
+///
+/// The arguments of the `wl_output.scale` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_scale_args {
+    /// Scaling factor of output.
     pub factor: i32,
 }
-impl wl_output_v4_event_scale_args {
+impl wl_output_event_scale_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_scale_args, crate::Error> {
+    ) -> Result<wl_output_event_scale_args, crate::Error> {
         let mut __reader = __value.read();
         let factor = __reader.read_int()?;
         __reader.finish()?;
@@ -6920,29 +15734,69 @@ impl wl_output_v4_event_scale_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_scale_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_scale_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_scale_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_scale_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_scale_args),
+        __value: (crate::ObjectId, wl_output_event_scale_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_name_args {
+/// Name of this output.
+///
+/// Many compositors will assign user-friendly names to their outputs, show
+/// them to the user, allow the user to refer to an output, etc. The client
+/// may wish to know this name as well to offer the user similar behaviors.
+///
+/// The name is a UTF-8 string with no convention defined for its contents.
+/// Each name is unique among all wl_output globals. The name is only
+/// guaranteed to be unique for the compositor instance.
+///
+/// The same output name is used for all clients for a given wl_output
+/// global. Thus, the name can be shared across processes to refer to a
+/// specific wl_output global.
+///
+/// The name is not guaranteed to be persistent across sessions, thus cannot
+/// be used to reliably identify an output in e.g. configuration files.
+///
+/// Examples of names include 'HDMI-A-1', 'WL-1', 'X11-1', etc. However, do
+/// not assume that the name is a reflection of an underlying DRM connector,
+/// X11 connection, etc.
+///
+/// The name event is sent after binding the output object. This event is
+/// only sent once per output object, and the name does not change over the
+/// lifetime of the wl_output global.
+///
+/// Compositors may re-use the same output name if the wl_output global is
+/// destroyed and re-created later. Compositors should avoid re-using the
+/// same name if possible.
+///
+/// The name event will be followed by a done event.
+///
+/// Available since version 4.
+///
+/// The arguments of the `wl_output.name` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_name_args {
+    /// Output name.
     pub name: ::std::string::String,
 }
-impl wl_output_v4_event_name_args {
+impl wl_output_event_name_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 4;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_name_args, crate::Error> {
+    ) -> Result<wl_output_event_name_args, crate::Error> {
         let mut __reader = __value.read();
         let name = __reader.read_str()?;
         __reader.finish()?;
@@ -6957,29 +15811,55 @@ impl wl_output_v4_event_name_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_name_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_name_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_name_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_name_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_name_args),
+        __value: (crate::ObjectId, wl_output_event_name_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_output_v4_event_description_args {
+/// Human-readable description of this output.
+///
+/// Many compositors can produce human-readable descriptions of their
+/// outputs. The client may wish to know this description as well, e.g. for
+/// output selection purposes.
+///
+/// The description is a UTF-8 string with no convention defined for its
+/// contents. The description is not guaranteed to be unique among all
+/// wl_output globals. Examples might include 'Foocorp 11" Display' or
+/// 'Virtual X11 output via :1'.
+///
+/// The description event is sent after binding the output object and
+/// whenever the description changes. The description is optional, and may
+/// not be sent at all.
+///
+/// The description event will be followed by a done event.
+///
+/// Available since version 4.
+///
+/// The arguments of the `wl_output.description` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_event_description_args {
+    /// Output description.
     pub description: ::std::string::String,
 }
-impl wl_output_v4_event_description_args {
+impl wl_output_event_description_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 4;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_output_v4_event_description_args, crate::Error> {
+    ) -> Result<wl_output_event_description_args, crate::Error> {
         let mut __reader = __value.read();
         let description = __reader.read_str()?;
         __reader.finish()?;
@@ -6994,73 +15874,416 @@ impl wl_output_v4_event_description_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_output_v4_event_description_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_output_event_description_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_v4_event_description_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_output_event_description_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_output_v4_event_description_args),
+        __value: (crate::ObjectId, wl_output_event_description_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_region_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// An event sent to a `wl_output` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum wl_output_event {
+    /// Properties of the output.
+    geometry(wl_output_event_geometry_args),
+    /// Advertise available modes for the output.
+    mode(wl_output_event_mode_args),
+    /// Sent all information about output.
+    done(wl_output_event_done_args),
+    /// Output scaling properties.
+    scale(wl_output_event_scale_args),
+    /// Name of this output.
+    name(wl_output_event_name_args),
+    /// Human-readable description of this output.
+    description(wl_output_event_description_args),
+}
+impl wl_output_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::geometry(
+                wl_output_event_geometry_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::mode(wl_output_event_mode_args::try_from_packet(
+                __packet,
+            )?)),
+            2 => Ok(Self::done(wl_output_event_done_args::try_from_packet(
+                __packet,
+            )?)),
+            3 => Ok(Self::scale(wl_output_event_scale_args::try_from_packet(
+                __packet,
+            )?)),
+            4 => Ok(Self::name(wl_output_event_name_args::try_from_packet(
+                __packet,
+            )?)),
+            5 => Ok(Self::description(
+                wl_output_event_description_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "wl_output",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::geometry(_) => wl_output_event_geometry_args::OPCODE,
+            Self::mode(_) => wl_output_event_mode_args::OPCODE,
+            Self::done(_) => wl_output_event_done_args::OPCODE,
+            Self::scale(_) => wl_output_event_scale_args::OPCODE,
+            Self::name(_) => wl_output_event_name_args::OPCODE,
+            Self::description(_) => wl_output_event_description_args::OPCODE,
+        }
+    }
 }
-#[allow(unused)]
-impl<'a> wl_region_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+/// Subpixel geometry information.
+///
+/// This enumeration describes how the physical
+/// pixels on an output are laid out.
+///
+/// The enum `wl_output.subpixel`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_output_subpixel {
+    /// Unknown geometry.
+    unknown = 0,
+    /// No geometry.
+    none = 1,
+    /// Horizontal RGB.
+    horizontal_rgb = 2,
+    /// Horizontal BGR.
+    horizontal_bgr = 3,
+    /// Vertical RGB.
+    vertical_rgb = 4,
+    /// Vertical BGR.
+    vertical_bgr = 5,
+}
+impl ::std::convert::TryFrom<u32> for wl_output_subpixel {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::unknown),
+            1 => Ok(Self::none),
+            2 => Ok(Self::horizontal_rgb),
+            3 => Ok(Self::horizontal_bgr),
+            4 => Ok(Self::vertical_rgb),
+            5 => Ok(Self::vertical_bgr),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_output.subpixel",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_output_subpixel> for u32 {
+    fn from(__value: wl_output_subpixel) -> u32 {
+        __value as u32
+    }
+}
+/// Transformation applied to buffer contents.
+///
+/// This describes transformations that clients and compositors apply to
+/// buffer contents.
+///
+/// The flipped values correspond to an initial flip around a
+/// vertical axis followed by rotation.
+///
+/// The purpose is mainly to allow clients to render accordingly and
+/// tell the compositor, so that for fullscreen surfaces, the
+/// compositor will still be able to scan out directly from client
+/// surfaces.
+///
+/// The enum `wl_output.transform`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_output_transform {
+    /// No transform.
+    normal = 0,
+    /// 90 degrees counter-clockwise.
+    _90 = 1,
+    /// 180 degrees counter-clockwise.
+    _180 = 2,
+    /// 270 degrees counter-clockwise.
+    _270 = 3,
+    /// 180 degree flip around a vertical axis.
+    flipped = 4,
+    /// Flip and rotate 90 degrees counter-clockwise.
+    flipped_90 = 5,
+    /// Flip and rotate 180 degrees counter-clockwise.
+    flipped_180 = 6,
+    /// Flip and rotate 270 degrees counter-clockwise.
+    flipped_270 = 7,
+}
+impl ::std::convert::TryFrom<u32> for wl_output_transform {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::normal),
+            1 => Ok(Self::_90),
+            2 => Ok(Self::_180),
+            3 => Ok(Self::_270),
+            4 => Ok(Self::flipped),
+            5 => Ok(Self::flipped_90),
+            6 => Ok(Self::flipped_180),
+            7 => Ok(Self::flipped_270),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_output.transform",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_output_transform> for u32 {
+    fn from(__value: wl_output_transform) -> u32 {
+        __value as u32
+    }
+}
+/// Mode information.
+///
+/// These flags describe properties of an output mode.
+/// They are used in the flags bitfield of the mode event.
+///
+/// The bitfield `wl_output.mode`.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_output_mode(u32);
+impl wl_output_mode {
+    /// Indicates this is the current mode.
+    pub const current: Self = Self(1);
+    /// Indicates this is the preferred mode.
+    pub const preferred: Self = Self(2);
+    const FLAGS: &'static [(&'static str, u32)] = &[("current", 1), ("preferred", 2)];
+    /// No flags.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+    /// All flags known to the generated code.
+    pub const fn all() -> Self {
+        Self(3)
+    }
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
     }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
+    /// Whether all flags of `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
     }
+    /// Whether any flag of `other` is set.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+impl ::std::ops::BitOr for wl_output_mode {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+impl ::std::ops::BitOrAssign for wl_output_mode {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd for wl_output_mode {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+impl ::std::ops::BitAndAssign for wl_output_mode {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+impl ::std::fmt::Debug for wl_output_mode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        crate::protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+    }
+}
+impl ::std::convert::TryFrom<u32> for wl_output_mode {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        if __value & !Self::all().0 == 0 {
+            Ok(Self(__value))
+        } else {
+            Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_output.mode",
+                value: __value,
+            })
+        }
+    }
+}
+impl ::std::convert::From<wl_output_mode> for u32 {
+    fn from(__value: wl_output_mode) -> u32 {
+        __value.0
+    }
+}
+/// Static information about `wl_region`.
+pub const wl_region_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_region",
+    version: 1,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "destroy",
+            destructor: true,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "add",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+        crate::protocol::MessageInfo {
+            name: "subtract",
+            destructor: false,
+            args: &[
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+                crate::protocol::ArgKind::Int,
+            ],
+        },
+    ],
+    events: &[],
+};
+/// Region interface.
+///
+/// A region object describes an area.
+///
+/// Region objects are used to describe the opaque and input
+/// regions of a surface.
+///
+/// A proxy for a `wl_region` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_region {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_region {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_region_interface;
+}
+impl crate::protocol::Proxy for wl_region {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_region {
+    /// Destroy region.
+    ///
+    /// Destroy the region.  This will invalidate the object ID.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Add rectangle to region.
+    ///
+    /// Add the specified rectangle to the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: region-local x coordinate
+    /// * `y`: region-local y coordinate
+    /// * `width`: rectangle width
+    /// * `height`: rectangle height
     pub async fn send_add(
         &self,
-        __object_id: crate::ObjectId,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
         __packet.push_int(x);
         __packet.push_int(y);
         __packet.push_int(width);
         __packet.push_int(height);
-        self.connection.send_packet(&__packet).await
-    }
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Subtract rectangle from region.
+    ///
+    /// Subtract the specified rectangle from the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: region-local x coordinate
+    /// * `y`: region-local y coordinate
+    /// * `width`: rectangle width
+    /// * `height`: rectangle height
     pub async fn send_subtract(
         &self,
-        __object_id: crate::ObjectId,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
         __packet.push_int(x);
         __packet.push_int(y);
         __packet.push_int(width);
         __packet.push_int(height);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_region_v1_request_destroy_args {}
-impl wl_region_v1_request_destroy_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Destroy region.
+///
+/// Destroy the region.  This will invalidate the object ID.
+///
+/// The arguments of the `wl_region.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_region_request_destroy_args {}
+impl wl_region_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_region_v1_request_destroy_args, crate::Error> {
+    ) -> Result<wl_region_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7073,34 +16296,46 @@ impl wl_region_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_region_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_region_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_v1_request_destroy_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_request_destroy_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_region_v1_request_destroy_args),
+        __value: (crate::ObjectId, wl_region_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_region_v1_request_add_args {
+/// Add rectangle to region.
+///
+/// Add the specified rectangle to the region.
+///
+/// The arguments of the `wl_region.add` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_region_request_add_args {
+    /// Region-local x coordinate.
     pub x: i32,
+    /// Region-local y coordinate.
     pub y: i32,
+    /// Rectangle width.
     pub width: i32,
+    /// Rectangle height.
     pub height: i32,
 }
-impl wl_region_v1_request_add_args {
+impl wl_region_request_add_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_region_v1_request_add_args, crate::Error> {
+    ) -> Result<wl_region_request_add_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -7126,32 +16361,46 @@ impl wl_region_v1_request_add_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_region_v1_request_add_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_region_request_add_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_v1_request_add_args)> for crate::Packet {
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_request_add_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_region_v1_request_add_args),
+        __value: (crate::ObjectId, wl_region_request_add_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_region_v1_request_subtract_args {
+/// Subtract rectangle from region.
+///
+/// Subtract the specified rectangle from the region.
+///
+/// The arguments of the `wl_region.subtract` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_region_request_subtract_args {
+    /// Region-local x coordinate.
     pub x: i32,
+    /// Region-local y coordinate.
     pub y: i32,
+    /// Rectangle width.
     pub width: i32,
+    /// Rectangle height.
     pub height: i32,
 }
-impl wl_region_v1_request_subtract_args {
+impl wl_region_request_subtract_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_region_v1_request_subtract_args, crate::Error> {
+    ) -> Result<wl_region_request_subtract_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -7177,56 +16426,178 @@ impl wl_region_v1_request_subtract_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_region_v1_request_subtract_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_region_request_subtract_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_v1_request_subtract_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_region_request_subtract_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_region_v1_request_subtract_args),
+        __value: (crate::ObjectId, wl_region_request_subtract_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_subcompositor_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
-}
-#[allow(unused)]
-impl<'a> wl_subcompositor_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+/// Static information about `wl_subcompositor`.
+pub const wl_subcompositor_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_subcompositor",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "get_subsurface",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::NewId {
+                        interface: Some("wl_subsurface"),
+                    },
+                    crate::protocol::ArgKind::Object,
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+        ],
+        events: &[],
+    };
+/// Sub-surface compositing.
+///
+/// The global interface exposing sub-surface compositing capabilities.
+/// A wl_surface, that has sub-surfaces associated, is called the
+/// parent surface. Sub-surfaces can be arbitrarily nested and create
+/// a tree of sub-surfaces.
+///
+/// The root surface in a tree of sub-surfaces is the main
+/// surface. The main surface cannot be a sub-surface, because
+/// sub-surfaces must always have a parent.
+///
+/// A main surface with its sub-surfaces forms a (compound) window.
+/// For window management purposes, this set of wl_surface objects is
+/// to be considered as a single window, and it should also behave as
+/// such.
+///
+/// The aim of sub-surfaces is to offload some of the compositing work
+/// within a window from clients to the compositor. A prime example is
+/// a video player with decorations and video in separate wl_surface
+/// objects. This should allow the compositor to pass YUV video buffer
+/// processing to dedicated overlay hardware when possible.
+///
+/// A proxy for a `wl_subcompositor` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subcompositor {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_subcompositor {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_subcompositor_interface;
+}
+impl crate::protocol::Proxy for wl_subcompositor {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
     }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
+    fn id(&self) -> crate::ObjectId {
+        self.id
     }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_subcompositor {
+    /// Unbind from the subcompositor interface.
+    ///
+    /// Informs the server that the client will not be using this
+    /// protocol object anymore. This does not affect any other
+    /// objects, wl_subsurface objects included.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Give a surface the role sub-surface.
+    ///
+    /// Create a sub-surface interface for the given surface, and
+    /// associate it with the given parent surface. This turns a
+    /// plain wl_surface into a sub-surface.
+    ///
+    /// The to-be sub-surface must not already have another role, and it
+    /// must not have an existing wl_subsurface object. Otherwise the
+    /// bad_surface protocol error is raised.
+    ///
+    /// Adding sub-surfaces to a parent is a double-buffered operation on the
+    /// parent (see wl_surface.commit). The effect of adding a sub-surface
+    /// becomes visible on the next time the state of the parent surface is
+    /// applied.
+    ///
+    /// The parent surface must not be one of the child surface's descendants,
+    /// and the parent must be different from the child surface, otherwise the
+    /// bad_parent protocol error is raised.
+    ///
+    /// This request modifies the behaviour of wl_surface.commit request on
+    /// the sub-surface, see the documentation on wl_subsurface interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface`: the surface to be turned into a sub-surface
+    /// * `parent`: the parent surface
+    ///
+    /// Returns the new `wl_subsurface` object.
     pub async fn send_get_subsurface(
         &self,
-        __object_id: crate::ObjectId,
-        id: crate::NewObjectId,
-        surface: ::std::option::Option<crate::ObjectId>,
-        parent: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
-        __packet.push_new_id_known_interface(id);
+        surface: crate::ObjectId,
+        parent: crate::ObjectId,
+    ) -> Result<wl_subsurface, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id = __connection.new_object(&wl_subsurface_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
         __packet.push_object(surface);
         __packet.push_object(parent);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(wl_subsurface {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
     }
 }
-pub struct wl_subcompositor_v1_request_destroy_args {}
-impl wl_subcompositor_v1_request_destroy_args {
+/// Unbind from the subcompositor interface.
+///
+/// Informs the server that the client will not be using this
+/// protocol object anymore. This does not affect any other
+/// objects, wl_subsurface objects included.
+///
+/// The arguments of the `wl_subcompositor.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subcompositor_request_destroy_args {}
+impl wl_subcompositor_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subcompositor_v1_request_destroy_args, crate::Error> {
+    ) -> Result<wl_subcompositor_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7239,33 +16610,64 @@ impl wl_subcompositor_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subcompositor_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subcompositor_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subcompositor_v1_request_destroy_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subcompositor_request_destroy_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subcompositor_v1_request_destroy_args),
+        __value: (crate::ObjectId, wl_subcompositor_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subcompositor_v1_request_get_subsurface_args {
+/// Give a surface the role sub-surface.
+///
+/// Create a sub-surface interface for the given surface, and
+/// associate it with the given parent surface. This turns a
+/// plain wl_surface into a sub-surface.
+///
+/// The to-be sub-surface must not already have another role, and it
+/// must not have an existing wl_subsurface object. Otherwise the
+/// bad_surface protocol error is raised.
+///
+/// Adding sub-surfaces to a parent is a double-buffered operation on the
+/// parent (see wl_surface.commit). The effect of adding a sub-surface
+/// becomes visible on the next time the state of the parent surface is
+/// applied.
+///
+/// The parent surface must not be one of the child surface's descendants,
+/// and the parent must be different from the child surface, otherwise the
+/// bad_parent protocol error is raised.
+///
+/// This request modifies the behaviour of wl_surface.commit request on
+/// the sub-surface, see the documentation on wl_subsurface interface.
+///
+/// The arguments of the `wl_subcompositor.get_subsurface` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subcompositor_request_get_subsurface_args {
+    /// The new sub-surface object ID.
     pub id: crate::NewObjectId,
-    pub surface: ::std::option::Option<crate::ObjectId>,
-    pub parent: ::std::option::Option<crate::ObjectId>,
+    /// The surface to be turned into a sub-surface.
+    pub surface: crate::ObjectId,
+    /// The parent surface.
+    pub parent: crate::ObjectId,
 }
-impl wl_subcompositor_v1_request_get_subsurface_args {
+impl wl_subcompositor_request_get_subsurface_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subcompositor_v1_request_get_subsurface_args, crate::Error> {
+    ) -> Result<wl_subcompositor_request_get_subsurface_args, crate::Error> {
         let mut __reader = __value.read();
         let id = __reader.read_new_id_known_interface()?;
         let surface = __reader.read_object()?;
@@ -7288,7 +16690,7 @@ impl wl_subcompositor_v1_request_get_subsurface_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subcompositor_v1_request_get_subsurface_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subcompositor_request_get_subsurface_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
@@ -7297,77 +16699,325 @@ impl ::std::convert::TryFrom<crate::Packet> for wl_subcompositor_v1_request_get_
 impl
     ::std::convert::TryFrom<(
         crate::ObjectId,
-        wl_subcompositor_v1_request_get_subsurface_args,
+        wl_subcompositor_request_get_subsurface_args,
     )> for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
         __value: (
             crate::ObjectId,
-            wl_subcompositor_v1_request_get_subsurface_args,
+            wl_subcompositor_request_get_subsurface_args,
         ),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_subsurface_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// The enum `wl_subcompositor.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_subcompositor_error {
+    /// The to-be sub-surface is invalid.
+    bad_surface = 0,
+    /// The to-be sub-surface parent is invalid.
+    bad_parent = 1,
+}
+impl ::std::convert::TryFrom<u32> for wl_subcompositor_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::bad_surface),
+            1 => Ok(Self::bad_parent),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_subcompositor.error",
+                value: __value,
+            }),
+        }
+    }
 }
-#[allow(unused)]
-impl<'a> wl_subsurface_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+impl ::std::convert::From<wl_subcompositor_error> for u32 {
+    fn from(__value: wl_subcompositor_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_subsurface`.
+pub const wl_subsurface_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "wl_subsurface",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_position",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Int, crate::protocol::ArgKind::Int],
+            },
+            crate::protocol::MessageInfo {
+                name: "place_above",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "place_below",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_sync",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_desync",
+                destructor: false,
+                args: &[],
+            },
+        ],
+        events: &[],
+    };
+/// Sub-surface interface to a wl_surface.
+///
+/// An additional interface to a wl_surface object, which has been
+/// made a sub-surface. A sub-surface has one parent surface. A
+/// sub-surface's size and position are not limited to that of the parent.
+/// Particularly, a sub-surface is not automatically clipped to its
+/// parent's area.
+///
+/// A sub-surface becomes mapped, when a non-NULL wl_buffer is applied
+/// and the parent surface is mapped. The order of which one happens
+/// first is irrelevant. A sub-surface is hidden if the parent becomes
+/// hidden, or if a NULL wl_buffer is applied. These rules apply
+/// recursively through the tree of surfaces.
+///
+/// The behaviour of a wl_surface.commit request on a sub-surface
+/// depends on the sub-surface's mode. The possible modes are
+/// synchronized and desynchronized, see methods
+/// wl_subsurface.set_sync and wl_subsurface.set_desync. Synchronized
+/// mode caches the wl_surface state to be applied when the parent's
+/// state gets applied, and desynchronized mode applies the pending
+/// wl_surface state directly. A sub-surface is initially in the
+/// synchronized mode.
+///
+/// Sub-surfaces also have another kind of state, which is managed by
+/// wl_subsurface requests, as opposed to wl_surface requests. This
+/// state includes the sub-surface position relative to the parent
+/// surface (wl_subsurface.set_position), and the stacking order of
+/// the parent and its sub-surfaces (wl_subsurface.place_above and
+/// .place_below). This state is applied when the parent surface's
+/// wl_surface state is applied, regardless of the sub-surface's mode.
+/// As the exception, set_sync and set_desync are effective immediately.
+///
+/// The main surface can be thought to be always in desynchronized mode,
+/// since it does not have a parent in the sub-surfaces sense.
+///
+/// Even if a sub-surface is in desynchronized mode, it will behave as
+/// in synchronized mode, if its parent surface behaves as in
+/// synchronized mode. This rule is applied recursively throughout the
+/// tree of surfaces. This means, that one can set a sub-surface into
+/// synchronized mode, and then assume that all its child and grand-child
+/// sub-surfaces are synchronized, too, without explicitly setting them.
+///
+/// Destroying a sub-surface takes effect immediately. If you need to
+/// synchronize the removal of a sub-surface to the parent surface update,
+/// unmap the sub-surface first by attaching a NULL wl_buffer, update parent,
+/// and then destroy the sub-surface.
+///
+/// If the parent wl_surface object is destroyed, the sub-surface is
+/// unmapped.
+///
+/// A sub-surface never has the keyboard focus of any seat.
+///
+/// The wl_surface.offset request is ignored: clients must use set_position
+/// instead to move the sub-surface.
+///
+/// A proxy for a `wl_subsurface` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_subsurface {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_subsurface_interface;
+}
+impl crate::protocol::Proxy for wl_subsurface {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
     }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
+    fn id(&self) -> crate::ObjectId {
+        self.id
     }
-    pub async fn send_set_position(
-        &self,
-        __object_id: crate::ObjectId,
-        x: i32,
-        y: i32,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_subsurface {
+    /// Remove sub-surface interface.
+    ///
+    /// The sub-surface interface is removed from the wl_surface object
+    /// that was turned into a sub-surface with a
+    /// wl_subcompositor.get_subsurface request. The wl_surface's association
+    /// to the parent is deleted. The wl_surface is unmapped immediately.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Reposition the sub-surface.
+    ///
+    /// This schedules a sub-surface position change.
+    /// The sub-surface will be moved so that its origin (top left
+    /// corner pixel) will be at the location x, y of the parent surface
+    /// coordinate system. The coordinates are not restricted to the parent
+    /// surface area. Negative values are allowed.
+    ///
+    /// The scheduled coordinates will take effect whenever the state of the
+    /// parent surface is applied.
+    ///
+    /// If more than one set_position request is invoked by the client before
+    /// the commit of the parent surface, the position of a new request always
+    /// replaces the scheduled position from any previous request.
+    ///
+    /// The initial position is 0, 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `x`: x coordinate in the parent surface
+    /// * `y`: y coordinate in the parent surface
+    pub async fn send_set_position(&self, x: i32, y: i32) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
         __packet.push_int(x);
         __packet.push_int(y);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_place_above(
-        &self,
-        __object_id: crate::ObjectId,
-        sibling: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 2);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Restack the sub-surface.
+    ///
+    /// This sub-surface is taken from the stack, and put back just
+    /// above the reference surface, changing the z-order of the sub-surfaces.
+    /// The reference surface must be one of the sibling surfaces, or the
+    /// parent surface. Using any other surface, including this sub-surface,
+    /// will cause a protocol error.
+    ///
+    /// The z-order is double-buffered. Requests are handled in order and
+    /// applied immediately to a pending state. The final pending state is
+    /// copied to the active state the next time the state of the parent
+    /// surface is applied.
+    ///
+    /// A new sub-surface is initially added as the top-most in the stack
+    /// of its siblings and parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `sibling`: the reference surface
+    pub async fn send_place_above(&self, sibling: crate::ObjectId) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
         __packet.push_object(sibling);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_place_below(
-        &self,
-        __object_id: crate::ObjectId,
-        sibling: ::std::option::Option<crate::ObjectId>,
-    ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 3);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Restack the sub-surface.
+    ///
+    /// The sub-surface is placed just below the reference surface.
+    /// See wl_subsurface.place_above.
+    ///
+    /// # Arguments
+    ///
+    /// * `sibling`: the reference surface
+    pub async fn send_place_below(&self, sibling: crate::ObjectId) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 3);
         __packet.push_object(sibling);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_sync(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 4);
-        self.connection.send_packet(&__packet).await
-    }
-    pub async fn send_set_desync(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 5);
-        self.connection.send_packet(&__packet).await
-    }
-}
-pub struct wl_subsurface_v1_request_destroy_args {}
-impl wl_subsurface_v1_request_destroy_args {
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set sub-surface to synchronized mode.
+    ///
+    /// Change the commit behaviour of the sub-surface to synchronized
+    /// mode, also described as the parent dependent mode.
+    ///
+    /// In synchronized mode, wl_surface.commit on a sub-surface will
+    /// accumulate the committed state in a cache, but the state will
+    /// not be applied and hence will not change the compositor output.
+    /// The cached state is applied to the sub-surface immediately after
+    /// the parent surface's state is applied. This ensures atomic
+    /// updates of the parent and all its synchronized sub-surfaces.
+    /// Applying the cached state will invalidate the cache, so further
+    /// parent surface commits do not (re-)apply old state.
+    ///
+    /// See wl_subsurface for the recursive effect of this mode.
+    pub async fn send_set_sync(&self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 4);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Set sub-surface to desynchronized mode.
+    ///
+    /// Change the commit behaviour of the sub-surface to desynchronized
+    /// mode, also described as independent or freely running mode.
+    ///
+    /// In desynchronized mode, wl_surface.commit on a sub-surface will
+    /// apply the pending state directly, without caching, as happens
+    /// normally with a wl_surface. Calling wl_surface.commit on the
+    /// parent surface has no effect on the sub-surface's wl_surface
+    /// state. This mode allows a sub-surface to be updated on its own.
+    ///
+    /// If cached state exists when wl_surface.commit is called in
+    /// desynchronized mode, the pending state is added to the cached
+    /// state, and applied as a whole. This invalidates the cache.
+    ///
+    /// Note: even if a sub-surface is set to desynchronized, a parent
+    /// sub-surface may override it to behave as synchronized. For details,
+    /// see wl_subsurface.
+    ///
+    /// If a surface's parent surface behaves as desynchronized, then
+    /// the cached state is applied on set_desync.
+    pub async fn send_set_desync(&self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 5);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Remove sub-surface interface.
+///
+/// The sub-surface interface is removed from the wl_surface object
+/// that was turned into a sub-surface with a
+/// wl_subcompositor.get_subsurface request. The wl_surface's association
+/// to the parent is deleted. The wl_surface is unmapped immediately.
+///
+/// The arguments of the `wl_subsurface.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_destroy_args {}
+impl wl_subsurface_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_destroy_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7380,32 +17030,57 @@ impl wl_subsurface_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_destroy_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_destroy_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_destroy_args),
+        __value: (crate::ObjectId, wl_subsurface_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subsurface_v1_request_set_position_args {
+/// Reposition the sub-surface.
+///
+/// This schedules a sub-surface position change.
+/// The sub-surface will be moved so that its origin (top left
+/// corner pixel) will be at the location x, y of the parent surface
+/// coordinate system. The coordinates are not restricted to the parent
+/// surface area. Negative values are allowed.
+///
+/// The scheduled coordinates will take effect whenever the state of the
+/// parent surface is applied.
+///
+/// If more than one set_position request is invoked by the client before
+/// the commit of the parent surface, the position of a new request always
+/// replaces the scheduled position from any previous request.
+///
+/// The initial position is 0, 0.
+///
+/// The arguments of the `wl_subsurface.set_position` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_set_position_args {
+    /// X coordinate in the parent surface.
     pub x: i32,
+    /// Y coordinate in the parent surface.
     pub y: i32,
 }
-impl wl_subsurface_v1_request_set_position_args {
+impl wl_subsurface_request_set_position_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_set_position_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_set_position_args, crate::Error> {
         let mut __reader = __value.read();
         let x = __reader.read_int()?;
         let y = __reader.read_int()?;
@@ -7422,31 +17097,54 @@ impl wl_subsurface_v1_request_set_position_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_set_position_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_set_position_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_set_position_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_set_position_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_set_position_args),
+        __value: (crate::ObjectId, wl_subsurface_request_set_position_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subsurface_v1_request_place_above_args {
-    pub sibling: ::std::option::Option<crate::ObjectId>,
-}
-impl wl_subsurface_v1_request_place_above_args {
+/// Restack the sub-surface.
+///
+/// This sub-surface is taken from the stack, and put back just
+/// above the reference surface, changing the z-order of the sub-surfaces.
+/// The reference surface must be one of the sibling surfaces, or the
+/// parent surface. Using any other surface, including this sub-surface,
+/// will cause a protocol error.
+///
+/// The z-order is double-buffered. Requests are handled in order and
+/// applied immediately to a pending state. The final pending state is
+/// copied to the active state the next time the state of the parent
+/// surface is applied.
+///
+/// A new sub-surface is initially added as the top-most in the stack
+/// of its siblings and parent.
+///
+/// The arguments of the `wl_subsurface.place_above` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_place_above_args {
+    /// The reference surface.
+    pub sibling: crate::ObjectId,
+}
+impl wl_subsurface_request_place_above_args {
     pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_place_above_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_place_above_args, crate::Error> {
         let mut __reader = __value.read();
         let sibling = __reader.read_object()?;
         __reader.finish()?;
@@ -7461,31 +17159,43 @@ impl wl_subsurface_v1_request_place_above_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_place_above_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_place_above_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_place_above_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_place_above_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_place_above_args),
+        __value: (crate::ObjectId, wl_subsurface_request_place_above_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subsurface_v1_request_place_below_args {
-    pub sibling: ::std::option::Option<crate::ObjectId>,
+/// Restack the sub-surface.
+///
+/// The sub-surface is placed just below the reference surface.
+/// See wl_subsurface.place_above.
+///
+/// The arguments of the `wl_subsurface.place_below` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_place_below_args {
+    /// The reference surface.
+    pub sibling: crate::ObjectId,
 }
-impl wl_subsurface_v1_request_place_below_args {
+impl wl_subsurface_request_place_below_args {
     pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_place_below_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_place_below_args, crate::Error> {
         let mut __reader = __value.read();
         let sibling = __reader.read_object()?;
         __reader.finish()?;
@@ -7500,29 +17210,51 @@ impl wl_subsurface_v1_request_place_below_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_place_below_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_place_below_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_place_below_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_place_below_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_place_below_args),
+        __value: (crate::ObjectId, wl_subsurface_request_place_below_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subsurface_v1_request_set_sync_args {}
-impl wl_subsurface_v1_request_set_sync_args {
+/// Set sub-surface to synchronized mode.
+///
+/// Change the commit behaviour of the sub-surface to synchronized
+/// mode, also described as the parent dependent mode.
+///
+/// In synchronized mode, wl_surface.commit on a sub-surface will
+/// accumulate the committed state in a cache, but the state will
+/// not be applied and hence will not change the compositor output.
+/// The cached state is applied to the sub-surface immediately after
+/// the parent surface's state is applied. This ensures atomic
+/// updates of the parent and all its synchronized sub-surfaces.
+/// Applying the cached state will invalidate the cache, so further
+/// parent surface commits do not (re-)apply old state.
+///
+/// See wl_subsurface for the recursive effect of this mode.
+///
+/// The arguments of the `wl_subsurface.set_sync` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_set_sync_args {}
+impl wl_subsurface_request_set_sync_args {
     pub const OPCODE: u16 = 4;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_set_sync_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_set_sync_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7535,29 +17267,57 @@ impl wl_subsurface_v1_request_set_sync_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_set_sync_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_set_sync_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_set_sync_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_set_sync_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_set_sync_args),
+        __value: (crate::ObjectId, wl_subsurface_request_set_sync_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_subsurface_v1_request_set_desync_args {}
-impl wl_subsurface_v1_request_set_desync_args {
+/// Set sub-surface to desynchronized mode.
+///
+/// Change the commit behaviour of the sub-surface to desynchronized
+/// mode, also described as independent or freely running mode.
+///
+/// In desynchronized mode, wl_surface.commit on a sub-surface will
+/// apply the pending state directly, without caching, as happens
+/// normally with a wl_surface. Calling wl_surface.commit on the
+/// parent surface has no effect on the sub-surface's wl_surface
+/// state. This mode allows a sub-surface to be updated on its own.
+///
+/// If cached state exists when wl_surface.commit is called in
+/// desynchronized mode, the pending state is added to the cached
+/// state, and applied as a whole. This invalidates the cache.
+///
+/// Note: even if a sub-surface is set to desynchronized, a parent
+/// sub-surface may override it to behave as synchronized. For details,
+/// see wl_subsurface.
+///
+/// If a surface's parent surface behaves as desynchronized, then
+/// the cached state is applied on set_desync.
+///
+/// The arguments of the `wl_subsurface.set_desync` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_subsurface_request_set_desync_args {}
+impl wl_subsurface_request_set_desync_args {
     pub const OPCODE: u16 = 5;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_subsurface_v1_request_set_desync_args, crate::Error> {
+    ) -> Result<wl_subsurface_request_set_desync_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7570,52 +17330,151 @@ impl wl_subsurface_v1_request_set_desync_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_v1_request_set_desync_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_subsurface_request_set_desync_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_v1_request_set_desync_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_subsurface_request_set_desync_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_subsurface_v1_request_set_desync_args),
+        __value: (crate::ObjectId, wl_subsurface_request_set_desync_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-#[allow(unused)]
-pub struct wl_fixes_v1_request_proxy<'a> {
-    connection: &'a crate::Connection,
+/// The enum `wl_subsurface.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum wl_subsurface_error {
+    /// Wl_surface is not a sibling or the parent.
+    bad_surface = 0,
 }
-#[allow(unused)]
-impl<'a> wl_fixes_v1_request_proxy<'a> {
-    pub fn new(connection: &'a crate::Connection) -> Self {
-        Self { connection }
+impl ::std::convert::TryFrom<u32> for wl_subsurface_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            0 => Ok(Self::bad_surface),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "wl_subsurface.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<wl_subsurface_error> for u32 {
+    fn from(__value: wl_subsurface_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `wl_fixes`.
+pub const wl_fixes_interface: crate::protocol::InterfaceInfo = crate::protocol::InterfaceInfo {
+    name: "wl_fixes",
+    version: 1,
+    requests: &[
+        crate::protocol::MessageInfo {
+            name: "destroy",
+            destructor: true,
+            args: &[],
+        },
+        crate::protocol::MessageInfo {
+            name: "destroy_registry",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::Object],
+        },
+    ],
+    events: &[],
+};
+/// Wayland protocol fixes.
+///
+/// This global fixes problems with other core-protocol interfaces that
+/// cannot be fixed in these interfaces themselves.
+///
+/// A proxy for a `wl_fixes` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_fixes {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for wl_fixes {
+    const INFO: &'static crate::protocol::InterfaceInfo = &wl_fixes_interface;
+}
+impl crate::protocol::Proxy for wl_fixes {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
     }
-    pub async fn send_destroy(&self, __object_id: crate::ObjectId) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 0);
-        self.connection.send_packet(&__packet).await
+    fn id(&self) -> crate::ObjectId {
+        self.id
     }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl wl_fixes {
+    /// Destroys this object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy a wl_registry.
+    ///
+    /// This request destroys a wl_registry object.
+    ///
+    /// The client should no longer use the wl_registry after making this
+    /// request.
+    ///
+    /// The compositor will emit a wl_display.delete_id event with the object ID
+    /// of the registry and will no longer emit any events on the registry. The
+    /// client should re-use the object ID once it receives the
+    /// wl_display.delete_id event.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry`: the registry to destroy
     pub async fn send_destroy_registry(
         &self,
-        __object_id: crate::ObjectId,
-        registry: ::std::option::Option<crate::ObjectId>,
+        registry: crate::ObjectId,
     ) -> Result<(), crate::Error> {
-        let mut __packet = crate::Packet::new(__object_id, 1);
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
         __packet.push_object(registry);
-        self.connection.send_packet(&__packet).await
+        __connection.send_packet(&__packet).await?;
+        Ok(())
     }
 }
-pub struct wl_fixes_v1_request_destroy_args {}
-impl wl_fixes_v1_request_destroy_args {
+/// Destroys this object.
+///
+/// The arguments of the `wl_fixes.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_fixes_request_destroy_args {}
+impl wl_fixes_request_destroy_args {
     pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_fixes_v1_request_destroy_args, crate::Error> {
+    ) -> Result<wl_fixes_request_destroy_args, crate::Error> {
         let mut __reader = __value.read();
         __reader.finish()?;
         Ok(Self {})
@@ -7628,31 +17487,48 @@ impl wl_fixes_v1_request_destroy_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_fixes_v1_request_destroy_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_fixes_request_destroy_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_fixes_v1_request_destroy_args)>
-    for crate::Packet
-{
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_fixes_request_destroy_args)> for crate::Packet {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_fixes_v1_request_destroy_args),
+        __value: (crate::ObjectId, wl_fixes_request_destroy_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
     }
 }
-pub struct wl_fixes_v1_request_destroy_registry_args {
-    pub registry: ::std::option::Option<crate::ObjectId>,
-}
-impl wl_fixes_v1_request_destroy_registry_args {
+/// Destroy a wl_registry.
+///
+/// This request destroys a wl_registry object.
+///
+/// The client should no longer use the wl_registry after making this
+/// request.
+///
+/// The compositor will emit a wl_display.delete_id event with the object ID
+/// of the registry and will no longer emit any events on the registry. The
+/// client should re-use the object ID once it receives the
+/// wl_display.delete_id event.
+///
+/// The arguments of the `wl_fixes.destroy_registry` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct wl_fixes_request_destroy_registry_args {
+    /// The registry to destroy.
+    pub registry: crate::ObjectId,
+}
+impl wl_fixes_request_destroy_registry_args {
     pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
     pub fn try_from_packet(
         __value: &crate::Packet,
-    ) -> Result<wl_fixes_v1_request_destroy_registry_args, crate::Error> {
+    ) -> Result<wl_fixes_request_destroy_registry_args, crate::Error> {
         let mut __reader = __value.read();
         let registry = __reader.read_object()?;
         __reader.finish()?;
@@ -7667,18 +17543,18 @@ impl wl_fixes_v1_request_destroy_registry_args {
         Ok(__packet)
     }
 }
-impl ::std::convert::TryFrom<crate::Packet> for wl_fixes_v1_request_destroy_registry_args {
+impl ::std::convert::TryFrom<crate::Packet> for wl_fixes_request_destroy_registry_args {
     type Error = crate::Error;
     fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
         Self::try_from_packet(&__value)
     }
 }
-impl ::std::convert::TryFrom<(crate::ObjectId, wl_fixes_v1_request_destroy_registry_args)>
+impl ::std::convert::TryFrom<(crate::ObjectId, wl_fixes_request_destroy_registry_args)>
     for crate::Packet
 {
     type Error = crate::Error;
     fn try_from(
-        __value: (crate::ObjectId, wl_fixes_v1_request_destroy_registry_args),
+        __value: (crate::ObjectId, wl_fixes_request_destroy_registry_args),
     ) -> Result<Self, Self::Error> {
         let (__object_id, __structure) = __value;
         __structure.try_into_packet(__object_id)
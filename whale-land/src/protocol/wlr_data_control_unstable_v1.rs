@@ -0,0 +1,1945 @@
+// This file has been autogenerated from a Wayland XML protocol definition.
+
+#![allow(non_camel_case_types, non_upper_case_globals)]
+/// Static information about all interfaces of `wlr_data_control_unstable_v1`.
+pub const INTERFACES: &[&crate::protocol::InterfaceInfo] = &[
+    &zwlr_data_control_manager_v1_interface,
+    &zwlr_data_control_device_v1_interface,
+    &zwlr_data_control_source_v1_interface,
+    &zwlr_data_control_offer_v1_interface,
+];
+/// Static information about `zwlr_data_control_manager_v1`.
+pub const zwlr_data_control_manager_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwlr_data_control_manager_v1",
+        version: 2,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "create_data_source",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("zwlr_data_control_source_v1"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "get_data_device",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::NewId {
+                        interface: Some("zwlr_data_control_device_v1"),
+                    },
+                    crate::protocol::ArgKind::Object,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[],
+    };
+/// Manager to control data devices.
+///
+/// This interface is a manager that allows creating per-seat data device
+/// controls.
+///
+/// A proxy for a `zwlr_data_control_manager_v1` object. The generated code knows this interface up to version 2.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_manager_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwlr_data_control_manager_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &zwlr_data_control_manager_v1_interface;
+}
+impl crate::protocol::Proxy for zwlr_data_control_manager_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+#[allow(unused)]
+impl zwlr_data_control_manager_v1 {
+    /// Create a new data source.
+    ///
+    /// Create a new data source.
+    ///
+    /// Returns the new `zwlr_data_control_source_v1` object.
+    pub async fn send_create_data_source(
+        &self,
+    ) -> Result<zwlr_data_control_source_v1, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id =
+            __connection.new_object(&zwlr_data_control_source_v1_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __connection.send_packet(&__packet).await?;
+        Ok(zwlr_data_control_source_v1 {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Get a data device for a seat.
+    ///
+    /// Create a data device that can be used to manage a seat's selection.
+    ///
+    /// # Arguments
+    ///
+    /// * `seat`
+    ///
+    /// Returns the new `zwlr_data_control_device_v1` object.
+    pub async fn send_get_data_device(
+        &self,
+        seat: crate::ObjectId,
+    ) -> Result<zwlr_data_control_device_v1, crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let __new_id =
+            __connection.new_object(&zwlr_data_control_device_v1_interface, self.version);
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __packet.push_new_id_known_interface(crate::NewObjectId(__new_id));
+        __packet.push_object(seat);
+        __connection.send_packet(&__packet).await?;
+        Ok(zwlr_data_control_device_v1 {
+            id: __new_id,
+            version: self.version,
+            connection: self.connection.clone(),
+        })
+    }
+    /// Destroy the manager.
+    ///
+    /// All objects created by the manager will still remain valid, until their
+    /// appropriate destroy request has been called.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Create a new data source.
+///
+/// Create a new data source.
+///
+/// The arguments of the `zwlr_data_control_manager_v1.create_data_source` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_manager_v1_request_create_data_source_args {
+    /// Data source to create.
+    pub id: crate::NewObjectId,
+}
+impl zwlr_data_control_manager_v1_request_create_data_source_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_manager_v1_request_create_data_source_args, crate::Error> {
+        let mut __reader = __value.read();
+        let id = __reader.read_new_id_known_interface()?;
+        __reader.finish()?;
+        Ok(Self { id })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_new_id_known_interface(self.id);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwlr_data_control_manager_v1_request_create_data_source_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_manager_v1_request_create_data_source_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_manager_v1_request_create_data_source_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Get a data device for a seat.
+///
+/// Create a data device that can be used to manage a seat's selection.
+///
+/// The arguments of the `zwlr_data_control_manager_v1.get_data_device` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_manager_v1_request_get_data_device_args {
+    pub id: crate::NewObjectId,
+    pub seat: crate::ObjectId,
+}
+impl zwlr_data_control_manager_v1_request_get_data_device_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_manager_v1_request_get_data_device_args, crate::Error> {
+        let mut __reader = __value.read();
+        let id = __reader.read_new_id_known_interface()?;
+        let seat = __reader.read_object()?;
+        __reader.finish()?;
+        Ok(Self { id, seat })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_new_id_known_interface(self.id);
+        __packet.push_object(self.seat);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwlr_data_control_manager_v1_request_get_data_device_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_manager_v1_request_get_data_device_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_manager_v1_request_get_data_device_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Destroy the manager.
+///
+/// All objects created by the manager will still remain valid, until their
+/// appropriate destroy request has been called.
+///
+/// The arguments of the `zwlr_data_control_manager_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_manager_v1_request_destroy_args {}
+impl zwlr_data_control_manager_v1_request_destroy_args {
+    pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_manager_v1_request_destroy_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_manager_v1_request_destroy_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_manager_v1_request_destroy_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_manager_v1_request_destroy_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Static information about `zwlr_data_control_device_v1`.
+pub const zwlr_data_control_device_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwlr_data_control_device_v1",
+        version: 2,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "set_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "set_primary_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "data_offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::NewId {
+                    interface: Some("zwlr_data_control_offer_v1"),
+                }],
+            },
+            crate::protocol::MessageInfo {
+                name: "selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+            crate::protocol::MessageInfo {
+                name: "finished",
+                destructor: false,
+                args: &[],
+            },
+            crate::protocol::MessageInfo {
+                name: "primary_selection",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::Object],
+            },
+        ],
+    };
+/// Manage a data device for a seat.
+///
+/// This interface allows a client to manage a seat's selection.
+///
+/// When the seat is destroyed, this object becomes inert.
+///
+/// A proxy for a `zwlr_data_control_device_v1` object. The generated code knows this interface up to version 2.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwlr_data_control_device_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &zwlr_data_control_device_v1_interface;
+}
+impl crate::protocol::Proxy for zwlr_data_control_device_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `zwlr_data_control_device_v1` object.
+#[allow(unused)]
+pub trait zwlr_data_control_device_v1_event_handler: crate::protocol::EventHandler {
+    /// Introduce a new wlr_data_control_offer.
+    ///
+    /// The data_offer event introduces a new wlr_data_control_offer object,
+    /// which will subsequently be used in either the
+    /// wlr_data_control_device.selection event (for the regular clipboard
+    /// selections) or the wlr_data_control_device.primary_selection event (for
+    /// the primary clipboard selections). Immediately following the
+    /// wlr_data_control_device.data_offer event, the new data_offer object
+    /// will send out wlr_data_control_offer.offer events to describe the MIME
+    /// types it offers.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
+    fn handle_data_offer(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+        id: crate::NewObjectId,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise new selection.
+    ///
+    /// The selection event is sent out to notify the client of a new
+    /// wlr_data_control_offer for the selection for this device. The
+    /// wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The selection event is sent to a client when a new
+    /// selection is set. The wlr_data_control_offer is valid until a new
+    /// wlr_data_control_offer or NULL is received. The client must destroy the
+    /// previous selection wlr_data_control_offer, if any, upon receiving this
+    /// event.
+    ///
+    /// The first selection event is sent upon binding the
+    /// wlr_data_control_device object.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
+    fn handle_selection(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+        id: ::std::option::Option<crate::ObjectId>,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// This data control is no longer valid.
+    ///
+    /// This data control object is no longer valid and should be destroyed by
+    /// the client.
+    fn handle_finished(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Advertise new primary selection.
+    ///
+    /// The primary_selection event is sent out to notify the client of a new
+    /// wlr_data_control_offer for the primary selection for this device. The
+    /// wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+    /// events are sent out immediately before this event to introduce the data
+    /// offer object. The primary_selection event is sent to a client when a
+    /// new primary selection is set. The wlr_data_control_offer is valid until
+    /// a new wlr_data_control_offer or NULL is received. The client must
+    /// destroy the previous primary selection wlr_data_control_offer, if any,
+    /// upon receiving this event.
+    ///
+    /// If the compositor supports primary selection, the first
+    /// primary_selection event is sent upon binding the
+    /// wlr_data_control_device object.
+    ///
+    /// Available since version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`
+    fn handle_primary_selection(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+        id: ::std::option::Option<crate::ObjectId>,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
+    fn unknown_event(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "zwlr_data_control_device_v1",
+            opcode: packet.opcode(),
+        })))
+    }
+    /// Decodes an event and passes it to the matching handler function.
+    fn handle_event(
+        &self,
+        __connection: &crate::Connection,
+        __packet: crate::Packet,
+    ) -> impl std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync
+    where
+        Self: Sync,
+    {
+        async {
+            match __packet.opcode() {
+                0 => {
+                    let mut __packet_reader = __packet.read();
+                    let id = __packet_reader.read_new_id_known_interface()?;
+                    __packet_reader.finish()?;
+                    self.handle_data_offer(__connection, __packet, id).await
+                }
+                1 => {
+                    let mut __packet_reader = __packet.read();
+                    let id = __packet_reader.read_nullable_object()?;
+                    __packet_reader.finish()?;
+                    self.handle_selection(__connection, __packet, id).await
+                }
+                2 => {
+                    let mut __packet_reader = __packet.read();
+                    __packet_reader.finish()?;
+                    self.handle_finished(__connection, __packet).await
+                }
+                3 => {
+                    let mut __packet_reader = __packet.read();
+                    let id = __packet_reader.read_nullable_object()?;
+                    __packet_reader.finish()?;
+                    self.handle_primary_selection(__connection, __packet, id)
+                        .await
+                }
+                __other => self.unknown_event(__connection, __packet).await,
+            }
+        }
+    }
+}
+#[allow(unused)]
+impl zwlr_data_control_device_v1 {
+    /// Copy data to the selection.
+    ///
+    /// This request asks the compositor to set the selection to the data from
+    /// the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source is a protocol error.
+    ///
+    /// To unset the selection, set the source to NULL.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`
+    pub async fn send_set_selection(
+        &self,
+        source: ::std::option::Option<crate::ObjectId>,
+    ) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_nullable_object(source);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this data device.
+    ///
+    /// Destroys the data device object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Copy data to the primary selection.
+    ///
+    /// This request asks the compositor to set the primary selection to the
+    /// data from the source on behalf of the client.
+    ///
+    /// The given source may not be used in any further set_selection or
+    /// set_primary_selection requests. Attempting to use a previously used
+    /// source is a protocol error.
+    ///
+    /// To unset the primary selection, set the source to NULL.
+    ///
+    /// The compositor will ignore this request if it does not support primary
+    /// selection.
+    ///
+    /// Available since version 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`
+    pub async fn send_set_primary_selection(
+        &self,
+        source: ::std::option::Option<crate::ObjectId>,
+    ) -> Result<(), crate::Error> {
+        if self.version < 2 {
+            return Err(crate::Error::RequestTooNew {
+                interface: "zwlr_data_control_device_v1",
+                request: "set_primary_selection",
+                since: 2,
+                version: self.version,
+            });
+        }
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 2);
+        __packet.push_nullable_object(source);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Copy data to the selection.
+///
+/// This request asks the compositor to set the selection to the data from
+/// the source on behalf of the client.
+///
+/// The given source may not be used in any further set_selection or
+/// set_primary_selection requests. Attempting to use a previously used
+/// source is a protocol error.
+///
+/// To unset the selection, set the source to NULL.
+///
+/// The arguments of the `zwlr_data_control_device_v1.set_selection` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_request_set_selection_args {
+    pub source: ::std::option::Option<crate::ObjectId>,
+}
+impl zwlr_data_control_device_v1_request_set_selection_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_request_set_selection_args, crate::Error> {
+        let mut __reader = __value.read();
+        let source = __reader.read_nullable_object()?;
+        __reader.finish()?;
+        Ok(Self { source })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_nullable_object(self.source);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwlr_data_control_device_v1_request_set_selection_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_request_set_selection_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_request_set_selection_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Destroy this data device.
+///
+/// Destroys the data device object.
+///
+/// The arguments of the `zwlr_data_control_device_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_request_destroy_args {}
+impl zwlr_data_control_device_v1_request_destroy_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_request_destroy_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_device_v1_request_destroy_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_request_destroy_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_request_destroy_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Copy data to the primary selection.
+///
+/// This request asks the compositor to set the primary selection to the
+/// data from the source on behalf of the client.
+///
+/// The given source may not be used in any further set_selection or
+/// set_primary_selection requests. Attempting to use a previously used
+/// source is a protocol error.
+///
+/// To unset the primary selection, set the source to NULL.
+///
+/// The compositor will ignore this request if it does not support primary
+/// selection.
+///
+/// Available since version 2.
+///
+/// The arguments of the `zwlr_data_control_device_v1.set_primary_selection` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_request_set_primary_selection_args {
+    pub source: ::std::option::Option<crate::ObjectId>,
+}
+impl zwlr_data_control_device_v1_request_set_primary_selection_args {
+    pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_request_set_primary_selection_args, crate::Error> {
+        let mut __reader = __value.read();
+        let source = __reader.read_nullable_object()?;
+        __reader.finish()?;
+        Ok(Self { source })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_nullable_object(self.source);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwlr_data_control_device_v1_request_set_primary_selection_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_request_set_primary_selection_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_request_set_primary_selection_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Introduce a new wlr_data_control_offer.
+///
+/// The data_offer event introduces a new wlr_data_control_offer object,
+/// which will subsequently be used in either the
+/// wlr_data_control_device.selection event (for the regular clipboard
+/// selections) or the wlr_data_control_device.primary_selection event (for
+/// the primary clipboard selections). Immediately following the
+/// wlr_data_control_device.data_offer event, the new data_offer object
+/// will send out wlr_data_control_offer.offer events to describe the MIME
+/// types it offers.
+///
+/// The arguments of the `zwlr_data_control_device_v1.data_offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_event_data_offer_args {
+    pub id: crate::NewObjectId,
+}
+impl zwlr_data_control_device_v1_event_data_offer_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_event_data_offer_args, crate::Error> {
+        let mut __reader = __value.read();
+        let id = __reader.read_new_id_known_interface()?;
+        __reader.finish()?;
+        Ok(Self { id })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_new_id_known_interface(self.id);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_device_v1_event_data_offer_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_event_data_offer_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_event_data_offer_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Advertise new selection.
+///
+/// The selection event is sent out to notify the client of a new
+/// wlr_data_control_offer for the selection for this device. The
+/// wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+/// events are sent out immediately before this event to introduce the data
+/// offer object. The selection event is sent to a client when a new
+/// selection is set. The wlr_data_control_offer is valid until a new
+/// wlr_data_control_offer or NULL is received. The client must destroy the
+/// previous selection wlr_data_control_offer, if any, upon receiving this
+/// event.
+///
+/// The first selection event is sent upon binding the
+/// wlr_data_control_device object.
+///
+/// The arguments of the `zwlr_data_control_device_v1.selection` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_event_selection_args {
+    pub id: ::std::option::Option<crate::ObjectId>,
+}
+impl zwlr_data_control_device_v1_event_selection_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_event_selection_args, crate::Error> {
+        let mut __reader = __value.read();
+        let id = __reader.read_nullable_object()?;
+        __reader.finish()?;
+        Ok(Self { id })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_nullable_object(self.id);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_device_v1_event_selection_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_event_selection_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_event_selection_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// This data control is no longer valid.
+///
+/// This data control object is no longer valid and should be destroyed by
+/// the client.
+///
+/// The arguments of the `zwlr_data_control_device_v1.finished` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_event_finished_args {}
+impl zwlr_data_control_device_v1_event_finished_args {
+    pub const OPCODE: u16 = 2;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_event_finished_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_device_v1_event_finished_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_event_finished_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_event_finished_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Advertise new primary selection.
+///
+/// The primary_selection event is sent out to notify the client of a new
+/// wlr_data_control_offer for the primary selection for this device. The
+/// wlr_data_control_device.data_offer and the wlr_data_control_offer.offer
+/// events are sent out immediately before this event to introduce the data
+/// offer object. The primary_selection event is sent to a client when a
+/// new primary selection is set. The wlr_data_control_offer is valid until
+/// a new wlr_data_control_offer or NULL is received. The client must
+/// destroy the previous primary selection wlr_data_control_offer, if any,
+/// upon receiving this event.
+///
+/// If the compositor supports primary selection, the first
+/// primary_selection event is sent upon binding the
+/// wlr_data_control_device object.
+///
+/// Available since version 2.
+///
+/// The arguments of the `zwlr_data_control_device_v1.primary_selection` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_device_v1_event_primary_selection_args {
+    pub id: ::std::option::Option<crate::ObjectId>,
+}
+impl zwlr_data_control_device_v1_event_primary_selection_args {
+    pub const OPCODE: u16 = 3;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 2;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_device_v1_event_primary_selection_args, crate::Error> {
+        let mut __reader = __value.read();
+        let id = __reader.read_nullable_object()?;
+        __reader.finish()?;
+        Ok(Self { id })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_nullable_object(self.id);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet>
+    for zwlr_data_control_device_v1_event_primary_selection_args
+{
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_device_v1_event_primary_selection_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_device_v1_event_primary_selection_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// An event sent to a `zwlr_data_control_device_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum zwlr_data_control_device_v1_event {
+    /// Introduce a new wlr_data_control_offer.
+    data_offer(zwlr_data_control_device_v1_event_data_offer_args),
+    /// Advertise new selection.
+    selection(zwlr_data_control_device_v1_event_selection_args),
+    /// This data control is no longer valid.
+    finished(zwlr_data_control_device_v1_event_finished_args),
+    /// Advertise new primary selection.
+    primary_selection(zwlr_data_control_device_v1_event_primary_selection_args),
+}
+impl zwlr_data_control_device_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::data_offer(
+                zwlr_data_control_device_v1_event_data_offer_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::selection(
+                zwlr_data_control_device_v1_event_selection_args::try_from_packet(__packet)?,
+            )),
+            2 => Ok(Self::finished(
+                zwlr_data_control_device_v1_event_finished_args::try_from_packet(__packet)?,
+            )),
+            3 => Ok(Self::primary_selection(
+                zwlr_data_control_device_v1_event_primary_selection_args::try_from_packet(
+                    __packet,
+                )?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "zwlr_data_control_device_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::data_offer(_) => zwlr_data_control_device_v1_event_data_offer_args::OPCODE,
+            Self::selection(_) => zwlr_data_control_device_v1_event_selection_args::OPCODE,
+            Self::finished(_) => zwlr_data_control_device_v1_event_finished_args::OPCODE,
+            Self::primary_selection(_) => {
+                zwlr_data_control_device_v1_event_primary_selection_args::OPCODE
+            }
+        }
+    }
+}
+/// The enum `zwlr_data_control_device_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum zwlr_data_control_device_v1_error {
+    /// Source given to set_selection or set_primary_selection was already used before.
+    used_source = 1,
+}
+impl ::std::convert::TryFrom<u32> for zwlr_data_control_device_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            1 => Ok(Self::used_source),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "zwlr_data_control_device_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<zwlr_data_control_device_v1_error> for u32 {
+    fn from(__value: zwlr_data_control_device_v1_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `zwlr_data_control_source_v1`.
+pub const zwlr_data_control_source_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwlr_data_control_source_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "offer",
+                destructor: false,
+                args: &[crate::protocol::ArgKind::String],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[
+            crate::protocol::MessageInfo {
+                name: "send",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "cancelled",
+                destructor: false,
+                args: &[],
+            },
+        ],
+    };
+/// Offer to transfer data.
+///
+/// The wlr_data_control_source object is the source side of a
+/// wlr_data_control_offer. It is created by the source client in a data
+/// transfer and provides a way to describe the offered data and a way to
+/// respond to requests to transfer the data.
+///
+/// A proxy for a `zwlr_data_control_source_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_source_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwlr_data_control_source_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &zwlr_data_control_source_v1_interface;
+}
+impl crate::protocol::Proxy for zwlr_data_control_source_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `zwlr_data_control_source_v1` object.
+#[allow(unused)]
+pub trait zwlr_data_control_source_v1_event_handler: crate::protocol::EventHandler {
+    /// Send the data.
+    ///
+    /// Request for data from the client. Send the data as the specified MIME
+    /// type over the passed file descriptor, then close it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type for the data
+    /// * `fd`: file descriptor for the data
+    fn handle_send(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+        mime_type: ::std::string::String,
+        fd: ::std::os::fd::RawFd,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Selection was cancelled.
+    ///
+    /// This data source is no longer valid. The data source has been replaced
+    /// by another data source.
+    ///
+    /// The client should clean up and destroy this data source.
+    fn handle_cancelled(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
+    fn unknown_event(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "zwlr_data_control_source_v1",
+            opcode: packet.opcode(),
+        })))
+    }
+    /// Decodes an event and passes it to the matching handler function.
+    fn handle_event(
+        &self,
+        __connection: &crate::Connection,
+        __packet: crate::Packet,
+    ) -> impl std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync
+    where
+        Self: Sync,
+    {
+        async {
+            match __packet.opcode() {
+                0 => {
+                    let mut __packet_reader = __packet.read();
+                    let mime_type = __packet_reader.read_str()?;
+                    let fd = __packet_reader.read_fd()?;
+                    __packet_reader.finish()?;
+                    self.handle_send(__connection, __packet, mime_type, fd)
+                        .await
+                }
+                1 => {
+                    let mut __packet_reader = __packet.read();
+                    __packet_reader.finish()?;
+                    self.handle_cancelled(__connection, __packet).await
+                }
+                __other => self.unknown_event(__connection, __packet).await,
+            }
+        }
+    }
+}
+#[allow(unused)]
+impl zwlr_data_control_source_v1 {
+    /// Add an offered MIME type.
+    ///
+    /// This request adds a MIME type to the set of MIME types advertised to
+    /// targets. Can be called several times to offer multiple types.
+    ///
+    /// Calling this after wlr_data_control_device.set_selection is a protocol
+    /// error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type offered by the data source
+    pub async fn send_offer(&self, mime_type: &str) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_str(mime_type);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this source.
+    ///
+    /// Destroys the data source object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Add an offered MIME type.
+///
+/// This request adds a MIME type to the set of MIME types advertised to
+/// targets. Can be called several times to offer multiple types.
+///
+/// Calling this after wlr_data_control_device.set_selection is a protocol
+/// error.
+///
+/// The arguments of the `zwlr_data_control_source_v1.offer` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_source_v1_request_offer_args {
+    /// MIME type offered by the data source.
+    pub mime_type: ::std::string::String,
+}
+impl zwlr_data_control_source_v1_request_offer_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_source_v1_request_offer_args, crate::Error> {
+        let mut __reader = __value.read();
+        let mime_type = __reader.read_str()?;
+        __reader.finish()?;
+        Ok(Self { mime_type })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_str(&self.mime_type);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_source_v1_request_offer_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_source_v1_request_offer_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_source_v1_request_offer_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Destroy this source.
+///
+/// Destroys the data source object.
+///
+/// The arguments of the `zwlr_data_control_source_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_source_v1_request_destroy_args {}
+impl zwlr_data_control_source_v1_request_destroy_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_source_v1_request_destroy_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_source_v1_request_destroy_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_source_v1_request_destroy_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_source_v1_request_destroy_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Send the data.
+///
+/// Request for data from the client. Send the data as the specified MIME
+/// type over the passed file descriptor, then close it.
+///
+/// The arguments of the `zwlr_data_control_source_v1.send` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_source_v1_event_send_args {
+    /// MIME type for the data.
+    pub mime_type: ::std::string::String,
+    /// File descriptor for the data.
+    pub fd: ::std::os::fd::RawFd,
+}
+impl zwlr_data_control_source_v1_event_send_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_source_v1_event_send_args, crate::Error> {
+        let mut __reader = __value.read();
+        let mime_type = __reader.read_str()?;
+        let fd = __reader.read_fd()?;
+        __reader.finish()?;
+        Ok(Self { mime_type, fd })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_str(&self.mime_type);
+        __packet.push_fd(self.fd);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_source_v1_event_send_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl ::std::convert::TryFrom<(crate::ObjectId, zwlr_data_control_source_v1_event_send_args)>
+    for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (crate::ObjectId, zwlr_data_control_source_v1_event_send_args),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Selection was cancelled.
+///
+/// This data source is no longer valid. The data source has been replaced
+/// by another data source.
+///
+/// The client should clean up and destroy this data source.
+///
+/// The arguments of the `zwlr_data_control_source_v1.cancelled` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_source_v1_event_cancelled_args {}
+impl zwlr_data_control_source_v1_event_cancelled_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_source_v1_event_cancelled_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_source_v1_event_cancelled_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_source_v1_event_cancelled_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_source_v1_event_cancelled_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// An event sent to a `zwlr_data_control_source_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum zwlr_data_control_source_v1_event {
+    /// Send the data.
+    send(zwlr_data_control_source_v1_event_send_args),
+    /// Selection was cancelled.
+    cancelled(zwlr_data_control_source_v1_event_cancelled_args),
+}
+impl zwlr_data_control_source_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::send(
+                zwlr_data_control_source_v1_event_send_args::try_from_packet(__packet)?,
+            )),
+            1 => Ok(Self::cancelled(
+                zwlr_data_control_source_v1_event_cancelled_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "zwlr_data_control_source_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::send(_) => zwlr_data_control_source_v1_event_send_args::OPCODE,
+            Self::cancelled(_) => zwlr_data_control_source_v1_event_cancelled_args::OPCODE,
+        }
+    }
+}
+/// The enum `zwlr_data_control_source_v1.error`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum zwlr_data_control_source_v1_error {
+    /// Offer sent after wlr_data_control_device.set_selection.
+    invalid_offer = 1,
+}
+impl ::std::convert::TryFrom<u32> for zwlr_data_control_source_v1_error {
+    type Error = crate::Error;
+    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+        match __value {
+            1 => Ok(Self::invalid_offer),
+            _ => Err(crate::Error::InvalidEnumValue {
+                enum_name: "zwlr_data_control_source_v1.error",
+                value: __value,
+            }),
+        }
+    }
+}
+impl ::std::convert::From<zwlr_data_control_source_v1_error> for u32 {
+    fn from(__value: zwlr_data_control_source_v1_error) -> u32 {
+        __value as u32
+    }
+}
+/// Static information about `zwlr_data_control_offer_v1`.
+pub const zwlr_data_control_offer_v1_interface: crate::protocol::InterfaceInfo =
+    crate::protocol::InterfaceInfo {
+        name: "zwlr_data_control_offer_v1",
+        version: 1,
+        requests: &[
+            crate::protocol::MessageInfo {
+                name: "receive",
+                destructor: false,
+                args: &[
+                    crate::protocol::ArgKind::String,
+                    crate::protocol::ArgKind::Fd,
+                ],
+            },
+            crate::protocol::MessageInfo {
+                name: "destroy",
+                destructor: true,
+                args: &[],
+            },
+        ],
+        events: &[crate::protocol::MessageInfo {
+            name: "offer",
+            destructor: false,
+            args: &[crate::protocol::ArgKind::String],
+        }],
+    };
+/// Offer to transfer data.
+///
+/// A wlr_data_control_offer represents a piece of data offered for transfer
+/// by another client (the source client). The offer describes the different
+/// MIME types that the data can be converted to and provides the mechanism
+/// for transferring the data directly from the source client.
+///
+/// A proxy for a `zwlr_data_control_offer_v1` object. The generated code knows this interface up to version 1.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_offer_v1 {
+    id: crate::ObjectId,
+    version: u32,
+    connection: crate::WeakConnection,
+}
+impl crate::protocol::Interface for zwlr_data_control_offer_v1 {
+    const INFO: &'static crate::protocol::InterfaceInfo = &zwlr_data_control_offer_v1_interface;
+}
+impl crate::protocol::Proxy for zwlr_data_control_offer_v1 {
+    fn from_parts(
+        connection: crate::WeakConnection,
+        object_id: crate::ObjectId,
+        version: u32,
+    ) -> Self {
+        Self {
+            id: object_id,
+            version,
+            connection,
+        }
+    }
+    fn id(&self) -> crate::ObjectId {
+        self.id
+    }
+    fn version(&self) -> u32 {
+        self.version
+    }
+    fn connection(&self) -> &crate::WeakConnection {
+        &self.connection
+    }
+}
+/// Handles the events sent to a `zwlr_data_control_offer_v1` object.
+#[allow(unused)]
+pub trait zwlr_data_control_offer_v1_event_handler: crate::protocol::EventHandler {
+    /// Advertise offered MIME type.
+    ///
+    /// Sent immediately after creating the wlr_data_control_offer object.
+    /// One event per offered MIME type.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: offered MIME type
+    fn handle_offer(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+        mime_type: ::std::string::String,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync;
+    /// Called for events with an opcode unknown to the generated code.
+    ///
+    /// As there is no telling which file descriptors such an event carries, the default
+    /// implementation marks the connection as out of sync and fails.
+    fn unknown_event(
+        &self,
+        connection: &crate::Connection,
+        packet: crate::Packet,
+    ) -> impl ::std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync {
+        ::std::future::ready(Err(connection.desynchronize(crate::Error::UnknownEvent {
+            interface: "zwlr_data_control_offer_v1",
+            opcode: packet.opcode(),
+        })))
+    }
+    /// Decodes an event and passes it to the matching handler function.
+    fn handle_event(
+        &self,
+        __connection: &crate::Connection,
+        __packet: crate::Packet,
+    ) -> impl std::future::Future<Output = ::std::result::Result<(), crate::Error>>
+           + ::std::marker::Send
+           + ::std::marker::Sync
+    where
+        Self: Sync,
+    {
+        async {
+            match __packet.opcode() {
+                0 => {
+                    let mut __packet_reader = __packet.read();
+                    let mime_type = __packet_reader.read_str()?;
+                    __packet_reader.finish()?;
+                    self.handle_offer(__connection, __packet, mime_type).await
+                }
+                __other => self.unknown_event(__connection, __packet).await,
+            }
+        }
+    }
+}
+#[allow(unused)]
+impl zwlr_data_control_offer_v1 {
+    /// Request that the data is transferred.
+    ///
+    /// To transfer the offered data, the client issues this request and
+    /// indicates the MIME type it wants to receive. The transfer happens
+    /// through the passed file descriptor (typically created with the pipe
+    /// system call). The source client writes the data in the MIME type
+    /// representation requested and then closes the file descriptor.
+    ///
+    /// The receiving client reads from the read end of the pipe until EOF and
+    /// then closes its end, at which point the transfer is complete.
+    ///
+    /// This request may happen multiple times for different MIME types.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type`: MIME type desired by receiver
+    /// * `fd`: file descriptor for data transfer
+    pub async fn send_receive(
+        &self,
+        mime_type: &str,
+        fd: ::std::os::fd::RawFd,
+    ) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 0);
+        __packet.push_str(mime_type);
+        __packet.push_fd(fd);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+    /// Destroy this offer.
+    ///
+    /// Destroys the data offer object.
+    pub async fn send_destroy(self) -> Result<(), crate::Error> {
+        let __connection = self.connection.upgrade()?;
+        let mut __packet = crate::Packet::new(self.id, 1);
+        __connection.send_packet(&__packet).await?;
+        Ok(())
+    }
+}
+/// Request that the data is transferred.
+///
+/// To transfer the offered data, the client issues this request and
+/// indicates the MIME type it wants to receive. The transfer happens
+/// through the passed file descriptor (typically created with the pipe
+/// system call). The source client writes the data in the MIME type
+/// representation requested and then closes the file descriptor.
+///
+/// The receiving client reads from the read end of the pipe until EOF and
+/// then closes its end, at which point the transfer is complete.
+///
+/// This request may happen multiple times for different MIME types.
+///
+/// The arguments of the `zwlr_data_control_offer_v1.receive` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_offer_v1_request_receive_args {
+    /// MIME type desired by receiver.
+    pub mime_type: ::std::string::String,
+    /// File descriptor for data transfer.
+    pub fd: ::std::os::fd::RawFd,
+}
+impl zwlr_data_control_offer_v1_request_receive_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_offer_v1_request_receive_args, crate::Error> {
+        let mut __reader = __value.read();
+        let mime_type = __reader.read_str()?;
+        let fd = __reader.read_fd()?;
+        __reader.finish()?;
+        Ok(Self { mime_type, fd })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_str(&self.mime_type);
+        __packet.push_fd(self.fd);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_offer_v1_request_receive_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_offer_v1_request_receive_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_offer_v1_request_receive_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Destroy this offer.
+///
+/// Destroys the data offer object.
+///
+/// The arguments of the `zwlr_data_control_offer_v1.destroy` request.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_offer_v1_request_destroy_args {}
+impl zwlr_data_control_offer_v1_request_destroy_args {
+    pub const OPCODE: u16 = 1;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_offer_v1_request_destroy_args, crate::Error> {
+        let mut __reader = __value.read();
+        __reader.finish()?;
+        Ok(Self {})
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_offer_v1_request_destroy_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl
+    ::std::convert::TryFrom<(
+        crate::ObjectId,
+        zwlr_data_control_offer_v1_request_destroy_args,
+    )> for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (
+            crate::ObjectId,
+            zwlr_data_control_offer_v1_request_destroy_args,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// Advertise offered MIME type.
+///
+/// Sent immediately after creating the wlr_data_control_offer object.
+/// One event per offered MIME type.
+///
+/// The arguments of the `zwlr_data_control_offer_v1.offer` event.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct zwlr_data_control_offer_v1_event_offer_args {
+    /// Offered MIME type.
+    pub mime_type: ::std::string::String,
+}
+impl zwlr_data_control_offer_v1_event_offer_args {
+    pub const OPCODE: u16 = 0;
+    /// The version of the interface in which the message has been introduced.
+    pub const SINCE: u32 = 1;
+    /// The version of the interface in which the message has been deprecated, if any.
+    pub const DEPRECATED_SINCE: Option<u32> = None;
+    pub fn try_from_packet(
+        __value: &crate::Packet,
+    ) -> Result<zwlr_data_control_offer_v1_event_offer_args, crate::Error> {
+        let mut __reader = __value.read();
+        let mime_type = __reader.read_str()?;
+        __reader.finish()?;
+        Ok(Self { mime_type })
+    }
+    pub fn try_into_packet(
+        &self,
+        __object_id: crate::ObjectId,
+    ) -> Result<crate::Packet, crate::Error> {
+        let mut __packet = crate::Packet::new(__object_id, Self::OPCODE);
+        __packet.push_str(&self.mime_type);
+        Ok(__packet)
+    }
+}
+impl ::std::convert::TryFrom<crate::Packet> for zwlr_data_control_offer_v1_event_offer_args {
+    type Error = crate::Error;
+    fn try_from(__value: crate::Packet) -> Result<Self, Self::Error> {
+        Self::try_from_packet(&__value)
+    }
+}
+impl ::std::convert::TryFrom<(crate::ObjectId, zwlr_data_control_offer_v1_event_offer_args)>
+    for crate::Packet
+{
+    type Error = crate::Error;
+    fn try_from(
+        __value: (crate::ObjectId, zwlr_data_control_offer_v1_event_offer_args),
+    ) -> Result<Self, Self::Error> {
+        let (__object_id, __structure) = __value;
+        __structure.try_into_packet(__object_id)
+    }
+}
+/// An event sent to a `zwlr_data_control_offer_v1` object.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum zwlr_data_control_offer_v1_event {
+    /// Advertise offered MIME type.
+    offer(zwlr_data_control_offer_v1_event_offer_args),
+}
+impl zwlr_data_control_offer_v1_event {
+    /// Decodes an event according to its opcode.
+    ///
+    /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+    pub fn parse(__packet: &crate::Packet) -> Result<Self, crate::Error> {
+        match __packet.opcode() {
+            0 => Ok(Self::offer(
+                zwlr_data_control_offer_v1_event_offer_args::try_from_packet(__packet)?,
+            )),
+            __opcode => Err(crate::Error::UnknownEvent {
+                interface: "zwlr_data_control_offer_v1",
+                opcode: __opcode,
+            }),
+        }
+    }
+    pub fn opcode(&self) -> u16 {
+        match self {
+            Self::offer(_) => zwlr_data_control_offer_v1_event_offer_args::OPCODE,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/// A fixed-capacity byte ring buffer.
+///
+/// Data is written directly into the free space (see [`RingBuffer::free_slice_mut`] and
+/// [`RingBuffer::commit`]) and read back from the front.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub(crate) struct RingBuffer {
+    buf: Box<[u8]>,
+    start: usize,
+    len: usize,
+}
+impl RingBuffer {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+        Self {
+            buf: vec![0u8; capacity].into_boxed_slice(),
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the largest contiguous free region of the buffer after the stored data.
+    ///
+    /// The slice may be shorter than the total free space if the free space wraps around.
+    pub fn free_slice_mut(&mut self) -> &mut [u8] {
+        let capacity = self.buf.len();
+        let end = (self.start + self.len) % capacity;
+        if self.len == capacity {
+            &mut []
+        } else if end >= self.start {
+            // free space runs until the end of the buffer (and possibly wraps around)
+            &mut self.buf[end..]
+        } else {
+            // free space runs until the start of the data
+            &mut self.buf[end..self.start]
+        }
+    }
+
+    /// Marks the given number of bytes at the start of [`RingBuffer::free_slice_mut`] as stored.
+    pub fn commit(&mut self, count: usize) {
+        assert!(self.len + count <= self.buf.len());
+        self.len += count;
+    }
+
+    /// Copies stored data, starting at the given offset from the front, into `out`.
+    ///
+    /// Returns `false` (and does not modify `out`) if not enough data is stored.
+    pub fn peek(&self, offset: usize, out: &mut [u8]) -> bool {
+        if offset + out.len() > self.len {
+            return false;
+        }
+
+        let capacity = self.buf.len();
+        let first_pos = (self.start + offset) % capacity;
+        let first_len = out.len().min(capacity - first_pos);
+        out[..first_len].copy_from_slice(&self.buf[first_pos..first_pos+first_len]);
+        let rest_len = out.len() - first_len;
+        out[first_len..].copy_from_slice(&self.buf[..rest_len]);
+        true
+    }
+
+    /// Drops the given number of bytes from the front.
+    pub fn consume(&mut self, count: usize) {
+        assert!(count <= self.len);
+        self.start = (self.start + count) % self.buf.len();
+        self.len -= count;
+        if self.len == 0 {
+            // keep the free space contiguous for as long as possible
+            self.start = 0;
+        }
+    }
+}
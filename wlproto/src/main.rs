@@ -137,6 +137,19 @@ fn process_procedure(proc_elem: Element<'_>) -> Procedure {
     let name = proc_elem.attribute_value("name")
         .expect("<request>/<event> without name=\"...\"")
         .to_owned();
+    let destructor = match proc_elem.attribute_value("type") {
+        None => false,
+        Some("destructor") => true,
+        Some(other) => panic!("<request>/<event> with unknown type=\"{}\"", other),
+    };
+    let since = match proc_elem.attribute_value("since") {
+        None => 1,
+        Some(s) => parse_u32_base_prefix(s)
+            .expect("<request>/<event> with non-u32 since=\"...\""),
+    };
+    let deprecated_since = proc_elem.attribute_value("deprecated-since")
+        .map(|ds| parse_u32_base_prefix(ds)
+            .expect("<request>/<event> with non-u32 deprecated-since=\"...\""));
     let mut short_description = None;
     let mut description = None;
     let mut args = Vec::new();
@@ -159,6 +172,9 @@ fn process_procedure(proc_elem: Element<'_>) -> Procedure {
 
     Procedure {
         name,
+        destructor,
+        since,
+        deprecated_since,
         short_description,
         description,
         args,
@@ -179,6 +195,13 @@ fn process_arg(arg_elem: Element<'_>) -> Arg {
     ).expect("<arg> with unknown type=\"...\"");
     let interface = arg_elem.attribute_value("interface")
         .map(|i| i.to_owned());
+    let allow_null = match arg_elem.attribute_value("allow-null") {
+        None|Some("false") => false,
+        Some("true") => true,
+        Some(other) => panic!("<arg> with unknown allow-null=\"{}\"", other),
+    };
+    let enum_name = arg_elem.attribute_value("enum")
+        .map(|e| e.to_owned());
     let short_description = arg_elem.attribute_value("summary")
         .map(|sd| sd.to_owned());
 
@@ -186,6 +209,8 @@ fn process_arg(arg_elem: Element<'_>) -> Arg {
         name,
         arg_type,
         interface,
+        allow_null,
+        enum_name,
         short_description,
     }
 }
@@ -198,6 +223,11 @@ fn process_enum(enum_elem: Element<'_>) -> Enum {
     let name = enum_elem.attribute_value("name")
         .expect("<enum> without name=\"...\"")
         .to_owned();
+    let bitfield = match enum_elem.attribute_value("bitfield") {
+        None|Some("false") => false,
+        Some("true") => true,
+        Some(other) => panic!("<enum> with unknown bitfield=\"{}\"", other),
+    };
     let mut short_description = None;
     let mut description = None;
     let mut variants = Vec::new();
@@ -220,6 +250,7 @@ fn process_enum(enum_elem: Element<'_>) -> Enum {
 
     Enum {
         name,
+        bitfield,
         short_description,
         description,
         variants,
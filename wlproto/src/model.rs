@@ -18,6 +18,9 @@ pub struct Interface {
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Procedure {
     pub name: String,
+    pub destructor: bool,
+    pub since: u32,
+    pub deprecated_since: Option<u32>,
     pub short_description: Option<String>,
     pub description: Option<String>,
     pub args: Vec<Arg>,
@@ -26,6 +29,7 @@ pub struct Procedure {
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Enum {
     pub name: String,
+    pub bitfield: bool,
     pub short_description: Option<String>,
     pub description: Option<String>,
     pub variants: Vec<EnumVariant>,
@@ -43,6 +47,8 @@ pub struct Arg {
     pub name: String,
     pub arg_type: ArgType,
     pub interface: Option<String>,
+    pub allow_null: bool,
+    pub enum_name: Option<String>,
     pub short_description: Option<String>,
 }
 
@@ -1,7 +1,7 @@
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::quote;
 
-use crate::model::{ArgType, Interface, Protocol};
+use crate::model::{Arg, ArgType, Enum, Interface, Procedure, Protocol};
 
 
 pub struct Tokenizer {
@@ -66,30 +66,88 @@ impl Tokenizer {
 
     fn tokenize_protocol(&self, protocol: &Protocol) -> TokenStream {
         //let protocol_name = Ident::new(&protocol.name, Span::call_site());
+        let namespace_tokens = self.namespace_tokens();
         let interfaces = protocol.interfaces
             .iter()
-            .map(|i| self.tokenize_interface(i));
+            .map(|i| self.tokenize_interface(protocol, i));
+        let interface_info_names = protocol.interfaces
+            .iter()
+            .map(|i| Ident::new(&format!("{}_interface", i.name), Span::call_site()));
+        let interface_infos_doc = tokenize_doc(&[format!("Static information about all interfaces of `{}`.", protocol.name)]);
 
         quote! {
-            #![allow(non_camel_case_types)]
+            #![allow(non_camel_case_types, non_upper_case_globals)]
+
+            #interface_infos_doc
+            pub const INTERFACES: &[& #namespace_tokens protocol::InterfaceInfo] = &[ #( & #interface_info_names , )* ];
 
             #( #interfaces )*
         }
     }
 
-    fn tokenize_interface(&self, interface: &Interface) -> TokenStream {
-        let interface_name_ver = format!("{}_v{}", interface.name, interface.version);
+    fn tokenize_interface(&self, protocol: &Protocol, interface: &Interface) -> TokenStream {
         let namespace_tokens = self.namespace_tokens();
 
+        let interface_info_name = Ident::new(
+            &format!("{}_interface", interface.name),
+            Span::call_site(),
+        );
+        let interface_name_literal = Literal::string(&interface.name);
+        let interface_version_literal = Literal::u32_unsuffixed(interface.version);
+        let request_infos = self.tokenize_message_infos(&interface.requests);
+        let event_infos = self.tokenize_message_infos(&interface.events);
+        let interface_info_doc = tokenize_doc(&[format!("Static information about `{}`.", interface.name)]);
+        let interface_info = quote! {
+            #interface_info_doc
+            pub const #interface_info_name : #namespace_tokens protocol::InterfaceInfo = #namespace_tokens protocol::InterfaceInfo {
+                name: #interface_name_literal,
+                version: #interface_version_literal,
+                requests: &[ #( #request_infos , )* ],
+                events: &[ #( #event_infos , )* ],
+            };
+        };
+
+        let proxy_name = Ident::new(&interface.name, Span::call_site());
+        let mut proxy_doc_lines = doc_lines(interface.short_description.as_deref(), interface.description.as_deref());
+        push_doc_paragraph(&mut proxy_doc_lines, &format!(
+            "A proxy for a `{}` object. The generated code knows this interface up to version {}.",
+            interface.name, interface.version,
+        ));
+        let proxy_doc = tokenize_doc(&proxy_doc_lines);
+        let proxy_type = quote! {
+            #proxy_doc
+            #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct #proxy_name {
+                id: #namespace_tokens ObjectId,
+                version: u32,
+                connection: #namespace_tokens WeakConnection,
+            }
+            impl #namespace_tokens protocol::Interface for #proxy_name {
+                const INFO: &'static #namespace_tokens protocol::InterfaceInfo = & #interface_info_name;
+            }
+            impl #namespace_tokens protocol::Proxy for #proxy_name {
+                fn from_parts(connection: #namespace_tokens WeakConnection, object_id: #namespace_tokens ObjectId, version: u32) -> Self {
+                    Self {
+                        id: object_id,
+                        version,
+                        connection,
+                    }
+                }
+
+                fn id(&self) -> #namespace_tokens ObjectId { self.id }
+                fn version(&self) -> u32 { self.version }
+                fn connection(&self) -> & #namespace_tokens WeakConnection { &self.connection }
+            }
+        };
+
         let event_handlers = if interface.events.len() > 0 {
             let event_handler_trait_name = Ident::new(
-                &format!("{}_event_handler", interface_name_ver),
+                &format!("{}_event_handler", interface.name),
                 Span::call_site(),
             );
 
             let return_future_tokens_before = self.return_future_tokens_before();
             let return_future_tokens_after = self.return_future_tokens_after();
-            let empty_value_future_tokens = self.value_future_tokens(quote! { () });
             let dot_await_tokens = self.dot_await_tokens();
 
             let mut handle_func_prototypes = Vec::with_capacity(interface.events.len());
@@ -110,8 +168,10 @@ impl Tokenizer {
 
                     arg_names.push(quote! { #arg_name });
 
-                    let arg_type = self.tokenize_incoming_arg_type(&arg.arg_type, arg.interface.is_some());
-                    let arg_type_read_func = self.tokenize_arg_type_read_func(&arg.arg_type, arg.interface.is_some());
+                    let enum_type = self.tokenize_arg_enum_type(protocol, interface, arg);
+                    let arg_type = enum_type.clone()
+                        .unwrap_or_else(|| self.tokenize_incoming_arg_type(arg));
+                    let arg_type_read_func = self.tokenize_arg_type_read_func(arg);
 
                     args.push(quote! {
                         #arg_name : #arg_type
@@ -120,16 +180,26 @@ impl Tokenizer {
                     arg_decoders.push(quote! {
                         let #arg_name = __packet_reader . #arg_type_read_func ()?;
                     });
+                    if let Some(enum_type) = &enum_type {
+                        arg_decoders.push(self.tokenize_enum_from_raw(&arg.arg_type, enum_type, &arg_name));
+                    }
                 }
 
+                let mut handle_func_doc_lines = procedure_doc_lines(event);
+                push_doc_arguments(
+                    &mut handle_func_doc_lines,
+                    event.args.iter().map(|a| (a.name.clone(), a.short_description.as_deref())),
+                );
+                let handle_func_doc = tokenize_doc(&handle_func_doc_lines);
                 handle_func_prototypes.push(quote! {
+                    #handle_func_doc
                     fn #handle_func_name (
                         &self,
                         connection: & #namespace_tokens Connection,
                         packet: #namespace_tokens Packet,
                         #( #args , )*
                     )
-                        -> #return_future_tokens_before () #return_future_tokens_after ;
+                        -> #return_future_tokens_before ::std::result::Result<(), #namespace_tokens Error> #return_future_tokens_after ;
                 });
                 match_variants.push(quote! {
                     #event_index_literal => {
@@ -140,14 +210,17 @@ impl Tokenizer {
                             __connection,
                             __packet,
                             #( #arg_names , )*
-                        ) #dot_await_tokens ;
-                        Ok(())
+                        ) #dot_await_tokens
                     }
                 });
             }
 
+            let handle_event_doc = tokenize_doc(&[
+                "Decodes an event and passes it to the matching handler function.".to_owned(),
+            ]);
             let handle_event_func = if self.asynchronous {
                 quote! {
+                    #handle_event_doc
                     fn handle_event(
                         &self,
                         __connection: & #namespace_tokens Connection,
@@ -157,16 +230,14 @@ impl Tokenizer {
                         async {
                             match __packet.opcode() {
                                 #( #match_variants , )*
-                                __other => {
-                                    self.unknown_event(__connection, __packet).await;
-                                    Ok(())
-                                },
+                                __other => self.unknown_event(__connection, __packet).await,
                             }
                         }
                     }
                 }
             } else {
                 quote! {
+                    #handle_event_doc
                     fn handle_event(
                         &self,
                         __connection: & #namespace_tokens Connection,
@@ -175,27 +246,36 @@ impl Tokenizer {
                         async {
                             match __packet.opcode() {
                                 #( #match_variants , )*
-                                __other => {
-                                    self.unknown_event(__connection, __packet);
-                                    Ok(())
-                                },
+                                __other => self.unknown_event(__connection, __packet),
                             }
                         }
                     }
                 }
             };
 
+            let event_handler_doc = tokenize_doc(&[format!("Handles the events sent to a `{}` object.", interface.name)]);
+            let unknown_event_doc = tokenize_doc(&[
+                "Called for events with an opcode unknown to the generated code.".to_owned(),
+                String::new(),
+                "As there is no telling which file descriptors such an event carries, the default".to_owned(),
+                "implementation marks the connection as out of sync and fails.".to_owned(),
+            ]);
+            let unknown_event_value = self.value_future_tokens(quote! {
+                Err(connection.desynchronize(#namespace_tokens Error::UnknownEvent {
+                    interface: #interface_name_literal,
+                    opcode: packet.opcode(),
+                }))
+            });
             quote! {
+                #event_handler_doc
                 #[allow(unused)]
                 pub trait #event_handler_trait_name : #namespace_tokens protocol::EventHandler {
                     #( #handle_func_prototypes )*
 
+                    #unknown_event_doc
                     fn unknown_event(&self, connection: & #namespace_tokens Connection, packet: #namespace_tokens Packet)
-                            -> #return_future_tokens_before () #return_future_tokens_after {
-                        // do nothing by default
-                        let _ = connection;
-                        let _ = packet;
-                        #empty_value_future_tokens
+                            -> #return_future_tokens_before ::std::result::Result<(), #namespace_tokens Error> #return_future_tokens_after {
+                        #unknown_event_value
                     }
 
                     #handle_event_func
@@ -205,61 +285,140 @@ impl Tokenizer {
             TokenStream::new()
         };
 
-        let request_proxies = if interface.requests.len() > 0 {
-            let request_proxy_name = Ident::new(
-                &format!("{}_request_proxy", interface_name_ver),
-                Span::call_site(),
-            );
-
+        let request_funcs = if interface.requests.len() > 0 {
             let mut proxy_funcs = Vec::with_capacity(interface.requests.len());
             for (req_index, req) in interface.requests.iter().enumerate() {
                 let req_name = Ident::new(&format!("send_{}", req.name), Span::call_site());
                 let req_index_literal = Literal::usize_unsuffixed(req_index);
+                let version_check = self.tokenize_request_version_check(interface, req);
+
+                // destructors consume the proxy
+                let self_tokens = if req.destructor {
+                    quote! { self }
+                } else {
+                    quote! { &self }
+                };
+
+                // a new_id argument is allocated here and returned as a proxy
+                let mut generics = TokenStream::new();
+                let mut return_type = quote! { () };
+                let mut new_object = TokenStream::new();
+                let mut return_value = quote! { () };
+                let mut has_new_id = false;
+                let mut arg_docs = Vec::with_capacity(req.args.len());
+                let mut return_doc = None;
 
                 let mut args = Vec::with_capacity(req.args.len());
                 let mut arg_write_func_calls = Vec::with_capacity(req.args.len());
                 for arg in &req.args {
                     let arg_name = Ident::new(&arg.name, Span::call_site());
 
-                    let arg_type = self.tokenize_outgoing_arg_type(&arg.arg_type, arg.interface.is_some());
-                    let arg_write_func = self.tokenize_arg_type_write_func(&arg.arg_type, arg.interface.is_some());
-                    let arg_write_ref = self.tokenize_arg_type_write_reference(&arg.arg_type, arg.interface.is_some());
+                    if arg.arg_type == ArgType::NewId {
+                        if has_new_id {
+                            panic!("request {}.{} has multiple new_id arguments", interface.name, req.name);
+                        }
+                        has_new_id = true;
+                        match &arg.interface {
+                            Some(child_name) => {
+                                let child = protocol.interfaces.iter()
+                                    .find(|i| &i.name == child_name)
+                                    .unwrap_or_else(|| panic!(
+                                        "request {}.{} creates {}, which is not part of this protocol",
+                                        interface.name, req.name, child_name,
+                                    ));
+                                let child_proxy_name = Ident::new(&child.name, Span::call_site());
+                                let child_info_name = Ident::new(&format!("{}_interface", child.name), Span::call_site());
+
+                                return_type = quote! { #child_proxy_name };
+                                return_doc = Some(format!("Returns the new `{}` object.", child.name));
+                                new_object = quote! {
+                                    let __new_id = __connection.new_object(& #child_info_name, self.version);
+                                };
+                                arg_write_func_calls.push(quote! {
+                                    __packet.push_new_id_known_interface( #namespace_tokens NewObjectId(__new_id) );
+                                });
+                                return_value = quote! {
+                                    #child_proxy_name {
+                                        id: __new_id,
+                                        version: self.version,
+                                        connection: self.connection.clone(),
+                                    }
+                                };
+                            },
+                            None => {
+                                // the caller chooses the interface and version
+                                generics = quote! { < __P: #namespace_tokens protocol::Proxy > };
+                                return_type = quote! { __P };
+                                return_doc = Some("Returns the new object, which implements the interface of `__P`.".to_owned());
+                                args.push(quote! { __version: u32 });
+                                arg_docs.push(("__version".to_owned(), Some("the version at which to create the new object")));
+                                new_object = quote! {
+                                    let __info = <__P as #namespace_tokens protocol::Interface>::INFO;
+                                    let __new_id = __connection.new_object(__info, __version);
+                                };
+                                arg_write_func_calls.push(quote! {
+                                    __packet.push_new_id_unknown_interface(& #namespace_tokens NewObject {
+                                        object_id: __new_id,
+                                        interface: __info.name.to_owned(),
+                                        interface_version: __version,
+                                    });
+                                });
+                                return_value = quote! {
+                                    <__P as #namespace_tokens protocol::Proxy>::from_parts(self.connection.clone(), __new_id, __version)
+                                };
+                            },
+                        }
+                        continue;
+                    }
+
+                    let enum_type = self.tokenize_arg_enum_type(protocol, interface, arg);
+                    let arg_write_func = self.tokenize_arg_type_write_func(arg);
+                    let (arg_type, arg_write_value) = match &enum_type {
+                        Some(enum_type) => (
+                            enum_type.clone(),
+                            self.tokenize_enum_to_raw(&arg.arg_type, quote! { #arg_name }),
+                        ),
+                        None => (
+                            self.tokenize_outgoing_arg_type(arg),
+                            self.tokenize_arg_write_value(arg, quote! { #arg_name }, true),
+                        ),
+                    };
 
                     args.push(quote! {
                         #arg_name : #arg_type
                     });
+                    arg_docs.push((arg.name.clone(), arg.short_description.as_deref()));
                     arg_write_func_calls.push(quote! {
-                        __packet . #arg_write_func ( #arg_write_ref #arg_name );
+                        __packet . #arg_write_func ( #arg_write_value );
                     });
                 }
 
+                let mut proxy_func_doc_lines = procedure_doc_lines(req);
+                push_doc_arguments(&mut proxy_func_doc_lines, arg_docs.into_iter());
+                if let Some(return_doc) = return_doc {
+                    push_doc_paragraph(&mut proxy_func_doc_lines, &return_doc);
+                }
+                let proxy_func_doc = tokenize_doc(&proxy_func_doc_lines);
                 proxy_funcs.push(quote! {
-                    pub async fn #req_name (&self, __object_id: #namespace_tokens ObjectId #( , #args )* ) -> Result<(), #namespace_tokens Error> {
+                    #proxy_func_doc
+                    pub async fn #req_name #generics ( #self_tokens #( , #args )* ) -> Result< #return_type , #namespace_tokens Error> {
+                        #version_check
+                        let __connection = self.connection.upgrade()?;
+                        #new_object
                         let mut __packet = #namespace_tokens Packet::new(
-                            __object_id,
+                            self.id,
                             #req_index_literal ,
                         );
                         #( #arg_write_func_calls )*
-                        self.connection.send_packet(&__packet).await
+                        __connection.send_packet(&__packet).await?;
+                        Ok( #return_value )
                     }
                 })
             }
 
             quote! {
                 #[allow(unused)]
-                pub struct #request_proxy_name <'a> {
-                    connection: &'a #namespace_tokens Connection,
-                }
-                #[allow(unused)]
-                impl<'a> #request_proxy_name <'a> {
-                    pub fn new(
-                        connection: &'a #namespace_tokens Connection,
-                    ) -> Self {
-                        Self {
-                            connection,
-                        }
-                    }
-
+                impl #proxy_name {
                     #( #proxy_funcs )*
                 }
             }
@@ -278,10 +437,18 @@ impl Tokenizer {
             .map(|(i, e)| (i, "event", e));
         for (i, kind, procedure) in request_iterator.chain(event_iterator) {
             let arg_struct_name = Ident::new(
-                &format!("{}_{}_{}_args", interface_name_ver, kind, procedure.name),
+                &format!("{}_{}_{}_args", interface.name, kind, procedure.name),
                 Span::call_site(),
             );
             let opcode = Literal::usize_unsuffixed(i);
+            let since = Literal::u32_unsuffixed(procedure.since);
+            let deprecated_since = match procedure.deprecated_since {
+                Some(ds) => {
+                    let ds_literal = Literal::u32_unsuffixed(ds);
+                    quote! { Some( #ds_literal ) }
+                },
+                None => quote! { None },
+            };
 
             let mut fields = Vec::with_capacity(procedure.args.len());
             let mut field_reads = Vec::with_capacity(procedure.args.len());
@@ -293,22 +460,52 @@ impl Tokenizer {
                     Span::call_site(),
                 );
                 field_names.push(field_name.clone());
-                let field_type = self.tokenize_incoming_arg_type(&arg.arg_type, arg.interface.is_some());
-                fields.push(quote! { #field_name : #field_type });
-                let read_name = self.tokenize_arg_type_read_func(&arg.arg_type, arg.interface.is_some());
+                let enum_type = self.tokenize_arg_enum_type(protocol, interface, arg);
+                let field_type = enum_type.clone()
+                    .unwrap_or_else(|| self.tokenize_incoming_arg_type(arg));
+                let field_doc = match &arg.short_description {
+                    Some(summary) => tokenize_doc(&[summary_sentence(summary)]),
+                    None => TokenStream::new(),
+                };
+                fields.push(quote! { #field_doc pub #field_name : #field_type });
+                let read_name = self.tokenize_arg_type_read_func(arg);
                 field_reads.push(quote! { let #field_name = __reader. #read_name ()?; });
-                let write_name = self.tokenize_arg_type_write_func(&arg.arg_type, arg.interface.is_some());
-                let write_reference = self.tokenize_arg_type_write_reference(&arg.arg_type, arg.interface.is_some());
-                field_writes.push(quote! { __packet . #write_name ( #write_reference self. #field_name ); });
+                let write_name = self.tokenize_arg_type_write_func(arg);
+                match &enum_type {
+                    Some(enum_type) => {
+                        field_reads.push(self.tokenize_enum_from_raw(&arg.arg_type, enum_type, &field_name));
+                        let write_value = self.tokenize_enum_to_raw(&arg.arg_type, quote! { self. #field_name });
+                        field_writes.push(quote! { __packet . #write_name ( #write_value ); });
+                    },
+                    None => {
+                        let write_value = self.tokenize_arg_write_value(arg, quote! { self. #field_name }, false);
+                        field_writes.push(quote! { __packet . #write_name ( #write_value ); });
+                    },
+                }
             }
 
+            let mut arg_struct_doc_lines = procedure_doc_lines(procedure);
+            push_doc_paragraph(&mut arg_struct_doc_lines, &format!(
+                "The arguments of the `{}.{}` {}.",
+                interface.name, procedure.name, kind,
+            ));
+            let arg_struct_doc = tokenize_doc(&arg_struct_doc_lines);
+
             arg_structs.push(quote! {
+                #arg_struct_doc
+                #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
                 pub struct #arg_struct_name {
-                    #( pub #fields , )*
+                    #( #fields , )*
                 }
                 impl #arg_struct_name {
                     pub const OPCODE: u16 = #opcode ;
 
+                    /// The version of the interface in which the message has been introduced.
+                    pub const SINCE: u32 = #since ;
+
+                    /// The version of the interface in which the message has been deprecated, if any.
+                    pub const DEPRECATED_SINCE: Option<u32> = #deprecated_since ;
+
                     pub fn try_from_packet(__value: & #namespace_tokens Packet) -> Result< #arg_struct_name , #namespace_tokens Error > {
                         let mut __reader = __value.read();
                         #( #field_reads )*
@@ -340,22 +537,349 @@ impl Tokenizer {
             });
         }
 
+        let event_enum = self.tokenize_event_enum(interface);
+
+        let enums = interface.enums
+            .iter()
+            .map(|e| self.tokenize_enum(interface, e));
+
         quote! {
+            #interface_info
+            #proxy_type
             #event_handlers
-            #request_proxies
+            #request_funcs
             #( #arg_structs )*
+            #event_enum
+            #( #enums )*
         }
     }
 
-    fn tokenize_incoming_arg_type(&self, arg_type: &ArgType, known_interface: bool) -> TokenStream {
+    /// Returns an enum of the decoded events of an interface.
+    fn tokenize_event_enum(&self, interface: &Interface) -> TokenStream {
+        if interface.events.is_empty() {
+            return TokenStream::new();
+        }
+
         let namespace_tokens = self.namespace_tokens();
+        let event_enum_name = Ident::new(&format!("{}_event", interface.name), Span::call_site());
+        let interface_name_literal = Literal::string(&interface.name);
+
+        let mut variants = Vec::with_capacity(interface.events.len());
+        let mut parse_arms = Vec::with_capacity(interface.events.len());
+        let mut opcode_arms = Vec::with_capacity(interface.events.len());
+        for (event_index, event) in interface.events.iter().enumerate() {
+            let variant_name = enum_variant_ident(&event.name);
+            let arg_struct_name = Ident::new(
+                &format!("{}_event_{}_args", interface.name, event.name),
+                Span::call_site(),
+            );
+            let event_index_literal = Literal::usize_unsuffixed(event_index);
+            let variant_doc = match &event.short_description {
+                Some(summary) => tokenize_doc(&[summary_sentence(summary)]),
+                None => TokenStream::new(),
+            };
+
+            variants.push(quote! {
+                #variant_doc
+                #variant_name ( #arg_struct_name )
+            });
+            parse_arms.push(quote! {
+                #event_index_literal => Ok(Self:: #variant_name ( #arg_struct_name ::try_from_packet(__packet)? ))
+            });
+            opcode_arms.push(quote! {
+                Self:: #variant_name (_) => #arg_struct_name ::OPCODE
+            });
+        }
+
+        let event_enum_doc = tokenize_doc(&[format!("An event sent to a `{}` object.", interface.name)]);
+        quote! {
+            #event_enum_doc
+            #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub enum #event_enum_name {
+                #( #variants , )*
+            }
+            impl #event_enum_name {
+                /// Decodes an event according to its opcode.
+                ///
+                /// Fails with `Error::UnknownEvent` if the opcode is unknown to the generated code.
+                pub fn parse(__packet: & #namespace_tokens Packet) -> Result<Self, #namespace_tokens Error> {
+                    match __packet.opcode() {
+                        #( #parse_arms , )*
+                        __opcode => Err(#namespace_tokens Error::UnknownEvent {
+                            interface: #interface_name_literal ,
+                            opcode: __opcode,
+                        }),
+                    }
+                }
+
+                pub fn opcode(&self) -> u16 {
+                    match self {
+                        #( #opcode_arms , )*
+                    }
+                }
+            }
+        }
+    }
+
+    fn tokenize_enum(&self, interface: &Interface, enumeration: &Enum) -> TokenStream {
+        let namespace_tokens = self.namespace_tokens();
+        let enum_name = Ident::new(&enum_type_name(interface, &enumeration.name), Span::call_site());
+        let full_name_literal = Literal::string(&format!("{}.{}", interface.name, enumeration.name));
+
+        let variant_names: Vec<Ident> = enumeration.variants
+            .iter()
+            .map(|v| enum_variant_ident(&v.name))
+            .collect();
+        let variant_values: Vec<Literal> = enumeration.variants
+            .iter()
+            .map(|v| Literal::u32_unsuffixed(v.value))
+            .collect();
+        let variant_docs: Vec<TokenStream> = enumeration.variants
+            .iter()
+            .map(|v| match &v.short_description {
+                Some(summary) => tokenize_doc(&[summary_sentence(summary)]),
+                None => TokenStream::new(),
+            })
+            .collect();
+
+        let mut enum_doc_lines = doc_lines(enumeration.short_description.as_deref(), enumeration.description.as_deref());
+        push_doc_paragraph(&mut enum_doc_lines, &format!(
+            "The {} `{}.{}`.",
+            if enumeration.bitfield { "bitfield" } else { "enum" },
+            interface.name, enumeration.name,
+        ));
+        let enum_doc = tokenize_doc(&enum_doc_lines);
+
+        if enumeration.bitfield {
+            let variant_name_literals = enumeration.variants
+                .iter()
+                .map(|v| Literal::string(&v.name));
+            let all_bits = enumeration.variants
+                .iter()
+                .fold(0, |bits, v| bits | v.value);
+            let all_bits_literal = Literal::u32_unsuffixed(all_bits);
+            quote! {
+                #enum_doc
+                #[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+                pub struct #enum_name(u32);
+                impl #enum_name {
+                    #( #variant_docs pub const #variant_names : Self = Self( #variant_values ); )*
+
+                    const FLAGS: &'static [(&'static str, u32)] = &[ #( ( #variant_name_literals , #variant_values ) , )* ];
+
+                    /// No flags.
+                    pub const fn empty() -> Self { Self(0) }
+
+                    /// All flags known to the generated code.
+                    pub const fn all() -> Self { Self( #all_bits_literal ) }
+
+                    pub const fn bits(self) -> u32 { self.0 }
+                    pub const fn is_empty(self) -> bool { self.0 == 0 }
+
+                    /// Whether all flags of `other` are set.
+                    pub const fn contains(self, other: Self) -> bool { self.0 & other.0 == other.0 }
+
+                    /// Whether any flag of `other` is set.
+                    pub const fn intersects(self, other: Self) -> bool { self.0 & other.0 != 0 }
+                }
+                impl ::std::ops::BitOr for #enum_name {
+                    type Output = Self;
+                    fn bitor(self, rhs: Self) -> Self { Self(self.0 | rhs.0) }
+                }
+                impl ::std::ops::BitOrAssign for #enum_name {
+                    fn bitor_assign(&mut self, rhs: Self) { self.0 |= rhs.0; }
+                }
+                impl ::std::ops::BitAnd for #enum_name {
+                    type Output = Self;
+                    fn bitand(self, rhs: Self) -> Self { Self(self.0 & rhs.0) }
+                }
+                impl ::std::ops::BitAndAssign for #enum_name {
+                    fn bitand_assign(&mut self, rhs: Self) { self.0 &= rhs.0; }
+                }
+                impl ::std::fmt::Debug for #enum_name {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        #namespace_tokens protocol::fmt_bitfield(f, Self::FLAGS, self.0)
+                    }
+                }
+                impl ::std::convert::TryFrom<u32> for #enum_name {
+                    type Error = #namespace_tokens Error;
+                    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+                        if __value & !Self::all().0 == 0 {
+                            Ok(Self(__value))
+                        } else {
+                            Err(#namespace_tokens Error::InvalidEnumValue {
+                                enum_name: #full_name_literal ,
+                                value: __value,
+                            })
+                        }
+                    }
+                }
+                impl ::std::convert::From< #enum_name > for u32 {
+                    fn from(__value: #enum_name ) -> u32 { __value.0 }
+                }
+            }
+        } else {
+            quote! {
+                #enum_doc
+                #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+                #[repr(u32)]
+                pub enum #enum_name {
+                    #( #variant_docs #variant_names = #variant_values , )*
+                }
+                impl ::std::convert::TryFrom<u32> for #enum_name {
+                    type Error = #namespace_tokens Error;
+                    fn try_from(__value: u32) -> Result<Self, Self::Error> {
+                        match __value {
+                            #( #variant_values => Ok(Self:: #variant_names ), )*
+                            _ => Err(#namespace_tokens Error::InvalidEnumValue {
+                                enum_name: #full_name_literal ,
+                                value: __value,
+                            }),
+                        }
+                    }
+                }
+                impl ::std::convert::From< #enum_name > for u32 {
+                    fn from(__value: #enum_name ) -> u32 { __value as u32 }
+                }
+            }
+        }
+    }
+
+    /// Returns the type of an argument that takes the values of an enum.
+    ///
+    /// Enums of interfaces from other protocols are not resolved; such arguments keep their integer
+    /// type.
+    fn tokenize_arg_enum_type(&self, protocol: &Protocol, interface: &Interface, arg: &Arg) -> Option<TokenStream> {
+        let enum_ref = arg.enum_name.as_ref()?;
+        let (enum_interface, enum_name) = match enum_ref.split_once('.') {
+            Some((interface_name, enum_name)) => {
+                let enum_interface = protocol.interfaces.iter()
+                    .find(|i| i.name == interface_name)?;
+                (enum_interface, enum_name)
+            },
+            None => (interface, enum_ref.as_str()),
+        };
+        if !enum_interface.enums.iter().any(|e| e.name == enum_name) {
+            panic!("argument {} of {} refers to unknown enum {}", arg.name, interface.name, enum_ref);
+        }
+        let enum_type = Ident::new(&enum_type_name(enum_interface, enum_name), Span::call_site());
+        Some(quote! { #enum_type })
+    }
+
+    fn tokenize_enum_from_raw(&self, arg_type: &ArgType, enum_type: &TokenStream, arg_name: &Ident) -> TokenStream {
+        let raw_value = match arg_type {
+            ArgType::Uint => quote! { #arg_name },
+            ArgType::Int => quote! { #arg_name as u32 },
+            other => panic!("enum argument {} has non-integer type {:?}", arg_name, other),
+        };
+        quote! {
+            let #arg_name = < #enum_type as ::std::convert::TryFrom<u32> >::try_from( #raw_value )?;
+        }
+    }
+
+    fn tokenize_enum_to_raw(&self, arg_type: &ArgType, value: TokenStream) -> TokenStream {
         match arg_type {
+            ArgType::Uint => quote! { u32::from( #value ) },
+            ArgType::Int => quote! { u32::from( #value ) as i32 },
+            other => panic!("enum argument has non-integer type {:?}", other),
+        }
+    }
+
+    /// Returns the code with which a proxy refuses to send a request that does not exist at the
+    /// version of its object.
+    fn tokenize_request_version_check(&self, interface: &Interface, request: &Procedure) -> TokenStream {
+        let namespace_tokens = self.namespace_tokens();
+        let interface_name_literal = Literal::string(&interface.name);
+        let request_name_literal = Literal::string(&request.name);
+
+        let mut check = TokenStream::new();
+        if request.since > 1 {
+            let since_literal = Literal::u32_unsuffixed(request.since);
+            check.extend(quote! {
+                if self.version < #since_literal {
+                    return Err(#namespace_tokens Error::RequestTooNew {
+                        interface: #interface_name_literal ,
+                        request: #request_name_literal ,
+                        since: #since_literal ,
+                        version: self.version,
+                    });
+                }
+            });
+        }
+        if let Some(deprecated_since) = request.deprecated_since {
+            let deprecated_since_literal = Literal::u32_unsuffixed(deprecated_since);
+            check.extend(quote! {
+                if self.version >= #deprecated_since_literal {
+                    return Err(#namespace_tokens Error::RequestDeprecated {
+                        interface: #interface_name_literal ,
+                        request: #request_name_literal ,
+                        deprecated_since: #deprecated_since_literal ,
+                        version: self.version,
+                    });
+                }
+            });
+        }
+        check
+    }
+
+    fn tokenize_message_infos(&self, procedures: &[Procedure]) -> Vec<TokenStream> {
+        let namespace_tokens = self.namespace_tokens();
+        procedures.iter()
+            .map(|procedure| {
+                let name_literal = Literal::string(&procedure.name);
+                let destructor = procedure.destructor;
+                let arg_kinds = procedure.args.iter()
+                    .map(|arg| self.tokenize_arg_kind(arg));
+                quote! {
+                    #namespace_tokens protocol::MessageInfo {
+                        name: #name_literal,
+                        destructor: #destructor,
+                        args: &[ #( #arg_kinds , )* ],
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn tokenize_arg_kind(&self, arg: &Arg) -> TokenStream {
+        let namespace_tokens = self.namespace_tokens();
+        match arg.arg_type {
+            ArgType::Uint => quote! { #namespace_tokens protocol::ArgKind::Uint },
+            ArgType::Int => quote! { #namespace_tokens protocol::ArgKind::Int },
+            ArgType::Fixed => quote! { #namespace_tokens protocol::ArgKind::Fixed },
+            ArgType::String => quote! { #namespace_tokens protocol::ArgKind::String },
+            ArgType::ObjectId => quote! { #namespace_tokens protocol::ArgKind::Object },
+            ArgType::NewId => match &arg.interface {
+                Some(interface) => {
+                    let interface_literal = Literal::string(interface);
+                    quote! { #namespace_tokens protocol::ArgKind::NewId { interface: Some(#interface_literal) } }
+                },
+                None => quote! { #namespace_tokens protocol::ArgKind::NewId { interface: None } },
+            },
+            ArgType::Array => quote! { #namespace_tokens protocol::ArgKind::Array },
+            ArgType::FileDescriptor => quote! { #namespace_tokens protocol::ArgKind::Fd },
+        }
+    }
+
+    fn tokenize_incoming_arg_type(&self, arg: &Arg) -> TokenStream {
+        let namespace_tokens = self.namespace_tokens();
+        match arg.arg_type {
             ArgType::Uint => quote! { u32 },
             ArgType::Int => quote! { i32 },
             ArgType::Fixed => quote! { #namespace_tokens Fixed },
-            ArgType::String => quote! { ::std::string::String },
-            ArgType::ObjectId => quote! { ::std::option::Option< #namespace_tokens ObjectId > },
-            ArgType::NewId => if known_interface {
+            ArgType::String => if arg.allow_null {
+                quote! { ::std::option::Option< ::std::string::String > }
+            } else {
+                quote! { ::std::string::String }
+            },
+            ArgType::ObjectId => if arg.allow_null {
+                quote! { ::std::option::Option< #namespace_tokens ObjectId > }
+            } else {
+                quote! { #namespace_tokens ObjectId }
+            },
+            ArgType::NewId => if arg.allow_null {
+                panic!("nullable new_id argument {} is not supported", arg.name);
+            } else if arg.interface.is_some() {
                 quote! { #namespace_tokens NewObjectId }
             } else {
                 quote! { #namespace_tokens NewObject }
@@ -365,22 +889,34 @@ impl Tokenizer {
         }
     }
 
-    fn tokenize_outgoing_arg_type(&self, arg_type: &ArgType, known_interface: bool) -> TokenStream {
-        match arg_type {
-            ArgType::String => quote! { &str },
+    fn tokenize_outgoing_arg_type(&self, arg: &Arg) -> TokenStream {
+        match arg.arg_type {
+            ArgType::String => if arg.allow_null {
+                quote! { ::std::option::Option<&str> }
+            } else {
+                quote! { &str }
+            },
             ArgType::Array => quote! { &[u8] },
-            other => self.tokenize_incoming_arg_type(other, known_interface),
+            _ => self.tokenize_incoming_arg_type(arg),
         }
     }
 
-    fn tokenize_arg_type_read_func(&self, arg_type: &ArgType, known_interface: bool) -> TokenStream {
-        match arg_type {
+    fn tokenize_arg_type_read_func(&self, arg: &Arg) -> TokenStream {
+        match arg.arg_type {
             ArgType::Uint => quote! { read_uint },
             ArgType::Int => quote! { read_int },
             ArgType::Fixed => quote! { read_fixed },
-            ArgType::String => quote! { read_str },
-            ArgType::ObjectId => quote! { read_object },
-            ArgType::NewId => if known_interface {
+            ArgType::String => if arg.allow_null {
+                quote! { read_nullable_str }
+            } else {
+                quote! { read_str }
+            },
+            ArgType::ObjectId => if arg.allow_null {
+                quote! { read_nullable_object }
+            } else {
+                quote! { read_object }
+            },
+            ArgType::NewId => if arg.interface.is_some() {
                 quote! { read_new_id_known_interface }
             } else {
                 quote! { read_new_id_unknown_interface }
@@ -390,14 +926,22 @@ impl Tokenizer {
         }
     }
 
-    fn tokenize_arg_type_write_func(&self, arg_type: &ArgType, known_interface: bool) -> TokenStream {
-        match arg_type {
+    fn tokenize_arg_type_write_func(&self, arg: &Arg) -> TokenStream {
+        match arg.arg_type {
             ArgType::Uint => quote! { push_uint },
             ArgType::Int => quote! { push_int },
             ArgType::Fixed => quote! { push_fixed },
-            ArgType::String => quote! { push_str },
-            ArgType::ObjectId => quote! { push_object },
-            ArgType::NewId => if known_interface {
+            ArgType::String => if arg.allow_null {
+                quote! { push_nullable_str }
+            } else {
+                quote! { push_str }
+            },
+            ArgType::ObjectId => if arg.allow_null {
+                quote! { push_nullable_object }
+            } else {
+                quote! { push_object }
+            },
+            ArgType::NewId => if arg.interface.is_some() {
                 quote! { push_new_id_known_interface }
             } else {
                 quote! { push_new_id_unknown_interface }
@@ -407,16 +951,162 @@ impl Tokenizer {
         }
     }
 
-    fn tokenize_arg_type_write_reference(&self, arg_type: &ArgType, known_interface: bool) -> TokenStream {
-        match arg_type {
+    /// Returns the expression passed to the write function of an argument, given its value of the
+    /// outgoing or the incoming type.
+    fn tokenize_arg_write_value(&self, arg: &Arg, value: TokenStream, outgoing: bool) -> TokenStream {
+        match arg.arg_type {
             ArgType::Uint|ArgType::Int|ArgType::Fixed|ArgType::ObjectId
-                |ArgType::FileDescriptor => quote! { },
-            ArgType::String|ArgType::Array => quote! { & },
-            ArgType::NewId => if known_interface {
-                quote! { }
+                |ArgType::FileDescriptor => value,
+            ArgType::String|ArgType::Array if outgoing => value,
+            ArgType::String => if arg.allow_null {
+                quote! { #value .as_deref() }
             } else {
-                quote! { & }
+                quote! { & #value }
             },
+            ArgType::Array => quote! { & #value },
+            ArgType::NewId => if arg.interface.is_some() {
+                value
+            } else {
+                quote! { & #value }
+            },
+        }
+    }
+}
+
+
+fn enum_type_name(interface: &Interface, enum_name: &str) -> String {
+    format!("{}_{}", interface.name, enum_name)
+}
+
+/// Turns the name of an enum entry into an identifier.
+///
+/// Entries such as `wl_output.transform.90` start with a digit and receive an underscore prefix;
+/// entries that are keywords become raw identifiers.
+fn enum_variant_ident(name: &str) -> Ident {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+        "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+        "pub", "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use",
+        "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro", "override",
+        "priv", "try", "typeof", "unsized", "virtual", "yield",
+    ];
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        Ident::new(&format!("_{}", name), Span::call_site())
+    } else if KEYWORDS.contains(&name) {
+        Ident::new_raw(name, Span::call_site())
+    } else {
+        Ident::new(name, Span::call_site())
+    }
+}
+
+/// Returns the lines of a doc comment consisting of a summary and a description.
+///
+/// The indentation of the description is removed, as the protocol files indent it to match the
+/// XML and markdown would take it for code blocks. Brackets are escaped so that they do not
+/// become links.
+fn doc_lines(summary: Option<&str>, description: Option<&str>) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(summary) = summary {
+        push_doc_paragraph(&mut lines, &summary_sentence(summary));
+    }
+    if let Some(description) = description {
+        let mut paragraph = String::new();
+        let mut in_list_item = false;
+        for line in description.lines().map(|l| l.trim()) {
+            if line.is_empty() {
+                push_doc_paragraph(&mut lines, &paragraph);
+                paragraph.clear();
+                in_list_item = false;
+            } else {
+                if !paragraph.is_empty() {
+                    paragraph.push('\n');
+                }
+                if line.starts_with("- ") || line.starts_with("* ") {
+                    in_list_item = true;
+                } else if in_list_item {
+                    // indent continuation lines so that they stay part of the list item
+                    paragraph.push_str("  ");
+                }
+                paragraph.push_str(&escape_markdown(line));
+            }
+        }
+        push_doc_paragraph(&mut lines, &paragraph);
+    }
+    lines
+}
+
+/// Returns the doc comment lines of a request or event, including its version information.
+fn procedure_doc_lines(procedure: &Procedure) -> Vec<String> {
+    let mut lines = doc_lines(procedure.short_description.as_deref(), procedure.description.as_deref());
+    if procedure.since > 1 {
+        push_doc_paragraph(&mut lines, &format!("Available since version {}.", procedure.since));
+    }
+    if let Some(deprecated_since) = procedure.deprecated_since {
+        push_doc_paragraph(&mut lines, &format!("Deprecated since version {}.", deprecated_since));
+    }
+    lines
+}
+
+/// Appends a paragraph to the lines of a doc comment, separating it from the previous one.
+fn push_doc_paragraph(lines: &mut Vec<String>, paragraph: &str) {
+    if paragraph.is_empty() {
+        return;
+    }
+    if !lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines.extend(paragraph.lines().map(|l| l.to_owned()));
+}
+
+/// Appends a list of arguments and their summaries to the lines of a doc comment.
+fn push_doc_arguments<'a, I: Iterator<Item = (String, Option<&'a str>)>>(lines: &mut Vec<String>, args: I) {
+    let items: Vec<String> = args
+        .map(|(name, summary)| match summary {
+            Some(summary) => format!("* `{}`: {}", name, escape_markdown(summary)),
+            None => format!("* `{}`", name),
+        })
+        .collect();
+    if items.is_empty() {
+        return;
+    }
+    push_doc_paragraph(lines, "# Arguments");
+    push_doc_paragraph(lines, &items.join("\n"));
+}
+
+fn tokenize_doc(lines: &[String]) -> TokenStream {
+    let attributes = lines.iter()
+        .map(|line| {
+            let line_literal = if line.is_empty() {
+                Literal::string("")
+            } else {
+                Literal::string(&format!(" {}", line))
+            };
+            quote! { #[doc = #line_literal] }
+        });
+    quote! { #( #attributes )* }
+}
+
+/// Turns a summary from the protocol file into a sentence.
+fn summary_sentence(summary: &str) -> String {
+    let escaped = escape_markdown(summary.trim());
+    let mut chars = escaped.chars();
+    let mut sentence: String = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => return String::new(),
+    };
+    if !sentence.ends_with(['.', '!', '?']) {
+        sentence.push('.');
+    }
+    sentence
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '[' | ']' | '<' | '>') {
+            escaped.push('\\');
         }
+        escaped.push(c);
     }
+    escaped
 }